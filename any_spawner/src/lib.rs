@@ -27,9 +27,11 @@
 #![deny(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use std::{future::Future, pin::Pin, sync::OnceLock};
+use std::{future::Future, pin::Pin, sync::OnceLock, time::Duration};
 use thiserror::Error;
 
+mod timer;
+
 /// A future that has been pinned.
 pub type PinnedFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 /// A future that has been pinned.
@@ -41,6 +43,8 @@ type SpawnFn = fn(PinnedFuture<()>);
 type SpawnLocalFn = fn(PinnedLocalFuture<()>);
 // Type alias for the poll_local function pointer.
 type PollLocalFn = fn();
+// Type alias for the sleep function pointer.
+type SleepFn = fn(Duration) -> PinnedFuture<()>;
 
 /// Holds the function pointers for the current global executor.
 #[derive(Clone, Copy)]
@@ -48,6 +52,7 @@ struct ExecutorFns {
     spawn: SpawnFn,
     spawn_local: SpawnLocalFn,
     poll_local: PollLocalFn,
+    sleep: SleepFn,
 }
 
 // Use a single OnceLock to ensure atomic initialization of all functions.
@@ -154,6 +159,22 @@ impl Executor {
         _ = rx.await;
     }
 
+    /// Returns a future that resolves once the given duration has passed.
+    ///
+    /// This uses the timer of the globally configured executor: Tokio's timer, `setTimeout`
+    /// with `wasm-bindgen`, or GLib's main loop. The single-threaded local executor fires its
+    /// timers when it is polled. Other executors, and any code that runs before an executor has
+    /// been initialized, share a single background timer thread. On `wasm` targets, where there
+    /// is no such thread, they use `setTimeout` if the `wasm-bindgen` feature is enabled, and
+    /// otherwise never resolve.
+    #[inline(always)]
+    pub fn sleep(duration: Duration) -> impl Future<Output = ()> + Send {
+        match EXECUTOR_FNS.get() {
+            Some(fns) => (fns.sleep)(duration),
+            None => timer::sleep(duration),
+        }
+    }
+
     /// Polls the global async executor.
     ///
     /// Uses the globally configured executor.
@@ -185,6 +206,7 @@ impl Executor {
             },
             // Tokio doesn't have an explicit global poll function like LocalPool::run_until_stalled
             poll_local: no_op_poll,
+            sleep: |duration| Box::pin(tokio::time::sleep(duration)),
         };
         EXECUTOR_FNS
             .set(executor_impl)
@@ -206,6 +228,7 @@ impl Executor {
                 wasm_bindgen_futures::spawn_local(fut);
             },
             poll_local: no_op_poll,
+            sleep: timer::set_timeout,
         };
         EXECUTOR_FNS
             .set(executor_impl)
@@ -231,6 +254,7 @@ impl Executor {
             },
             // Glib needs event loop integration, explicit polling isn't the standard model here.
             poll_local: no_op_poll,
+            sleep: glib::timeout_future,
         };
         EXECUTOR_FNS
             .set(executor_impl)
@@ -291,6 +315,60 @@ impl Executor {
                     // If already borrowed, we're likely in a nested poll, so do nothing.
                 });
             },
+            sleep: timer::sleep,
+        };
+
+        EXECUTOR_FNS
+            .set(executor_impl)
+            .map_err(|_| ExecutorError::AlreadySet)
+    }
+
+    /// Globally sets a single-threaded [`futures`] executor as the executor used to spawn tasks.
+    ///
+    /// This is intended for runtimes that cannot spawn threads, like WASI. Both
+    /// [`spawn`](Executor::spawn) and [`spawn_local`](Executor::spawn_local) add tasks to a
+    /// thread-local pool, which only makes progress when [`poll_local`](Executor::poll_local)
+    /// is called. Timers created with [`sleep`](Executor::sleep) are also fired by
+    /// `poll_local`, once their deadline has passed.
+    ///
+    /// Returns `Err(_)` if a global executor has already been set.
+    ///
+    /// Requires the `local-executor` feature to be activated on this crate.
+    #[cfg(feature = "local-executor")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "local-executor")))]
+    pub fn init_local_executor() -> Result<(), ExecutorError> {
+        use futures::{
+            executor::{LocalPool, LocalSpawner},
+            task::LocalSpawnExt,
+        };
+        use std::cell::RefCell;
+
+        thread_local! {
+            static LOCAL_POOL: RefCell<LocalPool> = RefCell::new(LocalPool::new());
+            static SPAWNER: LocalSpawner = LOCAL_POOL.with(|pool| pool.borrow().spawner());
+        }
+
+        fn spawn_local(fut: PinnedLocalFuture<()>) {
+            SPAWNER.with(|spawner| {
+                spawner
+                    .spawn_local(fut)
+                    .expect("failed to spawn local future");
+            });
+        }
+
+        let executor_impl = ExecutorFns {
+            spawn: |fut| spawn_local(fut),
+            spawn_local,
+            poll_local: || {
+                timer::fire_local_timers();
+                LOCAL_POOL.with(|pool| {
+                    // does nothing if called from a task that is already being polled
+                    if let Ok(mut pool) = pool.try_borrow_mut() {
+                        pool.run_until_stalled();
+                    }
+                });
+            },
+            sleep: timer::local_sleep,
         };
 
         EXECUTOR_FNS
@@ -334,6 +412,7 @@ impl Executor {
                     pool.try_tick();
                 });
             },
+            sleep: timer::sleep,
         };
         EXECUTOR_FNS
             .set(executor_impl)
@@ -370,6 +449,9 @@ impl Executor {
             poll_local: || {
                 CUSTOM_EXECUTOR_INSTANCE.get().unwrap().poll_local();
             },
+            sleep: |duration| {
+                CUSTOM_EXECUTOR_INSTANCE.get().unwrap().sleep(duration)
+            },
         };
 
         EXECUTOR_FNS
@@ -422,6 +504,10 @@ impl Executor {
                 CUSTOM_EXECUTOR_INSTANCE
                     .with(|this| this.get().unwrap().poll_local());
             },
+            sleep: |duration| {
+                CUSTOM_EXECUTOR_INSTANCE
+                    .with(|this| this.get().unwrap().sleep(duration))
+            },
         };
 
         EXECUTOR_FNS
@@ -447,6 +533,12 @@ pub trait CustomExecutor {
     /// non-blocking or use mechanisms like `try_tick` or `try_borrow_mut` to handle
     /// re-entrant calls safely.
     fn poll_local(&self);
+    /// Returns a future that resolves once the given duration has passed.
+    ///
+    /// By default, this uses a single background timer thread that is shared by every call.
+    fn sleep(&self, duration: Duration) -> PinnedFuture<()> {
+        timer::sleep(duration)
+    }
 }
 
 // Ensure CustomExecutor is object-safe
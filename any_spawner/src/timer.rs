@@ -0,0 +1,189 @@
+//! Timers used by [`Executor::sleep`](crate::Executor::sleep) for executors that do not provide
+//! their own.
+
+use crate::PinnedFuture;
+use futures::channel::oneshot;
+use std::time::Duration;
+
+/// Creates a future that resolves once the sender has been used or dropped.
+fn wait_for(rx: oneshot::Receiver<()>) -> PinnedFuture<()> {
+    Box::pin(async move {
+        _ = rx.await;
+    })
+}
+
+/// Sleeps using a single background thread, which is shared by every timer.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn sleep(duration: Duration) -> PinnedFuture<()> {
+    use std::{
+        cmp::Reverse,
+        collections::BinaryHeap,
+        sync::{Condvar, Mutex, OnceLock},
+        time::Instant,
+    };
+
+    struct Timer {
+        deadline: Instant,
+        id: u64,
+        tx: oneshot::Sender<()>,
+    }
+
+    impl PartialEq for Timer {
+        fn eq(&self, other: &Self) -> bool {
+            (self.deadline, self.id) == (other.deadline, other.id)
+        }
+    }
+
+    impl Eq for Timer {}
+
+    impl PartialOrd for Timer {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Timer {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            (self.deadline, self.id).cmp(&(other.deadline, other.id))
+        }
+    }
+
+    #[derive(Default)]
+    struct Timers {
+        queue: Mutex<(BinaryHeap<Reverse<Timer>>, u64)>,
+        changed: Condvar,
+    }
+
+    static TIMERS: OnceLock<Timers> = OnceLock::new();
+
+    fn run(timers: &'static Timers) {
+        let mut queue = timers.queue.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            let now = Instant::now();
+            while queue.0.peek().is_some_and(|next| next.0.deadline <= now) {
+                if let Some(Reverse(timer)) = queue.0.pop() {
+                    _ = timer.tx.send(());
+                }
+            }
+            queue = match queue.0.peek() {
+                Some(next) => {
+                    let timeout = next.0.deadline - now;
+                    timers
+                        .changed
+                        .wait_timeout(queue, timeout)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+                None => timers
+                    .changed
+                    .wait(queue)
+                    .unwrap_or_else(|e| e.into_inner()),
+            };
+        }
+    }
+
+    let timers = TIMERS.get_or_init(|| {
+        std::thread::Builder::new()
+            .name("any_spawner-timer".into())
+            .spawn(|| run(TIMERS.wait()))
+            .expect("could not spawn the timer thread");
+        Timers::default()
+    });
+
+    let (tx, rx) = oneshot::channel();
+    {
+        let mut queue = timers.queue.lock().unwrap_or_else(|e| e.into_inner());
+        let id = queue.1;
+        queue.1 += 1;
+        queue.0.push(Reverse(Timer {
+            deadline: Instant::now() + duration,
+            id,
+            tx,
+        }));
+    }
+    timers.changed.notify_one();
+    wait_for(rx)
+}
+
+/// There is no timer thread on wasm, so this uses `setTimeout` if it is available, and otherwise
+/// returns a future that never resolves.
+#[cfg(target_family = "wasm")]
+pub(crate) fn sleep(duration: Duration) -> PinnedFuture<()> {
+    #[cfg(feature = "wasm-bindgen")]
+    {
+        set_timeout(duration)
+    }
+    #[cfg(not(feature = "wasm-bindgen"))]
+    {
+        _ = duration;
+        #[cfg(all(debug_assertions, feature = "tracing"))]
+        tracing::warn!(
+            target: "any_spawner",
+            "Executor::sleep is not supported by this executor on wasm without \
+             the `wasm-bindgen` feature. The timer will never fire."
+        );
+        #[cfg(all(debug_assertions, not(feature = "tracing")))]
+        eprintln!(
+            "Executor::sleep is not supported by this executor on wasm without \
+             the `wasm-bindgen` feature. The timer will never fire."
+        );
+        Box::pin(futures::future::pending())
+    }
+}
+
+/// Sleeps using the JavaScript `setTimeout` function, which is available in browsers, web
+/// workers and server-side JavaScript runtimes.
+#[cfg(feature = "wasm-bindgen")]
+pub(crate) fn set_timeout(duration: Duration) -> PinnedFuture<()> {
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+    let (tx, rx) = oneshot::channel();
+    let callback = Closure::once_into_js(move || {
+        _ = tx.send(());
+    });
+    let set_timeout = js_sys::Reflect::get(
+        &js_sys::global(),
+        &JsValue::from_str("setTimeout"),
+    )
+    .ok()
+    .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+    .expect("setTimeout is not available in this environment");
+    _ = set_timeout.call2(
+        &JsValue::UNDEFINED,
+        &callback,
+        &JsValue::from_f64(duration.as_millis() as f64),
+    );
+    wait_for(rx)
+}
+
+#[cfg(feature = "local-executor")]
+thread_local! {
+    static LOCAL_TIMERS: std::cell::RefCell<Vec<(std::time::Instant, oneshot::Sender<()>)>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Sleeps using a thread-local list of timers, which is checked by
+/// [`fire_local_timers`] each time the local executor is polled.
+#[cfg(feature = "local-executor")]
+pub(crate) fn local_sleep(duration: Duration) -> PinnedFuture<()> {
+    let (tx, rx) = oneshot::channel();
+    let deadline = std::time::Instant::now() + duration;
+    LOCAL_TIMERS.with_borrow_mut(|timers| timers.push((deadline, tx)));
+    wait_for(rx)
+}
+
+/// Resolves every local timer whose deadline has passed.
+#[cfg(feature = "local-executor")]
+pub(crate) fn fire_local_timers() {
+    let now = std::time::Instant::now();
+    let due = LOCAL_TIMERS.with_borrow_mut(|timers| {
+        let (due, pending) = std::mem::take(timers)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+        *timers = pending;
+        due
+    });
+    for (_, tx) in due {
+        _ = tx.send(());
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "local-executor")]
+
+use any_spawner::Executor;
+use futures::channel::oneshot;
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn test_local_executor() {
+    Executor::init_local_executor()
+        .expect("Failed to initialize local executor");
+
+    let (tx, rx) = oneshot::channel();
+    let result = Rc::new(Cell::new(None));
+
+    // thread-safe tasks run on the same thread as local ones
+    Executor::spawn(async move {
+        tx.send(42).expect("Failed to send value");
+    });
+    Executor::spawn_local({
+        let result = Rc::clone(&result);
+        async move {
+            result.set(rx.await.ok());
+        }
+    });
+
+    // no progress is made until the executor is polled
+    assert_eq!(result.get(), None);
+    Executor::poll_local();
+    assert_eq!(result.get(), Some(42));
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "local-executor")]
+
+use any_spawner::Executor;
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+#[test]
+fn test_local_executor_sleep() {
+    Executor::init_local_executor()
+        .expect("Failed to initialize local executor");
+
+    let done = Rc::new(Cell::new(false));
+    Executor::spawn_local({
+        let done = Rc::clone(&done);
+        async move {
+            Executor::sleep(Duration::from_millis(10)).await;
+            done.set(true);
+        }
+    });
+
+    // timers are only fired once their deadline has passed
+    Executor::poll_local();
+    assert!(!done.get());
+    std::thread::sleep(Duration::from_millis(20));
+    Executor::poll_local();
+    assert!(done.get());
+}
@@ -0,0 +1,74 @@
+use std::{fmt::Debug, sync::OnceLock};
+
+/// Determines how data that is shared from the server to the client is written into the HTML
+/// response, and how it is read back in the browser.
+///
+/// Each value has already been serialized to a `String` (for example, by a resource's codec)
+/// before it is passed to the format. The default [`PlainTextFormat`] writes that string into the
+/// page unchanged. A custom format can compress or re-encode it instead, for example to reduce
+/// the size of the HTML.
+///
+/// The same format needs to be set with [`set_shared_data_format`] both on the server and in the
+/// browser, before the app is rendered or hydrated.
+///
+/// ```rust,ignore
+/// #[derive(Debug)]
+/// struct Compressed;
+///
+/// impl SharedDataFormat for Compressed {
+///     fn encode(&self, data: String) -> String {
+///         BASE64.encode(compress(data.as_bytes()))
+///     }
+///
+///     fn decode(&self, data: String) -> Option<String> {
+///         let bytes = decompress(&BASE64.decode(data).ok()?)?;
+///         String::from_utf8(bytes).ok()
+///     }
+/// }
+///
+/// // in both `main` on the server and the `hydrate` function in the browser
+/// set_shared_data_format(Compressed).unwrap();
+/// ```
+pub trait SharedDataFormat: Debug + Send + Sync {
+    /// Encodes a serialized value on the server, before it is written into the HTML response.
+    fn encode(&self, data: String) -> String;
+
+    /// Decodes a value that was encoded by [`encode`](Self::encode), in the browser.
+    ///
+    /// Returning `None` treats the value as missing, so it will be loaded again in the browser.
+    fn decode(&self, data: String) -> Option<String>;
+}
+
+/// The default [`SharedDataFormat`], which writes each serialized value into the page as-is.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlainTextFormat;
+
+impl SharedDataFormat for PlainTextFormat {
+    fn encode(&self, data: String) -> String {
+        data
+    }
+
+    fn decode(&self, data: String) -> Option<String> {
+        Some(data)
+    }
+}
+
+static SHARED_DATA_FORMAT: OnceLock<Box<dyn SharedDataFormat>> =
+    OnceLock::new();
+
+/// Sets the [`SharedDataFormat`] used to share data from the server to the client. Returns
+/// `Err(_)` if the format has already been set.
+pub fn set_shared_data_format(
+    format: impl SharedDataFormat + 'static,
+) -> Result<(), Box<dyn SharedDataFormat>> {
+    SHARED_DATA_FORMAT.set(Box::new(format))
+}
+
+/// Returns the [`SharedDataFormat`] that has been set with [`set_shared_data_format`], or
+/// [`PlainTextFormat`] if none has been set.
+pub fn shared_data_format() -> &'static dyn SharedDataFormat {
+    match SHARED_DATA_FORMAT.get() {
+        Some(format) => format.as_ref(),
+        None => &PlainTextFormat,
+    }
+}
@@ -4,7 +4,7 @@
 #![allow(deprecated)]
 
 use super::{SerializedDataId, SharedContext};
-use crate::{PinnedFuture, PinnedStream};
+use crate::{shared_data_format, PinnedFuture, PinnedStream};
 use core::fmt::Debug;
 use js_sys::Array;
 use std::{
@@ -131,7 +131,9 @@ impl SharedContext for HydrateSharedContext {
     fn write_async(&self, _id: SerializedDataId, _fut: PinnedFuture<String>) {}
 
     fn read_data(&self, id: &SerializedDataId) -> Option<String> {
-        __RESOLVED_RESOURCES.with(|r| r.get(id.0 as u32).as_string())
+        __RESOLVED_RESOURCES
+            .with(|r| r.get(id.0 as u32).as_string())
+            .and_then(|data| shared_data_format().decode(data))
     }
 
     fn await_data(&self, _id: &SerializedDataId) -> Option<String> {
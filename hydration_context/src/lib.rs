@@ -7,6 +7,8 @@
 //!
 //! This crate helps with the second part of this process. It provides a [`SharedContext`] type
 //! that allows you to store data on the server, and then extract the same data in the client.
+//! The way in which that data is written into the page can be customized with a
+//! [`SharedDataFormat`].
 
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
@@ -15,12 +17,14 @@
 #[cfg(feature = "browser")]
 #[cfg_attr(docsrs, doc(cfg(feature = "browser")))]
 mod csr;
+mod format;
 #[cfg(feature = "browser")]
 #[cfg_attr(docsrs, doc(cfg(feature = "browser")))]
 mod hydrate;
 mod ssr;
 #[cfg(feature = "browser")]
 pub use csr::*;
+pub use format::*;
 use futures::Stream;
 #[cfg(feature = "browser")]
 pub use hydrate::*;
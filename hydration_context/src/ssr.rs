@@ -1,5 +1,5 @@
 use super::{SerializedDataId, SharedContext};
-use crate::{PinnedFuture, PinnedStream};
+use crate::{shared_data_format, PinnedFuture, PinnedStream};
 use futures::{
     future::join_all,
     stream::{self, once},
@@ -281,7 +281,9 @@ impl Stream for AsyncDataStream {
                     async_buf.push((id, fut));
                 }
                 Poll::Ready(data) => {
-                    let data = data.replace('<', "\\u003c");
+                    let data = shared_data_format()
+                        .encode(data)
+                        .replace('<', "\\u003c");
                     _ = write!(
                         resolved,
                         "__RESOLVED_RESOURCES[{}] = {:?};",
@@ -320,6 +322,7 @@ struct ResolvedData(SerializedDataId, String);
 impl ResolvedData {
     pub fn write_to_buf(&self, buf: &mut String) {
         let ResolvedData(id, ser) = self;
+        let ser = shared_data_format().encode(ser.clone());
         // escapes < to prevent it being interpreted as another opening HTML tag
         let ser = ser.replace('<', "\\u003c");
         write!(buf, "{}: {:?}", id.0, ser).unwrap();
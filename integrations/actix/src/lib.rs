@@ -3,6 +3,24 @@
 
 //! Provides functions to easily integrate Leptos with Actix.
 //!
+//! ## Features
+//! - `compression`: compresses server-rendered HTML with Brotli or gzip, depending on the
+//!   request’s `Accept-Encoding` header. Each chunk of the stream is flushed as soon as it is
+//!   ready, so this works with out-of-order streaming, unlike a compression middleware that
+//!   buffers its output.
+//! - `cookies`: provides the request’s cookies to the app and its server functions, so that
+//!   they can be read and set with [`Cookies`](leptos::cookies::Cookies). This enables the
+//!   `cookies` feature of `leptos`.
+//! - `csrf`: rejects server function requests that do not carry the
+//!   [CSRF token](leptos::csrf) of the current request, when CSRF protection has been provided.
+//!   This enables the `csrf` feature of `leptos`, and the `cookies` feature of this crate.
+//! - `ws`: adds [`handle_websocket`], which upgrades requests to websockets whose handlers run
+//!   with access to the Leptos context.
+//! - `tracing`: opens a span for each page that is rendered and each server function that
+//!   runs, which carries a [`RequestId`]. If the `tracing` feature of `leptos` is also
+//!   enabled, its spans for `<Suspense/>` boundaries and resources are nested inside it, so
+//!   exported traces show where server-side rendering time goes.
+//!
 //! For more details on how to use the integrations, see the
 //! [`examples`](https://github.com/leptos-rs/leptos/tree/main/examples)
 //! directory in the Leptos repository.
@@ -19,28 +37,50 @@ use actix_web::{
 use futures::{stream::once, Stream, StreamExt};
 use http::StatusCode;
 use hydration_context::SsrSharedContext;
+#[cfg(feature = "csrf")]
+use leptos::csrf::{csrf_field, verify_csrf_token, CSRF_HEADER};
 use leptos::{
     config::LeptosOptions,
     context::{provide_context, use_context},
-    hydration::IslandsRouterNavigation,
+    hydration::{IslandsRouterNavigation, RenderDeferredIslands},
     prelude::expect_context,
     reactive::{computed::ScopedFuture, owner::Owner},
     IntoView,
 };
+#[cfg(feature = "compression")]
+use leptos_integration_utils::compression::Encoding;
+#[cfg(feature = "tracing")]
+use leptos_integration_utils::trace::{
+    render_span, server_fn_span, REQUEST_ID_HEADER,
+};
+#[cfg(feature = "tracing")]
+pub use leptos_integration_utils::trace::{use_request_id, RequestId};
 use leptos_integration_utils::{
-    BoxedFnOnce, ExtendResponse, PinnedFuture, PinnedStream,
+    preload_link_headers, stream_out_of_order, BoxedFnOnce, ExtendResponse,
+    PinnedFuture, PinnedStream,
+};
+pub use leptos_integration_utils::{
+    shutdown::{begin_shutdown, drained, is_shutting_down},
+    RequestContext,
 };
 use leptos_meta::ServerMetaContext;
 use leptos_router::{
-    components::provide_server_redirect,
+    components::provide_server_redirect_with_options,
     location::RequestUrl,
+    sitemap::Sitemap,
     static_routes::{RegenerationFn, ResolvedStaticPath},
-    ExpandOptionals, Method, PathSegment, RouteList, RouteListing, SsrMode,
+    ClientRedirect, ExpandOptionals, Method, PathSegment, RouteList,
+    RouteListing, ServerRedirectOptions, SsrMode,
 };
 use or_poisoned::OrPoisoned;
 use send_wrapper::SendWrapper;
 use server_fn::{
-    error::ServerFnErrorErr, redirect::REDIRECT_HEADER,
+    error::ServerFnErrorErr,
+    limits::limits_for,
+    method_override::{
+        method_override_field, parse_override, METHOD_OVERRIDE_HEADER,
+    },
+    redirect::REDIRECT_HEADER,
     request::actix::ActixRequest,
 };
 use std::{
@@ -49,8 +89,11 @@ use std::{
     future::Future,
     ops::{Deref, DerefMut},
     path::Path,
-    sync::{Arc, LazyLock, RwLock},
+    sync::{Arc, LazyLock, OnceLock, RwLock},
+    time::Duration,
 };
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
 
 /// This struct lets you define headers and override the status of the Response from an Element or a Server Function
 /// Typically contained inside of a ResponseOptions. Setting this is useful for cookies and custom responses.
@@ -168,6 +211,29 @@ impl ExtendResponse for ActixResponse {
         )
     }
 
+    #[cfg(feature = "compression")]
+    fn accepted_encoding() -> Option<Encoding> {
+        let req = use_context::<Request>()?;
+        let accept_encoding = req.headers().get(header::ACCEPT_ENCODING)?;
+        Encoding::negotiate(accept_encoding.to_str().ok()?)
+    }
+
+    #[cfg(feature = "compression")]
+    fn from_compressed_stream(
+        stream: impl Stream<Item = Vec<u8>> + Send + 'static,
+        encoding: Encoding,
+    ) -> Self {
+        ActixResponse(
+            HttpResponse::Ok()
+                .content_type("text/html")
+                .insert_header((header::CONTENT_ENCODING, encoding.as_str()))
+                .insert_header((header::VARY, "accept-encoding"))
+                .streaming(stream.map(|chunk| {
+                    Ok(web::Bytes::from(chunk)) as Result<web::Bytes>
+                })),
+        )
+    }
+
     fn extend_response(&mut self, res_options: &Self::ResponseOptions) {
         let mut res_options = res_options.0.write().or_poisoned();
 
@@ -195,6 +261,31 @@ impl ExtendResponse for ActixResponse {
     }
 }
 
+/// Turns a [`RequestContext`] into an `additional_context` function, which can be passed to
+/// [`handle_server_fns_with_context`] and
+/// [`leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context).
+///
+/// The provider receives the [`HttpRequest`], so it can read headers, cookies or app data, and
+/// provide the values that your app needs.
+///
+/// ```rust,ignore
+/// let context = request_context(move |req: &HttpRequest| {
+///     provide_context(pool.clone());
+///     provide_context(Locale::from_headers(req.headers()));
+/// });
+///
+/// App::new()
+///     .route("/api/{tail:.*}", handle_server_fns_with_context(context.clone()))
+///     .leptos_routes_with_context(routes, context, app_fn)
+/// ```
+pub fn request_context(
+    provider: impl RequestContext<HttpRequest>,
+) -> impl Fn() + Clone + Send + Sync + 'static {
+    leptos_integration_utils::request_context::<Request>(
+        move |req: &Request| provider.provide(req),
+    )
+}
+
 /// Provides an easy way to redirect the user from within a server function.
 ///
 /// Calling `redirect` in a server function will redirect the browser in three
@@ -227,9 +318,70 @@ impl ExtendResponse for ActixResponse {
     tracing::instrument(level = "trace", fields(error), skip_all)
 )]
 pub fn redirect(path: &str) {
+    redirect_with_options(path, &ServerRedirectOptions::default())
+}
+
+/// Redirects the user, like [`redirect`], but with a custom status code and additional
+/// headers.
+///
+/// This allows you to use a permanent redirect (`301` or `308`) for SEO-relevant moves, or a
+/// method-preserving redirect (`307`). The status code is only used when the request is an
+/// ordinary navigation or `<form>` submission; for requests made by the server function client,
+/// the client-side router handles the redirect instead, or ignores it if
+/// [`ServerRedirectOptions::client`] is set to [`ClientRedirect::Ignore`].
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_router::{RedirectStatus, ServerRedirectOptions};
+///
+/// #[server]
+/// pub async fn moved() -> Result<(), ServerFnError> {
+///     leptos_actix::redirect_with_options(
+///         "/new-location",
+///         &ServerRedirectOptions::new(RedirectStatus::MovedPermanently)
+///             .header("Cache-Control", "max-age=3600"),
+///     );
+///     Ok(())
+/// }
+/// ```
+///
+/// A server function called by an `<ActionForm/>` can send users without JavaScript/WASM to a
+/// confirmation page with a `303 See Other`, while users with it stay on the page and receive
+/// the server function's value:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_router::{ClientRedirect, RedirectStatus, ServerRedirectOptions};
+///
+/// #[server]
+/// pub async fn subscribe(email: String) -> Result<String, ServerFnError> {
+///     leptos_actix::redirect_with_options(
+///         "/subscribed",
+///         &ServerRedirectOptions::new(RedirectStatus::SeeOther)
+///             .client(ClientRedirect::Ignore),
+///     );
+///     Ok(format!("Subscribed {email}"))
+/// }
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", fields(error), skip_all)
+)]
+pub fn redirect_with_options(path: &str, options: &ServerRedirectOptions) {
     if let (Some(req), Some(res)) =
         (use_context::<Request>(), use_context::<ResponseOptions>())
     {
+        let accepts_html = req
+            .headers()
+            .get(ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.contains("text/html"))
+            .unwrap_or(false);
+        // the server fn client can be told to ignore the redirect, and just return the value
+        if !accepts_html && options.client == ClientRedirect::Ignore {
+            return;
+        }
+
         // insert the Location header in any case
         res.insert_header(
             header::LOCATION,
@@ -237,16 +389,35 @@ pub fn redirect(path: &str) {
                 .expect("Failed to create HeaderValue"),
         );
 
-        let accepts_html = req
-            .headers()
-            .get(ACCEPT)
-            .and_then(|v| v.to_str().ok())
-            .map(|v| v.contains("text/html"))
-            .unwrap_or(false);
+        // as well as any additional headers
+        for (name, value) in &options.headers {
+            match (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(value)) => res.insert_header(name, value),
+                _ => {
+                    let msg = format!(
+                        "Invalid header {name:?}: {value:?} passed to \
+                         redirect_with_options()."
+                    );
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("{}", &msg);
+
+                    #[cfg(not(feature = "tracing"))]
+                    eprintln!("{}", &msg);
+                }
+            }
+        }
+
         if accepts_html {
             // if the request accepts text/html, it's a plain form request and needs
-            // to have the 302 code set
-            res.set_status(StatusCode::FOUND);
+            // to have the redirect status code set
+            res.set_status(
+                StatusCode::from_u16(options.status.as_u16())
+                    .expect("redirect status codes are always valid"),
+            );
         } else {
             // otherwise, we sent it from the server fn client and actually don't want
             // to set a real redirect, as this will break the ability to return data
@@ -258,7 +429,7 @@ pub fn redirect(path: &str) {
         }
     } else {
         let msg = "Couldn't retrieve either Parts or ResponseOptions while \
-                   trying to redirect().";
+                   trying to redirect_with_options().";
 
         #[cfg(feature = "tracing")]
         tracing::warn!("{}", &msg);
@@ -344,72 +515,142 @@ pub fn handle_server_fns_with_context(
         async move {
             let additional_context = additional_context.clone();
 
+            let (method, payload) =
+                match apply_method_override(&req, payload).await {
+                    Ok(overridden) => overridden,
+                    Err(StatusCode::PAYLOAD_TOO_LARGE) => {
+                        return HttpResponse::PayloadTooLarge()
+                            .body("The request body is too large.");
+                    }
+                    Err(status) => {
+                        return HttpResponse::build(status)
+                            .body("Could not read the request body.");
+                    }
+                };
             let path = req.path();
-            let method = req.method();
             if let Some(mut service) =
-                server_fn::actix::get_server_fn_service(path, method)
+                server_fn::actix::get_server_fn_service(path, &method)
             {
+                let limits = limits_for(path);
+                let ser = service.ser;
+                if limits.exceeds_body_size(content_length(&req)) {
+                    return limit_exceeded(
+                        ser,
+                        path,
+                        ServerFnErrorErr::PayloadTooLarge(
+                            limits.max_body_size.unwrap_or_default(),
+                        ),
+                    );
+                }
+                let path = path.to_string();
+
+                #[cfg(feature = "tracing")]
+                let request_id = request_id(&req);
+                #[cfg(feature = "tracing")]
+                let span = server_fn_span(path, &request_id);
+
                 let owner = Owner::new();
-                owner
-                    .with(|| {
-                        ScopedFuture::new(async move {
-                            provide_context(Request::new(&req));
-                            let res_options = ResponseOptions::default();
-                            provide_context(res_options.clone());
-                            additional_context();
-
-                            // store Accepts and Referer in case we need them for redirect (below)
-                            let accepts_html = req
-                                .headers()
-                                .get(ACCEPT)
-                                .and_then(|v| v.to_str().ok())
-                                .map(|v| v.contains("text/html"))
-                                .unwrap_or(false);
-                            let referrer = req.headers().get(REFERER).cloned();
-
-                            // actually run the server fn
-                            let mut res = ActixResponse(
-                                service
-                                    .run(ActixRequest::from((req, payload)))
-                                    .await
-                                    .take(),
+                let res = owner.with(|| {
+                    ScopedFuture::new(async move {
+                        provide_context(Request::new(&req));
+                        let res_options = ResponseOptions::default();
+                        #[cfg(feature = "cookies")]
+                        provide_cookies(&req, &res_options);
+                        provide_context(res_options.clone());
+                        #[cfg(feature = "tracing")]
+                        provide_context(request_id);
+                        additional_context();
+
+                        // store Accepts and Referer in case we need them for redirect (below)
+                        let accepts_html = req
+                            .headers()
+                            .get(ACCEPT)
+                            .and_then(|v| v.to_str().ok())
+                            .map(|v| v.contains("text/html"))
+                            .unwrap_or(false);
+                        let referrer = req.headers().get(REFERER).cloned();
+
+                        // bodies without a `Content-Length` are cut off once they reach the
+                        // limit
+                        let payload = match limits.max_body_size {
+                            Some(max) => {
+                                limit_payload(&req, payload, max).await
+                            }
+                            None => payload,
+                        };
+                        #[cfg(feature = "csrf")]
+                        let payload = match check_csrf(&req, payload).await {
+                            Ok(payload) => payload,
+                            Err(StatusCode::PAYLOAD_TOO_LARGE) => {
+                                return limit_exceeded(
+                                    ser,
+                                    &path,
+                                    ServerFnErrorErr::PayloadTooLarge(
+                                        limits
+                                            .max_body_size
+                                            .unwrap_or_default(),
+                                    ),
+                                );
+                            }
+                            Err(status) => {
+                                return HttpResponse::build(status)
+                                    .body("Invalid CSRF token.");
+                            }
+                        };
+
+                        // actually run the server fn
+                        let res =
+                            service.run(ActixRequest::from((req, payload)));
+                        let Some(res) =
+                            run_with_timeout(res, limits.timeout).await
+                        else {
+                            return limit_exceeded(
+                                ser,
+                                &path,
+                                ServerFnErrorErr::Timeout(
+                                    limits.timeout.unwrap_or_default(),
+                                ),
                             );
-
-                            // if it accepts text/html (i.e., is a plain form post) and doesn't already have a
-                            // Location set, then redirect to the Referer
-                            if accepts_html {
-                                if let Some(referrer) = referrer {
-                                    let has_location =
-                                        res.0.headers().get(LOCATION).is_some();
-                                    if !has_location {
-                                        *res.0.status_mut() = StatusCode::FOUND;
-                                        res.0
-                                            .headers_mut()
-                                            .insert(LOCATION, referrer);
-                                    }
+                        };
+                        let mut res = ActixResponse(res.take());
+
+                        // if it accepts text/html (i.e., is a plain form post) and doesn't already have a
+                        // Location set, then redirect to the Referer
+                        if accepts_html {
+                            if let Some(referrer) = referrer {
+                                let has_location =
+                                    res.0.headers().get(LOCATION).is_some();
+                                if !has_location {
+                                    *res.0.status_mut() = StatusCode::FOUND;
+                                    res.0
+                                        .headers_mut()
+                                        .insert(LOCATION, referrer);
                                 }
                             }
+                        }
+
+                        // the Location header may have been set to Referer, so any redirection by the
+                        // user must overwrite it
+                        {
+                            let mut res_options =
+                                res_options.0.write().or_poisoned();
+                            let headers = res.0.headers_mut();
 
-                            // the Location header may have been set to Referer, so any redirection by the
-                            // user must overwrite it
+                            for location in
+                                res_options.headers.remove(header::LOCATION)
                             {
-                                let mut res_options =
-                                    res_options.0.write().or_poisoned();
-                                let headers = res.0.headers_mut();
-
-                                for location in
-                                    res_options.headers.remove(header::LOCATION)
-                                {
-                                    headers.insert(header::LOCATION, location);
-                                }
+                                headers.insert(header::LOCATION, location);
                             }
+                        }
 
-                            // apply status code and headers if user changed them
-                            res.extend_response(&res_options);
-                            res.0
-                        })
+                        // apply status code and headers if user changed them
+                        res.extend_response(&res_options);
+                        res.0
                     })
-                    .await
+                });
+                #[cfg(feature = "tracing")]
+                let res = res.instrument(span);
+                res.await
             } else {
                 HttpResponse::BadRequest().body(format!(
                     "Could not find a server function at the route {:?}. \
@@ -685,7 +926,11 @@ where
                 } else {
                     app.to_html_stream_in_order()
                 };
-                Box::pin(app.chain(chunks())) as PinnedStream<String>
+                if supports_ooo {
+                    stream_out_of_order(app, chunks)
+                } else {
+                    Box::pin(app.chain(chunks())) as PinnedStream<String>
+                }
             })
         },
     )
@@ -777,6 +1022,266 @@ where
     })
 }
 
+/// An Actix [struct@Route](actix_web::Route) that upgrades `GET` requests to websockets, and
+/// passes each session and its stream of messages to the handler.
+///
+/// The handler runs in its own reactive owner, so it can use the same context as a server
+/// function: the [`Request`] and, if it has been added as app data, the [`LeptosOptions`] are
+/// both provided.
+///
+/// ```rust,ignore
+/// use futures::StreamExt;
+///
+/// App::new()
+///     .route("/ws", leptos_actix::handle_websocket(|mut session, mut messages| async move {
+///         while let Some(Ok(actix_ws::Message::Text(text))) = messages.next().await {
+///             _ = session.text(text).await;
+///         }
+///     }))
+/// ```
+#[cfg(feature = "ws")]
+pub fn handle_websocket<F, Fut>(handler: F) -> Route
+where
+    F: Fn(actix_ws::Session, actix_ws::MessageStream) -> Fut + Clone + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    handle_websocket_with_context(|| {}, handler)
+}
+
+/// An Actix [struct@Route](actix_web::Route) that upgrades requests to websockets, like
+/// [`handle_websocket`], providing additional context to the handler.
+#[cfg(feature = "ws")]
+pub fn handle_websocket_with_context<F, Fut>(
+    additional_context: impl Fn() + 'static + Clone + Send,
+    handler: F,
+) -> Route
+where
+    F: Fn(actix_ws::Session, actix_ws::MessageStream) -> Fut + Clone + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    web::get().to(move |req: HttpRequest, body: Payload| {
+        let additional_context = additional_context.clone();
+        let handler = handler.clone();
+        async move {
+            let (response, session, messages) = actix_ws::handle(&req, body)?;
+            let owner = Owner::new();
+            let fut = owner.with(|| {
+                provide_context(Request::new(&req));
+                if let Some(options) = req.app_data::<Data<LeptosOptions>>() {
+                    provide_context(options.get_ref().clone());
+                }
+                additional_context();
+                ScopedFuture::new(handler(session, messages))
+            });
+            actix_web::rt::spawn(async move {
+                fut.await;
+                drop(owner);
+            });
+            Ok::<_, actix_web::Error>(response)
+        }
+    })
+}
+
+/// Reads the `Content-Length` of a request, if it has one.
+fn content_length(req: &HttpRequest) -> Option<usize> {
+    req.headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Ends the request body with an error once more than `max` bytes have been read.
+async fn limit_payload(
+    req: &HttpRequest,
+    payload: Payload,
+    max: usize,
+) -> Payload {
+    let mut read = 0;
+    let limited = payload.map(move |chunk| {
+        let chunk = chunk?;
+        read += chunk.len();
+        if read > max {
+            Err(error::PayloadError::Overflow)
+        } else {
+            Ok(chunk)
+        }
+    });
+    let mut limited =
+        dev::Payload::from(Box::pin(limited) as actix_http::BoxedPayloadStream);
+    Payload::from_request(req, &mut limited)
+        .await
+        .expect("extracting the payload is infallible")
+}
+
+/// Runs a server function, returning `None` if it does not finish within the timeout.
+async fn run_with_timeout<T>(
+    fut: impl Future<Output = T>,
+    timeout: Option<Duration>,
+) -> Option<T> {
+    match timeout {
+        Some(timeout) => rt::time::timeout(timeout, fut).await.ok(),
+        None => Some(fut.await),
+    }
+}
+
+/// Responds to a server function request that went over one of its
+/// [`ServerFnLimits`](server_fn::limits::ServerFnLimits).
+fn limit_exceeded(
+    ser: fn(ServerFnErrorErr) -> web::Bytes,
+    path: &str,
+    err: ServerFnErrorErr,
+) -> HttpResponse {
+    use server_fn::response::Res as _;
+
+    let status = match err {
+        ServerFnErrorErr::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+        _ => StatusCode::REQUEST_TIMEOUT,
+    };
+    let mut res = server_fn::response::actix::ActixResponse::error_response(
+        path,
+        ser(err),
+    )
+    .take();
+    *res.status_mut() = status;
+    res
+}
+
+/// Checks the CSRF token of a server function request, if CSRF protection has been provided
+/// via [`provide_csrf`](leptos::csrf::provide_csrf). Returns the request body, restored if it
+/// had to be read, if the token is valid.
+///
+/// The payload should already be limited with [`limit_payload`]. If it goes over the limit, this
+/// returns `413 Payload Too Large`; if the token is missing or wrong, `403 Forbidden`.
+#[cfg(feature = "csrf")]
+async fn check_csrf(
+    req: &HttpRequest,
+    payload: Payload,
+) -> Result<Payload, StatusCode> {
+    if req.method() == actix_web::http::Method::GET
+        || verify_csrf_token(
+            req.headers()
+                .get(CSRF_HEADER)
+                .and_then(|value| value.to_str().ok()),
+        )
+    {
+        return Ok(payload);
+    }
+
+    let is_form = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/x-www-form-urlencoded")
+        });
+    if !is_form {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let mut payload = payload;
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        match chunk {
+            Ok(chunk) => body.extend_from_slice(&chunk),
+            Err(error::PayloadError::Overflow) => {
+                return Err(StatusCode::PAYLOAD_TOO_LARGE)
+            }
+            Err(_) => return Err(StatusCode::BAD_REQUEST),
+        }
+    }
+    let body = body.freeze();
+    if !verify_csrf_token(csrf_field(&body)) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Payload::from_request(req, &mut dev::Payload::from(body))
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)
+}
+
+/// Returns the method a server function request should be handled as: a `POST` request is
+/// handled as a `PUT`, `PATCH`, or `DELETE` request if it sets a
+/// [method override](server_fn::method_override), and there is no `POST` server function at its
+/// path. Returns the payload along with it, restored if it had to be read.
+///
+/// A form body is read up to the `max_body_size` of the server functions at its path. If it is
+/// larger, this returns `413 Payload Too Large`; if it cannot be read, `400 Bad Request`.
+async fn apply_method_override(
+    req: &HttpRequest,
+    payload: Payload,
+) -> Result<(actix_web::http::Method, Payload), StatusCode> {
+    let method = req.method().clone();
+    if method != actix_web::http::Method::POST
+        || server_fn::actix::get_server_fn_service(req.path(), &method)
+            .is_some()
+    {
+        return Ok((method, payload));
+    }
+    let to_actix = |method: server_fn::http_export::Method| {
+        actix_web::http::Method::from_bytes(method.as_str().as_bytes()).ok()
+    };
+
+    if let Some(method) = req
+        .headers()
+        .get(METHOD_OVERRIDE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_override)
+        .and_then(to_actix)
+    {
+        return Ok((method, payload));
+    }
+
+    let is_form = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/x-www-form-urlencoded")
+        });
+    if !is_form {
+        return Ok((method, payload));
+    }
+    let limits = limits_for(req.path());
+    if limits.exceeds_body_size(content_length(req)) {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+    let max = limits.max_body_size.unwrap_or(usize::MAX);
+    let mut payload = payload;
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk.map_err(|_| StatusCode::BAD_REQUEST)?;
+        if body.len() + chunk.len() > max {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    let body = body.freeze();
+    let method = method_override_field(&body)
+        .and_then(to_actix)
+        .unwrap_or(method);
+    let payload = Payload::from_request(req, &mut dev::Payload::from(body))
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    Ok((method, payload))
+}
+
+#[cfg(feature = "cookies")]
+/// Provides the request's cookies via context, so that they can be used with
+/// [`Cookies`](leptos::cookies::Cookies). Cookies that are set are added to the response
+/// options as `Set-Cookie` headers.
+fn provide_cookies(req: &HttpRequest, res_options: &ResponseOptions) {
+    let header = req
+        .headers()
+        .get_all(header::COOKIE)
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join("; ");
+    let res_options = res_options.clone();
+    leptos::cookies::provide_server_cookies(Some(&header), move |cookie| {
+        if let Ok(value) = header::HeaderValue::from_str(cookie) {
+            res_options.append_header(header::SET_COOKIE, value);
+        }
+    });
+}
+
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(level = "trace", fields(error), skip_all)
@@ -790,9 +1295,11 @@ fn provide_contexts(
 
     provide_context(RequestUrl::new(&path));
     provide_context(meta_context.clone());
+    #[cfg(feature = "cookies")]
+    provide_cookies(&req, res_options);
     provide_context(res_options.clone());
     provide_context(req);
-    provide_server_redirect(redirect);
+    provide_server_redirect_with_options(redirect_with_options);
     leptos::nonce::provide_nonce();
 }
 
@@ -820,28 +1327,58 @@ fn handle_response<IV>(
 where
     IV: IntoView + 'static,
 {
+    // the options are only available from the request, so the headers are computed once, for
+    // the first request
+    let link_headers = Arc::new(OnceLock::<Vec<HeaderValue>>::new());
     let handler = move |req: HttpRequest| {
         let app_fn = app_fn.clone();
         let add_context = additional_context.clone();
+        let link_headers = Arc::clone(&link_headers);
 
         async move {
             let is_island_router_navigation = cfg!(feature = "islands-router")
                 && req.headers().get("Islands-Router").is_some();
+            let render_deferred_islands =
+                req.headers().get("Leptos-Deferred-Islands").is_some();
+
+            #[cfg(feature = "tracing")]
+            let request_id = request_id(&req);
+            #[cfg(feature = "tracing")]
+            let span =
+                render_span(req.method().as_str(), req.path(), &request_id);
 
             let res_options = ResponseOptions::default();
             let (meta_context, meta_output) = ServerMetaContext::new();
 
+            // lists the app's critical assets in `Link` headers
+            if let Some(options) = req.app_data::<Data<LeptosOptions>>() {
+                let links = link_headers.get_or_init(|| {
+                    preload_link_headers(options)
+                        .iter()
+                        .filter_map(|link| HeaderValue::from_str(link).ok())
+                        .collect()
+                });
+                for value in links {
+                    res_options.append_header(header::LINK, value.clone());
+                }
+            }
+
             let additional_context = {
                 let meta_context = meta_context.clone();
                 let res_options = res_options.clone();
                 let req = Request::new(&req);
                 move || {
                     provide_contexts(req, &meta_context, &res_options);
+                    #[cfg(feature = "tracing")]
+                    provide_context(request_id);
                     add_context();
 
                     if is_island_router_navigation {
                         provide_context(IslandsRouterNavigation);
                     }
+                    if render_deferred_islands {
+                        provide_context(RenderDeferredIslands);
+                    }
                 }
             };
 
@@ -852,10 +1389,25 @@ where
                 res_options,
                 stream_builder,
                 !is_island_router_navigation,
-            )
-            .await;
-
-            res.0
+            );
+            #[cfg(feature = "tracing")]
+            let res = res.instrument(span);
+
+            let mut res = res.await.0;
+            // the same URL returns different HTML when deferred islands are requested, and that
+            // HTML contains the per-user content, so it must not be cached
+            let headers = res.headers_mut();
+            headers.append(
+                header::VARY,
+                HeaderValue::from_static("leptos-deferred-islands"),
+            );
+            if render_deferred_islands {
+                headers.insert(
+                    header::CACHE_CONTROL,
+                    HeaderValue::from_static("private, no-store"),
+                );
+            }
+            res
         }
     };
     match method {
@@ -867,6 +1419,16 @@ where
     }
 }
 
+/// Reads the ID of the request from its headers, or generates one.
+#[cfg(feature = "tracing")]
+fn request_id(req: &HttpRequest) -> RequestId {
+    RequestId::new(
+        req.headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok()),
+    )
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Actix's App without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generated Actix compatible paths.
@@ -1038,16 +1600,10 @@ impl ActixRouteListing {
     }
 }
 
-/// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
-/// create routes in Actix's App without having to use wildcard matching or fallbacks. Takes in your root app Element
-/// as an argument so it can walk you app tree. This version is tailored to generated Actix compatible paths. Adding excluded_routes
-/// to this function will stop `.leptos_routes()` from generating a route for it, allowing a custom handler. These need to be in Actix path format.
-/// Additional context will be provided to the app Element.
-pub fn generate_route_list_with_exclusions_and_ssg_and_context<IV>(
-    app_fn: impl Fn() -> IV + 'static + Send + Clone,
-    excluded_routes: Option<Vec<String>>,
-    additional_context: impl Fn() + 'static + Send + Clone,
-) -> (Vec<ActixRouteListing>, StaticRouteGenerator)
+fn generate_leptos_route_list<IV>(
+    app_fn: &(impl Fn() -> IV + 'static),
+    additional_context: &impl Fn(),
+) -> RouteList
 where
     IV: IntoView + 'static,
 {
@@ -1055,16 +1611,85 @@ where
 
     let owner = Owner::new_root(Some(Arc::new(SsrSharedContext::new())));
     let (mock_meta, _) = ServerMetaContext::new();
-    let routes = owner
+    owner
         .with(|| {
             // stub out a path for now
             provide_context(RequestUrl::new(""));
             provide_context(ResponseOptions::default());
             provide_context(mock_meta);
             additional_context();
-            RouteList::generate(&app_fn)
+            RouteList::generate(app_fn)
         })
-        .unwrap_or_default();
+        .unwrap_or_default()
+}
+
+/// Generates a [`Sitemap`] containing every route in your app that does not contain any params.
+/// `base_url` is the scheme and host (like `https://example.com`) that will be prepended to each path.
+///
+/// Entries for routes with params can be added with [`Sitemap::entry`]. The sitemap can then be
+/// served with [`sitemap_route`].
+pub fn generate_sitemap<IV>(
+    app_fn: impl Fn() -> IV + 'static + Send + Clone,
+    base_url: impl Into<String>,
+) -> Sitemap
+where
+    IV: IntoView + 'static,
+{
+    generate_sitemap_with_context(app_fn, base_url, || {})
+}
+
+/// Generates a [`Sitemap`] containing every route in your app that does not contain any params.
+/// Additional context will be provided to the app Element.
+pub fn generate_sitemap_with_context<IV>(
+    app_fn: impl Fn() -> IV + 'static + Send + Clone,
+    base_url: impl Into<String>,
+    additional_context: impl Fn() + 'static + Send + Clone,
+) -> Sitemap
+where
+    IV: IntoView + 'static,
+{
+    let routes = generate_leptos_route_list(&app_fn, &additional_context);
+    Sitemap::from_route_list(base_url, &routes)
+}
+
+/// An Actix [struct@Route](actix_web::Route) that serves the given [`Sitemap`] as
+/// `application/xml` in response to `GET` requests.
+///
+/// ```no_run
+/// use actix_web::App;
+/// use leptos::prelude::*;
+/// use leptos_actix::{generate_sitemap, sitemap_route};
+///
+/// # fn app() -> impl IntoView {}
+/// let sitemap = generate_sitemap(app, "https://example.com");
+/// let app = App::new().route("/sitemap.xml", sitemap_route(sitemap));
+/// ```
+pub fn sitemap_route(sitemap: Sitemap) -> Route {
+    let xml: Arc<str> = sitemap.to_xml().into();
+    web::get().to(move || {
+        let xml = Arc::clone(&xml);
+        async move {
+            HttpResponse::Ok()
+                .content_type("application/xml; charset=utf-8")
+                .body(xml.to_string())
+        }
+    })
+}
+
+/// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
+/// create routes in Actix's App without having to use wildcard matching or fallbacks. Takes in your root app Element
+/// as an argument so it can walk you app tree. This version is tailored to generated Actix compatible paths. Adding excluded_routes
+/// to this function will stop `.leptos_routes()` from generating a route for it, allowing a custom handler. These need to be in Actix path format.
+/// Additional context will be provided to the app Element.
+pub fn generate_route_list_with_exclusions_and_ssg_and_context<IV>(
+    app_fn: impl Fn() -> IV + 'static + Send + Clone,
+    excluded_routes: Option<Vec<String>>,
+    additional_context: impl Fn() + 'static + Send + Clone,
+) -> (Vec<ActixRouteListing>, StaticRouteGenerator)
+where
+    IV: IntoView + 'static,
+{
+    let routes = generate_leptos_route_list(&app_fn, &additional_context);
 
     let generator = StaticRouteGenerator::new(
         &routes,
@@ -1439,12 +2064,13 @@ where
             .collect::<HashSet<_>>();
 
         // register server functions first to allow for wildcard route in Leptos's Router
+        let prefix = server_fn::client::get_server_fn_path_prefix();
         for (path, _) in server_fn::actix::server_fn_paths() {
             if !excluded.contains(path) {
                 let additional_context = additional_context.clone();
                 let handler =
                     handle_server_fns_with_context(additional_context);
-                router = router.route(path, handler);
+                router = router.route(&format!("{prefix}{path}"), handler);
             }
         }
 
@@ -1552,12 +2178,13 @@ impl LeptosRoutes for &mut ServiceConfig {
             .collect::<HashSet<_>>();
 
         // register server functions first to allow for wildcard route in Leptos's Router
+        let prefix = server_fn::client::get_server_fn_path_prefix();
         for (path, _) in server_fn::actix::server_fn_paths() {
             if !excluded.contains(path) {
                 let additional_context = additional_context.clone();
                 let handler =
                     handle_server_fns_with_context(additional_context);
-                router = router.route(path, handler);
+                router = router.route(&format!("{prefix}{path}"), handler);
             }
         }
 
@@ -17,6 +17,25 @@
 //! - `default`: supports running in a typical native Tokio/Axum environment
 //! - `wasm`: with `default-features = false`, supports running in a JS Fetch-based
 //!   environment
+//! - `wasi`: with `default-features = false`, supports running in a WASI runtime, which has
+//!   no threads, by using a single-threaded executor instead of Tokio. See the `leptos_wasi`
+//!   crate for serving the router with WASI HTTP.
+//! - `compression`: compresses server-rendered HTML with Brotli or gzip, depending on the
+//!   request’s `Accept-Encoding` header. Each chunk of the stream is flushed as soon as it is
+//!   ready, so this works with out-of-order streaming, unlike a compression layer that buffers
+//!   its output.
+//! - `cookies`: provides the request’s cookies to the app and its server functions, so that
+//!   they can be read and set with [`Cookies`](leptos::cookies::Cookies). This enables the
+//!   `cookies` feature of `leptos`.
+//! - `csrf`: rejects server function requests that do not carry the
+//!   [CSRF token](leptos::csrf) of the current request, when CSRF protection has been provided.
+//!   This enables the `csrf` feature of `leptos`, and the `cookies` feature of this crate.
+//! - `ws`: adds [`handle_websocket`], which upgrades requests to websockets whose handlers run
+//!   with access to the Leptos context.
+//! - `tracing`: opens a span for each page that is rendered and each server function that
+//!   runs, which carries a [`RequestId`]. If the `tracing` feature of `leptos` is also
+//!   enabled, its spans for `<Suspense/>` boundaries and resources are nested inside it, so
+//!   exported traces show where server-side rendering time goes.
 //!
 //! ### Important Note
 //! Prior to 0.5, using `default-features = false` on `leptos_axum` simply did nothing. Now, it actively
@@ -25,8 +44,29 @@
 //! `
 //! `spawn_local` called from outside of a `task::LocalSet`
 //! `
-//! If you are not using the `wasm` feature, do not set `default-features = false` on this package.
+//! If you are not using the `wasm` or `wasi` feature, do not set `default-features = false` on
+//! this package.
 //!
+//! ## Cloudflare Workers
+//! Workers run Rust as WebAssembly in a JS Fetch-based environment, so they use the `wasm`
+//! feature. With the `http` feature of the [`worker`](https://docs.rs/worker) crate, a Worker
+//! receives an `http::Request` and can return the response of an Axum router directly:
+//! ```rust,ignore
+//! #[worker::event(fetch)]
+//! async fn fetch(
+//!     req: worker::HttpRequest,
+//!     _env: worker::Env,
+//!     _ctx: worker::Context,
+//! ) -> worker::Result<axum::http::Response<axum::body::Body>> {
+//!     Ok(router().call(req).await?)
+//! }
+//! ```
+//! Server functions need to be registered with
+//! [`register_explicit`](server_fn::axum::register_explicit) in a WASM environment.
+//!
+//! ## Testing
+//! A [`TestClient`](testing::TestClient) sends requests to a router without starting a server,
+//! so that pages and server functions can be tested end to end with `cargo test`.
 //!
 //! ## More information
 //!
@@ -49,6 +89,8 @@ use axum::{
 };
 use futures::{stream::once, Future, Stream, StreamExt};
 use hydration_context::SsrSharedContext;
+#[cfg(feature = "csrf")]
+use leptos::csrf::{csrf_field, verify_csrf_token, CSRF_HEADER};
 use leptos::{
     config::LeptosOptions,
     context::{provide_context, use_context},
@@ -56,19 +98,35 @@ use leptos::{
     reactive::{computed::ScopedFuture, owner::Owner},
     IntoView,
 };
+#[cfg(feature = "compression")]
+use leptos_integration_utils::compression::Encoding;
+#[cfg(feature = "tracing")]
+use leptos_integration_utils::trace::{
+    render_span, server_fn_span, REQUEST_ID_HEADER,
+};
 use leptos_integration_utils::{
-    BoxedFnOnce, ExtendResponse, PinnedFuture, PinnedStream,
+    preload_link_headers, stream_out_of_order, BoxedFnOnce, ExtendResponse,
+    PinnedFuture, PinnedStream,
 };
 use leptos_meta::ServerMetaContext;
 #[cfg(feature = "default")]
 use leptos_router::static_routes::ResolvedStaticPath;
 use leptos_router::{
-    components::provide_server_redirect, location::RequestUrl,
-    static_routes::RegenerationFn, ExpandOptionals, PathSegment, RouteList,
-    RouteListing, SsrMode,
+    components::provide_server_redirect_with_options, location::RequestUrl,
+    sitemap::Sitemap, static_routes::RegenerationFn, ClientRedirect,
+    ExpandOptionals, PathSegment, RouteList, RouteListing,
+    ServerRedirectOptions, SsrMode,
 };
 use or_poisoned::OrPoisoned;
-use server_fn::{error::ServerFnErrorErr, redirect::REDIRECT_HEADER};
+use server_fn::{
+    error::ServerFnErrorErr,
+    limits::limits_for,
+    method_override::{
+        accepts_override, method_override_field, parse_override,
+        METHOD_OVERRIDE_HEADER,
+    },
+    redirect::REDIRECT_HEADER,
+};
 #[cfg(feature = "default")]
 use std::sync::LazyLock;
 #[cfg(feature = "default")]
@@ -79,17 +137,26 @@ use std::{
     io,
     pin::Pin,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 #[cfg(feature = "default")]
 use tower::util::ServiceExt;
 #[cfg(feature = "default")]
 use tower_http::services::ServeDir;
-// use tracing::Instrument; // TODO check tracing span -- was this used in 0.6 for a missing link?
-
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+
+#[cfg(feature = "tracing")]
+pub use leptos_integration_utils::trace::{use_request_id, RequestId};
+pub use leptos_integration_utils::{
+    shutdown::{begin_shutdown, drained, is_shutting_down},
+    RequestContext,
+};
 #[cfg(feature = "default")]
 mod service;
 #[cfg(feature = "default")]
 pub use service::ErrorHandler;
+pub mod testing;
 
 /// This struct lets you define headers and override the status of the Response from an Element or a Server Function
 /// Typically contained inside of a ResponseOptions. Setting this is useful for cookies and custom responses.
@@ -174,6 +241,32 @@ impl ExtendResponse for AxumResponse {
         )
     }
 
+    #[cfg(feature = "compression")]
+    fn accepted_encoding() -> Option<Encoding> {
+        let parts = use_context::<Parts>()?;
+        let accept_encoding = parts.headers.get(header::ACCEPT_ENCODING)?;
+        Encoding::negotiate(accept_encoding.to_str().ok()?)
+    }
+
+    #[cfg(feature = "compression")]
+    fn from_compressed_stream(
+        stream: impl Stream<Item = Vec<u8>> + Send + 'static,
+        encoding: Encoding,
+    ) -> Self {
+        let mut res = Body::from_stream(
+            stream.map(|chunk| Ok(chunk) as Result<Vec<u8>, std::io::Error>),
+        )
+        .into_response();
+        let headers = res.headers_mut();
+        headers.insert(
+            header::CONTENT_ENCODING,
+            HeaderValue::from_static(encoding.as_str()),
+        );
+        headers
+            .insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+        AxumResponse(res)
+    }
+
     fn extend_response(&mut self, res_options: &Self::ResponseOptions) {
         let mut res_options = res_options.0.write().or_poisoned();
         if let Some(status) = res_options.status {
@@ -225,9 +318,66 @@ impl ExtendResponse for AxumResponse {
 /// redirect, and can therefore return the value of the server function and then handle
 /// the redirect with client-side routing.
 pub fn redirect(path: &str) {
+    redirect_with_options(path, &ServerRedirectOptions::default())
+}
+
+/// Redirects the user, like [`redirect`], but with a custom status code and additional
+/// headers.
+///
+/// This allows you to use a permanent redirect (`301` or `308`) for SEO-relevant moves, or a
+/// method-preserving redirect (`307`). The status code is only used when the request is an
+/// ordinary navigation or `<form>` submission; for requests made by the server function client,
+/// the client-side router handles the redirect instead, or ignores it if
+/// [`ServerRedirectOptions::client`] is set to [`ClientRedirect::Ignore`].
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_router::{RedirectStatus, ServerRedirectOptions};
+///
+/// #[server]
+/// pub async fn moved() -> Result<(), ServerFnError> {
+///     leptos_axum::redirect_with_options(
+///         "/new-location",
+///         &ServerRedirectOptions::new(RedirectStatus::MovedPermanently)
+///             .header("Cache-Control", "max-age=3600"),
+///     );
+///     Ok(())
+/// }
+/// ```
+///
+/// A server function called by an `<ActionForm/>` can send users without JavaScript/WASM to a
+/// confirmation page with a `303 See Other`, while users with it stay on the page and receive
+/// the server function's value:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_router::{ClientRedirect, RedirectStatus, ServerRedirectOptions};
+///
+/// #[server]
+/// pub async fn subscribe(email: String) -> Result<String, ServerFnError> {
+///     leptos_axum::redirect_with_options(
+///         "/subscribed",
+///         &ServerRedirectOptions::new(RedirectStatus::SeeOther)
+///             .client(ClientRedirect::Ignore),
+///     );
+///     Ok(format!("Subscribed {email}"))
+/// }
+/// ```
+pub fn redirect_with_options(path: &str, options: &ServerRedirectOptions) {
     if let (Some(req), Some(res)) =
         (use_context::<Parts>(), use_context::<ResponseOptions>())
     {
+        let accepts_html = req
+            .headers
+            .get(ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.contains("text/html"))
+            .unwrap_or(false);
+        // the server fn client can be told to ignore the redirect, and just return the value
+        if !accepts_html && options.client == ClientRedirect::Ignore {
+            return;
+        }
+
         // insert the Location header in any case
         res.insert_header(
             header::LOCATION,
@@ -235,16 +385,35 @@ pub fn redirect(path: &str) {
                 .expect("Failed to create HeaderValue"),
         );
 
-        let accepts_html = req
-            .headers
-            .get(ACCEPT)
-            .and_then(|v| v.to_str().ok())
-            .map(|v| v.contains("text/html"))
-            .unwrap_or(false);
+        // as well as any additional headers
+        for (name, value) in &options.headers {
+            match (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(value)) => res.insert_header(name, value),
+                _ => {
+                    let msg = format!(
+                        "Invalid header {name:?}: {value:?} passed to \
+                         redirect_with_options()."
+                    );
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("{}", &msg);
+
+                    #[cfg(not(feature = "tracing"))]
+                    eprintln!("{}", &msg);
+                }
+            }
+        }
+
         if accepts_html {
             // if the request accepts text/html, it's a plain form request and needs
-            // to have the 302 code set
-            res.set_status(StatusCode::FOUND);
+            // to have the redirect status code set
+            res.set_status(
+                StatusCode::from_u16(options.status.as_u16())
+                    .expect("redirect status codes are always valid"),
+            );
         } else {
             // otherwise, we sent it from the server fn client and actually don't want
             // to set a real redirect, as this will break the ability to return data
@@ -259,19 +428,44 @@ pub fn redirect(path: &str) {
         {
             tracing::warn!(
                 "Couldn't retrieve either Parts or ResponseOptions while \
-                 trying to redirect()."
+                 trying to redirect_with_options()."
             );
         }
         #[cfg(not(feature = "tracing"))]
         {
             eprintln!(
                 "Couldn't retrieve either Parts or ResponseOptions while \
-                 trying to redirect()."
+                 trying to redirect_with_options()."
             );
         }
     }
 }
 
+/// Turns a [`RequestContext`] into an `additional_context` function, which can be passed to
+/// [`handle_server_fns_with_context`] and
+/// [`leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context).
+///
+/// The provider receives the request's [`Parts`], so it can read headers, cookies or
+/// extensions set by Axum middleware, and provide the values that your app needs.
+///
+/// ```rust,ignore
+/// let context = request_context(move |parts: &Parts| {
+///     provide_context(pool.clone());
+///     provide_context(Locale::from_headers(&parts.headers));
+/// });
+///
+/// let app = Router::new()
+///     .leptos_routes_with_context(&leptos_options, routes, context.clone(), app_fn)
+///     .route("/api/{*fn_name}", post(move |req| {
+///         handle_server_fns_with_context(context.clone(), req)
+///     }));
+/// ```
+pub fn request_context(
+    provider: impl RequestContext<Parts>,
+) -> impl Fn() + Clone + Send + Sync + 'static {
+    leptos_integration_utils::request_context::<Parts>(provider)
+}
+
 /// Decomposes an HTTP request into its parts, allowing you to read its headers
 /// and other data without consuming the body. Creates a new Request from the
 /// original parts for further processing
@@ -327,12 +521,34 @@ pub async fn handle_server_fns(req: Request<Body>) -> impl IntoResponse {
     handle_server_fns_inner(|| {}, req).await
 }
 
+/// Lists the app's critical assets in `Link` headers on the current response.
+fn add_preload_link_headers(links: &[String]) {
+    if let Some(res) = use_context::<ResponseOptions>() {
+        for link in links {
+            if let Ok(value) = HeaderValue::from_str(link) {
+                res.append_header(header::LINK, value);
+            }
+        }
+    }
+}
+
 fn init_executor() {
     #[cfg(feature = "wasm")]
     let _ = any_spawner::Executor::init_wasm_bindgen();
     #[cfg(all(not(feature = "wasm"), feature = "default"))]
     let _ = any_spawner::Executor::init_tokio();
-    #[cfg(all(not(feature = "wasm"), not(feature = "default")))]
+    // the default features win if they are enabled by another crate in the dependency graph
+    #[cfg(all(
+        not(feature = "wasm"),
+        not(feature = "default"),
+        feature = "wasi"
+    ))]
+    let _ = any_spawner::Executor::init_local_executor();
+    #[cfg(all(
+        not(feature = "wasm"),
+        not(feature = "wasi"),
+        not(feature = "default")
+    ))]
     {
         eprintln!(
             "It appears you have set 'default-features = false' on \
@@ -379,6 +595,19 @@ async fn handle_server_fns_inner(
     additional_context: impl Fn() + 'static + Clone + Send,
     req: Request<Body>,
 ) -> impl IntoResponse {
+    let req = match apply_method_override(req).await {
+        Ok(req) => req,
+        Err(status) => {
+            return Response::builder()
+                .status(status)
+                .body(Body::from(if status == StatusCode::PAYLOAD_TOO_LARGE {
+                    "The request body is too large."
+                } else {
+                    "Could not read the request body."
+                }))
+                .expect("could not build Response");
+        }
+    };
     let method = req.method().clone();
     let path = req.uri().path().to_string();
     let (req, parts) = generate_request_and_parts(req);
@@ -386,46 +615,126 @@ async fn handle_server_fns_inner(
     if let Some(mut service) =
         server_fn::axum::get_server_fn_service(&path, method)
     {
+        let limits = limits_for(&path);
+        let ser = service.ser;
+        if limits.exceeds_body_size(content_length(req.headers())) {
+            return limit_exceeded(
+                ser,
+                &path,
+                ServerFnErrorErr::PayloadTooLarge(
+                    limits.max_body_size.unwrap_or_default(),
+                ),
+            );
+        }
+        // bodies without a `Content-Length` are read up to the limit before the server function
+        // runs, so that going over it is reported as `PayloadTooLarge`
+        let req = match limits.max_body_size {
+            Some(max) if content_length(req.headers()).is_none() => {
+                let (req_parts, body) = req.into_parts();
+                match axum::body::to_bytes(body, max).await {
+                    Ok(body) => {
+                        Request::from_parts(req_parts, Body::from(body))
+                    }
+                    Err(err) if is_length_limit_error(&err) => {
+                        return limit_exceeded(
+                            ser,
+                            &path,
+                            ServerFnErrorErr::PayloadTooLarge(max),
+                        );
+                    }
+                    Err(_) => {
+                        return Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::from(
+                                "Could not read the request body.",
+                            ))
+                            .expect("could not build Response");
+                    }
+                }
+            }
+            _ => req,
+        };
+
+        #[cfg(feature = "tracing")]
+        let request_id = request_id(&parts.headers);
+        #[cfg(feature = "tracing")]
+        let span = server_fn_span(&path, &request_id);
+
         let owner = Owner::new();
-        owner
-            .with(|| {
-                ScopedFuture::new(async move {
-                    provide_context(parts);
-                    let res_options = ResponseOptions::default();
-                    provide_context(res_options.clone());
-                    additional_context();
-
-                    // store Accepts and Referer in case we need them for redirect (below)
-                    let accepts_html = req
-                        .headers()
-                        .get(ACCEPT)
-                        .and_then(|v| v.to_str().ok())
-                        .map(|v| v.contains("text/html"))
-                        .unwrap_or(false);
-                    let referrer = req.headers().get(REFERER).cloned();
-
-                    // actually run the server fn
-                    let mut res = AxumResponse(service.run(req).await);
-
-                    // if it accepts text/html (i.e., is a plain form post) and doesn't already have a
-                    // Location set, then redirect to the Referer
-                    if accepts_html {
-                        if let Some(referrer) = referrer {
-                            let has_location =
-                                res.0.headers().get(LOCATION).is_some();
-                            if !has_location {
-                                *res.0.status_mut() = StatusCode::FOUND;
-                                res.0.headers_mut().insert(LOCATION, referrer);
-                            }
+        let res = owner.with(|| {
+            ScopedFuture::new(async move {
+                let res_options = ResponseOptions::default();
+                #[cfg(feature = "cookies")]
+                provide_cookies(&parts, &res_options);
+                provide_context(parts);
+                provide_context(res_options.clone());
+                #[cfg(feature = "tracing")]
+                provide_context(request_id);
+                additional_context();
+
+                // store Accepts and Referer in case we need them for redirect (below)
+                let accepts_html = req
+                    .headers()
+                    .get(ACCEPT)
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v.contains("text/html"))
+                    .unwrap_or(false);
+                let referrer = req.headers().get(REFERER).cloned();
+
+                #[cfg(feature = "csrf")]
+                let req = match check_csrf(req, limits.max_body_size).await {
+                    Ok(req) => req,
+                    Err(StatusCode::PAYLOAD_TOO_LARGE) => {
+                        return Ok(limit_exceeded(
+                            ser,
+                            &path,
+                            ServerFnErrorErr::PayloadTooLarge(
+                                limits.max_body_size.unwrap_or_default(),
+                            ),
+                        ));
+                    }
+                    Err(status) => {
+                        return Response::builder()
+                            .status(status)
+                            .body(Body::from("Invalid CSRF token."));
+                    }
+                };
+
+                // actually run the server fn
+                let Some(res) =
+                    run_with_timeout(service.run(req), limits.timeout).await
+                else {
+                    return Ok(limit_exceeded(
+                        ser,
+                        &path,
+                        ServerFnErrorErr::Timeout(
+                            limits.timeout.unwrap_or_default(),
+                        ),
+                    ));
+                };
+                let mut res = AxumResponse(res);
+
+                // if it accepts text/html (i.e., is a plain form post) and doesn't already have a
+                // Location set, then redirect to the Referer
+                if accepts_html {
+                    if let Some(referrer) = referrer {
+                        let has_location =
+                            res.0.headers().get(LOCATION).is_some();
+                        if !has_location {
+                            *res.0.status_mut() = StatusCode::FOUND;
+                            res.0.headers_mut().insert(LOCATION, referrer);
                         }
                     }
+                }
 
-                    // apply status code and headers if user changed them
-                    res.extend_response(&res_options);
-                    Ok(res.0)
-                })
+                // apply status code and headers if user changed them
+                res.extend_response(&res_options);
+                Ok(res.0)
             })
-            .await
+        });
+        #[cfg(feature = "tracing")]
+        let res = res.instrument(span);
+        res.await
     } else {
         Response::builder()
             .status(StatusCode::BAD_REQUEST)
@@ -443,6 +752,232 @@ async fn handle_server_fns_inner(
     .expect("could not build Response")
 }
 
+/// Reads the `Content-Length` of a request, if it has one.
+fn content_length(headers: &HeaderMap) -> Option<usize> {
+    headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Runs a server function, returning `None` if it does not finish within the timeout.
+///
+/// Timeouts rely on Tokio, so they are only applied with the `default` feature.
+async fn run_with_timeout(
+    fut: impl Future<Output = Response<Body>>,
+    timeout: Option<Duration>,
+) -> Option<Response<Body>> {
+    match timeout {
+        #[cfg(feature = "default")]
+        Some(timeout) => tokio::time::timeout(timeout, fut).await.ok(),
+        _ => Some(fut.await),
+    }
+}
+
+/// Responds to a server function request that went over one of its
+/// [`ServerFnLimits`](server_fn::limits::ServerFnLimits).
+fn limit_exceeded(
+    ser: fn(ServerFnErrorErr) -> Bytes,
+    path: &str,
+    err: ServerFnErrorErr,
+) -> Response<Body> {
+    use server_fn::response::Res as _;
+
+    let status = match err {
+        ServerFnErrorErr::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+        _ => StatusCode::REQUEST_TIMEOUT,
+    };
+    let mut res = Response::<Body>::error_response(path, ser(err));
+    *res.status_mut() = status;
+    res
+}
+
+/// Checks the CSRF token of a server function request, if CSRF protection has been provided
+/// via [`provide_csrf`](leptos::csrf::provide_csrf). Returns the request, with its body
+/// restored, if the token is valid.
+///
+/// A form body is read up to the request's `max_body_size`. If it is larger, this returns
+/// `413 Payload Too Large`; if the token is missing or wrong, `403 Forbidden`.
+#[cfg(feature = "csrf")]
+async fn check_csrf(
+    req: Request<Body>,
+    max_body_size: Option<usize>,
+) -> Result<Request<Body>, StatusCode> {
+    if req.method() == Method::GET
+        || verify_csrf_token(
+            req.headers()
+                .get(CSRF_HEADER)
+                .and_then(|value| value.to_str().ok()),
+        )
+    {
+        return Ok(req);
+    }
+
+    let is_form = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/x-www-form-urlencoded")
+        });
+    if !is_form {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let (parts, body) = req.into_parts();
+    let body = axum::body::to_bytes(body, max_body_size.unwrap_or(usize::MAX))
+        .await
+        .map_err(|err| {
+            if is_length_limit_error(err) {
+                StatusCode::PAYLOAD_TOO_LARGE
+            } else {
+                StatusCode::BAD_REQUEST
+            }
+        })?;
+    if !verify_csrf_token(csrf_field(&body)) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(Request::from_parts(parts, Body::from(body)))
+}
+
+/// Whether reading a body failed because it went over the length limit passed to
+/// [`axum::body::to_bytes`].
+fn is_length_limit_error(err: &axum::Error) -> bool {
+    std::iter::successors(
+        Some(err as &(dyn std::error::Error + 'static)),
+        |err| err.source(),
+    )
+    .any(|err| err.is::<http_body_util::LengthLimitError>())
+}
+
+/// Handles a `POST` request as a `PUT`, `PATCH`, or `DELETE` request if it sets a
+/// [method override](server_fn::method_override), and there is no `POST` server function at its
+/// path. Returns the request, with its body restored if it had to be read.
+///
+/// A form body is read up to the `max_body_size` of the server functions at its path. If it is
+/// larger, this returns `413 Payload Too Large`; if it cannot be read, `400 Bad Request`.
+async fn apply_method_override(
+    req: Request<Body>,
+) -> Result<Request<Body>, StatusCode> {
+    if req.method() != Method::POST
+        || server_fn::axum::get_server_fn_service(
+            req.uri().path(),
+            Method::POST,
+        )
+        .is_some()
+    {
+        return Ok(req);
+    }
+
+    let mut req = req;
+    if let Some(method) = req
+        .headers()
+        .get(METHOD_OVERRIDE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_override)
+    {
+        *req.method_mut() = method;
+        return Ok(req);
+    }
+
+    let is_form = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/x-www-form-urlencoded")
+        });
+    if !is_form {
+        return Ok(req);
+    }
+    let max_body_size = limits_for(req.uri().path()).max_body_size;
+    let (mut parts, body) = req.into_parts();
+    let body = axum::body::to_bytes(body, max_body_size.unwrap_or(usize::MAX))
+        .await
+        .map_err(|err| {
+            if is_length_limit_error(&err) {
+                StatusCode::PAYLOAD_TOO_LARGE
+            } else {
+                StatusCode::BAD_REQUEST
+            }
+        })?;
+    if let Some(method) = method_override_field(&body) {
+        parts.method = method;
+    }
+    Ok(Request::from_parts(parts, Body::from(body)))
+}
+
+/// Returns a route that upgrades `GET` requests to websockets, and passes each socket to the
+/// handler.
+///
+/// The handler runs in its own reactive owner, so it can use the same context as a server
+/// function: the [`LeptosOptions`] from the router state and the request's [`Parts`] are both
+/// provided.
+///
+/// ```rust,ignore
+/// use axum::extract::ws::{Message, WebSocket};
+///
+/// let app = Router::new()
+///     .route("/ws", leptos_axum::handle_websocket(|mut socket: WebSocket| async move {
+///         let options = expect_context::<LeptosOptions>();
+///         while let Some(Ok(Message::Text(text))) = socket.recv().await {
+///             _ = socket.send(Message::Text(text)).await;
+///         }
+///     }))
+///     .leptos_routes(&leptos_options, routes, app_fn)
+///     .with_state(leptos_options);
+/// ```
+#[cfg(feature = "ws")]
+pub fn handle_websocket<S, F, Fut>(handler: F) -> axum::routing::MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+    LeptosOptions: FromRef<S>,
+    F: Fn(axum::extract::ws::WebSocket) -> Fut + Clone + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    handle_websocket_with_context(|| {}, handler)
+}
+
+/// Returns a route that upgrades requests to websockets, like [`handle_websocket`], providing
+/// additional context to the handler.
+#[cfg(feature = "ws")]
+pub fn handle_websocket_with_context<S, F, Fut>(
+    additional_context: impl Fn() + Clone + Send + Sync + 'static,
+    handler: F,
+) -> axum::routing::MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+    LeptosOptions: FromRef<S>,
+    F: Fn(axum::extract::ws::WebSocket) -> Fut + Clone + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    use axum::extract::ws::WebSocketUpgrade;
+
+    get(
+        move |State(options): State<LeptosOptions>,
+              ws: WebSocketUpgrade,
+              req: Request<Body>| {
+            let additional_context = additional_context.clone();
+            let handler = handler.clone();
+            async move {
+                let (_, parts) = generate_request_and_parts(req);
+                ws.on_upgrade(move |socket| {
+                    let owner = Owner::new();
+                    let fut = owner.with(|| {
+                        provide_context(options);
+                        provide_context(parts);
+                        additional_context();
+                        ScopedFuture::new(handler(socket))
+                    });
+                    async move {
+                        fut.await;
+                        drop(owner);
+                    }
+                })
+            }
+        },
+    )
+}
+
 /// A stream of bytes of HTML.
 pub type PinnedHtmlStream =
     Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>>;
@@ -795,7 +1330,11 @@ where
             } else {
                 app.to_html_stream_in_order()
             };
-            Box::pin(app.chain(chunks())) as PinnedStream<String>
+            if supports_ooo {
+                stream_out_of_order(app, chunks)
+            } else {
+                Box::pin(app.chain(chunks())) as PinnedStream<String>
+            }
         })
     })
 }
@@ -905,6 +1444,14 @@ where
     Box::pin(async move {
         let is_island_router_navigation = cfg!(feature = "islands-router")
             && req.headers().get("Islands-Router").is_some();
+        let render_deferred_islands =
+            req.headers().get("Leptos-Deferred-Islands").is_some();
+
+        #[cfg(feature = "tracing")]
+        let request_id = request_id(req.headers());
+        #[cfg(feature = "tracing")]
+        let span =
+            render_span(req.method().as_str(), req.uri().path(), &request_id);
 
         let add_context = additional_context.clone();
         let res_options = ResponseOptions::default();
@@ -926,11 +1473,16 @@ where
                     req_parts,
                     res_options.clone(),
                 );
+                #[cfg(feature = "tracing")]
+                provide_context(request_id);
                 add_context();
 
                 if is_island_router_navigation {
                     provide_context(IslandsRouterNavigation);
                 }
+                if render_deferred_islands {
+                    provide_context(RenderDeferredIslands);
+                }
             }
         };
 
@@ -941,13 +1493,38 @@ where
             res_options,
             stream_builder,
             !is_island_router_navigation,
-        )
-        .await;
-
-        res.0
+        );
+        #[cfg(feature = "tracing")]
+        let res = res.instrument(span);
+
+        let mut res = res.await.0;
+        // the same URL returns different HTML when deferred islands are requested, and that HTML
+        // contains the per-user content, so it must not be cached
+        let headers = res.headers_mut();
+        headers.append(
+            header::VARY,
+            HeaderValue::from_static("leptos-deferred-islands"),
+        );
+        if render_deferred_islands {
+            headers.insert(
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("private, no-store"),
+            );
+        }
+        res
     })
 }
 
+/// Reads the ID of the request from its headers, or generates one.
+#[cfg(feature = "tracing")]
+fn request_id(headers: &HeaderMap) -> RequestId {
+    RequestId::new(
+        headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok()),
+    )
+}
+
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(level = "trace", fields(error), skip_all)
@@ -960,12 +1537,34 @@ fn provide_contexts(
 ) {
     provide_context(RequestUrl::new(path));
     provide_context(meta_context.clone());
+    #[cfg(feature = "cookies")]
+    provide_cookies(&parts, &default_res_options);
     provide_context(parts);
     provide_context(default_res_options);
-    provide_server_redirect(redirect);
+    provide_server_redirect_with_options(redirect_with_options);
     leptos::nonce::provide_nonce();
 }
 
+#[cfg(feature = "cookies")]
+/// Provides the request's cookies via context, so that they can be used with
+/// [`Cookies`](leptos::cookies::Cookies). Cookies that are set are added to the response
+/// options as `Set-Cookie` headers.
+fn provide_cookies(parts: &Parts, res_options: &ResponseOptions) {
+    let header = parts
+        .headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join("; ");
+    let res_options = res_options.clone();
+    leptos::cookies::provide_server_cookies(Some(&header), move |cookie| {
+        if let Ok(value) = HeaderValue::from_str(cookie) {
+            res_options.append_header(header::SET_COOKIE, value);
+        }
+    });
+}
+
 /// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
 /// to route it using [leptos_router], asynchronously rendering an HTML page after all
 /// `async` resources have loaded.
@@ -1316,22 +1915,94 @@ impl AxumRouteListing {
     pub fn methods(&self) -> impl Iterator<Item = leptos_router::Method> + '_ {
         self.methods.iter().copied()
     }
+
+    /// Whether this path was excluded from the route list, and should not be served by Leptos.
+    pub fn is_excluded(&self) -> bool {
+        self.exclude
+    }
 }
 
-/// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
-/// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
-/// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths. Adding excluded_routes
-/// to this function will stop `.leptos_routes()` from generating a route for it, allowing a custom handler. These need to be in Axum path format
-/// Additional context will be provided to the app Element.
+/// Returns a handler that renders the app for one route, using that route's [`SsrMode`].
+///
+/// [`LeptosRoutes`] registers one of these for each method of each route. Integrations for
+/// other frameworks can use it to serve Leptos routes by converting their requests into a
+/// [`Request<Body>`], without reimplementing each rendering mode.
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(level = "trace", fields(error), skip_all)
 )]
-pub fn generate_route_list_with_exclusions_and_ssg_and_context<IV>(
-    app_fn: impl Fn() -> IV + Clone + Send + 'static,
-    excluded_routes: Option<Vec<String>>,
-    additional_context: impl Fn() + Clone + Send + 'static,
-) -> (Vec<AxumRouteListing>, StaticRouteGenerator)
+pub fn render_route_listing<IV>(
+    listing: &AxumRouteListing,
+    method: leptos_router::Method,
+    options: LeptosOptions,
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+    app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+) -> impl Fn(Request<Body>) -> PinnedFuture<Response<Body>>
+       + Clone
+       + Send
+       + Sync
+       + 'static
+where
+    IV: IntoView + 'static,
+{
+    let mode = listing.mode().clone();
+    #[allow(unused)]
+    let regenerate = listing.regenerate.clone();
+    let link_headers = Arc::new(preload_link_headers(&options));
+    let additional_context = move || {
+        provide_context(method);
+        additional_context();
+        add_preload_link_headers(&link_headers);
+    };
+
+    move |req| {
+        let additional_context = additional_context.clone();
+        let app_fn = app_fn.clone();
+        match &mode {
+            SsrMode::OutOfOrder => render_app_to_stream_with_context(
+                additional_context,
+                app_fn,
+            )(req),
+            SsrMode::PartiallyBlocked => {
+                render_app_to_stream_with_context_and_replace_blocks(
+                    additional_context,
+                    app_fn,
+                    true,
+                )(req)
+            }
+            SsrMode::InOrder => render_app_to_stream_in_order_with_context(
+                additional_context,
+                app_fn,
+            )(req),
+            SsrMode::Async => {
+                render_app_async_with_context(additional_context, app_fn)(req)
+            }
+            SsrMode::Static(_) => {
+                #[cfg(feature = "default")]
+                {
+                    handle_static_route::<LeptosOptions, IV>(
+                        additional_context,
+                        app_fn,
+                        regenerate.clone(),
+                    )(State(options.clone()), req)
+                }
+                #[cfg(not(feature = "default"))]
+                {
+                    _ = &options;
+                    panic!(
+                        "Static routes are not currently supported on WASM32 \
+                         server targets."
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn generate_leptos_route_list<IV>(
+    app_fn: &(impl Fn() -> IV + 'static),
+    additional_context: &impl Fn(),
+) -> RouteList
 where
     IV: IntoView + 'static,
 {
@@ -1339,7 +2010,7 @@ where
     init_executor();
     let owner = Owner::new_root(Some(Arc::new(SsrSharedContext::new())));
 
-    let routes = owner
+    owner
         .with(|| {
             // stub out a path for now
             provide_context(RequestUrl::new(""));
@@ -1347,9 +2018,98 @@ where
             let (mock_meta, _) = ServerMetaContext::new();
             provide_contexts("", &mock_meta, mock_parts, Default::default());
             additional_context();
-            RouteList::generate(&app_fn)
+            RouteList::generate(app_fn)
+        })
+        .unwrap_or_default()
+}
+
+/// Generates a [`Sitemap`] containing every route in your app that does not contain any params.
+/// `base_url` is the scheme and host (like `https://example.com`) that will be prepended to each path.
+///
+/// Entries for routes with params can be added with [`Sitemap::entry`]. The sitemap can then be
+/// served with [`sitemap_handler`].
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", fields(error), skip_all)
+)]
+pub fn generate_sitemap<IV>(
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+    base_url: impl Into<String>,
+) -> Sitemap
+where
+    IV: IntoView + 'static,
+{
+    generate_sitemap_with_context(app_fn, base_url, || {})
+}
+
+/// Generates a [`Sitemap`] containing every route in your app that does not contain any params.
+/// Additional context will be provided to the app Element.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", fields(error), skip_all)
+)]
+pub fn generate_sitemap_with_context<IV>(
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+    base_url: impl Into<String>,
+    additional_context: impl Fn() + Clone + Send + 'static,
+) -> Sitemap
+where
+    IV: IntoView + 'static,
+{
+    let routes = generate_leptos_route_list(&app_fn, &additional_context);
+    Sitemap::from_route_list(base_url, &routes)
+}
+
+/// Returns an Axum [Handler](axum::handler::Handler) that serves the given [`Sitemap`] as
+/// `application/xml`.
+///
+/// ```no_run
+/// use axum::{routing::get, Router};
+/// use leptos::prelude::*;
+/// use leptos_axum::{generate_sitemap, sitemap_handler};
+///
+/// # fn app() -> impl IntoView {}
+/// let sitemap = generate_sitemap(app, "https://example.com");
+/// let router: Router<()> =
+///     Router::new().route("/sitemap.xml", get(sitemap_handler(sitemap)));
+/// ```
+pub fn sitemap_handler(
+    sitemap: Sitemap,
+) -> impl Fn() -> Pin<Box<dyn Future<Output = Response<Body>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    let xml: Arc<str> = sitemap.to_xml().into();
+    move || {
+        let xml = Arc::clone(&xml);
+        Box::pin(async move {
+            (
+                [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+                xml.to_string(),
+            )
+                .into_response()
         })
-        .unwrap_or_default();
+    }
+}
+
+/// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
+/// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
+/// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths. Adding excluded_routes
+/// to this function will stop `.leptos_routes()` from generating a route for it, allowing a custom handler. These need to be in Axum path format
+/// Additional context will be provided to the app Element.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", fields(error), skip_all)
+)]
+pub fn generate_route_list_with_exclusions_and_ssg_and_context<IV>(
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+    excluded_routes: Option<Vec<String>>,
+    additional_context: impl Fn() + Clone + Send + 'static,
+) -> (Vec<AxumRouteListing>, StaticRouteGenerator)
+where
+    IV: IntoView + 'static,
+{
+    let routes = generate_leptos_route_list(&app_fn, &additional_context);
 
     let generator = StaticRouteGenerator::new(
         &routes,
@@ -1806,6 +2566,9 @@ where
     {
         init_executor();
 
+        let link_headers =
+            Arc::new(preload_link_headers(&LeptosOptions::from_ref(state)));
+
         // S represents the router's finished state allowing us to provide
         // it to the user's server functions.
         let state = state.clone();
@@ -1823,6 +2586,7 @@ where
             .collect::<HashSet<_>>();
 
         // register server functions
+        let prefix = server_fn::client::get_server_fn_path_prefix();
         for (path, method) in server_fn::axum::server_fn_paths() {
             let cx_with_state = cx_with_state.clone();
             let handler = move |req: Request<Body>| async move {
@@ -1830,22 +2594,27 @@ where
             };
 
             if !excluded.contains(path) {
-                router = router.route(
-                    path,
-                    match method {
-                        Method::GET => get(handler),
-                        Method::POST => post(handler),
-                        Method::PUT => put(handler),
-                        Method::DELETE => delete(handler),
-                        Method::PATCH => patch(handler),
-                        _ => {
-                            panic!(
-                                "Unsupported server function HTTP method: \
-                                 {method:?}"
-                            );
-                        }
-                    },
-                );
+                let method_router = match method {
+                    Method::GET => get(handler.clone()),
+                    Method::POST => post(handler.clone()),
+                    Method::PUT => put(handler.clone()),
+                    Method::DELETE => delete(handler.clone()),
+                    Method::PATCH => patch(handler.clone()),
+                    _ => {
+                        panic!(
+                            "Unsupported server function HTTP method: \
+                             {method:?}"
+                        );
+                    }
+                };
+                // plain HTML forms call these with a `POST` and a method override
+                let method_router = if accepts_override(&method) {
+                    method_router.post(handler)
+                } else {
+                    method_router
+                };
+                router =
+                    router.route(&format!("{prefix}{path}"), method_router);
             }
         }
 
@@ -1855,9 +2624,11 @@ where
 
             for method in listing.methods() {
                 let cx_with_state = cx_with_state.clone();
+                let link_headers = Arc::clone(&link_headers);
                 let cx_with_state_and_method = move || {
                     provide_context(method);
                     cx_with_state();
+                    add_preload_link_headers(&link_headers);
                 };
                 router = if matches!(listing.mode(), SsrMode::Static(_)) {
                     #[cfg(feature = "default")]
@@ -0,0 +1,158 @@
+//! Utilities to test a Leptos app end to end, without running a server.
+//!
+//! A [`TestClient`] sends requests straight to a [`Router`], so that they go through the same
+//! handlers as real requests: routes are rendered, server functions run, and any context or
+//! middleware that has been set up on the router applies.
+//!
+//! ```rust,ignore
+//! use leptos_axum::{generate_route_list, testing::TestClient, LeptosRoutes};
+//!
+//! #[tokio::test]
+//! async fn home_page() {
+//!     let options = LeptosOptions::builder().output_name("app").build();
+//!     let routes = generate_route_list(App);
+//!     let router = axum::Router::new()
+//!         .leptos_routes(&options, routes, App)
+//!         .with_state(options);
+//!     let client = TestClient::new(router);
+//!
+//!     let res = client.get("/").await;
+//!     assert!(res.status().is_success());
+//!     assert!(res.text().contains("Welcome to Leptos!"));
+//!
+//!     let res = client.call_server_fn::<AddTodo>("title=Test").await;
+//!     assert_eq!(res.text(), "null");
+//! }
+//! ```
+
+use axum::{
+    body::{Body, Bytes},
+    http::{
+        header::{self, AsHeaderName},
+        HeaderMap, Method, Request, StatusCode,
+    },
+    Router,
+};
+use futures::StreamExt;
+use server_fn::{Protocol, ServerFn};
+use tower::ServiceExt;
+
+/// Sends requests to a [`Router`], as if they had been made to a running server.
+#[derive(Clone, Debug)]
+pub struct TestClient {
+    router: Router,
+}
+
+impl TestClient {
+    /// Creates a client for the router, which should have all of its routes and state.
+    pub fn new(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// Sends a `GET` request for the given URI.
+    pub async fn get(&self, uri: &str) -> TestResponse {
+        self.request(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+    }
+
+    /// Sends a `POST` request with a URL-encoded form body, such as `"title=Test&done=true"`.
+    pub async fn post_form(
+        &self,
+        uri: &str,
+        body: impl Into<String>,
+    ) -> TestResponse {
+        self.request(
+            Request::post(uri)
+                .header(
+                    header::CONTENT_TYPE,
+                    "application/x-www-form-urlencoded",
+                )
+                .body(Body::from(body.into()))
+                .unwrap(),
+        )
+        .await
+    }
+
+    /// Calls a server function with its arguments encoded as a URL-encoded form, such as
+    /// `"title=Test&done=true"`.
+    ///
+    /// The arguments are sent in the query string of a `GET` request or in the body of a `POST`
+    /// request, depending on the server function's protocol, so this is meant for server
+    /// functions that use the default `PostUrl` or `GetUrl` encodings.
+    pub async fn call_server_fn<T: ServerFn>(
+        &self,
+        args: &str,
+    ) -> TestResponse {
+        let path = format!(
+            "{}{}",
+            server_fn::client::get_server_fn_path_prefix(),
+            T::PATH
+        );
+        if T::Protocol::METHOD == Method::GET {
+            self.get(&format!("{path}?{args}")).await
+        } else {
+            self.post_form(&path, args).await
+        }
+    }
+
+    /// Sends a request, and reads the whole response.
+    pub async fn request(&self, req: Request<Body>) -> TestResponse {
+        let Ok(res) = self.router.clone().oneshot(req).await;
+        let (parts, body) = res.into_parts();
+
+        let mut chunks = Vec::new();
+        let mut stream = body.into_data_stream();
+        while let Some(Ok(chunk)) = stream.next().await {
+            chunks.push(chunk);
+        }
+
+        TestResponse {
+            status: parts.status,
+            headers: parts.headers,
+            chunks,
+        }
+    }
+}
+
+/// A response that has been read in full by a [`TestClient`].
+#[derive(Clone, Debug)]
+pub struct TestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    chunks: Vec<Bytes>,
+}
+
+impl TestResponse {
+    /// The status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The value of a header, if it is set and is valid text.
+    pub fn header(&self, name: impl AsHeaderName) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// The body of the response, in the chunks in which it was streamed.
+    ///
+    /// This can be used to check what is sent before and after `<Suspense/>` boundaries
+    /// resolve.
+    pub fn chunks(&self) -> &[Bytes] {
+        &self.chunks
+    }
+
+    /// The whole body of the response.
+    pub fn bytes(&self) -> Bytes {
+        self.chunks.concat().into()
+    }
+
+    /// The whole body of the response, as text.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.bytes()).into_owned()
+    }
+}
@@ -0,0 +1,47 @@
+use axum::{http::StatusCode, Router};
+use leptos::prelude::*;
+use leptos_axum::{generate_route_list, testing::TestClient, LeptosRoutes};
+use leptos_router::{
+    components::{Route, Router as LeptosRouter, Routes},
+    path,
+};
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <LeptosRouter>
+            <Routes fallback=|| "Not found.">
+                <Route path=path!("") view=|| view! { <p>"Hello, world!"</p> }/>
+            </Routes>
+        </LeptosRouter>
+    }
+}
+
+#[server]
+async fn add(a: i32, b: i32) -> Result<i32, ServerFnError> {
+    Ok(a + b)
+}
+
+fn client() -> TestClient {
+    let options = LeptosOptions::builder().output_name("test").build();
+    let routes = generate_route_list(App);
+    let router = Router::new()
+        .leptos_routes(&options, routes, App)
+        .with_state(options);
+    TestClient::new(router)
+}
+
+#[tokio::test]
+async fn renders_routes_without_a_server() {
+    let res = client().get("/").await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.header("content-type"), Some("text/html; charset=utf-8"));
+    assert!(res.text().contains("<p>Hello, world!</p>"));
+}
+
+#[tokio::test]
+async fn calls_server_functions_without_a_server() {
+    let res = client().call_server_fn::<Add>("a=2&b=3").await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.text(), "5");
+}
@@ -0,0 +1,340 @@
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+//! Provides functions to easily integrate Leptos with [Rocket](https://rocket.rs).
+//!
+//! Server functions use the same request and response types as the Axum integration, so this
+//! crate converts each Rocket request into an Axum request and reuses the rendering and server
+//! function handlers from [`leptos_axum`]. Anything that works with those handlers, like
+//! [`ResponseOptions`], [`redirect`] and [`extract`], works here too.
+//!
+//! ```rust,ignore
+//! use leptos::prelude::*;
+//! use leptos_rocket::{generate_route_list, LeptosRoutes};
+//!
+//! #[rocket::launch]
+//! fn rocket() -> _ {
+//!     let conf = get_configuration(None).unwrap();
+//!     let leptos_options = conf.leptos_options;
+//!     let routes = generate_route_list(App);
+//!
+//!     rocket::build().leptos_routes(&leptos_options, routes, {
+//!         let leptos_options = leptos_options.clone();
+//!         move || shell(leptos_options.clone())
+//!     })
+//! }
+//! ```
+//!
+//! Request bodies are read in full before they are passed to a server function. They are
+//! limited to 1 MiB by default, which can be changed with the `leptos` data limit in
+//! `Rocket.toml`.
+//!
+//! ## Features
+//! - `compression`: compresses server-rendered HTML with Brotli or gzip. See [`leptos_axum`].
+//! - `islands-router`: enables the islands router.
+//!
+//! Static files, like the `/pkg` directory, should be served with Rocket’s own
+//! [`FileServer`](rocket::fs::FileServer).
+
+use axum::{
+    body::Body,
+    http::{Request, Response},
+    response::IntoResponse,
+};
+use futures::{future::BoxFuture, TryStreamExt};
+use leptos::{config::LeptosOptions, IntoView};
+pub use leptos_axum::{
+    extract, generate_route_list, generate_route_list_with_exclusions,
+    redirect, redirect_with_options, request_context, AxumRouteListing,
+    RequestContext, ResponseOptions, ResponseParts,
+};
+use rocket::{
+    data::ToByteUnit,
+    http::{Method, Status},
+    route::{Handler, Outcome, Route},
+    Build, Data, Rocket,
+};
+use std::{io, sync::Arc};
+use tokio_util::io::StreamReader;
+
+type BoxedHandler = Arc<
+    dyn Fn(Request<Body>) -> BoxFuture<'static, Response<Body>> + Send + Sync,
+>;
+
+/// A Rocket route handler that passes requests to one of the Axum handlers.
+#[derive(Clone)]
+struct LeptosHandler(BoxedHandler);
+
+#[rocket::async_trait]
+impl Handler for LeptosHandler {
+    async fn handle<'r>(
+        &self,
+        req: &'r rocket::Request<'_>,
+        data: Data<'r>,
+    ) -> Outcome<'r> {
+        match to_axum_request(req, data).await {
+            Ok(req) => {
+                Outcome::Success(to_rocket_response((self.0)(req).await))
+            }
+            Err(status) => Outcome::Error(status),
+        }
+    }
+}
+
+/// Converts a Rocket request into the request type used by server functions.
+async fn to_axum_request(
+    req: &rocket::Request<'_>,
+    data: Data<'_>,
+) -> Result<Request<Body>, Status> {
+    let limit = req.limits().get("leptos").unwrap_or(1.mebibytes());
+    let body = data
+        .open(limit)
+        .into_bytes()
+        .await
+        .map_err(|_| Status::BadRequest)?;
+    if !body.is_complete() {
+        return Err(Status::PayloadTooLarge);
+    }
+
+    let mut builder = Request::builder()
+        .method(req.method().as_str())
+        .uri(req.uri().to_string());
+    for header in req.headers().iter() {
+        builder = builder.header(header.name().as_str(), header.value());
+    }
+    builder
+        .body(Body::from(body.into_inner()))
+        .map_err(|_| Status::BadRequest)
+}
+
+/// Converts a response from the Axum handlers into a Rocket response.
+fn to_rocket_response<'r>(res: Response<Body>) -> rocket::Response<'r> {
+    let (parts, body) = res.into_parts();
+    let mut builder = rocket::Response::build();
+    builder.status(Status::new(parts.status.as_u16()));
+    for (name, value) in parts.headers.iter() {
+        if let Ok(value) = value.to_str() {
+            builder.raw_header_adjoin(name.to_string(), value.to_string());
+        }
+    }
+    builder.streamed_body(StreamReader::new(
+        body.into_data_stream().map_err(io::Error::other),
+    ));
+    builder.finalize()
+}
+
+/// Converts a path from the route list into a Rocket path.
+fn to_rocket_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if let Some(name) =
+                segment.strip_prefix("{*").and_then(|s| s.strip_suffix('}'))
+            {
+                format!("<{name}..>")
+            } else if let Some(name) =
+                segment.strip_prefix('{').and_then(|s| s.strip_suffix('}'))
+            {
+                format!("<{name}>")
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn to_method(method: leptos_router::Method) -> Method {
+    match method {
+        leptos_router::Method::Get => Method::Get,
+        leptos_router::Method::Post => Method::Post,
+        leptos_router::Method::Put => Method::Put,
+        leptos_router::Method::Delete => Method::Delete,
+        leptos_router::Method::Patch => Method::Patch,
+    }
+}
+
+/// Returns Rocket routes for every registered server function.
+///
+/// These are included in [`leptos_routes`], so this is only needed if you are mounting your
+/// Leptos routes some other way.
+///
+/// ## Provided Context Types
+/// These routes always provide context values including the following types:
+/// - [`Parts`](axum::http::request::Parts)
+/// - [`ResponseOptions`]
+pub fn server_fn_routes(
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+) -> Vec<Route> {
+    server_fn_routes_excluding(additional_context, &[])
+}
+
+fn server_fn_routes_excluding(
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+    excluded: &[&str],
+) -> Vec<Route> {
+    let prefix = server_fn::client::get_server_fn_path_prefix();
+    server_fn::axum::server_fn_paths()
+        .filter(|(path, _)| !excluded.contains(path))
+        .map(|(path, method)| {
+            let method =
+                method.as_str().parse::<Method>().unwrap_or_else(|_| {
+                    panic!(
+                        "Unsupported server function HTTP method: {method:?}"
+                    )
+                });
+            let additional_context = additional_context.clone();
+            let handler: BoxedHandler = Arc::new(move |req| {
+                let additional_context = additional_context.clone();
+                Box::pin(async move {
+                    leptos_axum::handle_server_fns_with_context(
+                        additional_context,
+                        req,
+                    )
+                    .await
+                    .into_response()
+                })
+            });
+            Route::new(
+                method,
+                &format!("{prefix}{path}"),
+                LeptosHandler(handler),
+            )
+        })
+        .collect()
+}
+
+/// Returns Rocket routes for every path generated by the Leptos router, along with
+/// [`server_fn_routes`].
+///
+/// These routes can be mounted at `/` with [`Rocket::mount`], or with
+/// [`LeptosRoutes::leptos_routes`].
+pub fn leptos_routes<IV>(
+    options: &LeptosOptions,
+    paths: Vec<AxumRouteListing>,
+    app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+) -> Vec<Route>
+where
+    IV: IntoView + 'static,
+{
+    leptos_routes_with_context(options, paths, || {}, app_fn)
+}
+
+/// Returns Rocket routes like [`leptos_routes`], providing additional context to both the app
+/// and its server functions.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", fields(error), skip_all)
+)]
+pub fn leptos_routes_with_context<IV>(
+    options: &LeptosOptions,
+    paths: Vec<AxumRouteListing>,
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+    app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+) -> Vec<Route>
+where
+    IV: IntoView + 'static,
+{
+    _ = any_spawner::Executor::init_tokio();
+
+    let excluded = paths
+        .iter()
+        .filter(|p| p.is_excluded())
+        .map(|p| p.path())
+        .collect::<Vec<_>>();
+    let mut routes =
+        server_fn_routes_excluding(additional_context.clone(), &excluded);
+
+    for listing in paths.iter().filter(|p| !p.is_excluded()) {
+        for method in listing.methods() {
+            let render = leptos_axum::render_route_listing(
+                listing,
+                method,
+                options.clone(),
+                additional_context.clone(),
+                app_fn.clone(),
+            );
+            let handler: BoxedHandler = Arc::new(move |req| render(req));
+            routes.push(Route::new(
+                to_method(method),
+                &to_rocket_path(listing.path()),
+                LeptosHandler(handler),
+            ));
+        }
+    }
+
+    routes
+}
+
+/// This trait allows one to pass a list of routes and a render function to Rocket,
+/// letting us avoid having to use wildcards or manually define all routes in multiple places.
+pub trait LeptosRoutes {
+    /// Mounts the routes that have been generated by the Leptos router at `/`, along with
+    /// handlers for every registered server function.
+    fn leptos_routes<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static;
+
+    /// Mounts the routes like [`leptos_routes`](LeptosRoutes::leptos_routes), providing
+    /// additional context to both the app and its server functions.
+    fn leptos_routes_with_context<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        additional_context: impl Fn() + 'static + Clone + Send + Sync,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static;
+}
+
+impl LeptosRoutes for Rocket<Build> {
+    fn leptos_routes<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        self.mount("/", leptos_routes(options, paths, app_fn))
+    }
+
+    fn leptos_routes_with_context<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        additional_context: impl Fn() + 'static + Clone + Send + Sync,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        self.mount(
+            "/",
+            leptos_routes_with_context(
+                options,
+                paths,
+                additional_context,
+                app_fn,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_rocket_path;
+
+    #[test]
+    fn converts_params_and_splats() {
+        assert_eq!(to_rocket_path("/"), "/");
+        assert_eq!(to_rocket_path("/users/{id}"), "/users/<id>");
+        assert_eq!(to_rocket_path("/files/{*rest}"), "/files/<rest..>");
+    }
+}
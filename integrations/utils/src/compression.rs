@@ -0,0 +1,127 @@
+//! Compression for streamed HTML responses.
+//!
+//! A generic compression layer may buffer its output until it has enough data to compress
+//! efficiently, which holds back the shell and each out-of-order chunk. Here, the encoder is
+//! flushed after every chunk of the stream, so that the browser can decode and render each chunk
+//! as soon as it arrives.
+
+use futures::{stream, Stream, StreamExt};
+use std::io::Write;
+
+/// A `Content-Encoding` that a streamed response can be compressed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Brotli compression.
+    Brotli,
+    /// Gzip compression.
+    Gzip,
+}
+
+impl Encoding {
+    /// Chooses an encoding from the value of an `Accept-Encoding` header, preferring Brotli.
+    ///
+    /// Returns `None` if the client does not accept either encoding.
+    pub fn negotiate(accept_encoding: &str) -> Option<Encoding> {
+        let mut brotli = false;
+        let mut gzip = false;
+        for coding in accept_encoding.split(',') {
+            let mut parts = coding.split(';').map(str::trim);
+            let name = parts.next().unwrap_or_default();
+            // a quality of 0 means that the encoding is not acceptable
+            let refused = parts.any(|param| {
+                param
+                    .strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q == 0.0)
+            });
+            if refused {
+                continue;
+            }
+            match name.to_ascii_lowercase().as_str() {
+                "br" => brotli = true,
+                "gzip" => gzip = true,
+                _ => {}
+            }
+        }
+        if brotli {
+            Some(Encoding::Brotli)
+        } else if gzip {
+            Some(Encoding::Gzip)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the `Content-Encoding` header for this encoding.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Brotli => "br",
+            Encoding::Gzip => "gzip",
+        }
+    }
+}
+
+enum Encoder {
+    Brotli(Box<brotli::CompressorWriter<Vec<u8>>>),
+    Gzip(flate2::write::GzEncoder<Vec<u8>>),
+}
+
+impl Encoder {
+    fn new(encoding: Encoding) -> Self {
+        match encoding {
+            // a lower quality than the default, which is too slow to use for every response
+            Encoding::Brotli => Encoder::Brotli(Box::new(
+                brotli::CompressorWriter::new(Vec::new(), 4096, 5, 22),
+            )),
+            Encoding::Gzip => Encoder::Gzip(flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::fast(),
+            )),
+        }
+    }
+
+    /// Compresses a chunk, returning all of the output that is needed to decode it.
+    fn write_chunk(&mut self, chunk: &[u8]) -> Vec<u8> {
+        // writing to a Vec<u8> cannot fail
+        match self {
+            Encoder::Brotli(encoder) => {
+                _ = encoder.write_all(chunk);
+                _ = encoder.flush();
+                std::mem::take(encoder.get_mut())
+            }
+            Encoder::Gzip(encoder) => {
+                _ = encoder.write_all(chunk);
+                _ = encoder.flush();
+                std::mem::take(encoder.get_mut())
+            }
+        }
+    }
+
+    /// Finishes the compressed stream, returning the remaining output.
+    fn finish(self) -> Vec<u8> {
+        match self {
+            Encoder::Brotli(encoder) => encoder.into_inner(),
+            Encoder::Gzip(encoder) => encoder.finish().unwrap_or_default(),
+        }
+    }
+}
+
+/// Compresses a stream of HTML, flushing the encoder after every chunk.
+pub fn compress_stream(
+    stream: impl Stream<Item = String> + Send + 'static,
+    encoding: Encoding,
+) -> impl Stream<Item = Vec<u8>> + Send {
+    stream::unfold(
+        (Box::pin(stream), Some(Encoder::new(encoding))),
+        |(mut stream, encoder)| async move {
+            let mut encoder = encoder?;
+            match stream.next().await {
+                Some(chunk) => {
+                    let output = encoder.write_chunk(chunk.as_bytes());
+                    Some((output, (stream, Some(encoder))))
+                }
+                None => Some((encoder.finish(), (stream, None))),
+            }
+        },
+    )
+}
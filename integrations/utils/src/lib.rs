@@ -1,17 +1,30 @@
 #![allow(clippy::type_complexity)]
 
-use futures::{stream::once, Stream, StreamExt};
+use futures::{future::ready, stream::once, FutureExt, Stream, StreamExt};
 use hydration_context::{SharedContext, SsrSharedContext};
 use leptos::{
-    context::provide_context,
+    context::{provide_context, use_context},
+    hydration::AssetFileNames,
     nonce::use_nonce,
     prelude::ReadValue,
     reactive::owner::{Owner, Sandboxed},
+    tachys::ssr::StreamBuilder,
     IntoView, PrefetchLazyFn, WasmSplitManifest,
 };
 use leptos_config::LeptosOptions;
 use leptos_meta::{Link, ServerMetaContextOutput};
-use std::{future::Future, pin::Pin, sync::Arc};
+use or_poisoned::OrPoisoned;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+#[cfg(feature = "compression")]
+pub mod compression;
+pub mod shutdown;
+#[cfg(feature = "tracing")]
+pub mod trace;
 
 pub type PinnedStream<T> = Pin<Box<dyn Stream<Item = T> + Send>>;
 pub type PinnedFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
@@ -23,6 +36,32 @@ pub trait ExtendResponse: Sized {
     fn from_stream(stream: impl Stream<Item = String> + Send + 'static)
         -> Self;
 
+    /// Chooses how to compress the response, based on the current request, which has been
+    /// provided via context.
+    ///
+    /// By default, responses are not compressed. This has a default so that enabling the
+    /// `compression` feature does not break integrations that do not support it.
+    #[cfg(feature = "compression")]
+    fn accepted_encoding() -> Option<compression::Encoding> {
+        None
+    }
+
+    /// Creates a response from a compressed stream, setting the `Content-Encoding` header.
+    ///
+    /// This is only called with an encoding returned by
+    /// [`accepted_encoding`](Self::accepted_encoding), so it must be implemented along with it.
+    #[cfg(feature = "compression")]
+    fn from_compressed_stream(
+        stream: impl Stream<Item = Vec<u8>> + Send + 'static,
+        encoding: compression::Encoding,
+    ) -> Self {
+        _ = stream;
+        unreachable!(
+            "from_compressed_stream() called with {encoding:?}, but \
+             accepted_encoding() is not implemented"
+        )
+    }
+
     fn extend_response(&mut self, opt: &Self::ResponseOptions);
 
     fn set_default_content_type(&mut self, content_type: &str);
@@ -43,6 +82,8 @@ pub trait ExtendResponse: Sized {
         IV: IntoView + 'static,
     {
         async move {
+            #[cfg(feature = "tracing")]
+            let span = tracing::Span::current();
             let prefetches = PrefetchLazyFn::default();
 
             let (owner, stream) = build_response(
@@ -56,6 +97,9 @@ pub trait ExtendResponse: Sized {
 
             let sc = owner.shared_context().unwrap();
 
+            #[cfg(feature = "compression")]
+            let encoding = owner.with(Self::accepted_encoding);
+
             let stream = stream.await.ready_chunks(32).map(|n| n.join(""));
 
             while let Some(pending) = sc.await_deferred() {
@@ -115,16 +159,36 @@ pub trait ExtendResponse: Sized {
             // wait for the first chunk of the stream, then set the status and headers
             let first_chunk = stream.next().await.unwrap_or_default();
 
-            let mut res = Self::from_stream(Sandboxed::new(
-                once(async move { first_chunk })
-                    .chain(stream)
-                    // drop the owner, cleaning up the reactive runtime,
-                    // once the stream is over
-                    .chain(once(async move {
-                        owner.unset_with_forced_cleanup();
-                        Default::default()
-                    })),
-            ));
+            let nonce = owner.with(use_nonce).map(|nonce| nonce.to_string());
+            let stream = Sandboxed::new(
+                shutdown::drain_on_shutdown(
+                    once(async move { first_chunk }).chain(stream),
+                    nonce,
+                )
+                // drop the owner, cleaning up the reactive runtime,
+                // once the stream is over
+                .chain(once(async move {
+                    owner.unset_with_forced_cleanup();
+                    Default::default()
+                })),
+            );
+            // the rest of the response is sent after this future has resolved, so the
+            // span is entered again each time the stream is polled
+            #[cfg(feature = "tracing")]
+            let stream = trace::in_span(stream, span);
+
+            // each chunk is compressed and flushed separately, so that out-of-order chunks
+            // are not held back by the encoder
+            #[cfg(feature = "compression")]
+            let mut res = match encoding {
+                Some(encoding) => Self::from_compressed_stream(
+                    compression::compress_stream(stream, encoding),
+                    encoding,
+                ),
+                None => Self::from_stream(stream),
+            };
+            #[cfg(not(feature = "compression"))]
+            let mut res = Self::from_stream(stream);
 
             res.extend_response(&res_options);
 
@@ -189,8 +253,9 @@ where
                 // the problem is that during the DOM walk, that would be mean random <script> tags
                 // interspersed where we expect other children
                 //
-                // we also don't actually start hydrating until after the whole stream is complete,
-                // so it's not useful to send those scripts down earlier.
+                // out-of-order streams send the initial data right after the shell instead (see
+                // `stream_out_of_order`), but the client still waits for the whole stream
+                // before it begins hydrating
                 stream_builder(app, chunks, is_islands_router_navigation)
             });
 
@@ -209,3 +274,113 @@ pub fn static_file_path(options: &LeptosOptions, path: &str) -> String {
     };
     format!("{}/{}.html", options.site_root, path)
 }
+
+/// Provides request-scoped values, like a database pool, an authenticated session or the
+/// user's locale, to the reactive system before a route is rendered or a server function runs.
+///
+/// `Req` is the request type that the integration provides via context (Axum's
+/// [`Parts`](https://docs.rs/http/latest/http/request/struct.Parts.html) or Actix's
+/// `HttpRequest`). Any `Fn(&Req)` implements this trait, so the values can be built from the
+/// request directly, instead of each component calling `use_context` for the request itself.
+pub trait RequestContext<Req>: Send + Sync + 'static {
+    /// Calls [`provide_context`] for each value that should be available while handling `req`.
+    fn provide(&self, req: &Req);
+}
+
+impl<Req, F> RequestContext<Req> for F
+where
+    F: Fn(&Req) + Send + Sync + 'static,
+{
+    fn provide(&self, req: &Req) {
+        self(req)
+    }
+}
+
+/// Turns a [`RequestContext`] into an `additional_context` function that can be passed to the
+/// `_with_context` functions of an integration.
+///
+/// The function reads the request of type `Req` from context, so it must run after the
+/// integration has provided it. If there is no request (for example, while the route list is
+/// being generated), nothing is provided.
+pub fn request_context<Req>(
+    provider: impl RequestContext<Req>,
+) -> impl Fn() + Clone + Send + Sync + 'static
+where
+    Req: Clone + 'static,
+{
+    let provider = Arc::new(provider);
+    move || {
+        if let Some(req) = use_context::<Req>() {
+            provider.provide(&req);
+        }
+    }
+}
+
+/// Returns the values of the `Link` headers that list the app's critical assets, if
+/// [`preload_link_headers`](LeptosOptions::preload_link_headers) is enabled.
+///
+/// Integrations compute these once, when the routes are created, and add them to each
+/// server-rendered page, so that the browser can start fetching the JS, WASM and CSS files
+/// before the page has been rendered. The CSS file is only listed if it exists.
+pub fn preload_link_headers(options: &LeptosOptions) -> Vec<String> {
+    if !options.preload_link_headers {
+        return Vec::new();
+    }
+
+    let root = options
+        .site_base_path
+        .as_deref()
+        .unwrap_or_default()
+        .trim_end_matches('/');
+    let pkg_dir = &options.site_pkg_dir;
+    let names = AssetFileNames::new(options);
+
+    let mut links = vec![
+        format!("<{root}/{pkg_dir}/{}.js>; rel=modulepreload", names.js),
+        format!(
+            "<{root}/{pkg_dir}/{}.wasm>; rel=preload; as=fetch; \
+             type=\"application/wasm\"; crossorigin",
+            names.wasm
+        ),
+    ];
+    let css_file = std::path::Path::new(&*options.site_root)
+        .join(&**pkg_dir)
+        .join(format!("{}.css", names.css));
+    if css_file.exists() {
+        links.push(format!(
+            "<{root}/{pkg_dir}/{}.css>; rel=preload; as=style",
+            names.css
+        ));
+    }
+    links
+}
+
+/// Chains an out-of-order app stream with its serialized data.
+///
+/// The initial data chunk is sent as soon as the shell of the app has been rendered. Resources
+/// that resolve later are sent once the rest of the app stream is complete. The client begins
+/// hydrating once the whole stream has arrived.
+pub fn stream_out_of_order(
+    mut app: StreamBuilder,
+    chunks: BoxedFnOnce<PinnedStream<String>>,
+) -> PinnedStream<String> {
+    let rest = Arc::new(Mutex::new(None::<PinnedStream<String>>));
+    app.on_shell_complete({
+        let rest = Arc::clone(&rest);
+        move || {
+            let mut chunks = chunks();
+            // the initial chunk is always ready immediately
+            let initial =
+                chunks.next().now_or_never().flatten().unwrap_or_default();
+            *rest.lock().or_poisoned() = Some(chunks);
+            initial
+        }
+    });
+    Box::pin(
+        app.chain(
+            once(async move { rest.lock().or_poisoned().take() })
+                .filter_map(ready)
+                .flatten(),
+        ),
+    )
+}
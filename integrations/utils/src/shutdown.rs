@@ -0,0 +1,218 @@
+//! Draining server-rendered responses when the server shuts down.
+//!
+//! Every HTML stream created by an integration is tracked while it is in flight. When
+//! [`begin_shutdown`] is called, streams are given a grace period to finish sending their
+//! remaining chunks. Any stream that is still pending at the end of the grace period (for
+//! example, because it is waiting on a slow `<Suspense/>`) sends a script that reloads the page,
+//! and then ends, so that the connection can be closed cleanly instead of being cut mid-stream.
+//!
+//! ```rust,ignore
+//! axum::serve(listener, app)
+//!     .with_graceful_shutdown(async {
+//!         tokio::signal::ctrl_c().await.unwrap();
+//!         leptos_axum::begin_shutdown(Duration::from_secs(10));
+//!         leptos_axum::drained().await;
+//!     })
+//!     .await
+//! ```
+//!
+//! Reloading the page only helps if another server (or the restarted one) can answer the new
+//! request, as during a rolling deployment. Streams that start after the grace period has ended
+//! are not drained, so a reload that reaches the same server is answered normally rather than
+//! reloading again. The reload script is only sent after the first chunk of a stream.
+
+use any_spawner::Executor;
+use futures::{
+    channel::oneshot,
+    future::{select, BoxFuture, Either, FutureExt, Shared},
+    stream, Stream, StreamExt,
+};
+use or_poisoned::OrPoisoned;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        LazyLock, Mutex, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+struct ShutdownState {
+    started: AtomicBool,
+    in_flight: AtomicUsize,
+    deadline_at: OnceLock<Instant>,
+    deadline_tx: Mutex<Option<oneshot::Sender<Instant>>>,
+    deadline: Shared<BoxFuture<'static, ()>>,
+    drained_tx: Mutex<Option<oneshot::Sender<()>>>,
+    drained: Shared<oneshot::Receiver<()>>,
+}
+
+static STATE: LazyLock<ShutdownState> = LazyLock::new(|| {
+    let (deadline_tx, deadline) = oneshot::channel();
+    let (drained_tx, drained) = oneshot::channel();
+    ShutdownState {
+        started: AtomicBool::new(false),
+        in_flight: AtomicUsize::new(0),
+        deadline_at: OnceLock::new(),
+        deadline_tx: Mutex::new(Some(deadline_tx)),
+        deadline: async move {
+            match deadline.await {
+                Ok(at) => {
+                    Executor::sleep(
+                        at.saturating_duration_since(Instant::now()),
+                    )
+                    .await
+                }
+                Err(_) => futures::future::pending().await,
+            }
+        }
+        .boxed()
+        .shared(),
+        drained_tx: Mutex::new(Some(drained_tx)),
+        drained: drained.shared(),
+    }
+});
+
+impl ShutdownState {
+    fn notify_drained(&self) {
+        if let Some(tx) = self.drained_tx.lock().or_poisoned().take() {
+            _ = tx.send(());
+        }
+    }
+}
+
+/// Counts a stream as in flight for as long as it is alive.
+struct InFlight;
+
+impl InFlight {
+    fn new() -> Self {
+        STATE.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlight
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if STATE.in_flight.fetch_sub(1, Ordering::SeqCst) == 1
+            && STATE.started.load(Ordering::SeqCst)
+        {
+            STATE.notify_drained();
+        }
+    }
+}
+
+/// Starts draining in-flight HTML streams, which are ended once the grace period has passed.
+///
+/// Only the first call has any effect. The grace period is measured with
+/// [`Executor::sleep`], while the in-flight streams wait for it.
+pub fn begin_shutdown(grace_period: Duration) {
+    if STATE.started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    if STATE.in_flight.load(Ordering::SeqCst) == 0 {
+        STATE.notify_drained();
+    }
+    let deadline = Instant::now() + grace_period;
+    _ = STATE.deadline_at.set(deadline);
+    if let Some(tx) = STATE.deadline_tx.lock().or_poisoned().take() {
+        _ = tx.send(deadline);
+    }
+}
+
+/// Whether [`begin_shutdown`] has been called.
+pub fn is_shutting_down() -> bool {
+    STATE.started.load(Ordering::SeqCst)
+}
+
+/// Resolves once shutdown has begun and every in-flight HTML stream has ended.
+pub async fn drained() {
+    _ = STATE.drained.clone().await;
+}
+
+/// Tracks an HTML stream, so that it can be drained when the server shuts down.
+///
+/// If the grace period ends before the stream does, a script that reloads the page is sent (with
+/// the given nonce, if any) and the stream ends. Streams that start once the grace period has
+/// already ended are passed through unchanged.
+pub fn drain_on_shutdown(
+    stream: impl Stream<Item = String> + Send + 'static,
+    nonce: Option<String>,
+) -> impl Stream<Item = String> + Send + 'static {
+    if STATE
+        .deadline_at
+        .get()
+        .is_some_and(|deadline| Instant::now() >= *deadline)
+    {
+        return stream.boxed();
+    }
+    let state = (
+        Box::pin(stream),
+        STATE.deadline.clone(),
+        Some(InFlight::new()),
+        true,
+    );
+    stream::unfold(state, move |(mut stream, mut deadline, guard, first)| {
+        let nonce = nonce.clone();
+        async move {
+            let guard = guard?;
+            // the first chunk is always sent, so that the page never only contains the script
+            let next = if first {
+                Some(stream.next().await)
+            } else {
+                match select(stream.next(), &mut deadline).await {
+                    Either::Left((chunk, _)) => Some(chunk),
+                    Either::Right(_) => None,
+                }
+            };
+            match next {
+                Some(Some(chunk)) => {
+                    Some((chunk, (stream, deadline, Some(guard), false)))
+                }
+                Some(None) => None,
+                None => {
+                    let nonce = nonce
+                        .map(|nonce| format!(" nonce=\"{nonce}\""))
+                        .unwrap_or_default();
+                    let script = format!(
+                        "<script{nonce}>window.location.reload()</script>"
+                    );
+                    Some((script, (stream, deadline, None, false)))
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn pending_streams_reload_after_the_grace_period() {
+        let finished =
+            drain_on_shutdown(stream::iter(["<p>done</p>".to_string()]), None);
+        let pending = drain_on_shutdown(
+            stream::once(async { "<p>shell</p>".to_string() })
+                .chain(stream::pending()),
+            Some("abc".into()),
+        );
+
+        assert_eq!(block_on(finished.collect::<Vec<_>>()), ["<p>done</p>"]);
+        begin_shutdown(Duration::from_millis(10));
+        assert!(is_shutting_down());
+        assert_eq!(
+            block_on(pending.collect::<Vec<_>>()),
+            [
+                "<p>shell</p>",
+                "<script nonce=\"abc\">window.location.reload()</script>"
+            ]
+        );
+        block_on(drained());
+
+        // a reload that reaches this server after the grace period is not drained again
+        let reloaded =
+            drain_on_shutdown(stream::iter(["<p>page</p>".to_string()]), None);
+        assert_eq!(block_on(reloaded.collect::<Vec<_>>()), ["<p>page</p>"]);
+    }
+}
@@ -0,0 +1,107 @@
+//! [`tracing`] spans for server-side rendering and server functions.
+//!
+//! With the `tracing` feature enabled, integrations open a span for each page they render and
+//! each server function they run. The span carries the request's [`RequestId`], and stays
+//! entered while the HTML stream is being sent, so that the spans `leptos` creates for
+//! `<Suspense/>` boundaries and resources (with its own `tracing` feature, which is enabled
+//! separately) are nested inside it. Exporting these spans (for
+//! example, with `tracing-opentelemetry`) shows where the time spent rendering a page goes.
+
+use futures::{stream, Stream, StreamExt};
+use leptos::context::use_context;
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, LazyLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::Span;
+
+/// The header from which the ID of a request is read, if the client or a proxy has set it.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Identifies the current request in traces. It is provided via context.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RequestId(Arc<str>);
+
+impl RequestId {
+    /// Uses the value of the [`REQUEST_ID_HEADER`], if there is one, or generates a new ID.
+    pub fn new(header: Option<&str>) -> Self {
+        static PREFIX: LazyLock<u64> = LazyLock::new(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_secs())
+                .unwrap_or_default()
+        });
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        match header.filter(|id| !id.is_empty()) {
+            Some(id) => RequestId(id.into()),
+            None => {
+                let next = NEXT.fetch_add(1, Ordering::Relaxed);
+                RequestId(format!("{:x}-{next:x}", *PREFIX).into())
+            }
+        }
+    }
+
+    /// The ID, as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Returns the ID of the current request, if it is being traced.
+pub fn use_request_id() -> Option<RequestId> {
+    use_context::<RequestId>()
+}
+
+/// Creates the span in which a page is rendered.
+pub fn render_span(method: &str, path: &str, request_id: &RequestId) -> Span {
+    tracing::info_span!(
+        "render",
+        http.request.method = method,
+        url.path = path,
+        request_id = %request_id,
+    )
+}
+
+/// Creates the span in which a server function runs.
+pub fn server_fn_span(path: &str, request_id: &RequestId) -> Span {
+    tracing::info_span!(
+        "server_fn",
+        url.path = path,
+        request_id = %request_id,
+    )
+}
+
+/// Enters the span whenever the stream is polled.
+pub fn in_span<T>(
+    stream: impl Stream<Item = T> + Send + 'static,
+    span: Span,
+) -> impl Stream<Item = T> + Send + 'static {
+    let mut stream = Box::pin(stream);
+    stream::poll_fn(move |cx| {
+        let _guard = span.enter();
+        stream.poll_next_unpin(cx)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_ids_come_from_the_header_or_are_unique() {
+        assert_eq!(RequestId::new(Some("abc")).as_str(), "abc");
+        assert_ne!(RequestId::new(None), RequestId::new(None));
+        assert_ne!(RequestId::new(Some("")).as_str(), "");
+    }
+}
@@ -0,0 +1,236 @@
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+//! Provides functions to easily integrate Leptos with [Viz](https://viz.rs).
+//!
+//! Server functions use the same request and response types as the Axum integration, so this
+//! crate converts each Viz request into an Axum request and reuses the rendering and server
+//! function handlers from [`leptos_axum`]. Anything that works with those handlers, like
+//! [`ResponseOptions`], [`redirect`] and [`extract`], works here too.
+//!
+//! ```rust,ignore
+//! use leptos::prelude::*;
+//! use leptos_viz::{generate_route_list, LeptosRoutes};
+//!
+//! let conf = get_configuration(None).unwrap();
+//! let leptos_options = conf.leptos_options;
+//! let routes = generate_route_list(App);
+//!
+//! let app = viz::Router::new()
+//!     .leptos_routes(&leptos_options, routes, {
+//!         let leptos_options = leptos_options.clone();
+//!         move || shell(leptos_options.clone())
+//!     });
+//! ```
+//!
+//! ## Features
+//! - `compression`: compresses server-rendered HTML with Brotli or gzip. See [`leptos_axum`].
+//! - `islands-router`: enables the islands router.
+//!
+//! Static files, like the `/pkg` directory, should be served with Viz’s own
+//! [`ServeDir`](https://docs.rs/viz/latest/viz/handlers/serve/struct.ServeDir.html).
+
+use axum::{body::Body, response::IntoResponse};
+use futures::TryStreamExt;
+use http_body_util::BodyExt;
+use leptos::{config::LeptosOptions, IntoView};
+pub use leptos_axum::{
+    extract, generate_route_list, generate_route_list_with_exclusions,
+    redirect, redirect_with_options, request_context, AxumRouteListing,
+    RequestContext, ResponseOptions, ResponseParts,
+};
+use std::{collections::HashMap, io};
+use viz::{Method, Request, Response, ResponseExt, Route, Router};
+
+/// Converts a Viz request into the request type used by server functions.
+fn to_axum_request(req: Request) -> axum::http::Request<Body> {
+    let (parts, body) = req.into_parts();
+    let body = Body::from_stream(
+        body.into_data_stream()
+            .map_err(|e| io::Error::other(e.to_string())),
+    );
+    axum::http::Request::from_parts(parts, body)
+}
+
+/// Converts a response from the Axum handlers into a Viz response.
+fn to_viz_response(res: axum::http::Response<Body>) -> Response {
+    let (parts, body) = res.into_parts();
+    let mut res = Response::stream(body.into_data_stream());
+    *res.status_mut() = parts.status;
+    *res.headers_mut() = parts.headers;
+    res
+}
+
+/// A Viz handler for server functions.
+///
+/// This can be mounted on the paths returned by [`server_fn::axum::server_fn_paths`], but it is
+/// usually simpler to use [`LeptosRoutes::leptos_routes`], which registers it for you.
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [`Parts`](axum::http::request::Parts)
+/// - [`ResponseOptions`]
+pub async fn handle_server_fns(req: Request) -> viz::Result<Response> {
+    handle_server_fns_with_context(|| {}, req).await
+}
+
+/// A Viz handler for server functions, which provides additional context to the server
+/// function.
+///
+/// **NOTE**: If your server functions expect a context, make sure to provide it both here
+/// **and** in [`leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context).
+pub async fn handle_server_fns_with_context(
+    additional_context: impl Fn() + 'static + Clone + Send,
+    req: Request,
+) -> viz::Result<Response> {
+    let res = leptos_axum::handle_server_fns_with_context(
+        additional_context,
+        to_axum_request(req),
+    )
+    .await;
+    Ok(to_viz_response(res.into_response()))
+}
+
+/// Converts a path from the route list into a Viz path.
+fn to_viz_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if let Some(name) =
+                segment.strip_prefix("{*").and_then(|s| s.strip_suffix('}'))
+            {
+                format!(":{name}*")
+            } else if let Some(name) =
+                segment.strip_prefix('{').and_then(|s| s.strip_suffix('}'))
+            {
+                format!(":{name}")
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn to_method(method: leptos_router::Method) -> Method {
+    match method {
+        leptos_router::Method::Get => Method::GET,
+        leptos_router::Method::Post => Method::POST,
+        leptos_router::Method::Put => Method::PUT,
+        leptos_router::Method::Delete => Method::DELETE,
+        leptos_router::Method::Patch => Method::PATCH,
+    }
+}
+
+/// This trait allows one to pass a list of routes and a render function to Viz's router,
+/// letting us avoid having to use wildcards or manually define all routes in multiple places.
+pub trait LeptosRoutes {
+    /// Adds routes to the Viz router that have been generated by the Leptos router, along with
+    /// handlers for every registered server function.
+    fn leptos_routes<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static;
+
+    /// Adds routes to the Viz router, like [`leptos_routes`](LeptosRoutes::leptos_routes),
+    /// providing additional context to both the app and its server functions.
+    fn leptos_routes_with_context<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        additional_context: impl Fn() + 'static + Clone + Send + Sync,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static;
+}
+
+impl LeptosRoutes for Router {
+    fn leptos_routes<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        self.leptos_routes_with_context(options, paths, || {}, app_fn)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", fields(error), skip_all)
+    )]
+    fn leptos_routes_with_context<IV>(
+        self,
+        options: &LeptosOptions,
+        paths: Vec<AxumRouteListing>,
+        additional_context: impl Fn() + 'static + Clone + Send + Sync,
+        app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        _ = any_spawner::Executor::init_tokio();
+
+        // Viz expects every method for a path to be registered on the same route
+        let mut routes = HashMap::<String, Route>::new();
+
+        let excluded = paths
+            .iter()
+            .filter(|p| p.is_excluded())
+            .map(|p| p.path())
+            .collect::<Vec<_>>();
+        let prefix = server_fn::client::get_server_fn_path_prefix();
+        for (path, method) in server_fn::axum::server_fn_paths() {
+            if excluded.contains(&path) {
+                continue;
+            }
+            let additional_context = additional_context.clone();
+            let handler = move |req: Request| {
+                handle_server_fns_with_context(additional_context.clone(), req)
+            };
+            let route = routes.entry(format!("{prefix}{path}")).or_default();
+            *route = std::mem::take(route).on(method, handler);
+        }
+
+        for listing in paths.iter().filter(|p| !p.is_excluded()) {
+            for method in listing.methods() {
+                let render = leptos_axum::render_route_listing(
+                    listing,
+                    method,
+                    options.clone(),
+                    additional_context.clone(),
+                    app_fn.clone(),
+                );
+                let handler = move |req: Request| {
+                    let res = render(to_axum_request(req));
+                    async move { viz::Result::Ok(to_viz_response(res.await)) }
+                };
+                let route =
+                    routes.entry(to_viz_path(listing.path())).or_default();
+                *route = std::mem::take(route).on(to_method(method), handler);
+            }
+        }
+
+        routes
+            .into_iter()
+            .fold(self, |router, (path, route)| router.route(&path, route))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_viz_path;
+
+    #[test]
+    fn converts_params_and_splats() {
+        assert_eq!(to_viz_path("/"), "/");
+        assert_eq!(to_viz_path("/users/{id}"), "/users/:id");
+        assert_eq!(to_viz_path("/files/{*rest}"), "/files/:rest*");
+    }
+}
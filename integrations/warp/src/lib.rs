@@ -0,0 +1,353 @@
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+//! Provides functions to easily integrate Leptos with [Warp](https://docs.rs/warp).
+//!
+//! Server functions use the same request and response types as the Axum integration, so this
+//! crate converts each Warp request into an Axum request and reuses the rendering and server
+//! function handlers from [`leptos_axum`]. Anything that works with those handlers, like
+//! [`ResponseOptions`], [`redirect`] and [`extract`], works here too.
+//!
+//! ```rust,ignore
+//! use leptos::prelude::*;
+//! use leptos_warp::{generate_route_list, leptos_routes};
+//! use warp::Filter;
+//!
+//! let conf = get_configuration(None).unwrap();
+//! let leptos_options = conf.leptos_options;
+//! let addr = leptos_options.site_addr;
+//! let routes = generate_route_list(App);
+//!
+//! let app = warp::path("pkg")
+//!     .and(warp::fs::dir(format!("{}/pkg", leptos_options.site_root)))
+//!     .or(leptos_routes(&leptos_options, routes, {
+//!         let leptos_options = leptos_options.clone();
+//!         move || shell(leptos_options.clone())
+//!     }));
+//! warp::serve(app).run(addr).await;
+//! ```
+//!
+//! Warp does not match routes by path pattern, so [`leptos_routes`] returns a single filter
+//! that matches the request against the route list itself. Like Axum, it prefers static path
+//! segments to params, and params to wildcards. Request bodies are read in full before they
+//! are passed to a server function.
+//!
+//! ## Features
+//! - `compression`: compresses server-rendered HTML with Brotli or gzip. See [`leptos_axum`].
+//! - `islands-router`: enables the islands router.
+
+use axum::{
+    body::Body,
+    http::{Request, Response},
+    response::IntoResponse,
+};
+use futures::future::{ready, BoxFuture};
+use leptos::{config::LeptosOptions, IntoView};
+pub use leptos_axum::{
+    extract, generate_route_list, generate_route_list_with_exclusions,
+    redirect, redirect_with_options, request_context, AxumRouteListing,
+    RequestContext, ResponseOptions, ResponseParts,
+};
+use std::sync::Arc;
+use warp::{
+    filters::BoxedFilter,
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
+    hyper::body::Bytes,
+    path::FullPath,
+    Filter,
+};
+
+type BoxedHandler = Arc<
+    dyn Fn(Request<Body>) -> BoxFuture<'static, Response<Body>> + Send + Sync,
+>;
+
+/// One route that is handled by Leptos.
+struct LeptosRoute {
+    method: Method,
+    segments: Vec<Segment>,
+    handler: BoxedHandler,
+}
+
+/// One segment of a route's path.
+#[derive(Debug)]
+enum Segment {
+    Static(String),
+    Param,
+    Splat,
+}
+
+/// Splits a path from the route list into its segments.
+fn to_segments(path: &str) -> Vec<Segment> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            if segment.starts_with("{*") {
+                Segment::Splat
+            } else if segment.starts_with('{') {
+                Segment::Param
+            } else {
+                Segment::Static(segment.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Checks whether a request path matches the segments of a route.
+fn matches(segments: &[Segment], path: &str) -> bool {
+    let mut path = path.split('/').filter(|segment| !segment.is_empty());
+    for segment in segments {
+        match (segment, path.next()) {
+            (Segment::Splat, _) => return true,
+            (Segment::Param, Some(_)) => {}
+            (Segment::Static(expected), Some(actual)) if expected == actual => {
+            }
+            _ => return false,
+        }
+    }
+    path.next().is_none()
+}
+
+/// Sorts routes so that the first match for any path is the most specific one.
+fn rank(segments: &[Segment]) -> Vec<u8> {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Static(_) => 0,
+            Segment::Param => 1,
+            Segment::Splat => 2,
+        })
+        .collect()
+}
+
+/// Converts a Warp request into the request type used by server functions.
+fn to_axum_request(
+    method: Method,
+    path: FullPath,
+    query: String,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Option<Request<Body>> {
+    let uri = if query.is_empty() {
+        path.as_str().to_string()
+    } else {
+        format!("{}?{query}", path.as_str())
+    };
+    let mut builder = Request::builder().method(method.as_str()).uri(uri);
+    for (name, value) in headers.iter() {
+        builder = builder.header(name.as_str(), value.as_bytes());
+    }
+    builder.body(Body::from(body)).ok()
+}
+
+/// Converts a response from the Axum handlers into a Warp response.
+fn to_warp_response(res: Response<Body>) -> warp::reply::Response {
+    let (parts, body) = res.into_parts();
+    let mut res = warp::reply::Response::new(warp::hyper::Body::wrap_stream(
+        body.into_data_stream(),
+    ));
+    *res.status_mut() = StatusCode::from_u16(parts.status.as_u16())
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    for (name, value) in parts.headers.iter() {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_str().as_bytes()),
+            HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            res.headers_mut().append(name, value);
+        }
+    }
+    res
+}
+
+/// Returns a filter that passes any request that matches one of the routes to its handler, and
+/// rejects all others.
+fn routes_filter(
+    mut routes: Vec<LeptosRoute>,
+) -> BoxedFilter<(warp::reply::Response,)> {
+    routes.sort_by_cached_key(|route| rank(&route.segments));
+    let routes = Arc::new(routes);
+
+    warp::method()
+        .and(warp::path::full())
+        .and_then(move |method: Method, path: FullPath| {
+            // matches before reading the body, so that other filters can still use it
+            let handler = routes
+                .iter()
+                .find(|route| {
+                    route.method == method
+                        && matches(&route.segments, path.as_str())
+                })
+                .map(|route| Arc::clone(&route.handler))
+                .ok_or_else(warp::reject::not_found);
+            ready(handler)
+        })
+        .and(warp::method())
+        .and(warp::path::full())
+        .and(
+            warp::query::raw()
+                .or(warp::any().map(String::new))
+                .unify(),
+        )
+        .and(warp::header::headers_cloned())
+        .and(warp::body::bytes())
+        .then(
+            |handler: BoxedHandler, method, path, query, headers, body| async move {
+                match to_axum_request(method, path, query, headers, body) {
+                    Some(req) => to_warp_response(handler(req).await),
+                    None => {
+                        let mut res = warp::reply::Response::default();
+                        *res.status_mut() = StatusCode::BAD_REQUEST;
+                        res
+                    }
+                }
+            },
+        )
+        .boxed()
+}
+
+fn server_fn_routes_excluding(
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+    excluded: &[&str],
+) -> Vec<LeptosRoute> {
+    let prefix = server_fn::client::get_server_fn_path_prefix();
+    server_fn::axum::server_fn_paths()
+        .filter(|(path, _)| !excluded.contains(path))
+        .map(|(path, method)| {
+            let additional_context = additional_context.clone();
+            let handler: BoxedHandler = Arc::new(move |req| {
+                let additional_context = additional_context.clone();
+                Box::pin(async move {
+                    leptos_axum::handle_server_fns_with_context(
+                        additional_context,
+                        req,
+                    )
+                    .await
+                    .into_response()
+                })
+            });
+            LeptosRoute {
+                method: Method::from_bytes(method.as_str().as_bytes())
+                    .expect("server function methods are valid"),
+                segments: to_segments(&format!("{prefix}{path}")),
+                handler,
+            }
+        })
+        .collect()
+}
+
+/// Returns a filter that handles every registered server function.
+///
+/// This is included in [`leptos_routes`], so it is only needed if you are serving your Leptos
+/// routes some other way.
+///
+/// ## Provided Context Types
+/// This filter always provides context values including the following types:
+/// - [`Parts`](axum::http::request::Parts)
+/// - [`ResponseOptions`]
+pub fn handle_server_fns() -> BoxedFilter<(warp::reply::Response,)> {
+    handle_server_fns_with_context(|| {})
+}
+
+/// Returns a filter that handles every registered server function, providing additional
+/// context to each of them.
+///
+/// **NOTE**: If your server functions expect a context, make sure to provide it both here
+/// **and** in [`leptos_routes_with_context`].
+pub fn handle_server_fns_with_context(
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+) -> BoxedFilter<(warp::reply::Response,)> {
+    routes_filter(server_fn_routes_excluding(additional_context, &[]))
+}
+
+/// Returns a filter that serves every path generated by the Leptos router, along with
+/// [`handle_server_fns`].
+pub fn leptos_routes<IV>(
+    options: &LeptosOptions,
+    paths: Vec<AxumRouteListing>,
+    app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+) -> BoxedFilter<(warp::reply::Response,)>
+where
+    IV: IntoView + 'static,
+{
+    leptos_routes_with_context(options, paths, || {}, app_fn)
+}
+
+/// Returns a filter like [`leptos_routes`], providing additional context to both the app and
+/// its server functions.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", fields(error), skip_all)
+)]
+pub fn leptos_routes_with_context<IV>(
+    options: &LeptosOptions,
+    paths: Vec<AxumRouteListing>,
+    additional_context: impl Fn() + 'static + Clone + Send + Sync,
+    app_fn: impl Fn() -> IV + Clone + Send + Sync + 'static,
+) -> BoxedFilter<(warp::reply::Response,)>
+where
+    IV: IntoView + 'static,
+{
+    _ = any_spawner::Executor::init_tokio();
+
+    let excluded = paths
+        .iter()
+        .filter(|p| p.is_excluded())
+        .map(|p| p.path())
+        .collect::<Vec<_>>();
+    let mut routes =
+        server_fn_routes_excluding(additional_context.clone(), &excluded);
+
+    for listing in paths.iter().filter(|p| !p.is_excluded()) {
+        for method in listing.methods() {
+            let render = leptos_axum::render_route_listing(
+                listing,
+                method,
+                options.clone(),
+                additional_context.clone(),
+                app_fn.clone(),
+            );
+            routes.push(LeptosRoute {
+                method: match method {
+                    leptos_router::Method::Get => Method::GET,
+                    leptos_router::Method::Post => Method::POST,
+                    leptos_router::Method::Put => Method::PUT,
+                    leptos_router::Method::Delete => Method::DELETE,
+                    leptos_router::Method::Patch => Method::PATCH,
+                },
+                segments: to_segments(listing.path()),
+                handler: Arc::new(move |req| render(req)),
+            });
+        }
+    }
+
+    routes_filter(routes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches, rank, to_segments};
+
+    #[test]
+    fn matches_params_and_splats() {
+        let root = to_segments("/");
+        assert!(matches(&root, "/"));
+        assert!(!matches(&root, "/users"));
+
+        let user = to_segments("/users/{id}");
+        assert!(matches(&user, "/users/1"));
+        assert!(matches(&user, "/users/1/"));
+        assert!(!matches(&user, "/users"));
+        assert!(!matches(&user, "/users/1/posts"));
+
+        let files = to_segments("/files/{*rest}");
+        assert!(matches(&files, "/files"));
+        assert!(matches(&files, "/files/a/b"));
+        assert!(!matches(&files, "/other/a"));
+    }
+
+    #[test]
+    fn ranks_static_segments_first() {
+        let mut paths = vec!["/{*any}", "/users/{id}", "/users/new"];
+        paths.sort_by_key(|path| rank(&to_segments(path)));
+        assert_eq!(paths, ["/users/new", "/users/{id}", "/{*any}"]);
+    }
+}
@@ -0,0 +1,222 @@
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+//! Provides functions to serve a Leptos app from a WASI runtime, using
+//! [WASI HTTP](https://github.com/WebAssembly/wasi-http) (`wasm32-wasip2`).
+//!
+//! The app is routed with an Axum [`Router`], built with [`leptos_axum`] and its `wasi`
+//! feature, which uses a single-threaded executor instead of Tokio. This crate converts each
+//! incoming WASI request into a request for that router, and streams the response back to the
+//! host.
+//!
+//! ```rust,ignore
+//! use leptos::prelude::*;
+//! use leptos_axum::{generate_route_list, LeptosRoutes};
+//! use wasi::http::types::{IncomingRequest, ResponseOutparam};
+//!
+//! struct Component;
+//!
+//! impl wasi::exports::http::incoming_handler::Guest for Component {
+//!     fn handle(request: IncomingRequest, response_out: ResponseOutparam) {
+//!         // server functions cannot be collected automatically in WASM
+//!         server_fn::axum::register_explicit::<GetPosts>();
+//!
+//!         let leptos_options = LeptosOptions::builder().output_name("app").build();
+//!         let routes = generate_route_list(App);
+//!         let router = axum::Router::new()
+//!             .leptos_routes(&leptos_options, routes, {
+//!                 let leptos_options = leptos_options.clone();
+//!                 move || shell(leptos_options.clone())
+//!             })
+//!             .with_state(leptos_options);
+//!
+//!         leptos_wasi::serve(router, request, response_out);
+//!     }
+//! }
+//!
+//! wasi::http::proxy::export!(Component);
+//! ```
+//!
+//! ## Limitations
+//! - Request bodies are read in full before they are passed to the router, and requests with
+//!   a body larger than [`MAX_BODY_SIZE`] are rejected with `413 Payload Too Large`.
+//! - The executor has no way to wait for host I/O, so while nothing has woken the response, the
+//!   thread sleeps for [`IDLE_SLEEP`] between polls.
+//! - Static routes and websocket server functions are not supported, because they rely on
+//!   Tokio.
+
+use any_spawner::Executor;
+use axum::{body::Body, http::Request, Router};
+use futures::StreamExt;
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+    time::Duration,
+};
+use tower::ServiceExt;
+use wasi::{
+    http::types::{
+        Fields, IncomingBody, IncomingRequest, Method, OutgoingBody,
+        OutgoingResponse, ResponseOutparam, Scheme,
+    },
+    io::streams::StreamError,
+};
+
+/// The largest number of bytes that can be written to a WASI stream at once.
+const WRITE_SIZE: usize = 4096;
+/// The number of bytes to read from a WASI stream at once.
+const READ_SIZE: u64 = 64 * 1024;
+/// The largest request body that will be read, which matches the default limit of Axum.
+pub const MAX_BODY_SIZE: usize = 2 * 1024 * 1024;
+/// How long to sleep for when the response is waiting on something other than the executor.
+pub const IDLE_SLEEP: Duration = Duration::from_millis(1);
+
+/// Serves one WASI HTTP request with the given router.
+///
+/// This should be called from the `handle` function of your
+/// `wasi::exports::http::incoming_handler::Guest` implementation. It blocks until the whole
+/// response has been written.
+pub fn serve(
+    router: Router,
+    request: IncomingRequest,
+    response_out: ResponseOutparam,
+) {
+    _ = Executor::init_local_executor();
+
+    let req = match to_axum_request(&request) {
+        Ok(req) => req,
+        Err(status) => {
+            let response = OutgoingResponse::new(Fields::new());
+            _ = response.set_status_code(status);
+            if let Ok(body) = response.body() {
+                ResponseOutparam::set(response_out, Ok(response));
+                _ = OutgoingBody::finish(body, None);
+            }
+            return;
+        }
+    };
+
+    let Ok(res) = block_on(router.oneshot(req));
+    let (parts, body) = res.into_parts();
+
+    // headers that are not allowed in WASI responses are skipped
+    let headers = Fields::new();
+    for (name, value) in parts.headers.iter() {
+        _ = headers.append(name.as_str(), value.as_bytes());
+    }
+    let response = OutgoingResponse::new(headers);
+    _ = response.set_status_code(parts.status.as_u16());
+    let outgoing_body = response
+        .body()
+        .expect("response body can only be taken once");
+    ResponseOutparam::set(response_out, Ok(response));
+
+    let stream = outgoing_body
+        .write()
+        .expect("response stream can only be taken once");
+    let mut chunks = body.into_data_stream();
+    'chunks: while let Some(Ok(chunk)) = block_on(chunks.next()) {
+        for piece in chunk.chunks(WRITE_SIZE) {
+            if stream.blocking_write_and_flush(piece).is_err() {
+                break 'chunks;
+            }
+        }
+    }
+    // the stream must be dropped before the body is finished
+    drop(stream);
+    _ = OutgoingBody::finish(outgoing_body, None);
+}
+
+/// Converts a WASI request into an Axum request, reading its body in full.
+///
+/// Returns the status code of the error response if the request cannot be converted.
+fn to_axum_request(request: &IncomingRequest) -> Result<Request<Body>, u16> {
+    let method = match request.method() {
+        Method::Get => "GET".to_string(),
+        Method::Head => "HEAD".to_string(),
+        Method::Post => "POST".to_string(),
+        Method::Put => "PUT".to_string(),
+        Method::Delete => "DELETE".to_string(),
+        Method::Connect => "CONNECT".to_string(),
+        Method::Options => "OPTIONS".to_string(),
+        Method::Trace => "TRACE".to_string(),
+        Method::Patch => "PATCH".to_string(),
+        Method::Other(method) => method,
+    };
+    let path = request.path_with_query().unwrap_or_else(|| "/".into());
+    let uri = match (request.scheme(), request.authority()) {
+        (Some(scheme), Some(authority)) => {
+            let scheme = match scheme {
+                Scheme::Http => "http".to_string(),
+                Scheme::Https => "https".to_string(),
+                Scheme::Other(scheme) => scheme,
+            };
+            format!("{scheme}://{authority}{path}")
+        }
+        _ => path,
+    };
+
+    let mut builder = Request::builder().method(method.as_str()).uri(uri);
+    for (name, value) in request.headers().entries() {
+        builder = builder.header(name, value);
+    }
+    builder
+        .body(Body::from(read_body(request)?))
+        .map_err(|_| 400)
+}
+
+fn read_body(request: &IncomingRequest) -> Result<Vec<u8>, u16> {
+    let body = request.consume().map_err(|_| 400_u16)?;
+    let stream = body.stream().map_err(|_| 400_u16)?;
+    let mut buf = Vec::new();
+    loop {
+        match stream.blocking_read(READ_SIZE) {
+            Ok(chunk) => {
+                if buf.len() + chunk.len() > MAX_BODY_SIZE {
+                    return Err(413);
+                }
+                buf.extend(chunk);
+            }
+            Err(StreamError::Closed) => break,
+            Err(StreamError::LastOperationFailed(_)) => return Err(400),
+        }
+    }
+    // the stream must be dropped before the body is finished
+    drop(stream);
+    IncomingBody::finish(body);
+    Ok(buf)
+}
+
+/// Wakes [`block_on`] by setting a flag.
+struct FlagWaker(AtomicBool);
+
+impl Wake for FlagWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// Runs a future to completion, polling the local executor until it is ready.
+///
+/// If running the executor's tasks did not wake the future, it is waiting on the host, which
+/// cannot wake it, so the thread sleeps before polling it again rather than spinning.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let flag = Arc::new(FlagWaker(AtomicBool::new(false)));
+    let waker = Waker::from(Arc::clone(&flag));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+            return value;
+        }
+        Executor::poll_local();
+        if !flag.0.swap(false, Ordering::AcqRel) {
+            std::thread::sleep(IDLE_SLEEP);
+        }
+    }
+}
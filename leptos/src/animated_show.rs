@@ -6,10 +6,11 @@ use reactive_graph::{
     effect::RenderEffect,
     owner::{on_cleanup, StoredValue},
     signal::RwSignal,
-    traits::{Get, GetUntracked, GetValue, Set, SetValue},
+    traits::{Get, GetUntracked, Set, SetValue, UpdateValue},
     wrappers::read::Signal,
 };
 use tachys::prelude::*;
+use web_sys::EventTarget;
 
 /// A component that will show its children when the `when` condition is `true`.
 /// Additionally, you need to specify a `hide_delay`. If the `when` condition changes to `false`,
@@ -17,6 +18,12 @@ use tachys::prelude::*;
 /// If you provide the optional `show_class` and `hide_class`, you can create very easy mount /
 /// unmount animations.
 ///
+/// The children are wrapped in a `<div>` that has the `show_class` while they are shown. When
+/// `when` changes to `false`, the `<div>` is given the `hide_class` and stays mounted, so that
+/// the exit animation can play. It is unmounted once the CSS animation or transition of the
+/// `<div>` itself ends, or after `hide_delay` at the latest. If `when` changes back to `true`
+/// before then, the children are kept rather than being mounted again.
+///
 /// ```rust
 /// # use core::time::Duration;
 /// # use leptos::prelude::*;
@@ -60,7 +67,8 @@ pub fn AnimatedShow(
     /// Optional CSS class to apply if `when == false`
     #[prop(optional)]
     hide_class: &'static str,
-    /// The timeout after which the component will be unmounted if `when == false`
+    /// The timeout after which the component will be unmounted if `when == false`, if its exit
+    /// animation or transition has not ended before then
     hide_delay: Duration,
 ) -> impl IntoView {
     let handle: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
@@ -71,17 +79,25 @@ pub fn AnimatedShow(
     });
     let show = RwSignal::new(when.get_untracked());
 
+    let clear_timer = move || {
+        if let Some(Some(h)) = handle.try_update_value(Option::take) {
+            h.clear();
+        }
+    };
+
     let eff = RenderEffect::new(move |_| {
         if when.get() {
             // clear any possibly active timer
-            if let Some(h) = handle.get_value() {
-                h.clear();
-            }
+            clear_timer();
 
             cls.set(show_class);
             show.set(true);
         } else {
             cls.set(hide_class);
+            // nothing to animate if the children are not mounted
+            if !show.get_untracked() {
+                return;
+            }
 
             let h = leptos_dom::helpers::set_timeout_with_handle(
                 move || show.set(false),
@@ -93,15 +109,34 @@ pub fn AnimatedShow(
     });
 
     on_cleanup(move || {
-        if let Some(Some(h)) = handle.try_get_value() {
-            h.clear();
-        }
+        clear_timer();
         drop(eff);
     });
 
+    // unmounts as soon as the exit animation of the wrapper (rather than one of its children)
+    // has ended
+    let exit_ended =
+        move |target: Option<EventTarget>,
+              current_target: Option<EventTarget>| {
+            if !when.get_untracked() && target == current_target {
+                clear_timer();
+                show.set(false);
+            }
+        };
+
     view! {
         <Show when=move || show.get() fallback=|| ()>
-            <div class=move || cls.get()>{children()}</div>
+            <div
+                class=move || cls.get()
+                on:animationend=move |ev| {
+                    exit_ended(ev.target(), ev.current_target())
+                }
+                on:transitionend=move |ev| {
+                    exit_ended(ev.target(), ev.current_target())
+                }
+            >
+                {children()}
+            </div>
         </Show>
     }
 }
@@ -81,3 +81,20 @@ where
         (self)(props)
     }
 }
+
+/// Props of an `#[island]` are serialized into the island's HTML on the server and deserialized
+/// when the island hydrates, so they must be serializable.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as an island prop",
+    label = "island props must implement `Serialize` and `Deserialize`",
+    note = "island props are serialized into the HTML on the server and \
+            deserialized when the island hydrates, so their types must \
+            implement `serde::Serialize` and `serde::de::DeserializeOwned`"
+)]
+pub trait IslandProp: serde::Serialize + serde::de::DeserializeOwned {}
+
+impl<T> IslandProp for T where T: serde::Serialize + serde::de::DeserializeOwned {}
+
+#[doc(hidden)]
+pub fn assert_island_prop<T: IslandProp>() {}
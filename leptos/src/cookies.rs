@@ -0,0 +1,385 @@
+//! Typed cookies that can be read and written the same way on the server and in the browser.
+//!
+//! Values are serialized as JSON. On the server, cookies are read from the current request and
+//! written to the response as `Set-Cookie` headers; in the browser, they are read from and
+//! written to `document.cookie`.
+//!
+//! ```rust,no_run
+//! use leptos::{cookies::Cookies, prelude::*};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Default)]
+//! struct Preferences {
+//!     dark_mode: bool,
+//! }
+//!
+//! #[server]
+//! async fn toggle_dark_mode() -> Result<(), ServerFnError> {
+//!     let cookies = Cookies::new();
+//!     let mut prefs = cookies.get::<Preferences>("prefs").unwrap_or_default();
+//!     prefs.dark_mode = !prefs.dark_mode;
+//!     cookies.set("prefs", &prefs);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Defaults
+//!
+//! Unless other [`CookieOptions`] are given, cookies are set for the path `/` with
+//! `SameSite=Lax` and `Secure`, and are kept until the browser is closed. Browsers accept
+//! `Secure` cookies from `http://localhost`, so this also works during development.
+//!
+//! Cookies marked [`http_only`](CookieOptions::http_only) can only be set on the server. The
+//! browser ignores them when they are written to `document.cookie`, and does not expose them when
+//! it is read.
+//!
+//! ## Signed and private cookies
+//!
+//! [`Cookies::signed`] cookies can be read by the browser, but are rejected if they have been
+//! changed. [`Cookies::private`] cookies are also encrypted, so their value cannot be read
+//! without the key. Both need a secret [`Key`], so they are only available on the server.
+//!
+//! ## Server integrations
+//!
+//! With their `cookies` feature enabled, server integrations call [`provide_server_cookies`]
+//! for each request, so that cookies can be used while rendering a route or running a server
+//! function. Cookies that are set after the
+//! response headers have been sent (for example, while streaming a `<Suspense/>`) are not sent
+//! to the browser.
+
+use leptos_dom::helpers::{document, is_server};
+use or_poisoned::OrPoisoned;
+use reactive_graph::owner::{provide_context, use_context};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use wasm_bindgen::JsCast;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use cookie::Key;
+pub use cookie::SameSite;
+use cookie::{Cookie, CookieJar};
+
+/// Options that are used when setting a cookie.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CookieOptions {
+    /// The path for which the cookie is sent. Defaults to `/`.
+    pub path: Option<String>,
+    /// The domain for which the cookie is sent. Defaults to the current host.
+    pub domain: Option<String>,
+    /// How long the browser should keep the cookie. Defaults to the end of the session.
+    pub max_age: Option<Duration>,
+    /// Whether the cookie is sent with cross-site requests. Defaults to [`SameSite::Lax`].
+    pub same_site: SameSite,
+    /// Whether the cookie is only sent over HTTPS. Defaults to `true`.
+    pub secure: bool,
+    /// Whether the cookie is hidden from JavaScript. Defaults to `false`.
+    pub http_only: bool,
+}
+
+impl Default for CookieOptions {
+    fn default() -> Self {
+        Self {
+            path: Some("/".into()),
+            domain: None,
+            max_age: None,
+            same_site: SameSite::Lax,
+            secure: true,
+            http_only: false,
+        }
+    }
+}
+
+impl CookieOptions {
+    fn build(&self, name: &str, value: String) -> Cookie<'static> {
+        let mut cookie = Cookie::build((name.to_string(), value))
+            .same_site(self.same_site)
+            .secure(self.secure)
+            .http_only(self.http_only);
+        if let Some(path) = &self.path {
+            cookie = cookie.path(path.clone());
+        }
+        if let Some(domain) = &self.domain {
+            cookie = cookie.domain(domain.clone());
+        }
+        if let Some(max_age) = self.max_age {
+            cookie = cookie.max_age(cookie::time::Duration::seconds(
+                max_age.as_secs() as i64,
+            ));
+        }
+        cookie.build()
+    }
+}
+
+/// The cookies of the current request, which are provided via context by server integrations.
+///
+/// See [`provide_server_cookies`].
+#[derive(Clone)]
+pub struct ServerCookies {
+    jar: Arc<Mutex<CookieJar>>,
+    set_cookie: Arc<dyn Fn(&str) + Send + Sync>,
+}
+
+impl Debug for ServerCookies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerCookies")
+            .field("jar", &self.jar)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Provides the cookies of the current request via context.
+///
+/// This is called by server integrations with the value of the request's `Cookie` header, and a
+/// function that adds a `Set-Cookie` header with the given value to the response.
+pub fn provide_server_cookies(
+    cookie_header: Option<&str>,
+    set_cookie: impl Fn(&str) + Send + Sync + 'static,
+) {
+    provide_context(ServerCookies {
+        jar: Arc::new(Mutex::new(parse_jar(cookie_header.unwrap_or_default()))),
+        set_cookie: Arc::new(set_cookie),
+    });
+}
+
+fn parse_jar(header: &str) -> CookieJar {
+    let mut jar = CookieJar::new();
+    for cookie in Cookie::split_parse_encoded(header).flatten() {
+        jar.add_original(cookie.into_owned());
+    }
+    jar
+}
+
+fn html_document() -> Option<web_sys::HtmlDocument> {
+    document().dyn_into::<web_sys::HtmlDocument>().ok()
+}
+
+#[derive(Clone)]
+enum Security {
+    Plain,
+    #[cfg(not(target_arch = "wasm32"))]
+    Signed(Arc<Key>),
+    #[cfg(not(target_arch = "wasm32"))]
+    Private(Arc<Key>),
+}
+
+/// Reads and writes typed cookies, on the server or in the browser.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Clone)]
+pub struct Cookies {
+    security: Security,
+    options: CookieOptions,
+}
+
+impl Debug for Cookies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cookies")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Cookies {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cookies {
+    /// Creates a handle to plain cookies, which can be read and changed by the browser.
+    pub fn new() -> Self {
+        Self {
+            security: Security::Plain,
+            options: CookieOptions::default(),
+        }
+    }
+
+    /// Creates a handle to cookies that are signed with the given key, so that any change made
+    /// outside the server is detected. Cookies with an invalid signature are ignored.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn signed(key: Key) -> Self {
+        Self {
+            security: Security::Signed(Arc::new(key)),
+            options: CookieOptions::default(),
+        }
+    }
+
+    /// Creates a handle to cookies that are encrypted and authenticated with the given key, so
+    /// that their values can neither be read nor changed outside the server.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn private(key: Key) -> Self {
+        Self {
+            security: Security::Private(Arc::new(key)),
+            options: CookieOptions::default(),
+        }
+    }
+
+    /// Sets the options that are used by [`Cookies::set`].
+    pub fn with_options(mut self, options: CookieOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the deserialized value of the cookie with the given name, if it exists and is
+    /// valid.
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        self.get_raw(name)
+            .and_then(|value| serde_json::from_str(&value).ok())
+    }
+
+    /// Returns the raw value of the cookie with the given name, if it exists and is valid.
+    pub fn get_raw(&self, name: &str) -> Option<String> {
+        self.with_jar(|jar| self.read(jar, name))
+    }
+
+    /// Serializes the value and sets it as the cookie with the given name.
+    pub fn set<T: Serialize>(&self, name: &str, value: &T) {
+        if let Ok(value) = serde_json::to_string(value) {
+            self.set_raw(name, value);
+        }
+    }
+
+    /// Sets the raw value of the cookie with the given name.
+    pub fn set_raw(&self, name: &str, value: impl Into<String>) {
+        let cookie = self.options.build(name, value.into());
+        self.write(name, |jar| self.add(jar, cookie));
+    }
+
+    /// Removes the cookie with the given name.
+    ///
+    /// The cookie is only removed if its path and domain match the options of this handle.
+    pub fn remove(&self, name: &str) {
+        let cookie = self.options.build(name, String::new());
+        self.write(name, |jar| jar.remove(cookie));
+    }
+
+    fn read(&self, jar: &CookieJar, name: &str) -> Option<String> {
+        let cookie = match &self.security {
+            Security::Plain => jar.get(name).cloned(),
+            #[cfg(not(target_arch = "wasm32"))]
+            Security::Signed(key) => jar.signed(key).get(name),
+            #[cfg(not(target_arch = "wasm32"))]
+            Security::Private(key) => jar.private(key).get(name),
+        };
+        cookie.map(|cookie| cookie.value().to_string())
+    }
+
+    fn add(&self, jar: &mut CookieJar, cookie: Cookie<'static>) {
+        match &self.security {
+            Security::Plain => jar.add(cookie),
+            #[cfg(not(target_arch = "wasm32"))]
+            Security::Signed(key) => jar.signed_mut(key).add(cookie),
+            #[cfg(not(target_arch = "wasm32"))]
+            Security::Private(key) => jar.private_mut(key).add(cookie),
+        }
+    }
+
+    fn with_jar<U>(&self, fun: impl FnOnce(&CookieJar) -> U) -> Option<U> {
+        if let Some(cookies) = use_context::<ServerCookies>() {
+            Some(fun(&cookies.jar.lock().or_poisoned()))
+        } else if !is_server() {
+            let header = html_document()?.cookie().ok()?;
+            Some(fun(&parse_jar(&header)))
+        } else {
+            None
+        }
+    }
+
+    /// Applies a change to the jar, then sends the resulting cookie to the browser.
+    fn write(&self, name: &str, fun: impl FnOnce(&mut CookieJar)) {
+        if let Some(cookies) = use_context::<ServerCookies>() {
+            let mut jar = cookies.jar.lock().or_poisoned();
+            fun(&mut jar);
+            if let Some(cookie) = delta(&jar, name) {
+                (cookies.set_cookie)(&cookie);
+            }
+        } else if !is_server() {
+            let Some(document) = html_document() else {
+                return;
+            };
+            let mut jar = parse_jar(&document.cookie().unwrap_or_default());
+            fun(&mut jar);
+            if let Some(cookie) = delta(&jar, name) {
+                _ = document.set_cookie(&cookie);
+            }
+        }
+    }
+}
+
+/// Returns the encoded `Set-Cookie` value for the last change to the cookie with this name.
+fn delta(jar: &CookieJar, name: &str) -> Option<String> {
+    jar.delta()
+        .filter(|cookie| cookie.name() == name)
+        .last()
+        .map(|cookie| cookie.encoded().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reactive_graph::owner::Owner;
+
+    fn with_server_cookies(header: &str, fun: impl FnOnce()) -> Vec<String> {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_server_cookies(Some(header), {
+                let sent = Arc::clone(&sent);
+                move |cookie| sent.lock().unwrap().push(cookie.to_string())
+            });
+            fun();
+        });
+        Arc::try_unwrap(sent).unwrap().into_inner().unwrap()
+    }
+
+    #[test]
+    fn reads_typed_values_from_the_request() {
+        with_server_cookies("count=3; name=%22leptos%22", || {
+            let cookies = Cookies::new();
+            assert_eq!(cookies.get::<u32>("count"), Some(3));
+            assert_eq!(
+                cookies.get::<String>("name").as_deref(),
+                Some("leptos")
+            );
+            assert_eq!(cookies.get::<u32>("missing"), None);
+        });
+    }
+
+    #[test]
+    fn sets_cookies_with_secure_defaults() {
+        let sent = with_server_cookies("", || {
+            let cookies = Cookies::new();
+            cookies.set("count", &4);
+            assert_eq!(cookies.get::<u32>("count"), Some(4));
+        });
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].starts_with("count=4;"));
+        assert!(sent[0].contains("SameSite=Lax"));
+        assert!(sent[0].contains("Secure"));
+        assert!(sent[0].contains("Path=/"));
+    }
+
+    #[test]
+    fn signed_cookies_reject_changed_values() {
+        let key = Key::generate();
+        let sent = with_server_cookies("", || {
+            Cookies::signed(key.clone()).set("user", &1);
+        });
+        let signed = sent[0].split(';').next().unwrap().to_string();
+        let tampered = signed.replace("user=", "user=0");
+
+        with_server_cookies(&signed, || {
+            assert_eq!(
+                Cookies::signed(key.clone()).get::<u32>("user"),
+                Some(1)
+            );
+        });
+        with_server_cookies(&tampered, || {
+            assert_eq!(Cookies::signed(key.clone()).get::<u32>("user"), None);
+        });
+    }
+}
@@ -0,0 +1,308 @@
+//! Protection against cross-site request forgery (CSRF) for server functions.
+//!
+//! When CSRF protection has been provided for a request with [`provide_csrf`], every
+//! [`ActionForm`](crate::form::ActionForm) and [`MultiActionForm`](crate::form::MultiActionForm)
+//! includes a hidden input with the current token. Server integrations then reject any request
+//! to a server function that does not include the same token, either in that form field or in
+//! the `X-CSRF-Token` header.
+//!
+//! [`HydrationScripts`](crate::hydration::HydrationScripts) also renders the token in a
+//! `<meta name="csrf-token">` tag. When the app is hydrated, the token is read from that tag and
+//! sent in the header of every server function request made from the browser, whether or not it
+//! comes from a form.
+//!
+//! The integrations only check tokens with their `csrf` feature enabled.
+//!
+//! ```rust,ignore
+//! use leptos::{csrf::{provide_csrf, CsrfStrategy}, prelude::*};
+//!
+//! // passed to `leptos_routes_with_context` and `handle_server_fns_with_context`
+//! let context = move || provide_csrf(CsrfStrategy::DoubleSubmitCookie);
+//! ```
+//!
+//! ## Strategies
+//! - [`CsrfStrategy::DoubleSubmitCookie`] stores the token in an `HttpOnly` cookie, and checks
+//!   that the submitted token matches the cookie.
+//! - [`CsrfStrategy::Session`] stores the token in the current [session](crate::session), which
+//!   must be provided before [`provide_csrf`] is called.
+//!
+//! Requests with the `GET` method are never checked, so server functions with side effects
+//! should use `POST` (the default). Tokens in `multipart/form-data` bodies are not read, so
+//! those forms must send the header.
+
+use crate::{
+    cookies::{CookieOptions, Cookies},
+    session::use_session,
+};
+use base64::{
+    alphabet,
+    engine::{self, general_purpose},
+    Engine,
+};
+use leptos_dom::helpers::{document, is_server};
+use rand::{rng, RngCore};
+use reactive_graph::owner::{provide_context, use_context};
+use std::{cell::OnceCell, fmt::Display, ops::Deref, sync::Arc};
+use tachys::html::element::{input, meta};
+use wasm_bindgen::JsCast;
+
+/// The name of the form field that contains the CSRF token.
+pub const CSRF_FIELD: &str = "_csrf";
+
+/// The name of the header that contains the CSRF token.
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+/// The name of the `<meta>` tag that contains the CSRF token.
+pub const CSRF_META: &str = "csrf-token";
+
+/// The name of the cookie and session key in which the token is stored.
+const CSRF_KEY: &str = "leptos_csrf";
+
+const CSRF_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::URL_SAFE, general_purpose::NO_PAD);
+
+/// Where the expected CSRF token is stored between requests.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CsrfStrategy {
+    /// Stores the token in an `HttpOnly` cookie.
+    #[default]
+    DoubleSubmitCookie,
+    /// Stores the token in the current [session](crate::session).
+    Session,
+}
+
+/// A random token that is submitted with forms, to show that they were rendered by this site.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CsrfToken(Arc<str>);
+
+impl CsrfToken {
+    /// Generates a new token from 32 bytes (256 bits) of random data.
+    pub fn new() -> Self {
+        let mut bytes = [0; 32];
+        rng().fill_bytes(&mut bytes);
+        CsrfToken(CSRF_ENGINE.encode(bytes).into())
+    }
+
+    /// Checks whether the submitted token matches this one, in constant time.
+    pub fn matches(&self, submitted: &str) -> bool {
+        let expected = self.0.as_bytes();
+        let submitted = submitted.as_bytes();
+        expected.len() == submitted.len()
+            && expected
+                .iter()
+                .zip(submitted)
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+impl Default for CsrfToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for CsrfToken {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for CsrfToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// CSRF protection for the current request, which is provided via context.
+#[derive(Clone, Debug)]
+struct CsrfProtection {
+    token: Option<CsrfToken>,
+}
+
+/// Enables CSRF protection for the current request, loading the expected token with the given
+/// strategy (or creating one, if there is none yet).
+///
+/// This should be called on the server, in the `additional_context` of both rendered routes and
+/// server functions.
+pub fn provide_csrf(strategy: CsrfStrategy) {
+    let token = match strategy {
+        CsrfStrategy::DoubleSubmitCookie => {
+            let cookies = Cookies::new().with_options(CookieOptions {
+                http_only: true,
+                ..Default::default()
+            });
+            match cookies.get_raw(CSRF_KEY) {
+                Some(token) => Some(CsrfToken(token.into())),
+                None => {
+                    let token = CsrfToken::new();
+                    cookies.set_raw(CSRF_KEY, token.to_string());
+                    Some(token)
+                }
+            }
+        }
+        CsrfStrategy::Session => {
+            use_session().map(|session| match session.get::<String>(CSRF_KEY) {
+                Some(token) => CsrfToken(token.into()),
+                None => {
+                    let token = CsrfToken::new();
+                    session.insert(CSRF_KEY, &token.to_string());
+                    token
+                }
+            })
+        }
+    };
+    provide_context(CsrfProtection { token });
+}
+
+/// Returns the CSRF token for the current request, if CSRF protection has been provided.
+pub fn use_csrf_token() -> Option<CsrfToken> {
+    use_context::<CsrfProtection>().and_then(|csrf| csrf.token)
+}
+
+/// Checks the token that was submitted with a request.
+///
+/// This returns `true` if CSRF protection has not been provided for the current request, and
+/// `false` if it has been provided but no token, or the wrong one, was submitted.
+pub fn verify_csrf_token(submitted: Option<&str>) -> bool {
+    match use_context::<CsrfProtection>() {
+        None => true,
+        Some(CsrfProtection { token: None }) => false,
+        Some(CsrfProtection { token: Some(token) }) => {
+            submitted.is_some_and(|submitted| token.matches(submitted))
+        }
+    }
+}
+
+/// Finds the value of the [`CSRF_FIELD`] in a URL-encoded form body.
+pub fn csrf_field(body: &[u8]) -> Option<&str> {
+    std::str::from_utf8(body)
+        .ok()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(CSRF_FIELD)?.strip_prefix('='))
+}
+
+/// A hidden input that contains the current CSRF token, which is added to action forms.
+///
+/// It is empty if CSRF protection has not been provided. In the browser, the token is read from
+/// the `<meta>` tag rendered by the server, so that forms created after hydration have it too.
+pub(crate) fn csrf_input() -> impl crate::IntoView {
+    input().r#type("hidden").name(CSRF_FIELD).value(
+        use_csrf_token()
+            .map(|token| token.to_string())
+            .or_else(document_token)
+            .unwrap_or_default(),
+    )
+}
+
+/// A `<meta>` tag that contains the current CSRF token, which is rendered in the `<head>` by
+/// [`HydrationScripts`](crate::hydration::HydrationScripts).
+pub(crate) fn csrf_meta() -> Option<impl crate::IntoView> {
+    use_csrf_token()
+        .map(|token| meta().name(CSRF_META).content(token.to_string()))
+}
+
+/// Returns the token from the `<meta>` tag rendered by the server, if there is one.
+fn document_token() -> Option<String> {
+    thread_local! {
+        static TOKEN: OnceCell<Option<String>> = const { OnceCell::new() };
+    }
+
+    if is_server() {
+        return None;
+    }
+    TOKEN.with(|token| {
+        token
+            .get_or_init(|| {
+                document()
+                    .query_selector(&format!("meta[name={CSRF_META}]"))
+                    .ok()
+                    .flatten()
+                    .and_then(|meta| meta.get_attribute("content"))
+                    .filter(|token| !token.is_empty())
+            })
+            .clone()
+    })
+}
+
+/// Sends the token from the `<meta>` tag rendered by the server with every server function
+/// request made from the browser. This is called when the app is hydrated.
+pub(crate) fn send_document_token() {
+    if let Some(token) = document_token() {
+        server_fn::request::browser::set_default_header(CSRF_HEADER, token);
+    }
+}
+
+/// Sends the token from the form's hidden input with every server function request made from
+/// the browser, so that forms submitted with JavaScript/WASM pass the same check.
+pub(crate) fn send_token_from(ev: &web_sys::Event) {
+    let token = ev
+        .target()
+        .and_then(|form| form.dyn_into::<web_sys::Element>().ok())
+        .and_then(|form| {
+            form.query_selector(&format!("input[name={CSRF_FIELD}]"))
+                .ok()
+                .flatten()
+        })
+        .or_else(|| {
+            document()
+                .query_selector(&format!("input[name={CSRF_FIELD}]"))
+                .ok()
+                .flatten()
+        })
+        .and_then(|input| input.dyn_into::<web_sys::HtmlInputElement>().ok())
+        .map(|input| input.value())
+        .filter(|token| !token.is_empty());
+    if let Some(token) = token {
+        server_fn::request::browser::set_default_header(CSRF_HEADER, token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cookies::provide_server_cookies;
+    use reactive_graph::owner::Owner;
+
+    #[test]
+    fn finds_the_token_field_in_a_form_body() {
+        assert_eq!(csrf_field(b"title=hi&_csrf=abc123"), Some("abc123"));
+        assert_eq!(csrf_field(b"_csrf=abc&x=1"), Some("abc"));
+        assert_eq!(csrf_field(b"_csrfx=abc"), None);
+        assert_eq!(csrf_field(b"title=hi"), None);
+    }
+
+    #[test]
+    fn double_submit_cookie_verifies_the_cookie_token() {
+        let sent = std::sync::Mutex::new(Vec::<String>::new());
+        let sent = Arc::new(sent);
+        let token = Owner::new().with(|| {
+            provide_server_cookies(None, {
+                let sent = Arc::clone(&sent);
+                move |cookie| sent.lock().unwrap().push(cookie.to_string())
+            });
+            provide_csrf(CsrfStrategy::DoubleSubmitCookie);
+            use_csrf_token().unwrap()
+        });
+        let cookie = sent.lock().unwrap()[0]
+            .split(';')
+            .next()
+            .unwrap()
+            .to_string();
+
+        Owner::new().with(|| {
+            provide_server_cookies(Some(&cookie), |_| {});
+            provide_csrf(CsrfStrategy::DoubleSubmitCookie);
+            assert!(verify_csrf_token(Some(&token)));
+            assert!(!verify_csrf_token(Some("forged")));
+            assert!(!verify_csrf_token(None));
+        });
+    }
+
+    #[test]
+    fn requests_without_protection_are_allowed() {
+        Owner::new().with(|| assert!(verify_csrf_token(None)));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{children::Children, component, IntoView};
+use leptos_dom::helpers::document;
+use leptos_macro::view;
+use reactive_graph::{
+    callback::{Callable, Callback},
+    effect::Effect,
+    owner::StoredValue,
+    signal::RwSignal,
+    traits::{Get, GetUntracked, Set, SetValue, UpdateValue},
+};
+use tachys::{html::element, prelude::*, reactive_graph::node_ref::NodeRef};
+use wasm_bindgen::JsCast;
+
+/// A dialog built on the native [`<dialog>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dialog)
+/// element, which is opened and closed by a signal.
+///
+/// While `open` is `true`, the dialog is shown with `showModal()`, or with `show()` if `modal`
+/// is `false`. When the dialog is closed, whether by setting `open` to `false`, by pressing
+/// Escape, or by submitting a `<form method="dialog">`, `open` is set to `false`, `on_close` is
+/// called with the dialog's `returnValue`, and focus returns to the element that was focused
+/// before the dialog opened.
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos::dialog::Dialog;
+/// # #[component]
+/// # pub fn App() -> impl IntoView {
+/// let open = RwSignal::new(false);
+///
+/// view! {
+///     <button on:click=move |_| open.set(true)>"Delete"</button>
+///     <Dialog open=open on_close=|answer: String| leptos::logging::log!("{answer}")>
+///         <form method="dialog">
+///             <p>"Are you sure?"</p>
+///             <button value="cancel">"Cancel"</button>
+///             <button value="delete">"Delete"</button>
+///         </form>
+///     </Dialog>
+/// }
+/// # }
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+#[component]
+pub fn Dialog(
+    /// Whether the dialog is open. This is set to `false` when the dialog is closed.
+    open: RwSignal<bool>,
+    /// Whether the dialog is modal. Defaults to `true`.
+    #[prop(default = true)]
+    modal: bool,
+    /// Called with the dialog's `returnValue` when it is closed.
+    #[prop(optional, into)]
+    on_close: Option<Callback<String>>,
+    /// The contents of the dialog.
+    children: Children,
+) -> impl IntoView {
+    let dialog = NodeRef::<element::Dialog>::new();
+    let return_focus = StoredValue::new_local(None::<web_sys::HtmlElement>);
+
+    Effect::new(move |_| {
+        let Some(el) = dialog.get() else {
+            return;
+        };
+        let should_open = open.get();
+        if should_open && !el.open() {
+            return_focus.set_value(
+                document()
+                    .active_element()
+                    .and_then(|active| active.dyn_into().ok()),
+            );
+            if modal {
+                if el.show_modal().is_err() {
+                    open.set(false);
+                }
+            } else {
+                el.show();
+            }
+        } else if !should_open && el.open() {
+            el.close();
+        }
+    });
+
+    let on_close_event = move |_| {
+        open.set(false);
+        if let Some(on_close) = on_close {
+            let return_value = dialog
+                .get_untracked()
+                .map(|el| el.return_value())
+                .unwrap_or_default();
+            on_close.run(return_value);
+        }
+        if let Some(el) = return_focus.try_update_value(Option::take).flatten()
+        {
+            _ = el.focus();
+        }
+    };
+
+    view! {
+        <dialog node_ref=dialog on:close=on_close_event>
+            {children()}
+        </dialog>
+    }
+}
@@ -5,6 +5,7 @@ use reactive_graph::{
     signal::{ArcRwSignal, ReadSignal},
     traits::Set,
 };
+use reactive_stores::{AtKeyed, KeyedSubfield};
 use std::hash::Hash;
 use tachys::{
     reactive_graph::OwnedView,
@@ -222,6 +223,83 @@ where
     move || keyed(each(), key.clone(), children.clone())
 }
 
+/// Iterates over the items in a keyed collection in a [`Store`](reactive_stores::Store), and
+/// displays each of them with a field that gives access to that item in the store.
+///
+/// Unlike [`For`], the items are never cloned out of the store, and the key of each item comes
+/// from the key given for the collection with `#[store(key: ...)]`, so it does not need to be
+/// read either. Each row can read and update its own item through its field, and only the rows
+/// whose items have changed are updated.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// use reactive_stores::Store;
+///
+/// #[derive(Store)]
+/// struct TodoList {
+///     #[store(key: usize = |todo| todo.id)]
+///     todos: Vec<Todo>,
+/// }
+///
+/// #[derive(Store)]
+/// struct Todo {
+///     id: usize,
+///     label: String,
+///     done: bool,
+/// }
+///
+/// #[component]
+/// fn Todos() -> impl IntoView {
+///     let store = Store::new(TodoList { todos: vec![] });
+///
+///     view! {
+///         <ul>
+///             <ForStore each=store.todos() let:todo>
+///                 <li class:done=move || todo.done().get()>
+///                     {move || todo.label().get()}
+///                     <button on:click=move |_| todo.done().set(true)>"Done"</button>
+///                 </li>
+///             </ForStore>
+///         </ul>
+///     }
+/// }
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+#[component]
+pub fn ForStore<Inner, Prev, K, T, EF, N>(
+    /// The keyed collection in the store over which the component should iterate.
+    each: KeyedSubfield<Inner, Prev, K, T>,
+    /// A function that takes the field for an item, and returns the view that will be displayed
+    /// for it.
+    children: EF,
+) -> impl IntoView
+where
+    KeyedSubfield<Inner, Prev, K, T>: IntoIterator<Item = AtKeyed<Inner, Prev, K, T>>
+        + Clone
+        + Send
+        + 'static,
+    AtKeyed<Inner, Prev, K, T>: Send + 'static,
+    for<'a> &'a T: IntoIterator,
+    EF: Fn(AtKeyed<Inner, Prev, K, T>) -> N + Send + Clone + 'static,
+    N: IntoView + 'static,
+    K: Eq + Hash + Clone + SerializableKey + 'static,
+{
+    // see `For` for how the owners of the rows are arranged
+    let parent = Owner::current().expect("no reactive owner");
+    let children = move |_, child| {
+        let owner = parent.with(Owner::new);
+        let view = owner.with(|| children(child));
+        (drop, OwnedView::new_with_owner(view, owner))
+    };
+    move || {
+        keyed(
+            each.clone(),
+            |row: &AtKeyed<Inner, Prev, K, T>| row.key(),
+            children.clone(),
+        )
+    }
+}
+
 /*
 #[cfg(test)]
 mod tests {
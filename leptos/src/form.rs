@@ -1,18 +1,27 @@
+mod data;
+mod state;
+
 use crate::{children::Children, component, prelude::*, IntoView};
+#[doc(hidden)]
+pub use data::macro_helpers;
+pub use data::{parse_form_field, FormData, FormFields};
 use leptos_dom::helpers::window;
+pub use leptos_macro::FormData;
 use leptos_server::{ServerAction, ServerMultiAction};
 use serde::de::DeserializeOwned;
+pub use server_fn::error::FieldErrors;
 use server_fn::{
-    client::Client,
-    codec::PostUrl,
-    error::{IntoAppError, ServerFnErrorErr},
+    client::{get_server_fn_path_prefix, Client},
+    codec::FormUrlEncoding,
+    error::{FromServerFnError, IntoAppError, ServerFnErrorErr},
+    method_override::{accepts_override, METHOD_OVERRIDE_FIELD},
     request::ClientReq,
     Http, ServerFn,
 };
+pub use state::*;
 use tachys::{
-    either::Either,
     html::{
-        element::{form, Form},
+        element::{form, input, Form},
         event::submit,
     },
     reactive_graph::node_ref::NodeRef,
@@ -20,8 +29,8 @@ use tachys::{
 use thiserror::Error;
 use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
 use web_sys::{
-    Event, FormData, HtmlButtonElement, HtmlFormElement, HtmlInputElement,
-    SubmitEvent,
+    Element, Event, HtmlButtonElement, HtmlElement, HtmlFormElement,
+    HtmlInputElement, SubmitEvent,
 };
 
 /// Automatically turns a server [Action](leptos_server::Action) into an HTML
@@ -33,9 +42,17 @@ use web_sys::{
 /// default `Url` encoding. This is to ensure that `<ActionForm/>` works correctly
 /// both before and after WASM has loaded.
 ///
+/// Server functions that use `PutUrl`, `PatchUrl`, or `DeleteUrl` work too. Once
+/// the WASM has loaded they are called with their own method. Without
+/// JavaScript/WASM, the browser can only `POST` the form, so it includes a hidden
+/// `_method` field, and the server integrations handle the request as that method.
+/// See [`server_fn::method_override`].
+///
 /// ## Complex Inputs
 /// Server function arguments that are structs with nested serializable fields
-/// should make use of indexing notation of `serde_qs`.
+/// should make use of indexing notation of `serde_qs`. Dotted names such as
+/// `hefty_arg.first_name` work too, and a `Vec` argument can be filled from
+/// repeated `tags[]` inputs, or `items[][title]` for a list of structs.
 ///
 /// ```rust
 /// # use leptos::prelude::*;
@@ -73,27 +90,159 @@ use web_sys::{
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Without JavaScript/WASM
+/// `<ActionForm/>` renders a plain `<form method="post">` whose `action` is the server
+/// function's URL, so it can be submitted before the WASM has loaded, with JavaScript disabled,
+/// or in a part of an islands app that is never hydrated. In that case the browser posts the
+/// form directly to the server function, and:
+/// 1. If the server function calls `leptos_axum::redirect` or `leptos_actix::redirect`, the
+///    browser follows that redirect. `redirect_with_options` can also set its status code (such
+///    as `303 See Other`), and whether a submission made with JavaScript/WASM follows it too or
+///    ignores it and just receives the server function's value (`ClientRedirect::Ignore`).
+/// 2. Otherwise, if it succeeds, the browser is redirected to the `no_js_redirect` path if one
+///    is set, or back to the page the form was submitted from.
+/// 3. If it fails, the browser is redirected back to the page the form was submitted from, with
+///    the error encoded in the URL's query. When that page is rendered inside `<Routes/>`, the
+///    error is used as the initial [`value`](ServerAction::value) of any action for the same
+///    server function, so it can be shown just as it would be after a client-side submission.
+///
+/// ## After submitting
+/// `on_success` is called each time the action resolves with `Ok`, and `on_error` each time it
+/// resolves with `Err`. They are called once the value is set, so the result can be read from the
+/// action's [`value`](ServerAction::value). With `reset_on_success`, the form's fields are reset
+/// to their initial values and the first of them is focused before `on_success` is called, ready
+/// for the next entry.
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// use leptos::form::ActionForm;
+///
+/// #[component]
+/// fn AddComment() -> impl IntoView {
+///     let add_comment = ServerAction::<AddComment>::new();
+///     let (toast, set_toast) = signal(None::<&'static str>);
+///
+///     view! {
+///         <ActionForm
+///             action=add_comment
+///             reset_on_success=true
+///             on_success=move |_| set_toast.set(Some("Comment added."))
+///             on_error=move |_| set_toast.set(Some("Could not add the comment."))
+///         >
+///             <textarea name="text"></textarea>
+///             <input type="submit"/>
+///         </ActionForm>
+///         <p>{toast}</p>
+///     }
+/// }
+///
+/// #[server]
+/// async fn add_comment(text: String) -> Result<(), ServerFnError> {
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Validation
+/// The `on_submit` hook is called with the typed input before it is dispatched, and can cancel
+/// the submission by returning the errors for each invalid field. Those fields are marked with
+/// `aria-invalid="true"`, the first of them is focused, and the errors are set in the `errors`
+/// signal, if one is given, so that they can be shown next to the fields.
+///
+/// The hook only runs once the WASM has loaded. Forms that are submitted without it are posted
+/// straight to the server function, so the server function still has to validate its input.
+///
+/// The server function can report invalid fields in the same way, by returning [`FieldErrors`]
+/// in [`ServerFnError::FieldErrors`](server_fn::ServerFnError::FieldErrors), or in a custom
+/// error type whose [`field_errors`](server_fn::error::FromServerFnError::field_errors) returns
+/// them.
+/// When the action resolves with those errors, the fields are marked and the `errors` signal is
+/// set in the same way; when it succeeds, the errors are cleared.
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// use leptos::form::{ActionForm, FieldErrors, FormErrors};
+///
+/// #[component]
+/// fn NewTodo() -> impl IntoView {
+///     let add_todo = ServerAction::<AddTodo>::new();
+///     let errors = RwSignal::new(FormErrors::new());
+///     let validate = |input: AddTodo| {
+///         let mut errors = FormErrors::new();
+///         if input.title.trim().is_empty() {
+///             errors.insert("title".into(), "Enter a title.".into());
+///         }
+///         if errors.is_empty() {
+///             Ok(())
+///         } else {
+///             Err(errors)
+///         }
+///     };
+///
+///     view! {
+///         <ActionForm action=add_todo on_submit=validate errors=errors>
+///             <input type="text" name="title"/>
+///             <p>{move || errors.with(|errors| errors.get("title").cloned())}</p>
+///             <input type="submit"/>
+///         </ActionForm>
+///     }
+/// }
+///
+/// #[server]
+/// async fn add_todo(title: String) -> Result<(), ServerFnError> {
+///     if title.len() > 100 {
+///         let errors = FieldErrors::new().with("title", "The title is too long.");
+///         return Err(ServerFnError::FieldErrors(errors));
+///     }
+///     Ok(())
+/// }
+/// ```
 #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 #[component]
-pub fn ActionForm<ServFn, OutputProtocol>(
+pub fn ActionForm<ServFn, OutputProtocol, InputProtocol>(
     /// The action from which to build the form.
     action: ServerAction<ServFn>,
     /// A [`NodeRef`] in which the `<form>` element should be stored.
     #[prop(optional)]
     node_ref: Option<NodeRef<Form>>,
+    /// A path on the same site to which the browser should be redirected after a successful
+    /// submission, when the form is submitted without JavaScript/WASM. By default, it is
+    /// redirected back to the current page.
+    #[prop(optional, into)]
+    no_js_redirect: Option<String>,
+    /// Called with the input before it is dispatched, when the form is submitted in the
+    /// browser. Returning an error cancels the submission, and marks the fields that are named
+    /// in it as invalid.
+    #[prop(optional, into)]
+    on_submit: Option<Callback<ServFn, Result<(), FormErrors>>>,
+    /// Set to the errors returned by `on_submit` each time the form is submitted, and to the
+    /// field errors returned by the server function each time the action resolves.
+    #[prop(optional)]
+    errors: Option<RwSignal<FormErrors>>,
+    /// Called each time the action resolves successfully.
+    #[prop(optional, into)]
+    on_success: Option<Callback<()>>,
+    /// Called each time the action resolves with an error.
+    #[prop(optional, into)]
+    on_error: Option<Callback<()>>,
+    /// Whether to reset the form's fields and focus the first of them each time the action
+    /// resolves successfully.
+    #[prop(optional)]
+    reset_on_success: bool,
     /// Component children; should include the HTML of the form elements.
     children: Children,
 ) -> impl IntoView
 where
     ServFn: DeserializeOwned
-        + ServerFn<Protocol = Http<PostUrl, OutputProtocol>>
+        + ServerFn<Protocol = Http<InputProtocol, OutputProtocol>>
         + Clone
         + Send
         + Sync
         + 'static,
+    InputProtocol: FormUrlEncoding,
     <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<
         ServFn::Error,
-    >>::FormData: From<FormData>,
+    >>::FormData: From<web_sys::FormData>,
     ServFn: Send + Sync + 'static,
     ServFn::Output: Send + Sync + 'static,
     ServFn::Error: Send + Sync + 'static,
@@ -108,6 +257,48 @@ where
 
     let version = action.version();
     let value = action.value();
+    let marked = StoredValue::new(FormErrors::new());
+    let form_ref = node_ref.unwrap_or_default();
+    let show_errors = move |form: Option<Element>, field_errors: FormErrors| {
+        if let Some(form) = form {
+            marked.with_value(|marked| {
+                mark_invalid_fields(&form, marked, &field_errors)
+            });
+        }
+        marked.set_value(field_errors.clone());
+        if let Some(errors) = errors {
+            errors.set(field_errors);
+        }
+    };
+
+    // marks the fields that the server function reported as invalid, and runs the hooks for
+    // the result
+    Effect::new(move |_| {
+        let field_errors = value.with(|value| match value {
+            None => None,
+            Some(Ok(_)) => Some(FormErrors::new()),
+            Some(Err(e)) => Some(
+                e.field_errors()
+                    .cloned()
+                    .map(FieldErrors::into_inner)
+                    .unwrap_or_default(),
+            ),
+        });
+        let Some(field_errors) = field_errors else {
+            return;
+        };
+        let form = form_ref.get_untracked();
+        let succeeded =
+            value.with_untracked(|value| matches!(value, Some(Ok(_))));
+        show_errors(form.clone().map(JsCast::unchecked_into), field_errors);
+        after_submission(
+            form.as_ref(),
+            succeeded,
+            reset_on_success,
+            on_success,
+            on_error,
+        );
+    });
 
     let on_submit = {
         move |ev: SubmitEvent| {
@@ -116,9 +307,23 @@ where
             }
 
             ev.prevent_default();
+            #[cfg(feature = "csrf")]
+            crate::csrf::send_token_from(&ev);
 
             match ServFn::from_event(&ev) {
                 Ok(new_input) => {
+                    if let Some(on_submit) = on_submit {
+                        let result = on_submit.run(new_input.clone());
+                        let field_errors =
+                            result.as_ref().err().cloned().unwrap_or_default();
+                        let form = ev
+                            .target()
+                            .and_then(|form| form.dyn_into::<Element>().ok());
+                        show_errors(form, field_errors);
+                        if result.is_err() {
+                            return;
+                        }
+                    }
                     action.dispatch(new_input);
                 }
                 Err(err) => {
@@ -136,28 +341,42 @@ where
         }
     };
 
-    let action_form = form()
-        .action(ServFn::url())
+    form()
+        .action(action_url::<ServFn>(no_js_redirect.as_deref()))
         .method("post")
         .on(submit, on_submit)
-        .child(children());
-    if let Some(node_ref) = node_ref {
-        Either::Left(action_form.node_ref(node_ref))
-    } else {
-        Either::Right(action_form)
-    }
+        .child((method_input::<InputProtocol>(), form_children(children)))
+        .node_ref(form_ref)
 }
 
 /// Automatically turns a server [MultiAction](leptos_server::MultiAction) into an HTML
 /// [`form`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form)
 /// progressively enhanced to use client-side routing.
+///
+/// Like [`ActionForm`], this can also be submitted without JavaScript/WASM. See its
+/// documentation for details.
 #[component]
-pub fn MultiActionForm<ServFn, OutputProtocol>(
+pub fn MultiActionForm<ServFn, OutputProtocol, InputProtocol>(
     /// The action from which to build the form.
     action: ServerMultiAction<ServFn>,
     /// A [`NodeRef`] in which the `<form>` element should be stored.
     #[prop(optional)]
     node_ref: Option<NodeRef<Form>>,
+    /// A path on the same site to which the browser should be redirected after a successful
+    /// submission, when the form is submitted without JavaScript/WASM. By default, it is
+    /// redirected back to the current page.
+    #[prop(optional, into)]
+    no_js_redirect: Option<String>,
+    /// Called each time a submission resolves successfully.
+    #[prop(optional, into)]
+    on_success: Option<Callback<()>>,
+    /// Called each time a submission resolves with an error.
+    #[prop(optional, into)]
+    on_error: Option<Callback<()>>,
+    /// Whether to reset the form's fields and focus the first of them each time a submission
+    /// resolves successfully.
+    #[prop(optional)]
+    reset_on_success: bool,
     /// Component children; should include the HTML of the form elements.
     children: Children,
 ) -> impl IntoView
@@ -166,12 +385,13 @@ where
         + Sync
         + Clone
         + DeserializeOwned
-        + ServerFn<Protocol = Http<PostUrl, OutputProtocol>>
+        + ServerFn<Protocol = Http<InputProtocol, OutputProtocol>>
         + 'static,
+    InputProtocol: FormUrlEncoding,
     ServFn::Output: Send + Sync + 'static,
     <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<
         ServFn::Error,
-    >>::FormData: From<FormData>,
+    >>::FormData: From<web_sys::FormData>,
     ServFn::Error: Send + Sync + 'static,
     <ServFn as ServerFn>::Client: Client<<ServFn as ServerFn>::Error>,
 {
@@ -182,12 +402,56 @@ where
         }
     });
 
+    let form_ref = node_ref.unwrap_or_default();
+
+    // runs the hooks for each submission as it resolves. submissions are told apart by their
+    // `canceled` signal rather than their position, which changes if others are removed
+    Effect::watch(
+        move || {
+            action.submissions().with(|submissions| {
+                submissions
+                    .iter()
+                    .map(|submission| {
+                        let result = submission.value().with(|value| {
+                            value.as_ref().map(|value| value.is_ok())
+                        });
+                        (submission.canceled(), result)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        },
+        move |results, previous, _| {
+            let resolved = previous
+                .into_iter()
+                .flatten()
+                .filter(|(_, result)| result.is_some())
+                .map(|(submission, _)| submission)
+                .collect::<Vec<_>>();
+            for (submission, result) in results {
+                if let (Some(succeeded), false) =
+                    (result, resolved.contains(&submission))
+                {
+                    after_submission(
+                        form_ref.get_untracked().as_ref(),
+                        *succeeded,
+                        reset_on_success,
+                        on_success,
+                        on_error,
+                    );
+                }
+            }
+        },
+        false,
+    );
+
     let on_submit = move |ev: SubmitEvent| {
         if ev.default_prevented() {
             return;
         }
 
         ev.prevent_default();
+        #[cfg(feature = "csrf")]
+        crate::csrf::send_token_from(&ev);
 
         match ServFn::from_event(&ev) {
             Ok(new_input) => {
@@ -202,16 +466,95 @@ where
         }
     };
 
-    let action_form = form()
-        .action(ServFn::url())
+    form()
+        .action(action_url::<ServFn>(no_js_redirect.as_deref()))
         .method("post")
         .attr("method", "post")
         .on(submit, on_submit)
-        .child(children());
-    if let Some(node_ref) = node_ref {
-        Either::Left(action_form.node_ref(node_ref))
-    } else {
-        Either::Right(action_form)
+        .child((method_input::<InputProtocol>(), form_children(children)))
+        .node_ref(form_ref)
+}
+
+/// Resets the form if it should be, and calls the hook for the result of a submission.
+fn after_submission(
+    form: Option<&HtmlFormElement>,
+    succeeded: bool,
+    reset_on_success: bool,
+    on_success: Option<Callback<()>>,
+    on_error: Option<Callback<()>>,
+) {
+    if !succeeded {
+        if let Some(on_error) = on_error {
+            on_error.run(());
+        }
+        return;
+    }
+    if let Some(form) = form.filter(|_| reset_on_success) {
+        form.reset();
+        let first_field = form
+            .query_selector(
+                "input:not([type=hidden]):not([disabled]), \
+                 select:not([disabled]), textarea:not([disabled])",
+            )
+            .ok()
+            .flatten()
+            .and_then(|field| field.dyn_into::<HtmlElement>().ok());
+        if let Some(field) = first_field {
+            _ = field.focus();
+        }
+    }
+    if let Some(on_success) = on_success {
+        on_success.run(());
+    }
+}
+
+/// Marks the fields named in the errors with `aria-invalid`, unmarks the fields that were marked
+/// after the previous submission, and focuses the first invalid field.
+fn mark_invalid_fields(
+    form: &Element,
+    previous: &FormErrors,
+    errors: &FormErrors,
+) {
+    let selector =
+        |name: &str| format!("[name=\"{}\"]", name.replace('"', "\\\""));
+    let field =
+        |name: &str| form.query_selector(&selector(name)).ok().flatten();
+    for name in previous.keys().filter(|name| !errors.contains_key(*name)) {
+        if let Some(field) = field(name) {
+            _ = field.remove_attribute("aria-invalid");
+        }
+    }
+    for name in errors.keys() {
+        if let Some(field) = field(name) {
+            _ = field.set_attribute("aria-invalid", "true");
+        }
+    }
+    // the errors are sorted by name, so the first field in document order is found by
+    // matching all of them at once
+    let all = errors
+        .keys()
+        .map(|name| selector(name))
+        .collect::<Vec<_>>()
+        .join(",");
+    if all.is_empty() {
+        return;
+    }
+    if let Some(field) = form
+        .query_selector(&all)
+        .ok()
+        .flatten()
+        .and_then(|field| field.dyn_into::<HtmlElement>().ok())
+    {
+        _ = field.focus();
+    }
+}
+
+/// Returns the URL of a server function, to be used as the `action` of a `<form>`.
+fn action_url<ServFn: ServerFn>(no_js_redirect: Option<&str>) -> String {
+    let url = format!("{}{}", get_server_fn_path_prefix(), ServFn::url());
+    match no_js_redirect {
+        Some(path) => server_fn::redirect::with_form_redirect(&url, path),
+        None => url,
     }
 }
 
@@ -287,13 +630,35 @@ where
             web_sys::UrlSearchParams::new_with_str_sequence_sequence(form_data)
                 .unwrap_throw();
         let data = data.to_string().as_string().unwrap_or_default();
-        serde_qs::Config::new(5, false).deserialize_str::<Self>(&data)
+        server_fn::codec::deserialize_form::<Self>(&data)
     }
 }
 
+/// A hidden input with the method override for a server function that uses `PUT`, `PATCH`, or
+/// `DELETE`, which a plain HTML form can't submit with.
+fn method_input<InputProtocol: FormUrlEncoding>() -> Option<impl IntoView> {
+    accepts_override(&InputProtocol::METHOD).then(|| {
+        input()
+            .r#type("hidden")
+            .name(METHOD_OVERRIDE_FIELD)
+            .value(InputProtocol::METHOD.as_str().to_owned())
+    })
+}
+
+/// Adds a hidden input with the CSRF token to the children of an action form.
+#[cfg(feature = "csrf")]
+fn form_children(children: Children) -> impl IntoView {
+    (crate::csrf::csrf_input(), children())
+}
+
+#[cfg(not(feature = "csrf"))]
+fn form_children(children: Children) -> impl IntoView {
+    children()
+}
+
 fn form_data_from_event(
     ev: &SubmitEvent,
-) -> Result<FormData, FromFormDataError> {
+) -> Result<web_sys::FormData, FromFormDataError> {
     let submitter = ev.submitter();
     let mut submitter_name_value = None;
     let opt_form = match &submitter {
@@ -312,7 +677,7 @@ fn form_data_from_event(
         }
         None => ev.target().map(|form| form.unchecked_into()),
     };
-    match opt_form.as_ref().map(FormData::new_with_form) {
+    match opt_form.as_ref().map(web_sys::FormData::new_with_form) {
         None => Err(FromFormDataError::MissingForm(ev.clone().into())),
         Some(Err(e)) => Err(FromFormDataError::FormData(e)),
         Some(Ok(form_data)) => {
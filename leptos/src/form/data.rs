@@ -0,0 +1,385 @@
+use super::{form_data_from_event, FormErrors, FromFormDataError};
+use percent_encoding::percent_decode_str;
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+/// The fields of a submitted form, as a map from each field's name to its values.
+///
+/// Nested fields use the same bracket notation as `serde_qs`, so a field named
+/// `address[city]` is the `city` field of the `address` field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormFields(BTreeMap<String, Vec<String>>);
+
+impl FormFields {
+    /// Creates an empty set of fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a URL-encoded form body or query string, such as `name=Leptos&tags=a&tags=b`.
+    pub fn from_urlencoded(data: &str) -> Self {
+        let decode = |s: &str| {
+            percent_decode_str(&s.replace('+', " "))
+                .decode_utf8_lossy()
+                .into_owned()
+        };
+        let mut fields = Self::new();
+        for pair in data.split('&').filter(|pair| !pair.is_empty()) {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            fields.insert(decode(name), decode(value));
+        }
+        fields
+    }
+
+    /// Reads the fields from the browser's `FormData`.
+    pub fn from_form_data(form_data: &web_sys::FormData) -> Self {
+        let data =
+            web_sys::UrlSearchParams::new_with_str_sequence_sequence(form_data)
+                .unwrap_throw();
+        Self::from_urlencoded(&data.to_string().as_string().unwrap_or_default())
+    }
+
+    /// Reads the fields of the form that was submitted, given only the `submit` event.
+    pub fn from_event(ev: &web_sys::Event) -> Result<Self, FromFormDataError> {
+        form_data_from_event(ev.unchecked_ref())
+            .map(|form_data| Self::from_form_data(&form_data))
+    }
+
+    /// Adds a value for the field with the given name.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) {
+        self.0.entry(name.into()).or_default().push(value.into());
+    }
+
+    /// The first value of the field with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .get(name)
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    }
+
+    /// Every value of the field with the given name.
+    pub fn get_all(&self, name: &str) -> &[String] {
+        self.0.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Whether the field, or any field nested inside it, has a value that is not empty.
+    pub fn has_value(&self, name: &str) -> bool {
+        self.0.iter().any(|(key, values)| {
+            (key == name
+                || key
+                    .strip_prefix(name)
+                    .is_some_and(|rest| rest.starts_with('[')))
+                && values.iter().any(|value| !value.is_empty())
+        })
+    }
+
+    /// The indices of a list of nested fields, such as `items[0][name]` and `items[1][name]`.
+    fn indices(&self, name: &str) -> Vec<usize> {
+        let prefix = format!("{name}[");
+        let mut indices = self
+            .0
+            .keys()
+            .filter_map(|key| {
+                let rest = key.strip_prefix(&prefix)?;
+                rest[..rest.find(']')?].parse().ok()
+            })
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+/// A type that can be parsed from the fields of a submitted form, with a separate error for each
+/// field that is missing, could not be parsed, or is invalid.
+///
+/// This is usually derived with `#[derive(FormData)]`. Each field of the struct is read from the
+/// form field of the same name, and nested structs from the fields inside it (such as
+/// `address[city]`). Fields can be:
+/// - strings, numbers, `char`s, or `bool`s (which are `true` for a checked checkbox);
+/// - other types that implement `FormData`, such as nested structs;
+/// - `Option`s of these, which are `None` if the field is missing or empty;
+/// - `Vec`s of these, read from repeated fields (such as a group of checkboxes) or from indexed
+///   fields (such as `items[0][name]`);
+/// - any other type that implements [`FromStr`].
+///
+/// The `#[form]` attribute can rename a field with `#[form(rename = "...")]`, or validate it
+/// once it has been parsed with `#[form(validate = path::to_fn)]`, where the function takes a
+/// reference to the value and returns a `Result<(), impl Display>`.
+///
+/// The errors are a [`FormErrors`] map from the name of each form field to its error, which can
+/// be shown next to the input with that name, or used by a
+/// [`FormState`](crate::form::FormState).
+///
+/// ```rust
+/// use leptos::{
+///     form::{FormData, FormErrors, FormFields},
+///     prelude::*,
+/// };
+///
+/// #[derive(FormData)]
+/// struct Signup {
+///     #[form(validate = valid_email)]
+///     email: String,
+///     age: u8,
+///     address: Address,
+/// }
+///
+/// #[derive(FormData)]
+/// struct Address {
+///     city: String,
+///     postcode: Option<String>,
+/// }
+///
+/// fn valid_email(email: &String) -> Result<(), &'static str> {
+///     email.contains('@').then_some(()).ok_or("Enter a valid email.")
+/// }
+///
+/// let fields = FormFields::from_urlencoded("email=leptos&age=old&address[city]=");
+/// let errors = Signup::from_form(&fields).err().unwrap();
+/// assert_eq!(errors["email"], "Enter a valid email.");
+/// assert_eq!(errors["age"], "invalid digit found in string");
+/// assert_eq!(errors["address[city]"], "This field is required.");
+///
+/// #[component]
+/// fn SignupForm() -> impl IntoView {
+///     let errors = RwSignal::new(FormErrors::new());
+///     let on_submit = move |ev: leptos::ev::SubmitEvent| {
+///         let Ok(fields) = FormFields::from_event(&ev) else { return };
+///         match Signup::from_form(&fields) {
+///             Ok(_) => errors.set(FormErrors::new()),
+///             Err(e) => {
+///                 ev.prevent_default();
+///                 errors.set(e);
+///             }
+///         }
+///     };
+///
+///     view! {
+///         <form on:submit=on_submit>
+///             <input name="email"/>
+///             <p>{move || errors.with(|errors| errors.get("email").cloned())}</p>
+///             // ...
+///         </form>
+///     }
+/// }
+/// ```
+pub trait FormData: Sized {
+    /// Parses the value of the field with the given name. For a struct, this is the name inside
+    /// which its fields are nested; the name of the form as a whole is empty.
+    fn from_fields(fields: &FormFields, name: &str)
+        -> Result<Self, FormErrors>;
+
+    /// Parses the value from all of the fields of a form.
+    fn from_form(fields: &FormFields) -> Result<Self, FormErrors> {
+        Self::from_fields(fields, "")
+    }
+}
+
+const REQUIRED: &str = "This field is required.";
+
+fn error(name: &str, error: impl Display) -> FormErrors {
+    FormErrors::from([(name.to_string(), error.to_string())])
+}
+
+/// Parses a required field with [`FromStr`].
+pub fn parse_form_field<T>(
+    fields: &FormFields,
+    name: &str,
+) -> Result<T, FormErrors>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match fields.get(name) {
+        None | Some("") => Err(error(name, REQUIRED)),
+        Some(value) => value.parse().map_err(|e| error(name, e)),
+    }
+}
+
+macro_rules! form_data_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl FormData for $ty {
+                fn from_fields(
+                    fields: &FormFields,
+                    name: &str,
+                ) -> Result<Self, FormErrors> {
+                    parse_form_field(fields, name)
+                }
+            }
+        )*
+    };
+}
+
+form_data_from_str!(
+    String, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+    usize, f32, f64
+);
+
+impl FormData for bool {
+    fn from_fields(
+        fields: &FormFields,
+        name: &str,
+    ) -> Result<Self, FormErrors> {
+        // checkboxes are only submitted when they are checked, with the value `on` by default
+        match fields.get(name) {
+            None | Some("" | "false" | "off") => Ok(false),
+            Some("true" | "on") => Ok(true),
+            Some(value) => value.parse().map_err(|e| error(name, e)),
+        }
+    }
+}
+
+impl<T: FormData> FormData for Option<T> {
+    fn from_fields(
+        fields: &FormFields,
+        name: &str,
+    ) -> Result<Self, FormErrors> {
+        if fields.has_value(name) {
+            T::from_fields(fields, name).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<T: FormData> FormData for Vec<T> {
+    fn from_fields(
+        fields: &FormFields,
+        name: &str,
+    ) -> Result<Self, FormErrors> {
+        let mut items = Vec::new();
+        let mut errors = FormErrors::new();
+        let mut push = |item| match item {
+            Ok(item) => items.push(item),
+            Err(e) => errors.extend(e),
+        };
+
+        let indices = fields.indices(name);
+        if indices.is_empty() {
+            // repeated fields, with or without the `[]` suffix
+            let list_name = format!("{name}[]");
+            for value in fields
+                .get_all(name)
+                .iter()
+                .chain(fields.get_all(&list_name))
+            {
+                let mut item = FormFields::new();
+                item.insert(name, value.as_str());
+                push(T::from_fields(&item, name));
+            }
+        } else {
+            for index in indices {
+                push(T::from_fields(fields, &format!("{name}[{index}]")));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(items)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[doc(hidden)]
+pub mod macro_helpers {
+    use super::{parse_form_field, FormData, FormErrors, FormFields};
+    use std::{fmt::Display, str::FromStr};
+
+    /// Returns the name of a field nested inside the field with the given name.
+    pub fn field_name(parent: &str, field: &str) -> String {
+        if parent.is_empty() {
+            field.to_string()
+        } else {
+            format!("{parent}[{field}]")
+        }
+    }
+
+    /// Adds the error returned by a field's validator, if any.
+    pub fn validate<T, E: Display>(
+        value: &T,
+        name: &str,
+        validator: impl Fn(&T) -> Result<(), E>,
+        errors: &mut FormErrors,
+    ) {
+        if let Err(e) = validator(value) {
+            errors.insert(name.to_string(), e.to_string());
+        }
+    }
+
+    /// This struct is never actually created; it just exists so that we can impl associated
+    /// functions on it.
+    pub struct Wrapper<T>(T);
+
+    impl<T: FormData> Wrapper<T> {
+        /// This is the 'preferred' impl to be used for all `T` that implement `FormData`.
+        /// Because it is directly on the struct, the compiler will pick this over the impl from
+        /// the `Fallback` trait.
+        #[inline]
+        pub fn __from_fields(
+            fields: &FormFields,
+            name: &str,
+        ) -> Result<T, FormErrors> {
+            T::from_fields(fields, name)
+        }
+    }
+
+    /// If the Fallback trait is in scope, then the compiler has two possible implementations for
+    /// `__from_fields`. It will pick the one from this trait if the inherent one doesn't exist.
+    /// (which it won't if `T` does not implement `FormData`)
+    pub trait Fallback<T>: Sized
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        /// Fallback function in case the inherent impl on the Wrapper struct does not exist for
+        /// `T`
+        #[inline]
+        fn __from_fields(
+            fields: &FormFields,
+            name: &str,
+        ) -> Result<T, FormErrors> {
+            parse_form_field(fields, name)
+        }
+    }
+
+    impl<T> Fallback<T> for Wrapper<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_lists_and_optional_fields() {
+        let fields = FormFields::from_urlencoded(
+            "tags=a&tags=b+c&items[0]=1&items[1]=x&note=&flag=on",
+        );
+        assert_eq!(
+            Vec::<String>::from_fields(&fields, "tags").unwrap(),
+            ["a", "b c"]
+        );
+        assert_eq!(
+            Vec::<u8>::from_fields(&fields, "items").unwrap_err(),
+            error("items[1]", "invalid digit found in string")
+        );
+        assert_eq!(
+            Option::<String>::from_fields(&fields, "note").unwrap(),
+            None
+        );
+        assert!(bool::from_fields(&fields, "flag").unwrap());
+        assert!(!bool::from_fields(&fields, "missing").unwrap());
+    }
+}
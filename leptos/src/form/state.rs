@@ -0,0 +1,483 @@
+use reactive_graph::{
+    actions::Action,
+    computed::Memo,
+    effect::Effect,
+    owner::StoredValue,
+    signal::RwSignal,
+    traits::{
+        Get, GetUntracked, GetValue, Set, Update, With, WithUntracked,
+        WithValue,
+    },
+    wrappers::{read::Signal, write::SignalSetter},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    sync::Arc,
+};
+use tachys::reactive_graph::bind::IntoSplitSignal;
+use web_sys::SubmitEvent;
+
+/// Validation errors for a form, as a map from each field name to its error message.
+pub type FormErrors = BTreeMap<String, String>;
+
+/// The submission status of a [`FormState`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SubmitStatus {
+    /// The form has not been submitted since it was created or reset.
+    #[default]
+    Idle,
+    /// The form has been submitted, and is waiting for a response.
+    Submitting,
+    /// The most recent submission succeeded.
+    Succeeded,
+    /// The most recent submission failed, with the given error message.
+    Failed(String),
+}
+
+type Validator<T> = Arc<dyn Fn(&T) -> FormErrors + Send + Sync>;
+
+/// Reactive state for a form: its current values, which fields have been touched, whether it
+/// has changed since it was created, its validation errors, and its submission status.
+///
+/// The values are stored in a single [`RwSignal`], which can be bound to individual inputs
+/// with [`RwSignal::slice`]. Validation errors are derived from the values, and are only shown
+/// for a field once it has been touched or the form has been submitted.
+///
+/// `FormState` works with [`ActionForm`](crate::form::ActionForm): listen for `submit` during
+/// the capture phase to block invalid submissions, and use
+/// [`track_action`](FormState::track_action) to follow the status of the server action.
+///
+/// ```rust
+/// use leptos::{
+///     form::{FormErrors, FormState},
+///     prelude::*,
+/// };
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Signup {
+///     email: String,
+/// }
+///
+/// #[component]
+/// fn SignupForm() -> impl IntoView {
+///     let save = ServerAction::<SaveSignup>::new();
+///     let form = FormState::new_with_validator(Signup::default(), |signup| {
+///         let mut errors = FormErrors::new();
+///         if !signup.email.contains('@') {
+///             errors.insert("email".into(), "Enter a valid email".into());
+///         }
+///         errors
+///     });
+///     form.track_action(&save);
+///     let email = form
+///         .values()
+///         .slice(|signup| signup.email.clone(), |signup, email| signup.email = email);
+///
+///     view! {
+///         <ActionForm action=save on:submit:capture=move |ev| form.validate_on_submit(&ev)>
+///             <input
+///                 name="email"
+///                 bind:value=email
+///                 on:blur=move |_| form.touch("email")
+///             />
+///             <p>{move || form.error_for("email")}</p>
+///             <button disabled=move || form.is_submitting()>"Sign up"</button>
+///         </ActionForm>
+///     }
+/// }
+///
+/// #[server]
+/// async fn save_signup(email: String) -> Result<(), ServerFnError> {
+///     Ok(())
+/// }
+/// ```
+pub struct FormState<T: 'static> {
+    initial: RwSignal<T>,
+    values: RwSignal<T>,
+    touched: RwSignal<BTreeSet<String>>,
+    submitted: RwSignal<bool>,
+    status: RwSignal<SubmitStatus>,
+    errors: Memo<FormErrors>,
+    validator: StoredValue<Validator<T>>,
+}
+
+impl<T> Clone for FormState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FormState<T> {}
+
+impl<T> FormState<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    /// Creates state for a form with the given initial values, and no validation.
+    #[track_caller]
+    pub fn new(initial: T) -> Self {
+        Self::new_with_validator(initial, |_| FormErrors::new())
+    }
+
+    /// Creates state for a form with the given initial values, validated by a function that
+    /// returns the errors for each invalid field.
+    #[track_caller]
+    pub fn new_with_validator(
+        initial: T,
+        validator: impl Fn(&T) -> FormErrors + Send + Sync + 'static,
+    ) -> Self {
+        let validator: Validator<T> = Arc::new(validator);
+        let values = RwSignal::new(initial.clone());
+        let errors = Memo::new({
+            let validator = Arc::clone(&validator);
+            move |_| values.with(|values| validator(values))
+        });
+        Self {
+            initial: RwSignal::new(initial),
+            values,
+            touched: RwSignal::new(BTreeSet::new()),
+            submitted: RwSignal::new(false),
+            status: RwSignal::new(SubmitStatus::Idle),
+            errors,
+            validator: StoredValue::new(validator),
+        }
+    }
+
+    /// The current values of the form.
+    pub fn values(&self) -> RwSignal<T> {
+        self.values
+    }
+
+    /// Whether the values have changed from their initial values.
+    pub fn is_dirty(&self) -> bool {
+        self.initial
+            .with(|initial| self.values.with(|values| initial != values))
+    }
+
+    /// A signal that is `true` while the values differ from their initial values.
+    ///
+    /// This can be passed to anything that takes a reactive condition, for example to warn
+    /// before leaving a page with unsaved changes.
+    pub fn dirty(&self) -> Signal<bool> {
+        let this = *self;
+        Memo::new(move |_| this.is_dirty()).into()
+    }
+
+    /// Creates a binding for one field of the values, which can be used with `bind:value` (or
+    /// `bind:checked`, and so on) and tracks whether that field is dirty or touched.
+    ///
+    /// The field is marked as touched as soon as its input changes it. Call
+    /// [`FormField::touch`] from `on:blur` to also mark it when the user leaves the input
+    /// without changing it.
+    ///
+    /// ```rust
+    /// use leptos::{form::FormState, prelude::*};
+    ///
+    /// #[derive(Clone, Default, PartialEq)]
+    /// struct Profile {
+    ///     name: String,
+    ///     newsletter: bool,
+    /// }
+    ///
+    /// #[component]
+    /// fn ProfileForm() -> impl IntoView {
+    ///     let form = FormState::new(Profile::default());
+    ///     let name = form.field("name", |p| p.name.clone(), |p, name| p.name = name);
+    ///     let newsletter = form.field(
+    ///         "newsletter",
+    ///         |p| p.newsletter,
+    ///         |p, newsletter| p.newsletter = newsletter,
+    ///     );
+    ///
+    ///     view! {
+    ///         <input name="name" bind:value=name on:blur=move |_| name.touch()/>
+    ///         <span class:changed=move || name.is_dirty()>"*"</span>
+    ///         <input type="checkbox" name="newsletter" bind:checked=newsletter/>
+    ///         <button disabled=move || !form.is_dirty()>"Save"</button>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn field<O>(
+        &self,
+        name: impl Into<String>,
+        getter: impl Fn(&T) -> O + Copy + Send + Sync + 'static,
+        setter: impl Fn(&mut T, O) + Copy + Send + Sync + 'static,
+    ) -> FormField<O>
+    where
+        O: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let this = *self;
+        let name = StoredValue::new(name.into());
+        let (value, set) = self.values.slice(getter, setter);
+        let dirty = Memo::new(move |_| {
+            this.initial.with(|initial| {
+                this.values.with(|values| getter(initial) != getter(values))
+            })
+        });
+        let touched =
+            Memo::new(move |_| name.with_value(|name| this.is_touched(name)));
+        let error = Signal::derive(move || {
+            name.with_value(|name| this.error_for(name))
+        });
+        let set = SignalSetter::map(move |value| {
+            set.set(value);
+            name.with_value(|name| this.touch(name));
+        });
+        FormField {
+            name,
+            value,
+            set,
+            dirty,
+            touched,
+            error,
+            form_touched: this.touched,
+        }
+    }
+
+    /// Marks a field as touched, which is usually done when the input loses focus.
+    pub fn touch(&self, field: &str) {
+        if !self
+            .touched
+            .with_untracked(|touched| touched.contains(field))
+        {
+            self.touched.update(|touched| {
+                touched.insert(field.to_string());
+            });
+        }
+    }
+
+    /// Whether a field has been touched.
+    pub fn is_touched(&self, field: &str) -> bool {
+        self.touched.with(|touched| touched.contains(field))
+    }
+
+    /// The validation errors for every field, whether or not it has been touched.
+    pub fn errors(&self) -> Memo<FormErrors> {
+        self.errors
+    }
+
+    /// Whether the current values pass validation.
+    pub fn is_valid(&self) -> bool {
+        self.errors.with(|errors| errors.is_empty())
+    }
+
+    /// The validation error for a field, if it has one and has been touched, or if submission
+    /// has been attempted. This is the error that should usually be shown to the user.
+    pub fn error_for(&self, field: &str) -> Option<String> {
+        if !self.submitted.get() && !self.is_touched(field) {
+            return None;
+        }
+        self.errors.with(|errors| errors.get(field).cloned())
+    }
+
+    /// The submission status of the form.
+    pub fn status(&self) -> SubmitStatus {
+        self.status.get()
+    }
+
+    /// Whether the form is waiting for a response to a submission.
+    pub fn is_submitting(&self) -> bool {
+        self.status
+            .with(|status| *status == SubmitStatus::Submitting)
+    }
+
+    /// Marks that submission has been attempted, so that errors are shown for every field, and
+    /// returns whether the values are valid.
+    ///
+    /// Validation runs against the current values immediately, so this can be called from an
+    /// event handler.
+    pub fn try_submit(&self) -> bool {
+        self.submitted.set(true);
+        let validator = self.validator.get_value();
+        self.values
+            .with_untracked(|values| validator(values).is_empty())
+    }
+
+    /// Calls [`try_submit`](FormState::try_submit), and prevents the event's default behavior
+    /// if the values are invalid.
+    ///
+    /// Use this in a capture-phase listener on an [`ActionForm`](crate::form::ActionForm)
+    /// (`on:submit:capture`), which runs before the form dispatches its action, so that invalid
+    /// values are never submitted.
+    pub fn validate_on_submit(&self, ev: &SubmitEvent) {
+        if !self.try_submit() {
+            ev.prevent_default();
+        }
+    }
+
+    /// Sets the submission status of the form. Use this when submitting without an action.
+    pub fn set_status(&self, status: SubmitStatus) {
+        self.status.set(status);
+    }
+
+    /// Follows the status of an action that submits the form: [`SubmitStatus::Submitting`] while
+    /// it is pending, and then [`SubmitStatus::Succeeded`] or [`SubmitStatus::Failed`] depending
+    /// on its result.
+    pub fn track_action<I, O, E>(&self, action: &Action<I, Result<O, E>>)
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        E: Display + Send + Sync + 'static,
+    {
+        let action = *action;
+        let status = self.status;
+        Effect::new(move |_| {
+            if action.pending().get() {
+                status.set(SubmitStatus::Submitting);
+                return;
+            }
+            let result = action.value().with(|value| match value {
+                Some(Ok(_)) => Some(SubmitStatus::Succeeded),
+                Some(Err(e)) => Some(SubmitStatus::Failed(e.to_string())),
+                None => None,
+            });
+            if let Some(result) = result {
+                status.set(result);
+            }
+        });
+    }
+
+    /// Restores the initial values, and clears touched fields and the submission status.
+    pub fn reset(&self) {
+        self.values.set(self.initial.get_untracked());
+        self.touched.update(BTreeSet::clear);
+        self.submitted.set(false);
+        self.status.set(SubmitStatus::Idle);
+    }
+
+    /// Makes the current values the new initial values, so that the form is no longer dirty.
+    /// This is useful after the values have been saved.
+    pub fn commit(&self) {
+        self.initial.set(self.values.get_untracked());
+    }
+}
+
+/// One field of a [`FormState`], created with [`FormState::field`].
+///
+/// It can be bound to an input with `bind:`, and tracks whether the field has changed from its
+/// initial value and whether it has been touched.
+pub struct FormField<O: Send + Sync + 'static> {
+    name: StoredValue<String>,
+    value: Signal<O>,
+    set: SignalSetter<O>,
+    dirty: Memo<bool>,
+    touched: Memo<bool>,
+    error: Signal<Option<String>>,
+    form_touched: RwSignal<BTreeSet<String>>,
+}
+
+impl<O> Clone for FormField<O>
+where
+    O: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O> Copy for FormField<O> where O: Send + Sync {}
+
+impl<O> FormField<O>
+where
+    O: Clone + Send + Sync + 'static,
+{
+    /// The current value of the field.
+    pub fn value(&self) -> Signal<O> {
+        self.value
+    }
+
+    /// Sets the value of the field, and marks it as touched.
+    pub fn set(&self, value: O) {
+        self.set.set(value);
+    }
+
+    /// A signal that is `true` while the field differs from its initial value.
+    pub fn dirty(&self) -> Memo<bool> {
+        self.dirty
+    }
+
+    /// Whether the field differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    /// A signal that is `true` once the field has been touched.
+    pub fn touched(&self) -> Memo<bool> {
+        self.touched
+    }
+
+    /// Whether the field has been touched.
+    pub fn is_touched(&self) -> bool {
+        self.touched.get()
+    }
+
+    /// Marks the field as touched, which is usually done when the input loses focus.
+    pub fn touch(&self) {
+        let form_touched = self.form_touched;
+        self.name.with_value(|name| {
+            if !form_touched.with_untracked(|touched| touched.contains(name)) {
+                form_touched.update(|touched| {
+                    touched.insert(name.clone());
+                });
+            }
+        });
+    }
+
+    /// The validation error for the field, if it should be shown. See
+    /// [`FormState::error_for`].
+    pub fn error(&self) -> Option<String> {
+        self.error.get()
+    }
+}
+
+impl<O> IntoSplitSignal for FormField<O>
+where
+    O: Clone + Send + Sync + 'static,
+{
+    type Value = O;
+    type Read = Signal<O>;
+    type Write = SignalSetter<O>;
+
+    fn into_split_signal(self) -> (Signal<O>, SignalSetter<O>) {
+        (self.value, self.set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormState;
+    use reactive_graph::{owner::Owner, traits::GetUntracked};
+
+    #[derive(Clone, Default, PartialEq)]
+    struct Profile {
+        name: String,
+        age: u8,
+    }
+
+    #[test]
+    fn fields_track_dirty_and_touched() {
+        let owner = Owner::new();
+        owner.set();
+
+        let form = FormState::new(Profile::default());
+        let name = form.field("name", |p| p.name.clone(), |p, n| p.name = n);
+        let age = form.field("age", |p| p.age, |p, age| p.age = age);
+        assert!(!form.dirty().get_untracked());
+
+        name.set("Leptos".into());
+        assert!(name.dirty().get_untracked());
+        assert!(name.touched().get_untracked());
+        assert!(!age.dirty().get_untracked());
+        assert!(!age.touched().get_untracked());
+        assert!(form.dirty().get_untracked());
+
+        age.touch();
+        assert!(age.touched().get_untracked());
+
+        name.set(String::new());
+        assert!(!name.dirty().get_untracked());
+        assert!(!form.dirty().get_untracked());
+    }
+}
@@ -1,7 +1,26 @@
-(function (root, pkg_path, output_name, wasm_output_name) {
+(function (root, pkg_path, output_name, wasm_output_name, wasm_integrity) {
+	// the WASM file is fetched with its integrity hash, if it has one, so that the preload is used
+	function wasmModule(url, integrity) {
+		return integrity ? fetch(url, { integrity }) : url;
+	}
+
+	// the resources and chunks sent after the shell of an out-of-order stream must all have
+	// arrived before hydration begins, so wait until the whole document has been parsed, while
+	// loading the WASM in the meantime
+	const documentLoaded = new Promise(resolve => {
+		if (document.readyState === "loading") {
+			document.addEventListener("DOMContentLoaded", resolve);
+		} else {
+			resolve();
+		}
+	});
+
 	import(`${root}/${pkg_path}/${output_name}.js`)
 		.then(mod => {
-			mod.default({module_or_path: `${root}/${pkg_path}/${wasm_output_name}.wasm`}).then(() => {
+			Promise.all([
+				mod.default({module_or_path: wasmModule(`${root}/${pkg_path}/${wasm_output_name}.wasm`, wasm_integrity)}),
+				documentLoaded
+			]).then(() => {
 				mod.hydrate();
 			});
 		})
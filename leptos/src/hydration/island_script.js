@@ -1,6 +1,4 @@
-((root, pkg_path, output_name, wasm_output_name) => {
-	let MOST_RECENT_CHILDREN_CB = [];
-
+((root, pkg_path, output_name, wasm_output_name, wasm_integrity) => {
 	function idle(c) {
 		if ("requestIdleCallback" in window) {
 			window.requestIdleCallback(c);
@@ -8,42 +6,157 @@
 			c();
 		}
 	}
+	// the WASM file is fetched with its integrity hash, if it has one, so that the preload is used
+	function wasmModule(url, integrity) {
+		return integrity ? fetch(url, { integrity }) : url;
+	}
+	// waits until the trigger in an island's `data-hydrate` attribute fires, then hydrates it
+	function onHydrationTrigger(el, trigger, hydrate) {
+		if (!trigger) {
+			return hydrate();
+		}
+		if (trigger === "idle") {
+			idle(hydrate);
+		} else if (trigger === "visible") {
+			if (!("IntersectionObserver" in window)) {
+				return hydrate();
+			}
+			const observer = new IntersectionObserver((entries) => {
+				if (entries.some(entry => entry.isIntersecting)) {
+					observer.disconnect();
+					hydrate();
+				}
+			});
+			// the island element is inline, so its own box may be empty; observe its children
+			const targets = el.children.length ? el.children : [el];
+			for (const target of targets) {
+				observer.observe(target);
+			}
+		} else if (trigger === "interaction") {
+			const events = ["pointerover", "pointerdown", "touchstart", "focusin"];
+			const listener = (e) => {
+				for (const event of events) {
+					el.removeEventListener(event, listener, { capture: true });
+				}
+				// the island had no event listeners when this event arrived, so dispatch a copy
+				// of it once the island has hydrated, to run its handlers
+				Promise.resolve(hydrate()).then(() => {
+					e.target.dispatchEvent(new e.constructor(e.type, e));
+				});
+			};
+			for (const event of events) {
+				el.addEventListener(event, listener, { capture: true, passive: true });
+			}
+		} else if (trigger.startsWith("media(") && trigger.endsWith(")")) {
+			const query = window.matchMedia(trigger.slice("media(".length, -1));
+			if (query.matches) {
+				return hydrate();
+			}
+			const listener = (e) => {
+				if (e.matches) {
+					query.removeEventListener("change", listener);
+					hydrate();
+				}
+			};
+			query.addEventListener("change", listener);
+		} else {
+			console.warn(`Unknown island hydration trigger ${trigger}.`);
+			return hydrate();
+		}
+	}
+	// deferred islands are rendered in full by a second request for the same page, which is
+	// shared by all of the deferred islands on that page
+	let deferredPage = null;
+	function fetchDeferredPage() {
+		const url = window.location.href;
+		if (!deferredPage || deferredPage.url !== url) {
+			deferredPage = {
+				url,
+				doc: fetch(url, { headers: { "Leptos-Deferred-Islands": "true" } })
+					.then(res => res.text())
+					.then(html => new DOMParser().parseFromString(html, "text/html"))
+			};
+		}
+		return deferredPage.doc;
+	}
+	function topLevelDeferredIslands(doc, component) {
+		return [...doc.querySelectorAll(`leptos-island[data-defer][data-component="${component}"]`)]
+			.filter(island => !island.parentElement.closest("leptos-island[data-defer]"));
+	}
+	// replaces the placeholder of a deferred island with its server-rendered contents
+	async function loadDeferredIsland(el) {
+		if (!("defer" in el.dataset) || el.$$deferLoaded) {
+			return;
+		}
+		el.$$deferLoaded = true;
+		const component = el.dataset.component;
+		const index = topLevelDeferredIslands(document, component).indexOf(el);
+		const doc = await fetchDeferredPage();
+		const rendered = topLevelDeferredIslands(doc, component)[index];
+		if (!rendered) {
+			console.warn(`Could not load the deferred island ${component}.`);
+			return;
+		}
+		el.replaceChildren(...[...rendered.childNodes].map(node => document.importNode(node, true)));
+		// deferred islands nested inside this one have already been rendered
+		for (const nested of el.querySelectorAll("leptos-island[data-defer]")) {
+			nested.$$deferLoaded = true;
+		}
+	}
 	async function hydrateIslands(rootNode, mod) {
-		async function traverse(node) {
+		// `childrenCbs` is a stack of the `on_hydrate` callbacks of the enclosing
+		// `<leptos-children>`, so that deferred islands still hydrate in the right owner
+		async function traverse(node, childrenCbs) {
 			if (node.nodeType === Node.ELEMENT_NODE) {
 				const tag = node.tagName.toLowerCase();
 				if(tag === 'leptos-island') {
-					const children = [];
-					const id = node.dataset.component || null;
-
-					await hydrateIsland(node, id, mod);
-					
-					for(const child of node.children) {
-						await traverse(child, children);
+					const trigger = node.dataset.hydrate;
+					if ("defer" in node.dataset) {
+						const stack = [...childrenCbs];
+						onHydrationTrigger(node, trigger, () =>
+							loadDeferredIsland(node).then(() => hydrateIslandTree(node, stack)));
+					} else if (trigger) {
+						const stack = [...childrenCbs];
+						onHydrationTrigger(node, trigger, () => hydrateIslandTree(node, stack));
+					} else {
+						await hydrateIslandTree(node, childrenCbs);
 					}
 				} else {
 					if (tag === 'leptos-children') {
-						MOST_RECENT_CHILDREN_CB.push(node.$$on_hydrate);
+						childrenCbs.push(node.$$on_hydrate);
 						for(const child of node.children) {
-							await traverse(child);
+							await traverse(child, childrenCbs);
 						};
 						// un-set the "most recent children"
-						MOST_RECENT_CHILDREN_CB.pop();
+						childrenCbs.pop();
 					} else {
 						for(const child of node.children) {
-							await traverse(child);
+							await traverse(child, childrenCbs);
 						};
 					}
 				}
 			}
 		}
+		async function hydrateIslandTree(node, childrenCbs) {
+			// this is separate from the `$$hydrated` flag used by the islands router, which marks
+			// every island on the initial page before this script has hydrated them
+			if (node.$$leptosHydrated) {
+				return;
+			}
+			node.$$leptosHydrated = true;
+			const id = node.dataset.component || null;
+			await hydrateIsland(node, id, mod, childrenCbs[childrenCbs.length-1]);
+
+			for(const child of node.children) {
+				await traverse(child, childrenCbs);
+			}
+		}
 
-		await traverse(rootNode);
+		await traverse(rootNode, []);
 	}
-	async function hydrateIsland(el, id, mod) {
+	async function hydrateIsland(el, id, mod, children_cb) {
 		const islandFn = mod[id];
 		if (islandFn) {
-			const children_cb = MOST_RECENT_CHILDREN_CB[MOST_RECENT_CHILDREN_CB.length-1];
 			if (children_cb) {
 				children_cb();
 			}
@@ -58,12 +171,13 @@
 	idle(() => {
 		import(`${root}/${pkg_path}/${output_name}.js`)
 			.then(mod => {
-				mod.default({module_or_path: `${root}/${pkg_path}/${wasm_output_name}.wasm`}).then(() => {
+				mod.default({module_or_path: wasmModule(`${root}/${pkg_path}/${wasm_output_name}.wasm`, wasm_integrity)}).then(() => {
 					mod.hydrate();
 					hydrateIslands(document.body, mod);
 				});
 
-				window.__hydrateIsland = (el, id) => hydrateIsland(el, id, mod);
+				window.__hydrateIsland = (el, id) => onHydrationTrigger(el, el.dataset.hydrate, () =>
+					loadDeferredIsland(el).then(() => hydrateIsland(el, id, mod)));
 			})
 	});
 })
@@ -120,8 +120,6 @@ function clickToReq(ev) {
 }
 
 function submitToReq(ev) {
-	event.preventDefault();
-
 	const target = ev.target;
 	/** @type HTMLFormElement */
 	let form;
@@ -134,15 +132,27 @@ function submitToReq(ev) {
 		form = target.form;
 	}
 
-	const method = form.method.toUpperCase();
+	// the button that submitted the form can override its action and method
+	const submitter = ev.submitter;
+	const method = (submitter?.getAttribute("formmethod") ?? form.method).toUpperCase();
 	if(method !== "GET" && method !== "POST") {
 		return;
 	}
 
-	const url = new URL(form.action);
-	let path = url.pathname;
+	// let the browser handle anything that can't be replayed as a same-origin navigation
+	const formTarget = submitter?.getAttribute("formtarget") ?? form.target;
+	const enctype = (submitter?.getAttribute("formenctype") ?? form.enctype).toLowerCase();
+	if((formTarget && formTarget !== "_self") || enctype === "multipart/form-data") {
+		return;
+	}
+
+	const url = new URL(submitter?.getAttribute("formaction") ?? form.action, window.location.href);
+	if(url.origin !== window.location.origin) {
+		return;
+	}
+
 	const requestInit = {};
-	const data = new FormData(form);
+	const data = new FormData(form, submitter);
 
 	const params = new URLSearchParams();
 	for (const [key, value] of data.entries()) {
@@ -153,7 +163,7 @@ function submitToReq(ev) {
 		Accept: "text/html"
 	};
 	if(method === "GET") {
-		path += `?${params.toString()}`;
+		url.search = params.toString();
 	}
 	else {
 		requestInit.method = "POST";
@@ -161,7 +171,7 @@ function submitToReq(ev) {
 	}
 
 	return new Request(
-		path,
+		url.pathname + url.search,
 		requestInit
 	);
 }
@@ -203,6 +213,14 @@ function diffRange(oldDocument, oldRoot, newDocument, newRoot, oldEnd, newEnd) {
 		else if (oldNode.nodeType === Node.ELEMENT_NODE && oldNode.tagName === "LEPTOS-ISLAND") {
 			// TODO: diff the leptos-children 
 
+			// an island with a different component or different props is a new island,
+			// so it is replaced with the server HTML and hydrated from scratch
+			if (islandKey(oldNode) !== islandKey(newNode)) {
+				const replacement = oldDocument.importNode(newNode, true);
+				oldNode.replaceWith(replacement);
+				oldDocWalker.currentNode = replacement;
+			}
+
 			// skip over leptos-island otherwise
 			oldDocWalker.nextSibling();
 			newDocWalker.nextSibling();
@@ -314,7 +332,10 @@ function replaceFor(oldDocument, oldDocWalker, newDocument, newDocWalker, oldNod
 		newRange.setStartAfter(newNode);
 		newRange.setEndAfter(newDocWalker.currentNode);
 		const newContents = newRange.extractContents();
-		oldRange.deleteContents();
+		const oldContents = oldRange.extractContents();
+		// adopt the new content first, so that kept islands never leave the current document
+		document.adoptNode(newContents);
+		keepIslands(oldContents, newContents);
 		oldRange.insertNode(newContents);
 		oldNode.replaceWith(newNode);
 		oldDocWalker.currentNode.replaceWith(newDocWalker.currentNode);
@@ -361,7 +382,10 @@ function replaceBranch(oldDocWalker, newDocWalker, oldNode, newNode) {
 		newRange.setStartAfter(newNode);
 		newRange.setEndAfter(newDocWalker.currentNode);
 		const newContents = newRange.extractContents();
-		oldRange.deleteContents();
+		const oldContents = oldRange.extractContents();
+		// adopt the new content first, so that kept islands never leave the current document
+		document.adoptNode(newContents);
+		keepIslands(oldContents, newContents);
 		oldRange.insertNode(newContents);
 		oldNode.replaceWith(newNode);
 		oldDocWalker.currentNode.replaceWith(newDocWalker.currentNode);
@@ -370,6 +394,41 @@ function replaceBranch(oldDocWalker, newDocWalker, oldNode, newNode) {
 	}
 }
 
+// identifies an island by its component and its props, so that an island with the same
+// props on the old and new pages can be kept alive
+function islandKey(island) {
+	return `${island.dataset.component}:${island.dataset.props || ""}`;
+}
+
+// moves hydrated islands from the old content into the matching places in the new content,
+// so that islands that appear on both pages keep their state rather than being rehydrated
+function keepIslands(oldContainer, newContainer) {
+	const oldIslands = {};
+	for(const island of oldContainer.querySelectorAll("leptos-island")) {
+		if(island.$$hydrated) {
+			const key = islandKey(island);
+			(oldIslands[key] ||= []).push(island);
+		}
+	}
+	for(const island of newContainer.querySelectorAll("leptos-island")) {
+		// skip islands nested inside an island that has already been replaced
+		if(!newContainer.contains(island)) {
+			continue;
+		}
+		const candidates = oldIslands[islandKey(island)] || [];
+		let old;
+		while((old = candidates.shift())) {
+			// skip islands nested inside an island that has already been moved
+			if(oldContainer.contains(old)) {
+				break;
+			}
+		}
+		if(old) {
+			island.replaceWith(old);
+		}
+	}
+}
+
 function diffElement(oldNode, newNode) {
 	/** @type Element */
 	const oldEl = oldNode;
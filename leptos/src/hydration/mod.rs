@@ -3,7 +3,17 @@
 use crate::{prelude::*, WasmSplitManifest};
 use leptos_config::LeptosOptions;
 use leptos_macro::{component, view};
-use std::{path::PathBuf, sync::OnceLock};
+use or_poisoned::OrPoisoned;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex, OnceLock},
+    time::SystemTime,
+};
+
+pub use hydration_context::{
+    set_shared_data_format, PlainTextFormat, SharedDataFormat,
+};
 
 /// Inserts auto-reloading code used in `cargo-leptos`.
 ///
@@ -41,6 +51,195 @@ pub fn AutoReload(
     })
 }
 
+/// The names of the JS, WASM and CSS files generated for the app, without their extensions.
+///
+/// These include the hashes of the files if [`hash_files`](LeptosOptions::hash_files) is
+/// enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetFileNames {
+    /// The name of the JS file.
+    pub js: String,
+    /// The name of the WASM file.
+    pub wasm: String,
+    /// The name of the CSS file.
+    pub css: String,
+    /// The integrity hash of the JS file, if it is listed in the [`AssetManifest`].
+    pub js_integrity: Option<String>,
+    /// The integrity hash of the WASM file, if it is listed in the [`AssetManifest`].
+    pub wasm_integrity: Option<String>,
+    /// The integrity hash of the CSS file, if it is listed in the [`AssetManifest`].
+    pub css_integrity: Option<String>,
+}
+
+impl AssetFileNames {
+    /// Returns the file names for the given options, reading the [`AssetManifest`] or the hash
+    /// file if necessary.
+    pub fn new(options: &LeptosOptions) -> Self {
+        let mut js = options.output_name.to_string();
+        let mut wasm = options.output_name.to_string();
+        let mut css = options.output_name.to_string();
+        if options.hash_files {
+            let hash_path = std::env::current_exe()
+                .map(|path| {
+                    path.parent().map(|p| p.to_path_buf()).unwrap_or_default()
+                })
+                .unwrap_or_default()
+                .join(options.hash_file.as_ref());
+            if hash_path.exists() {
+                let hashes = std::fs::read_to_string(&hash_path)
+                    .expect("failed to read hash file");
+                for line in hashes.lines() {
+                    let line = line.trim();
+                    if !line.is_empty() {
+                        if let Some((file, hash)) = line.split_once(':') {
+                            let name = match file {
+                                "js" => &mut js,
+                                "wasm" => &mut wasm,
+                                "css" => &mut css,
+                                _ => continue,
+                            };
+                            name.push_str(&format!(".{}", hash.trim()));
+                        }
+                    }
+                }
+            } else {
+                leptos::logging::error!(
+                    "File hashing is active but no hash file was found"
+                );
+            }
+        } else if std::option_env!("LEPTOS_OUTPUT_NAME").is_none() {
+            wasm.push_str("_bg");
+        }
+
+        let mut names = Self {
+            js,
+            wasm,
+            css,
+            js_integrity: None,
+            wasm_integrity: None,
+            css_integrity: None,
+        };
+        if let Some(manifest) = AssetManifest::load(options) {
+            for (asset, name, integrity, extension) in [
+                (manifest.js, &mut names.js, &mut names.js_integrity, ".js"),
+                (
+                    manifest.wasm,
+                    &mut names.wasm,
+                    &mut names.wasm_integrity,
+                    ".wasm",
+                ),
+                (
+                    manifest.css,
+                    &mut names.css,
+                    &mut names.css_integrity,
+                    ".css",
+                ),
+            ] {
+                if let Some(asset) = asset {
+                    *name = asset
+                        .file
+                        .strip_suffix(extension)
+                        .unwrap_or(&asset.file)
+                        .to_string();
+                    *integrity = asset.integrity;
+                }
+            }
+        }
+        names
+    }
+}
+
+/// The asset manifest produced by the build, which lists the hashed file names of the app's JS,
+/// WASM and CSS files, and their
+/// [subresource integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity)
+/// hashes.
+///
+/// It is read from the [`asset_manifest`](LeptosOptions::asset_manifest) file in the
+/// `site_pkg_dir`, if that option is set. [`HydrationScripts`] and `HashedStylesheet` use it
+/// (through [`AssetFileNames`]) to load the hashed files with their `integrity` attributes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+pub struct AssetManifest {
+    /// The JS file.
+    #[serde(default)]
+    pub js: Option<ManifestAsset>,
+    /// The WASM file.
+    #[serde(default)]
+    pub wasm: Option<ManifestAsset>,
+    /// The CSS file.
+    #[serde(default)]
+    pub css: Option<ManifestAsset>,
+}
+
+/// A file listed in the [`AssetManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ManifestAsset {
+    /// The file name, including its hash and extension.
+    pub file: String,
+    /// The integrity hash of the file, such as `sha384-...`.
+    #[serde(default)]
+    pub integrity: Option<String>,
+}
+
+impl AssetManifest {
+    /// Reads the manifest for the given options, if they set one.
+    ///
+    /// In release builds, the manifest is only read from disk the first time it is loaded, as it
+    /// does not change while the server is running. In debug builds, it is read again whenever
+    /// the file has been modified, so that a rebuild while the server is running (as with
+    /// `cargo leptos watch`) loads the new file names.
+    pub fn load(options: &LeptosOptions) -> Option<Self> {
+        type Cached = (Option<SystemTime>, Option<AssetManifest>);
+        static MANIFESTS: LazyLock<Mutex<HashMap<PathBuf, Cached>>> =
+            LazyLock::new(Default::default);
+
+        let file = options.asset_manifest.as_deref()?;
+        let path = Path::new(&*options.site_root)
+            .join(&*options.site_pkg_dir)
+            .join(file);
+        let mut manifests = MANIFESTS.lock().or_poisoned();
+        if cfg!(debug_assertions) {
+            if let Some((modified, _)) = manifests.get(&path) {
+                let current = std::fs::metadata(&path)
+                    .and_then(|meta| meta.modified())
+                    .ok();
+                if current != *modified {
+                    manifests.remove(&path);
+                }
+            }
+        }
+        manifests
+            .entry(path)
+            .or_insert_with_key(|path| {
+                let modified = std::fs::metadata(path)
+                    .and_then(|meta| meta.modified())
+                    .ok();
+                (modified, Self::read(path))
+            })
+            .1
+            .clone()
+    }
+
+    /// Reads and parses the manifest file, logging any error.
+    fn read(path: &Path) -> Option<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| {
+                leptos::logging::error!(
+                    "could not read asset manifest {}: {e}",
+                    path.display()
+                )
+            })
+            .ok()?;
+        serde_json::from_str(&text)
+            .map_err(|e| {
+                leptos::logging::error!(
+                    "could not parse asset manifest {}: {e}",
+                    path.display()
+                )
+            })
+            .ok()
+    }
+}
+
 /// Inserts hydration scripts that add interactivity to your server-rendered HTML.
 ///
 /// This should be included in the `<head>` of your application shell.
@@ -55,10 +254,17 @@ pub fn HydrationScripts(
     /// when running in islands mode.
     #[prop(optional)]
     islands_router: bool,
-    /// A base url, not including a trailing slash
+    /// A base url, not including a trailing slash. Defaults to
+    /// [`site_base_path`](leptos_config::LeptosOptions::site_base_path).
     #[prop(optional, into)]
     root: Option<String>,
 ) -> impl IntoView {
+    let root = root.or_else(|| {
+        options
+            .site_base_path
+            .as_deref()
+            .map(|base| base.trim_end_matches('/').to_string())
+    });
     static SPLIT_MANIFEST: OnceLock<Option<WasmSplitManifest>> =
         OnceLock::new();
 
@@ -122,39 +328,16 @@ pub fn HydrationScripts(
         provide_context(splits.clone());
     }
 
-    let mut js_file_name = options.output_name.to_string();
-    let mut wasm_file_name = options.output_name.to_string();
-    if options.hash_files {
-        let hash_path = std::env::current_exe()
-            .map(|path| {
-                path.parent().map(|p| p.to_path_buf()).unwrap_or_default()
-            })
-            .unwrap_or_default()
-            .join(options.hash_file.as_ref());
-        if hash_path.exists() {
-            let hashes = std::fs::read_to_string(&hash_path)
-                .expect("failed to read hash file");
-            for line in hashes.lines() {
-                let line = line.trim();
-                if !line.is_empty() {
-                    if let Some((file, hash)) = line.split_once(':') {
-                        if file == "js" {
-                            js_file_name.push_str(&format!(".{}", hash.trim()));
-                        } else if file == "wasm" {
-                            wasm_file_name
-                                .push_str(&format!(".{}", hash.trim()));
-                        }
-                    }
-                }
-            }
-        } else {
-            leptos::logging::error!(
-                "File hashing is active but no hash file was found"
-            );
-        }
-    } else if std::option_env!("LEPTOS_OUTPUT_NAME").is_none() {
-        wasm_file_name.push_str("_bg");
-    }
+    let AssetFileNames {
+        js: js_file_name,
+        wasm: wasm_file_name,
+        js_integrity,
+        wasm_integrity,
+        ..
+    } = AssetFileNames::new(&options);
+    // the WASM file is fetched with its integrity hash, so that it can use the preload below
+    let wasm_integrity_arg = serde_json::to_string(&wasm_integrity)
+        .expect("could not serialize integrity hash");
 
     let pkg_path = &options.site_pkg_dir;
     #[cfg(feature = "nonce")]
@@ -175,18 +358,31 @@ pub fn HydrationScripts(
         .unwrap_or_default();
 
     let root = root.unwrap_or_default();
+    #[cfg(feature = "csrf")]
+    let csrf_meta = crate::csrf::csrf_meta();
+    #[cfg(not(feature = "csrf"))]
+    let csrf_meta = ();
+    // the hydration script runs as soon as it loads, so that the WASM is loaded while the rest
+    // of the page streams in; it still waits for the whole page before hydrating
     view! {
-        <link rel="modulepreload" href=format!("{root}/{pkg_path}/{js_file_name}.js") crossorigin=nonce.clone()/>
+        <link
+            rel="modulepreload"
+            href=format!("{root}/{pkg_path}/{js_file_name}.js")
+            integrity=js_integrity
+            crossorigin=nonce.clone()
+        />
         <link
             rel="preload"
             href=format!("{root}/{pkg_path}/{wasm_file_name}.wasm")
             r#as="fetch"
             r#type="application/wasm"
+            integrity=wasm_integrity
             crossorigin=nonce.clone().unwrap_or_default()
         />
-        <script type="module" nonce=nonce>
-            {format!("{script}({root:?}, {pkg_path:?}, {js_file_name:?}, {wasm_file_name:?});{islands_router}")}
+        <script type="module" r#async=!islands nonce=nonce>
+            {format!("{script}({root:?}, {pkg_path:?}, {js_file_name:?}, {wasm_file_name:?}, {wasm_integrity_arg});{islands_router}")}
         </script>
+        {csrf_meta}
     }
 }
 
@@ -201,3 +397,69 @@ pub fn HydrationScripts(
 /// included, as they only need to be sent to the client once.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IslandsRouterNavigation;
+
+/// If this is provided via context, it means that the client has requested the full HTML of
+/// the deferred islands on this page.
+///
+/// This should be provided automatically by a server integration if it detects that the
+/// header `Leptos-Deferred-Islands` is present in the request.
+///
+/// Islands that are marked `#[island(defer)]` are usually rendered as empty placeholders, so
+/// that the rest of the page can be cached. The island runtime then requests the page again
+/// with this header, to load their contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderDeferredIslands;
+
+/// Whether a deferred island should be rendered as an empty placeholder.
+#[doc(hidden)]
+pub fn should_defer_island() -> bool {
+    cfg!(feature = "ssr") && use_context::<RenderDeferredIslands>().is_none()
+}
+
+/// Records how long one phase of hydration takes, using the browser's
+/// [User Timing API](https://developer.mozilla.org/en-US/docs/Web/API/Performance_API/User_timing).
+///
+/// When the `performance-marks` feature is enabled, hydrating the root of the app and each
+/// island adds a `leptos:hydrate:<name>` measure (and `:start`/`:end` marks) to the
+/// performance timeline, where it can be picked up by the browser's devtools or by real-user
+/// monitoring tools. The root of the app is named `app`, and each island is named
+/// `island:<ComponentName>`. Without the feature, this does nothing.
+#[derive(Debug)]
+#[must_use = "the phase is only measured once `finish` is called"]
+pub struct HydrationMeasure {
+    #[cfg(feature = "performance-marks")]
+    name: String,
+}
+
+impl HydrationMeasure {
+    /// Marks the start of a phase of hydration.
+    #[allow(unused_variables)]
+    pub fn start(name: &str) -> Self {
+        #[cfg(feature = "performance-marks")]
+        {
+            let name = format!("leptos:hydrate:{name}");
+            if let Some(performance) =
+                leptos_dom::helpers::window().performance()
+            {
+                _ = performance.mark(&format!("{name}:start"));
+            }
+            Self { name }
+        }
+        #[cfg(not(feature = "performance-marks"))]
+        Self {}
+    }
+
+    /// Marks the end of the phase, and measures how long it took.
+    pub fn finish(self) {
+        #[cfg(feature = "performance-marks")]
+        if let Some(performance) = leptos_dom::helpers::window().performance() {
+            let end = format!("{}:end", self.name);
+            _ = performance.mark(&end);
+            _ = performance.measure_with_start_mark_and_end_mark(
+                &self.name,
+                &format!("{}:start", self.name),
+                &end,
+            );
+        }
+    }
+}
@@ -15,6 +15,8 @@ function connect() {
 			document.querySelectorAll("link").forEach((link) => {
 				if (link.getAttribute("href").includes(msg.css)) {
 					let newHref = "/" + msg.css + "?version=" + Date.now();
+					// the new stylesheet will not match the old integrity hash
+					link.removeAttribute("integrity");
 					link.setAttribute("href", newHref);
 					found = true;
 				}
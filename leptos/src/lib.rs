@@ -92,6 +92,10 @@
 //!   client unless they use the `#[island]` macro.
 //! - **`hydrate`** Hydration: use this to add interactivity to an SSRed Leptos app.
 //! - **`nonce`** Adds support for nonces to be added as part of a Content Security Policy.
+//! - **`cookies`** Adds typed [`cookies`] that can be used on the server and in the browser.
+//! - **`sessions`** Adds a [`session`] trait and context, with an in-memory example store.
+//! - **`csrf`** Adds [`csrf`] protection for server functions that are called from action forms.
+//! - **`signed-fields`** Adds [`signed`] hidden form fields, which cannot be changed in the browser.
 //! - **`rkyv`** In SSR/hydrate mode, enables using [`rkyv`](https://docs.rs/rkyv/latest/rkyv/) to serialize resources.
 //! - **`tracing`** Adds support for [`tracing`](https://docs.rs/tracing/latest/tracing/).
 //! - **`trace-component-props`** Adds `tracing` support for component props.
@@ -100,6 +104,9 @@
 //!   in exchange for occasional edge cases in which events behave differently from native browser
 //!   events.)
 //! - **`rustls`** Use `rustls` for server functions.
+//! - **`performance-marks`** Records how long it takes to hydrate the app and each island as
+//!   [`performance.measure`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/measure)
+//!   entries. See [`HydrationMeasure`](crate::hydration::HydrationMeasure).
 //!
 //! **Important Note:** You must enable one of `csr`, `hydrate`, or `ssr` to tell Leptos
 //! which mode your app is operating in. You should only enable one of these per build target,
@@ -168,10 +175,12 @@ pub mod prelude {
     mod export_types {
         #[cfg(feature = "nonce")]
         pub use crate::nonce::*;
+        #[cfg(feature = "persist")]
+        pub use crate::persist::Persisted;
         pub use crate::{
             callback::*, children::*, component::*, control_flow::*, error::*,
             form::*, hydration::*, into_view::*, mount::*, suspense::*,
-            text_prop::*,
+            text_prop::*, timing::*,
         };
         pub use leptos_config::*;
         pub use leptos_dom::helpers::*;
@@ -182,7 +191,7 @@ pub mod prelude {
             actions::*,
             computed::*,
             effect::*,
-            graph::untrack,
+            graph::{untrack, untrack_writes, untracked},
             owner::*,
             signal::*,
             wrappers::{read::*, write::*},
@@ -192,7 +201,17 @@ pub mod prelude {
             error::{FromServerFnError, ServerFnError, ServerFnErrorErr},
         };
         pub use tachys::{
-            reactive_graph::{bind::BindAttribute, node_ref::*, Suspend},
+            classes,
+            dom::{
+                animate_layout, observe_size, observe_visibility, SizeEntry,
+                VisibilityEntry,
+            },
+            reactive_graph::{
+                bind::{BindAttribute, BindContentAttribute},
+                controlled::{controlled, ControlledAttribute},
+                node_ref::*,
+                ClassPart, Classes, Suspend,
+            },
             view::{fragment::Fragment, template::ViewTemplate},
         };
     }
@@ -237,6 +256,15 @@ mod show_let;
 /// A component that allows rendering a component somewhere else.
 pub mod portal;
 
+#[cfg(feature = "cookies")]
+pub mod cookies;
+
+#[cfg(feature = "csrf")]
+pub mod csrf;
+
+/// A component for modal dialogs built on the native `<dialog>` element.
+pub mod dialog;
+
 /// Components to enable server-side rendering and client-side hydration.
 pub mod hydration;
 
@@ -244,6 +272,21 @@ pub mod hydration;
 #[cfg(feature = "nonce")]
 pub mod nonce;
 
+#[cfg(feature = "offline")]
+pub mod offline;
+
+#[cfg(feature = "persist")]
+pub mod persist;
+
+#[cfg(feature = "sessions")]
+pub mod session;
+
+#[cfg(feature = "signed-fields")]
+pub mod signed;
+
+#[cfg(feature = "worker")]
+pub mod worker;
+
 /// Components to load asynchronous data.
 pub mod suspense {
     pub use crate::{suspense_component::*, transition::*};
@@ -254,6 +297,8 @@ mod suspense_component;
 
 /// Types for reactive string properties for components.
 pub mod text_prop;
+
+pub mod timing;
 mod transition;
 pub use leptos_macro::*;
 #[doc(inline)]
@@ -284,7 +329,9 @@ pub use reactive_graph as reactive;
 /// Provide and access data along the reactive graph, sharing data without directly passing arguments.
 pub mod context {
     pub use crate::provider::*;
-    pub use reactive_graph::owner::{provide_context, use_context};
+    pub use reactive_graph::owner::{
+        provide_context, provide_context_with_default, use_context,
+    };
 }
 
 #[doc(inline)]
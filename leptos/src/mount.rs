@@ -60,6 +60,7 @@ where
     F: FnOnce() -> N + 'static,
     N: IntoView,
 {
+    use crate::hydration::HydrationMeasure;
     use hydration_context::HydrateSharedContext;
     use std::sync::Arc;
 
@@ -68,6 +69,10 @@ where
     // already initialized, which is not an issue
     _ = Executor::init_wasm_bindgen();
 
+    // server function requests from the browser send the token rendered by the server
+    #[cfg(feature = "csrf")]
+    crate::csrf::send_document_token();
+
     #[cfg(debug_assertions)]
     {
         if !cfg!(feature = "hydrate") && FIRST_CALL.get() {
@@ -85,6 +90,7 @@ where
 
     // create a new reactive owner and use it as the root node to run the app
     let owner = Owner::new_root(Some(Arc::new(HydrateSharedContext::new())));
+    let measure = HydrationMeasure::start("app");
     let mountable = owner.with(move || {
         let view = f().into_view();
         view.hydrate::<true>(
@@ -92,6 +98,7 @@ where
             &PositionState::default(),
         )
     });
+    measure.finish();
 
     if let Some(sc) = Owner::current_shared_context() {
         sc.hydration_complete();
@@ -112,6 +119,7 @@ where
     F: FnOnce() -> N + 'static,
     N: IntoView,
 {
+    use crate::hydration::HydrationMeasure;
     use hydration_context::HydrateSharedContext;
     use std::sync::Arc;
 
@@ -120,6 +128,10 @@ where
     // already initialized, which is not an issue
     _ = Executor::init_wasm_bindgen();
 
+    // server function requests from the browser send the token rendered by the server
+    #[cfg(feature = "csrf")]
+    crate::csrf::send_document_token();
+
     #[cfg(debug_assertions)]
     {
         if !cfg!(feature = "hydrate") && FIRST_CALL.get() {
@@ -137,6 +149,7 @@ where
 
     // create a new reactive owner and use it as the root node to run the app
     let owner = Owner::new_root(Some(Arc::new(HydrateSharedContext::new())));
+    let measure = HydrationMeasure::start("app");
     let mountable = owner
         .with(move || {
             use reactive_graph::computed::ScopedFuture;
@@ -151,6 +164,7 @@ where
             })
         })
         .await;
+    measure.finish();
 
     if let Some(sc) = Owner::current_shared_context() {
         sc.hydration_complete();
@@ -251,6 +265,10 @@ pub fn hydrate_islands() {
     // already initialized, which is not an issue
     _ = Executor::init_wasm_bindgen();
 
+    // server function requests from the browser send the token rendered by the server
+    #[cfg(feature = "csrf")]
+    crate::csrf::send_document_token();
+
     #[cfg(debug_assertions)]
     FIRST_CALL.set(false);
 
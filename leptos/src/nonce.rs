@@ -17,7 +17,8 @@ use tachys::html::attribute::AttributeValue;
 /// scripts used for HTML streaming and resource loading.
 ///
 /// The nonce being used during the current server response can be
-/// accessed using [`use_nonce`].
+/// accessed using [`use_nonce`]. `leptos_meta`’s `<ContentSecurityPolicy/>`
+/// component can add it to the policy with `add_nonce=true`.
 ///
 /// ```rust,ignore
 /// #[component]
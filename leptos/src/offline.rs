@@ -0,0 +1,383 @@
+//! A queue that keeps server function calls made while the browser is offline, and sends them
+//! once it is back online.
+//!
+//! An [`OfflineQueue`] is an [`Action`] that calls a server function, like a
+//! [`ServerAction`](crate::prelude::ServerAction). If it is dispatched while the browser is
+//! offline, or the server cannot be reached, its input is stored in
+//! [IndexedDB](https://developer.mozilla.org/en-US/docs/Web/API/IndexedDB_API) instead of being
+//! lost. The stored calls are retried, in the order they were made, when the browser's
+//! [`online`](https://developer.mozilla.org/en-US/docs/Web/API/Window/online_event) event fires,
+//! and the next time a queue with the same name is created (for example, when the page is
+//! reloaded).
+//!
+//! ```rust,no_run
+//! use leptos::{offline::OfflineQueue, prelude::*};
+//!
+//! #[server]
+//! async fn save_note(text: String) -> Result<(), ServerFnError> {
+//!     todo!()
+//! }
+//!
+//! #[component]
+//! fn Notes() -> impl IntoView {
+//!     let save = OfflineQueue::<SaveNote>::new("notes");
+//!     let (text, set_text) = signal(String::new());
+//!
+//!     view! {
+//!         <textarea bind:value=(text, set_text)/>
+//!         <button on:click=move |_| {
+//!             save.dispatch(SaveNote { text: text.get() });
+//!         }>"Save"</button>
+//!         <Show when=move || save.pending_sync().get() != 0>
+//!             <p>
+//!                 {move || save.pending_sync().get()}
+//!                 " note(s) will be saved when you are back online."
+//!             </p>
+//!         </Show>
+//!     }
+//! }
+//! ```
+//!
+//! Calls that reach the server are never queued, even if the server function returns an error.
+//! A call is queued only if its error is a network error, as reported by
+//! [`FromServerFnError::is_network_error`]. The inputs are stored as JSON, so they should not
+//! include anything that must not be kept in the browser.
+
+use crate::{ev, task::spawn_local};
+use futures::channel::oneshot;
+use leptos_dom::helpers::{window, window_event_listener};
+use reactive_graph::{
+    actions::Action,
+    effect::Effect,
+    owner::{on_cleanup, StoredValue},
+    signal::RwSignal,
+    traits::{
+        GetUntracked, GetValue, Set, SetValue, Update, UpdateValue, WithValue,
+    },
+    wrappers::read::Signal,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use server_fn::{
+    error::{FromServerFnError, IntoAppError, ServerFnErrorErr},
+    ServerFn,
+};
+use std::{
+    cell::RefCell, collections::VecDeque, ops::Deref, rc::Rc, sync::Arc,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{
+    IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode,
+    IdbVersionChangeEvent,
+};
+
+/// The IndexedDB database in which queued calls are stored.
+const DATABASE: &str = "leptos-offline";
+/// The object store that holds the queued inputs of each queue, under the queue's name.
+const STORE: &str = "queues";
+
+/// An action that calls the server function `S`, and queues the calls that cannot reach the
+/// server until the browser is back online.
+///
+/// See the [module-level documentation](crate::offline) for more details.
+pub struct OfflineQueue<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    action: Action<S, Result<S::Output, S::Error>>,
+    queue: Queue<S>,
+}
+
+/// The calls waiting to be sent, shared between the action and the queue.
+struct Queue<S: 'static> {
+    name: StoredValue<Arc<str>>,
+    queued: StoredValue<VecDeque<S>>,
+    // whether the stored calls have been loaded: until then, writing the queue would overwrite
+    // them, and sending it would send calls out of order
+    loaded: StoredValue<bool>,
+    pending_sync: RwSignal<usize>,
+    syncing: RwSignal<bool>,
+}
+
+impl<S> OfflineQueue<S>
+where
+    S: ServerFn + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    S::Output: Send + Sync + 'static,
+    S::Error: Send + Sync + 'static,
+{
+    /// Creates a queue for the server function `S`, stored under the given name.
+    ///
+    /// Any calls that were stored under this name before, for example on an earlier visit to
+    /// the page, are loaded and retried.
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
+        let queue = Queue {
+            name: StoredValue::new(name.into()),
+            queued: StoredValue::new(VecDeque::new()),
+            loaded: StoredValue::new(false),
+            pending_sync: RwSignal::new(0),
+            syncing: RwSignal::new(false),
+        };
+        let action = Action::new(move |input: &S| {
+            let input = input.clone();
+            async move {
+                // calls made while earlier ones are still queued wait behind them, so that they
+                // reach the server in order
+                if !is_online() || queue.pending_sync.get_untracked() != 0 {
+                    queue.enqueue(input);
+                    return Err(ServerFnErrorErr::Request(
+                        "The browser is offline. The call has been queued."
+                            .into(),
+                    )
+                    .into_app_error());
+                }
+                let result = S::run_on_client(input.clone()).await;
+                if matches!(&result, Err(e) if e.is_network_error()) {
+                    queue.enqueue(input);
+                }
+                result
+            }
+        });
+        let this = Self { action, queue };
+
+        // effects only run in the browser, where IndexedDB is available
+        Effect::new(move || {
+            spawn_local(async move {
+                let mut stored = load::<S>(&queue.name.get_value()).await;
+                // calls queued before the stored ones were loaded go after them
+                queue.queued.update_value(|queued| {
+                    stored.extend(queued.drain(..));
+                    *queued = stored;
+                });
+                queue.loaded.set_value(true);
+                queue.changed();
+                this.sync();
+            });
+
+            let handle =
+                window_event_listener(ev::online, move |_| this.sync());
+            on_cleanup(move || handle.remove());
+        });
+
+        this
+    }
+
+    /// The number of calls that are waiting to be sent to the server.
+    pub fn pending_sync(&self) -> Signal<usize> {
+        self.queue.pending_sync.into()
+    }
+
+    /// Whether the queued calls are being sent to the server.
+    pub fn syncing(&self) -> Signal<bool> {
+        self.queue.syncing.into()
+    }
+
+    /// Sends the queued calls to the server, in the order they were made.
+    ///
+    /// This is called automatically when the browser comes back online, so it only needs to be
+    /// called to retry sooner, for example from a "Retry" button. Sending stops at the first
+    /// call that still cannot reach the server. The action's value is set to the result of each
+    /// call that is sent.
+    pub fn sync(&self) {
+        let Self { action, queue } = *self;
+        if !queue.loaded.get_value()
+            || queue.syncing.get_untracked()
+            || queue.pending_sync.get_untracked() == 0
+            || !is_online()
+        {
+            return;
+        }
+        queue.syncing.set(true);
+        spawn_local(async move {
+            while let Some(input) =
+                queue.queued.with_value(|queued| queued.front().cloned())
+            {
+                let result = S::run_on_client(input).await;
+                if matches!(&result, Err(e) if e.is_network_error()) {
+                    break;
+                }
+                queue.queued.update_value(|queued| {
+                    queued.pop_front();
+                });
+                queue.changed();
+                action.value().set(Some(result));
+                action.version().update(|n| *n += 1);
+            }
+            queue.syncing.set(false);
+        });
+    }
+}
+
+impl<S> Queue<S>
+where
+    S: Serialize + Send + Sync + 'static,
+{
+    fn enqueue(&self, input: S) {
+        self.queued.update_value(|queued| queued.push_back(input));
+        self.changed();
+    }
+
+    /// Updates the number of pending calls, and writes the queue to IndexedDB once the stored
+    /// calls have been loaded.
+    fn changed(&self) {
+        let queue = *self;
+        self.pending_sync
+            .set(self.queued.with_value(|queued| queued.len()));
+        if !self.loaded.get_value() {
+            return;
+        }
+        spawn_local(async move {
+            let name = queue.name.get_value();
+            if let Err(e) = store(&name, queue.queued).await {
+                crate::logging::error!(
+                    "Could not store the offline queue {name:?}: {e:?}"
+                );
+            }
+        });
+    }
+}
+
+impl<S> Clone for Queue<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Queue<S> {}
+
+impl<S> Clone for OfflineQueue<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for OfflineQueue<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+}
+
+impl<S> Deref for OfflineQueue<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    type Target = Action<S, Result<S::Output, S::Error>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.action
+    }
+}
+
+fn is_online() -> bool {
+    window().navigator().on_line()
+}
+
+/// Loads the inputs stored under a queue's name. Inputs that can no longer be deserialized are
+/// dropped.
+async fn load<S: DeserializeOwned>(name: &str) -> VecDeque<S> {
+    let stored = async {
+        let db = open().await?;
+        let request = db
+            .transaction_with_str(STORE)?
+            .object_store(STORE)?
+            .get(&JsValue::from_str(name))?;
+        completed(&request).await
+    };
+    match stored.await {
+        Ok(value) => value
+            .as_string()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
+        Err(e) => {
+            crate::logging::error!(
+                "Could not load the offline queue {name:?}: {e:?}"
+            );
+            VecDeque::new()
+        }
+    }
+}
+
+/// Writes a queue's inputs to IndexedDB, or removes them if there are none.
+async fn store<S: Serialize + Send + Sync + 'static>(
+    name: &str,
+    queued: StoredValue<VecDeque<S>>,
+) -> Result<(), JsValue> {
+    let db = open().await?;
+    let store = db
+        .transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite)?
+        .object_store(STORE)?;
+    let key = JsValue::from_str(name);
+    // the queue is read only once the database is open, so that the last write always has
+    // the latest inputs, even if the writes were started in a different order
+    let json = queued
+        .try_with_value(|queued| {
+            (!queued.is_empty()).then(|| serde_json::to_string(queued))
+        })
+        .flatten()
+        .transpose()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let request = match json {
+        Some(json) => store.put_with_key(&JsValue::from_str(&json), &key)?,
+        None => store.delete(&key)?,
+    };
+    completed(&request).await.map(|_| ())
+}
+
+/// Opens the database, creating its object store the first time.
+async fn open() -> Result<IdbDatabase, JsValue> {
+    let request = window()
+        .indexed_db()?
+        .ok_or_else(|| JsValue::from_str("IndexedDB is not available."))?
+        .open_with_u32(DATABASE, 1)?;
+    let on_upgrade =
+        Closure::<dyn FnMut(_)>::new(move |ev: IdbVersionChangeEvent| {
+            let db = ev
+                .target()
+                .and_then(|target| {
+                    target.unchecked_into::<IdbOpenDbRequest>().result().ok()
+                })
+                .map(JsCast::unchecked_into::<IdbDatabase>);
+            if let Some(db) = db {
+                if !db.object_store_names().contains(STORE) {
+                    _ = db.create_object_store(STORE);
+                }
+            }
+        });
+    request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+    completed(&request).await.map(JsCast::unchecked_into)
+}
+
+/// Waits for an IndexedDB request to succeed or fail.
+async fn completed(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let (tx, rx) = oneshot::channel();
+    let tx = Rc::new(RefCell::new(Some(tx)));
+    let on_success = Closure::<dyn FnMut()>::new({
+        let request = request.clone();
+        let tx = Rc::clone(&tx);
+        move || {
+            if let Some(tx) = tx.borrow_mut().take() {
+                _ = tx.send(request.result());
+            }
+        }
+    });
+    let on_error = Closure::<dyn FnMut()>::new({
+        let request = request.clone();
+        move || {
+            if let Some(tx) = tx.borrow_mut().take() {
+                let error = request.error().ok().flatten().map(JsValue::from);
+                _ = tx.send(Err(error.unwrap_or(JsValue::UNDEFINED)));
+            }
+        }
+    });
+    request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
+    request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    let result = rx.await.unwrap_or(Err(JsValue::UNDEFINED));
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+    result
+}
@@ -0,0 +1,785 @@
+//! Signals that are persisted to browser storage.
+//!
+//! A persisted signal loads its initial value from `localStorage`, `sessionStorage`, or a
+//! cookie, and writes every change back to the same place. Values are serialized as JSON.
+//!
+//! ```rust,no_run
+//! use leptos::{persist::Storage, prelude::*};
+//!
+//! #[component]
+//! fn Settings() -> impl IntoView {
+//!     let dark_mode = RwSignal::<bool>::persisted("dark-mode", Storage::Local);
+//!
+//!     view! {
+//!         <input type="checkbox" bind:checked=dark_mode/>
+//!     }
+//! }
+//! ```
+//!
+//! ## Server rendering and hydration
+//!
+//! Browser storage is not available on the server, so the signal is created with its default
+//! value when rendering on the server. While hydrating, it also starts with the default value, so
+//! that the hydrated view matches the server-rendered HTML, and loads the stored value once
+//! hydration is complete.
+//!
+//! ## Syncing across tabs
+//!
+//! Signals stored in `localStorage` listen for the
+//! [`storage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/storage_event) event, so
+//! a change made in one tab is reflected in every other open tab.
+//!
+//! ## Snapshots
+//!
+//! A [`Snapshot`] groups together some of the state of an island (or any other component), and
+//! saves it to `sessionStorage` when the user leaves the page. The next time the page is loaded
+//! in the same tab, for example when the user navigates back to it, the island resumes from that
+//! snapshot instead of starting over. Unlike a persisted signal, the state is only written when
+//! the page is hidden or [`Snapshot::save`] is called, not on every change.
+//!
+//! ```rust,no_run
+//! use leptos::{persist::Snapshot, prelude::*};
+//!
+//! // this is usually an `#[island]`
+//! #[component]
+//! fn Wizard() -> impl IntoView {
+//!     let snapshot = Snapshot::new("checkout-wizard");
+//!     let step = snapshot.signal("step", 1);
+//!     let notes = snapshot.signal("notes", String::new());
+//!
+//!     view! {
+//!         <p>"Step " {step}</p>
+//!         <textarea bind:value=notes/>
+//!         <button on:click=move |_| *step.write() += 1>"Next"</button>
+//!     }
+//! }
+//! ```
+//!
+//! Snapshots are saved on the
+//! [`pagehide`](https://developer.mozilla.org/en-US/docs/Web/API/Window/pagehide_event) event,
+//! rather than `beforeunload` or `unload`, so they do not prevent the page from being kept in the
+//! browser's back/forward cache. (When a page is restored from that cache, its state has been
+//! kept in memory, and the snapshot is not needed.)
+//!
+//! ## Forms
+//!
+//! A [`PersistedForm`] saves the values of a form's inputs to `sessionStorage` each time the
+//! user changes one of them, and fills them back in the next time the form is rendered in the
+//! same tab, for example when the user navigates back to an earlier step of a multi-step flow.
+//! This works with plain inputs, without binding each of them to a signal. Password, file and
+//! hidden inputs are never saved.
+//!
+//! ```rust,no_run
+//! use leptos::{form::ActionForm, persist::PersistedForm, prelude::*};
+//!
+//! #[component]
+//! fn Shipping() -> impl IntoView {
+//!     let save = ServerAction::<SaveShipping>::new();
+//!     let form_ref = NodeRef::new();
+//!     let persisted = PersistedForm::new("checkout-shipping", form_ref);
+//!     // once the form has been submitted, the values no longer need to be kept
+//!     Effect::new(move || {
+//!         if matches!(save.value().get(), Some(Ok(_))) {
+//!             persisted.clear();
+//!         }
+//!     });
+//!
+//!     view! {
+//!         <ActionForm action=save node_ref=form_ref>
+//!             <input type="text" name="street"/>
+//!             <input type="text" name="city"/>
+//!             <input type="submit" value="Next"/>
+//!         </ActionForm>
+//!     }
+//! }
+//!
+//! #[server]
+//! async fn save_shipping(street: String, city: String) -> Result<(), ServerFnError> {
+//!     Ok(())
+//! }
+//! ```
+
+use crate::ev;
+use leptos_dom::helpers::{
+    document, is_server, window, window_event_listener,
+    window_event_listener_untyped,
+};
+use or_poisoned::OrPoisoned;
+use reactive_graph::{
+    effect::Effect,
+    owner::{on_cleanup, Owner},
+    signal::{ArcRwSignal, RwSignal},
+    traits::{Get, GetUntracked, Set, With},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+use tachys::{html::element::Form, reactive_graph::node_ref::NodeRef};
+use wasm_bindgen::JsCast;
+use web_sys::{
+    HtmlFormElement, HtmlInputElement, HtmlOptionElement, HtmlSelectElement,
+    HtmlTextAreaElement,
+};
+
+/// How long a persisted cookie is kept by the browser: one year.
+const COOKIE_MAX_AGE: u32 = 60 * 60 * 24 * 365;
+
+/// Where the value of a persisted signal is stored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Storage {
+    /// [`localStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage),
+    /// which persists across sessions and is shared by every tab with the same origin.
+    Local,
+    /// [`sessionStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/sessionStorage),
+    /// which is cleared when the tab is closed.
+    Session,
+    /// A cookie with the same name as the key, which is also sent to the server with each
+    /// request. The name and value are both percent-encoded.
+    Cookie,
+}
+
+impl Storage {
+    /// Whether browser storage can be accessed in the current environment.
+    pub fn is_available() -> bool {
+        cfg!(target_arch = "wasm32") && !is_server()
+    }
+
+    /// Returns the raw value stored at `key`, if any.
+    pub fn get(&self, key: &str) -> Option<String> {
+        if !Self::is_available() {
+            return None;
+        }
+        match self {
+            Storage::Cookie => {
+                let cookies = html_document()?.cookie().ok()?;
+                cookies.split(';').find_map(|cookie| {
+                    let (name, value) = cookie.trim().split_once('=')?;
+                    (decode_cookie_value(name) == key)
+                        .then(|| decode_cookie_value(value))
+                })
+            }
+            _ => self.web_storage()?.get_item(key).ok().flatten(),
+        }
+    }
+
+    /// Stores the raw value at `key`.
+    pub fn set(&self, key: &str, value: &str) {
+        if !Self::is_available() {
+            return;
+        }
+        match self {
+            Storage::Cookie => {
+                if let Some(document) = html_document() {
+                    _ = document.set_cookie(&format!(
+                        "{}={}; path=/; max-age={COOKIE_MAX_AGE}; \
+                         SameSite=Lax",
+                        encode_cookie_value(key),
+                        encode_cookie_value(value)
+                    ));
+                }
+            }
+            _ => {
+                if let Some(storage) = self.web_storage() {
+                    _ = storage.set_item(key, value);
+                }
+            }
+        }
+    }
+
+    /// Removes the value stored at `key`.
+    pub fn remove(&self, key: &str) {
+        if !Self::is_available() {
+            return;
+        }
+        match self {
+            Storage::Cookie => {
+                if let Some(document) = html_document() {
+                    _ = document.set_cookie(&format!(
+                        "{}=; path=/; max-age=0; SameSite=Lax",
+                        encode_cookie_value(key)
+                    ));
+                }
+            }
+            _ => {
+                if let Some(storage) = self.web_storage() {
+                    _ = storage.remove_item(key);
+                }
+            }
+        }
+    }
+
+    fn web_storage(&self) -> Option<web_sys::Storage> {
+        match self {
+            Storage::Local => window().local_storage().ok().flatten(),
+            Storage::Session => window().session_storage().ok().flatten(),
+            Storage::Cookie => None,
+        }
+    }
+
+    fn load<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.get(key)
+            .and_then(|value| serde_json::from_str(&value).ok())
+    }
+}
+
+fn html_document() -> Option<web_sys::HtmlDocument> {
+    document().dyn_into::<web_sys::HtmlDocument>().ok()
+}
+
+/// Constructors for signals whose value is persisted to browser storage.
+///
+/// See the [module-level documentation](self) for details.
+pub trait Persisted<T>: Sized {
+    /// Creates a signal that is persisted at `key` in the given storage, starting with the
+    /// stored value if there is one, or `T::default()` otherwise.
+    #[track_caller]
+    fn persisted(key: impl Into<String>, storage: Storage) -> Self
+    where
+        T: Default,
+    {
+        Self::persisted_or(key, storage, T::default())
+    }
+
+    /// Creates a signal that is persisted at `key` in the given storage, starting with the
+    /// stored value if there is one, or `default` otherwise.
+    #[track_caller]
+    fn persisted_or(
+        key: impl Into<String>,
+        storage: Storage,
+        default: T,
+    ) -> Self;
+}
+
+impl<T> Persisted<T> for ArcRwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn persisted_or(
+        key: impl Into<String>,
+        storage: Storage,
+        default: T,
+    ) -> Self {
+        let key = key.into();
+        if !Storage::is_available() {
+            return ArcRwSignal::new(default);
+        }
+
+        let hydrating = Owner::current_shared_context()
+            .map(|sc| sc.during_hydration())
+            .unwrap_or(false);
+        let signal = if hydrating {
+            ArcRwSignal::new(default)
+        } else {
+            ArcRwSignal::new(storage.load(&key).unwrap_or(default))
+        };
+
+        // effects only run once hydration is complete
+        if hydrating {
+            Effect::new({
+                let signal = signal.clone();
+                let key = key.clone();
+                move || {
+                    if let Some(value) = storage.load(&key) {
+                        signal.set(value);
+                    }
+                }
+            });
+        }
+
+        // write each change back to storage
+        Effect::watch(
+            {
+                let signal = signal.clone();
+                move || signal.with(|value| serde_json::to_string(value).ok())
+            },
+            {
+                let key = key.clone();
+                move |value, _, _| {
+                    if let Some(value) = value {
+                        storage.set(&key, value);
+                    }
+                }
+            },
+            false,
+        );
+
+        // sync changes made in other tabs
+        if storage == Storage::Local {
+            let handle = window_event_listener(ev::storage, {
+                let signal = signal.clone();
+                move |ev| {
+                    if ev.key().as_deref() != Some(key.as_str()) {
+                        return;
+                    }
+                    if let Some(value) = ev
+                        .new_value()
+                        .and_then(|value| serde_json::from_str(&value).ok())
+                    {
+                        signal.set(value);
+                    }
+                }
+            });
+            on_cleanup(move || handle.remove());
+        }
+
+        signal
+    }
+}
+
+impl<T> Persisted<T> for RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn persisted_or(
+        key: impl Into<String>,
+        storage: Storage,
+        default: T,
+    ) -> Self {
+        ArcRwSignal::persisted_or(key, storage, default).into()
+    }
+}
+
+type SnapshotEntries =
+    Arc<Mutex<Vec<(String, Box<dyn Fn() -> Option<Value> + Send + Sync>)>>>;
+
+/// Some reactive state that is saved to `sessionStorage` when the page is hidden, and restored
+/// the next time the page is loaded.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Clone)]
+pub struct Snapshot {
+    key: Arc<str>,
+    restored: Option<Arc<Map<String, Value>>>,
+    entries: SnapshotEntries,
+}
+
+impl Debug for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("key", &self.key)
+            .field("restored", &self.restored)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Snapshot {
+    /// Creates a snapshot stored at `key` in `sessionStorage`, loading the previous snapshot if
+    /// there is one.
+    ///
+    /// The key should be unique to this island on the page. The snapshot is saved whenever the
+    /// page is hidden, for as long as the current reactive owner is alive.
+    pub fn new(key: impl Into<String>) -> Self {
+        let key: Arc<str> = key.into().into();
+        let restored = Storage::Session
+            .load::<Map<String, Value>>(&key)
+            .map(Arc::new);
+        let this = Self {
+            key,
+            restored,
+            entries: Default::default(),
+        };
+
+        if Storage::is_available() {
+            let handle = window_event_listener(ev::pagehide, {
+                let this = this.clone();
+                move |_| this.save()
+            });
+            on_cleanup(move || handle.remove());
+        }
+
+        this
+    }
+
+    /// Whether a previous snapshot was found when this one was created.
+    pub fn is_restored(&self) -> bool {
+        self.restored.is_some()
+    }
+
+    /// Creates a signal that is included in the snapshot as `name`, starting with the value from
+    /// the previous snapshot if there is one, or `default` otherwise.
+    ///
+    /// As with [persisted signals](Persisted), the restored value is only applied once hydration
+    /// is complete, so that the hydrated view matches the server-rendered HTML.
+    #[track_caller]
+    pub fn signal<T>(&self, name: impl Into<String>, default: T) -> RwSignal<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let restored = self
+            .restored
+            .as_ref()
+            .and_then(|snapshot| snapshot.get(&name).cloned())
+            .and_then(|value| serde_json::from_value::<T>(value).ok());
+
+        let hydrating = Owner::current_shared_context()
+            .map(|sc| sc.during_hydration())
+            .unwrap_or(false);
+        let signal = match restored {
+            Some(value) if hydrating => {
+                let signal = ArcRwSignal::new(default);
+                // effects only run once hydration is complete
+                let mut value = Some(value);
+                Effect::new({
+                    let signal = signal.clone();
+                    move || {
+                        if let Some(value) = value.take() {
+                            signal.set(value);
+                        }
+                    }
+                });
+                signal
+            }
+            Some(value) => ArcRwSignal::new(value),
+            None => ArcRwSignal::new(default),
+        };
+
+        self.entries.lock().or_poisoned().push((
+            name,
+            Box::new({
+                let signal = signal.clone();
+                move || signal.with(|value| serde_json::to_value(value).ok())
+            }),
+        ));
+        signal.into()
+    }
+
+    /// Saves the current value of every signal in the snapshot.
+    ///
+    /// This is called automatically when the page is hidden, but can also be called at any
+    /// other time, for example after an important change.
+    pub fn save(&self) {
+        let snapshot = self
+            .entries
+            .lock()
+            .or_poisoned()
+            .iter()
+            .filter_map(|(name, value)| Some((name.clone(), value()?)))
+            .collect::<Map<_, _>>();
+        if let Ok(snapshot) = serde_json::to_string(&snapshot) {
+            Storage::Session.set(&self.key, &snapshot);
+        }
+    }
+
+    /// Removes the saved snapshot, so that the next page load starts with the default values.
+    ///
+    /// The snapshot will be saved again the next time the page is hidden.
+    pub fn clear(&self) {
+        Storage::Session.remove(&self.key);
+    }
+}
+
+/// The values of a form's inputs that are saved to `sessionStorage` while it is edited, and
+/// restored the next time it is rendered.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct PersistedForm {
+    key: Arc<str>,
+    form: NodeRef<Form>,
+}
+
+impl PersistedForm {
+    /// Persists the values of the form in `form` at `key` in `sessionStorage`.
+    ///
+    /// As soon as the form is mounted (and, when hydrating, once hydration is complete), its
+    /// inputs are filled in with the saved values, if there are any, and `input` and `change`
+    /// events are dispatched on the inputs that changed so that any bound signals are updated.
+    /// The values are then saved each time an input in the form changes, for as long as the
+    /// current reactive owner is alive.
+    pub fn new(key: impl Into<String>, form: NodeRef<Form>) -> Self {
+        let this = Self {
+            key: key.into().into(),
+            form,
+        };
+
+        if Storage::is_available() {
+            Effect::new({
+                let this = this.clone();
+                move |restored: Option<bool>| {
+                    if restored == Some(true) {
+                        return true;
+                    }
+                    match form.get() {
+                        Some(el) => {
+                            this.restore(&el);
+                            true
+                        }
+                        None => false,
+                    }
+                }
+            });
+
+            for event in ["input", "change"] {
+                let handle = window_event_listener_untyped(event, {
+                    let this = this.clone();
+                    move |ev| {
+                        let Some(el) = form.get_untracked() else {
+                            return;
+                        };
+                        let target =
+                            ev.target().and_then(|t| t.dyn_into().ok());
+                        if el.contains(target.as_ref()) {
+                            this.save();
+                        }
+                    }
+                });
+                on_cleanup(move || handle.remove());
+            }
+        }
+
+        this
+    }
+
+    /// Saves the current values of the form's inputs.
+    ///
+    /// This is called automatically whenever an input changes, but can also be called after
+    /// changing an input's value in code.
+    pub fn save(&self) {
+        let Some(form) = self.form.get_untracked() else {
+            return;
+        };
+        let mut values = BTreeMap::<String, Vec<String>>::new();
+        for_each_field(&form, |name, field| {
+            let values = values.entry(name).or_default();
+            match field {
+                Field::Checkable(input) => {
+                    if input.checked() {
+                        values.push(input.value());
+                    }
+                }
+                Field::Input(input) => values.push(input.value()),
+                Field::TextArea(textarea) => values.push(textarea.value()),
+                Field::Select(_, options) => values.extend(
+                    options
+                        .iter()
+                        .filter(|option| option.selected())
+                        .map(HtmlOptionElement::value),
+                ),
+            }
+        });
+        if let Ok(values) = serde_json::to_string(&values) {
+            Storage::Session.set(&self.key, &values);
+        }
+    }
+
+    /// Removes the saved values, so that the next time the form is rendered it starts empty.
+    ///
+    /// The values will be saved again the next time an input in the form changes.
+    pub fn clear(&self) {
+        Storage::Session.remove(&self.key);
+    }
+
+    fn restore(&self, form: &HtmlFormElement) {
+        let Some(values) =
+            Storage::Session.load::<BTreeMap<String, Vec<String>>>(&self.key)
+        else {
+            return;
+        };
+        // inputs that share a name are filled in with that name's values, in order
+        let mut next = BTreeMap::<String, usize>::new();
+        for_each_field(form, |name, field| {
+            let Some(values) = values.get(&name) else {
+                return;
+            };
+            let changed = match &field {
+                Field::Checkable(input) => {
+                    let checked = values.contains(&input.value());
+                    let changed = input.checked() != checked;
+                    input.set_checked(checked);
+                    changed
+                }
+                Field::Input(_) | Field::TextArea(_) => {
+                    let index = next.entry(name).or_default();
+                    let Some(value) = values.get(*index) else {
+                        return;
+                    };
+                    *index += 1;
+                    match &field {
+                        Field::Input(input) if input.value() != *value => {
+                            input.set_value(value);
+                            true
+                        }
+                        Field::TextArea(textarea)
+                            if textarea.value() != *value =>
+                        {
+                            textarea.set_value(value);
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+                Field::Select(_, options) => {
+                    let mut changed = false;
+                    for option in options {
+                        let selected = values.contains(&option.value());
+                        changed |= option.selected() != selected;
+                        option.set_selected(selected);
+                    }
+                    changed
+                }
+            };
+            if changed {
+                let el: &web_sys::EventTarget = match &field {
+                    Field::Checkable(input) | Field::Input(input) => input,
+                    Field::TextArea(textarea) => textarea,
+                    // the events are dispatched on the select, rather than its options
+                    Field::Select(select, _) => select,
+                };
+                dispatch_change(el);
+            }
+        });
+    }
+}
+
+/// A form field whose value is persisted.
+enum Field {
+    /// A checkbox or radio button.
+    Checkable(HtmlInputElement),
+    /// Any other input.
+    Input(HtmlInputElement),
+    TextArea(HtmlTextAreaElement),
+    /// A `<select>`, with its options.
+    Select(HtmlSelectElement, Vec<HtmlOptionElement>),
+}
+
+/// Whether inputs of the given `type` are persisted. Secrets, files, and values that are set by
+/// the server or on buttons are not.
+fn persists_input_type(ty: &str) -> bool {
+    !matches!(
+        ty,
+        "password"
+            | "file"
+            | "hidden"
+            | "submit"
+            | "button"
+            | "reset"
+            | "image"
+    )
+}
+
+/// Calls `f` with the name of each persisted field in the form, in document order.
+fn for_each_field(form: &HtmlFormElement, mut f: impl FnMut(String, Field)) {
+    let Ok(fields) =
+        form.query_selector_all("input[name], select[name], textarea[name]")
+    else {
+        return;
+    };
+    for i in 0..fields.length() {
+        let Some(el) = fields.item(i) else { continue };
+        if let Some(input) = el.dyn_ref::<HtmlInputElement>() {
+            let ty = input.type_().to_ascii_lowercase();
+            if !persists_input_type(&ty) {
+                continue;
+            }
+            let field = if ty == "checkbox" || ty == "radio" {
+                Field::Checkable(input.clone())
+            } else {
+                Field::Input(input.clone())
+            };
+            f(input.name(), field);
+        } else if let Some(textarea) = el.dyn_ref::<HtmlTextAreaElement>() {
+            f(textarea.name(), Field::TextArea(textarea.clone()));
+        } else if let Some(select) = el.dyn_ref::<HtmlSelectElement>() {
+            let options = select
+                .query_selector_all("option")
+                .map(|options| {
+                    (0..options.length())
+                        .filter_map(|i| options.item(i)?.dyn_into().ok())
+                        .collect()
+                })
+                .unwrap_or_default();
+            f(select.name(), Field::Select(select.clone(), options));
+        }
+    }
+}
+
+/// Dispatches the events that a user's edit would, so that bound signals are updated. Like
+/// the browser's own, they bubble, so that delegated event listeners see them too.
+fn dispatch_change(el: &web_sys::EventTarget) {
+    let init = web_sys::EventInit::new();
+    init.set_bubbles(true);
+    for event in ["input", "change"] {
+        if let Ok(event) =
+            web_sys::Event::new_with_event_init_dict(event, &init)
+        {
+            _ = el.dispatch_event(&event);
+        }
+    }
+}
+
+fn encode_cookie_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'!'
+            | b'~'
+            | b'*'
+            | b'\''
+            | b'('
+            | b')' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn decode_cookie_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode_cookie_value, encode_cookie_value, persists_input_type,
+    };
+
+    #[test]
+    fn cookie_values_round_trip() {
+        let value = r#"{"theme":"dark; bold","size":100%}"#;
+        let encoded = encode_cookie_value(value);
+        assert!(!encoded.contains([';', ',', ' ', '"']));
+        assert_eq!(decode_cookie_value(&encoded), value);
+    }
+
+    #[test]
+    fn secret_and_server_set_inputs_are_not_persisted() {
+        for ty in ["text", "email", "number", "checkbox", "radio", "date"] {
+            assert!(persists_input_type(ty), "{ty}");
+        }
+        for ty in ["password", "file", "hidden", "submit"] {
+            assert!(!persists_input_type(ty), "{ty}");
+        }
+    }
+}
@@ -47,3 +47,56 @@ where
     });
     OwnedView::new_with_owner(children, owner)
 }
+
+#[component]
+/// Provides a context value of an explicitly-named type `T` to its children and descendants,
+/// without overwriting any contexts of the same type in its own reactive scope.
+///
+/// This works like [`Provider`], but the type is written out where the value is provided, and
+/// the value is converted into `T` with [`Into`]. This makes overrides of a context type easy to
+/// find, and avoids accidentally providing a value of a different type than the one that is
+/// later used.
+///
+/// ```rust
+/// use leptos::{context::ContextProvider, prelude::*};
+///
+/// #[derive(Clone)]
+/// pub struct Theme(String);
+///
+/// impl From<&str> for Theme {
+///     fn from(value: &str) -> Self {
+///         Theme(value.to_string())
+///     }
+/// }
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     provide_context_with_default(|| Theme::from("light"));
+///
+///     view! {
+///         // uses the default
+///         {expect_context::<Theme>().0}
+///         <ContextProvider<Theme, _> value="dark">
+///             // overrides the default for this part of the tree
+///             {expect_context::<Theme>().0}
+///         </ContextProvider<Theme, _>>
+///     }
+/// }
+/// ```
+pub fn ContextProvider<T, Chil>(
+    /// The value to be provided via context.
+    #[prop(into)]
+    value: T,
+    children: TypedChildren<Chil>,
+) -> impl IntoView
+where
+    T: Send + Sync + 'static,
+    Chil: IntoView + 'static,
+{
+    Provider(
+        ProviderProps::builder()
+            .value(value)
+            .children(children)
+            .build(),
+    )
+}
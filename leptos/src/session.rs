@@ -0,0 +1,273 @@
+//! Session data that can be used the same way in server functions and server-rendered components,
+//! with any server integration.
+//!
+//! A [`Session`] stores values for the user who made the current request. It is provided via
+//! context for each request (usually by passing an `additional_context` function to the server
+//! integration), and then accessed with [`use_session`].
+//!
+//! ```rust,ignore
+//! use leptos::{prelude::*, session::{provide_session, use_session, MemoryStore}};
+//!
+//! // when setting up the server
+//! let store = MemoryStore::new();
+//! let context = move || provide_session(store.session());
+//! // ... pass `context` to `leptos_routes_with_context` and `handle_server_fns_with_context`
+//!
+//! #[server]
+//! async fn log_in(name: String) -> Result<(), ServerFnError> {
+//!     let session = use_session().ok_or_else(|| ServerFnError::new("no session"))?;
+//!     session.insert("user", &name);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`MemoryStore`] is a simple example store that keeps sessions in memory, and identifies them
+//! with an `HttpOnly` cookie. Sessions are lost when the server restarts, and are not shared
+//! between server instances, so production apps will usually implement [`Session`] for a
+//! database or another shared store.
+
+use crate::cookies::{CookieOptions, Cookies};
+use base64::{
+    alphabet,
+    engine::{self, general_purpose},
+    Engine,
+};
+use or_poisoned::OrPoisoned;
+use rand::{rng, RngCore};
+use reactive_graph::owner::{provide_context, use_context};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+/// Data that is stored for the user who made the current request.
+///
+/// Values are stored as serialized JSON strings. Use [`AnySession`], which is returned by
+/// [`use_session`], to read and write typed values.
+pub trait Session: Send + Sync + 'static {
+    /// Returns the serialized value stored at `key`, if any.
+    fn get_raw(&self, key: &str) -> Option<String>;
+
+    /// Stores a serialized value at `key`.
+    fn insert_raw(&self, key: &str, value: String);
+
+    /// Removes the value stored at `key`.
+    fn remove(&self, key: &str);
+
+    /// Removes every value in the session, and the session itself.
+    fn clear(&self);
+}
+
+/// A type-erased [`Session`], which is provided via context.
+#[derive(Clone)]
+pub struct AnySession(Arc<dyn Session>);
+
+impl Debug for AnySession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnySession").finish_non_exhaustive()
+    }
+}
+
+impl AnySession {
+    /// Returns the deserialized value stored at `key`, if it exists and has the right type.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.0
+            .get_raw(key)
+            .and_then(|value| serde_json::from_str(&value).ok())
+    }
+
+    /// Serializes the value and stores it at `key`.
+    pub fn insert<T: Serialize>(&self, key: &str, value: &T) {
+        if let Ok(value) = serde_json::to_string(value) {
+            self.0.insert_raw(key, value);
+        }
+    }
+
+    /// Removes the value stored at `key`.
+    pub fn remove(&self, key: &str) {
+        self.0.remove(key);
+    }
+
+    /// Removes every value in the session, and the session itself.
+    pub fn clear(&self) {
+        self.0.clear();
+    }
+}
+
+/// Provides the session for the current request via context.
+pub fn provide_session(session: impl Session) {
+    provide_context(AnySession(Arc::new(session)));
+}
+
+/// Returns the session for the current request, if one has been provided.
+pub fn use_session() -> Option<AnySession> {
+    use_context::<AnySession>()
+}
+
+/// The name of the cookie that identifies a [`MemoryStore`] session.
+const SESSION_COOKIE: &str = "leptos_session";
+
+const SESSION_ID_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::URL_SAFE, general_purpose::NO_PAD);
+
+type Sessions = HashMap<String, HashMap<String, String>>;
+
+/// An example session store that keeps every session in memory.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Clone, Default)]
+pub struct MemoryStore {
+    sessions: Arc<Mutex<Sessions>>,
+}
+
+impl Debug for MemoryStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryStore").finish_non_exhaustive()
+    }
+}
+
+impl MemoryStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the session for the current request, which is identified by its cookie.
+    ///
+    /// This must be called in a reactive owner in which the request's
+    /// [cookies](crate::cookies) have been provided. A new session (and its cookie) is only
+    /// created once a value is inserted.
+    pub fn session(&self) -> MemorySession {
+        let cookies = Cookies::new().with_options(CookieOptions {
+            http_only: true,
+            ..Default::default()
+        });
+        let id = cookies
+            .get_raw(SESSION_COOKIE)
+            .filter(|id| self.sessions.lock().or_poisoned().contains_key(id));
+        MemorySession {
+            store: self.clone(),
+            cookies,
+            id: Mutex::new(id),
+        }
+    }
+}
+
+/// A session in a [`MemoryStore`].
+#[derive(Debug)]
+pub struct MemorySession {
+    store: MemoryStore,
+    cookies: Cookies,
+    id: Mutex<Option<String>>,
+}
+
+impl MemorySession {
+    /// Returns the ID of this session, creating the session if it does not exist yet.
+    fn create(&self) -> String {
+        let mut id = self.id.lock().or_poisoned();
+        if let Some(id) = &*id {
+            return id.clone();
+        }
+
+        let mut bytes = [0; 32];
+        rng().fill_bytes(&mut bytes);
+        let new_id = SESSION_ID_ENGINE.encode(bytes);
+        self.store
+            .sessions
+            .lock()
+            .or_poisoned()
+            .insert(new_id.clone(), HashMap::new());
+        self.cookies.set_raw(SESSION_COOKIE, new_id.clone());
+        *id = Some(new_id.clone());
+        new_id
+    }
+}
+
+impl Session for MemorySession {
+    fn get_raw(&self, key: &str) -> Option<String> {
+        let id = self.id.lock().or_poisoned();
+        let sessions = self.store.sessions.lock().or_poisoned();
+        sessions.get(id.as_ref()?)?.get(key).cloned()
+    }
+
+    fn insert_raw(&self, key: &str, value: String) {
+        let id = self.create();
+        if let Some(session) =
+            self.store.sessions.lock().or_poisoned().get_mut(&id)
+        {
+            session.insert(key.to_string(), value);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let id = self.id.lock().or_poisoned();
+        let mut sessions = self.store.sessions.lock().or_poisoned();
+        if let Some(session) = id.as_ref().and_then(|id| sessions.get_mut(id)) {
+            session.remove(key);
+        }
+    }
+
+    fn clear(&self) {
+        if let Some(id) = self.id.lock().or_poisoned().take() {
+            self.store.sessions.lock().or_poisoned().remove(&id);
+            self.cookies.remove(SESSION_COOKIE);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cookies::provide_server_cookies;
+    use reactive_graph::owner::Owner;
+
+    /// Runs `fun` as if handling a request with the given `Cookie` header, and returns the
+    /// `Set-Cookie` headers that were sent.
+    fn request(
+        store: &MemoryStore,
+        header: &str,
+        fun: impl FnOnce(AnySession),
+    ) -> Vec<String> {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        Owner::new().with(|| {
+            provide_server_cookies(Some(header), {
+                let sent = Arc::clone(&sent);
+                move |cookie| sent.lock().unwrap().push(cookie.to_string())
+            });
+            provide_session(store.session());
+            fun(use_session().unwrap());
+        });
+        Arc::try_unwrap(sent).unwrap().into_inner().unwrap()
+    }
+
+    #[test]
+    fn memory_sessions_persist_across_requests() {
+        let store = MemoryStore::new();
+        let sent = request(&store, "", |session| {
+            assert_eq!(session.get::<String>("user"), None);
+            session.insert("user", &"greg");
+        });
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].contains("HttpOnly"));
+        let cookie = sent[0].split(';').next().unwrap().to_string();
+
+        request(&store, &cookie, |session| {
+            assert_eq!(session.get::<String>("user").as_deref(), Some("greg"));
+            session.clear();
+        });
+        request(&store, &cookie, |session| {
+            assert_eq!(session.get::<String>("user"), None);
+        });
+    }
+
+    #[test]
+    fn sessions_are_only_created_when_written() {
+        let store = MemoryStore::new();
+        let sent = request(&store, "leptos_session=unknown", |session| {
+            assert_eq!(session.get::<u32>("count"), None);
+        });
+        assert!(sent.is_empty());
+    }
+}
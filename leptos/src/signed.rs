@@ -0,0 +1,411 @@
+//! Hidden form fields whose values are signed by the server, so that they cannot be changed in
+//! the browser.
+//!
+//! Forms often need to send values back to the server that the user should not be able to edit,
+//! such as the id of the row being edited, or the price that was shown. A [`Signed`] value
+//! carries a signature made with a key that only the server knows. It is rendered into a hidden
+//! input with [`SignedInput`], and when it is deserialized as a server function argument, the
+//! signature is checked, so a value that was changed in the browser is rejected without having to
+//! store it in a session between requests.
+//!
+//! ```rust,ignore
+//! use leptos::{
+//!     prelude::*,
+//!     signed::{provide_signing_key, Signed, SignedInput, SigningKey},
+//! };
+//!
+//! // passed to `leptos_routes_with_context` and `handle_server_fns_with_context`
+//! let context = move || provide_signing_key(SigningKey::new(secret.as_bytes()));
+//!
+//! #[server]
+//! async fn load_item(id: u32) -> Result<(Signed<u32>, String), ServerFnError> {
+//!     let item = db::item(id).await?;
+//!     Ok((Signed::new(item.id)?, item.name))
+//! }
+//!
+//! #[server]
+//! async fn rename_item(
+//!     id: Signed<u32>,
+//!     name: String,
+//! ) -> Result<(), ServerFnError> {
+//!     // `id` has been verified, so it is the id that `load_item` returned
+//!     db::rename(*id, &name).await
+//! }
+//!
+//! #[component]
+//! fn RenameItem(id: Signed<u32>, name: String) -> impl IntoView {
+//!     let rename = ServerAction::<RenameItem>::new();
+//!
+//!     view! {
+//!         <ActionForm action=rename>
+//!             <SignedInput name="id" value=id/>
+//!             <input type="text" name="name" value=name/>
+//!             <input type="submit"/>
+//!         </ActionForm>
+//!     }
+//! }
+//! ```
+//!
+//! ## In the browser
+//! The key is never sent to the browser, so values can only be signed on the server. A signed
+//! value can still be sent to the browser, for example as part of a server function's result or a
+//! resource, and rendered or sent back from there: it keeps the signature it was created with.
+//! In the browser, values are deserialized without being verified. With the `ssr` feature, a
+//! value is only deserialized if a key has been provided and the signature matches.
+//!
+//! The value itself is only encoded, not encrypted, so it can be read in the browser. Each
+//! signature covers the value and the [`SignedType::NAME`] of its type, but not the name of the
+//! field, so values of the same type can be swapped between fields. Use a different type, with its
+//! own name, for each kind of value if that matters.
+//!
+//! Each signature also covers the time at which the value was signed. If the key has a
+//! [`max_age`](SigningKey::max_age), older values are rejected, which limits how long a value
+//! that has been submitted once can be submitted again.
+
+use crate::{component, IntoView};
+use base64::{
+    alphabet,
+    engine::{self, general_purpose},
+    Engine,
+};
+use hmac::{Hmac, Mac};
+use reactive_graph::owner::{provide_context, use_context};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::Sha256;
+use std::{
+    fmt::Debug,
+    ops::Deref,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tachys::html::element::input;
+use thiserror::Error;
+
+const SIGNED_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::URL_SAFE, general_purpose::NO_PAD);
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The secret key with which values are signed and verified.
+///
+/// The same key must be used by every server that renders forms or handles server functions for
+/// them, and kept secret: anyone who knows it can sign any value.
+#[derive(Clone)]
+pub struct SigningKey {
+    secret: Arc<[u8]>,
+    max_age: Option<Duration>,
+}
+
+impl SigningKey {
+    /// Creates a key from a secret, which should be at least 32 random bytes.
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        Self {
+            secret: secret.as_ref().into(),
+            max_age: None,
+        }
+    }
+
+    /// Rejects values that were signed longer ago than `max_age`.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// The HMAC-SHA256 of an encoded value of type `T`, signed at `issued`.
+    fn mac<T: SignedType>(&self, issued: u64, payload: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.secret)
+            .expect("HMAC takes keys of any length");
+        mac.update(T::NAME.as_bytes());
+        mac.update(b".");
+        mac.update(issued.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload.as_bytes());
+        mac
+    }
+
+    /// The signature of an encoded value of type `T`, signed at `issued`.
+    fn sign<T: SignedType>(&self, issued: u64, payload: &str) -> String {
+        SIGNED_ENGINE
+            .encode(self.mac::<T>(issued, payload).finalize().into_bytes())
+    }
+
+    /// Checks the signature of an encoded value of type `T`, and that it has not expired.
+    fn verify<T: SignedType>(
+        &self,
+        issued: u64,
+        payload: &str,
+        signature: &str,
+    ) -> Result<(), SignedError> {
+        let signature = SIGNED_ENGINE
+            .decode(signature)
+            .map_err(|_| SignedError::InvalidSignature)?;
+        self.mac::<T>(issued, payload)
+            .verify_slice(&signature)
+            .map_err(|_| SignedError::InvalidSignature)?;
+        match self.max_age {
+            Some(max_age)
+                if now().saturating_sub(issued) > max_age.as_secs() =>
+            {
+                Err(SignedError::Expired)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Debug for SigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Provides the key with which values are signed and verified for the current request.
+///
+/// This should be called on the server, in the `additional_context` of both rendered routes and
+/// server functions.
+pub fn provide_signing_key(key: SigningKey) {
+    provide_context(key);
+}
+
+/// A type whose values can be [`Signed`].
+///
+/// Its [`NAME`](Self::NAME) is part of each signature, so that a value that was signed as one
+/// type is rejected as another. It must stay the same across builds, so that values signed by one
+/// version of the server can be verified by the next.
+pub trait SignedType {
+    /// The name of the type in signatures.
+    const NAME: &'static str;
+}
+
+macro_rules! signed_types {
+    ($($ty:ty),*) => {
+        $(
+            impl SignedType for $ty {
+                const NAME: &'static str = stringify!($ty);
+            }
+        )*
+    };
+}
+
+signed_types!(
+    bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+    f32, f64, String
+);
+
+/// Errors that can occur when signing or verifying a value.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SignedError {
+    /// No key has been provided with [`provide_signing_key`].
+    #[error("No signing key has been provided.")]
+    MissingKey,
+    /// The value could not be encoded or decoded.
+    #[error("Could not encode or decode the signed value: {0}")]
+    Encoding(String),
+    /// The signature does not match the value, so it has been changed.
+    #[error("The signature of the signed value is invalid.")]
+    InvalidSignature,
+    /// The value was signed longer ago than the key's [`max_age`](SigningKey::max_age).
+    #[error("The signed value has expired.")]
+    Expired,
+}
+
+/// A value along with a signature made by the server, which is checked when it is deserialized.
+///
+/// It is serialized as a single string, which contains the encoded value and its signature.
+/// See the [module-level documentation](crate::signed) for more details.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Signed<T> {
+    value: T,
+    token: Arc<str>,
+}
+
+impl<T> Signed<T>
+where
+    T: Serialize + SignedType,
+{
+    /// Signs a value with the key provided for the current request.
+    pub fn new(value: T) -> Result<Self, SignedError> {
+        let key = use_context::<SigningKey>().ok_or(SignedError::MissingKey)?;
+        let json = serde_json::to_vec(&value)
+            .map_err(|e| SignedError::Encoding(e.to_string()))?;
+        let payload = SIGNED_ENGINE.encode(json);
+        let issued = now();
+        let signature = key.sign::<T>(issued, &payload);
+        Ok(Self {
+            value,
+            token: format!("{payload}.{issued}.{signature}").into(),
+        })
+    }
+}
+
+impl<T> Signed<T> {
+    /// The encoded value, the time it was signed and its signature, as it is submitted in a
+    /// form.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Signed<T>
+where
+    T: DeserializeOwned + SignedType,
+{
+    /// Decodes a token, checking its signature if a key has been provided.
+    ///
+    /// With the `ssr` feature, a key is required.
+    pub fn from_token(token: &str) -> Result<Self, SignedError> {
+        let mut parts = token.split('.');
+        let (Some(payload), Some(issued), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(SignedError::InvalidSignature);
+        };
+        let issued =
+            issued.parse().map_err(|_| SignedError::InvalidSignature)?;
+        match use_context::<SigningKey>() {
+            Some(key) => key.verify::<T>(issued, payload, signature)?,
+            None if cfg!(feature = "ssr") => {
+                return Err(SignedError::MissingKey)
+            }
+            None => {}
+        }
+        let json = SIGNED_ENGINE
+            .decode(payload)
+            .map_err(|e| SignedError::Encoding(e.to_string()))?;
+        let value = serde_json::from_slice(&json)
+            .map_err(|e| SignedError::Encoding(e.to_string()))?;
+        Ok(Self {
+            value,
+            token: token.into(),
+        })
+    }
+}
+
+impl<T> Deref for Signed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> Serialize for Signed<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.token)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Signed<T>
+where
+    T: DeserializeOwned + SignedType,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let token = String::deserialize(deserializer)?;
+        Self::from_token(&token).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A hidden input that contains a signed value, to be submitted with a form.
+#[component]
+pub fn SignedInput<T>(
+    /// The name of the field, which is the name of the server function argument.
+    #[prop(into)]
+    name: String,
+    /// The signed value.
+    value: Signed<T>,
+) -> impl IntoView
+where
+    T: Send + 'static,
+{
+    input()
+        .r#type("hidden")
+        .name(name)
+        .value(value.token().to_string())
+}
+
+/// The current time, in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reactive_graph::owner::Owner;
+
+    #[test]
+    fn rejects_expired_values() {
+        let key =
+            SigningKey::new("a secret key").max_age(Duration::from_secs(60));
+        Owner::new().with(|| {
+            provide_signing_key(key.clone());
+            let signed = Signed::new(42_u32).unwrap();
+            assert_eq!(Signed::<u32>::from_token(signed.token()), Ok(signed));
+
+            let payload =
+                SIGNED_ENGINE.encode(serde_json::to_vec(&42_u32).unwrap());
+            let issued = now() - 120;
+            let signature = key.sign::<u32>(issued, &payload);
+            assert_eq!(
+                Signed::<u32>::from_token(&format!(
+                    "{payload}.{issued}.{signature}"
+                )),
+                Err(SignedError::Expired)
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_changed_values() {
+        Owner::new().with(|| {
+            provide_signing_key(SigningKey::new("a secret key"));
+            let signed = Signed::new(42_u32).unwrap();
+            let json = serde_json::to_string(&signed).unwrap();
+            let verified: Signed<u32> = serde_json::from_str(&json).unwrap();
+            assert_eq!(*verified, 42);
+
+            let (_, signature) = signed.token().split_once('.').unwrap();
+            // the original time and signature, with a different value
+            let forged = format!(
+                "{}.{signature}",
+                SIGNED_ENGINE.encode(serde_json::to_vec(&43_u32).unwrap())
+            );
+            assert_eq!(
+                Signed::<u32>::from_token(&forged),
+                Err(SignedError::InvalidSignature)
+            );
+            assert_eq!(
+                Signed::<i64>::from_token(signed.token()),
+                Err(SignedError::InvalidSignature)
+            );
+        });
+
+        Owner::new().with(|| {
+            provide_signing_key(SigningKey::new("another key"));
+            let signed = Signed::new(42_u32).unwrap();
+            Owner::new().with(|| {
+                provide_signing_key(SigningKey::new("a third key"));
+                assert_eq!(
+                    Signed::<u32>::from_token(signed.token()),
+                    Err(SignedError::InvalidSignature)
+                );
+            });
+        });
+    }
+}
@@ -410,59 +410,67 @@ where
             }
         });
 
-        let mut fut = Box::pin(ScopedFuture::new(ErrorHookFuture::new(
-            async move {
-                // race the local resource notifier against the set of tasks
-                //
-                // if there are local resources, we just return the fallback immediately
-                //
-                // otherwise, we want to wait for resources to load before trying to resolve the body
-                //
-                // this is *less efficient* than just resolving the body
-                // however, it means that you can use reactive accesses to resources/async derived
-                // inside component props, at any level, and have those picked up by Suspense, and
-                // that it will wait for those to resolve
-                select! {
-                    // if there are local resources, bail
-                    // this will only have fired by this point for local resources accessed
-                    // *synchronously*
-                    _ = local_rx => {
-                        let sc = Owner::current_shared_context().expect("no shared context");
-                        sc.set_incomplete_chunk(self.id);
-                        None
-                    }
-                    _ = tasks_rx => {
-                        let children = {
-                            let mut children_lock = children.lock().or_poisoned();
-                            children_lock.take().expect("children should not be removed until we render here")
-                        };
-
-                        // if we ran this earlier, reactive reads would always be registered as None
-                        // this is fine in the case where we want to use Suspend and .await on some future
-                        // but in situations like a <For each=|| some_resource.snapshot()/> we actually
-                        // want to be able to 1) synchronously read a resource's value, but still 2) wait
-                        // for it to load before we render everything
-                        let mut children = Box::pin(children.resolve().fuse());
-
-                        // we continue racing the children against the "do we have any local
-                        // resources?" Future
-                        select! {
-                            _ = local_rx => {
-                                let sc = Owner::current_shared_context().expect("no shared context");
-                                sc.set_incomplete_chunk(self.id);
-                                None
-                            }
-                            children = children => {
-                                // clean up the (now useless) effect
-                                eff.dispose();
+        #[cfg(feature = "tracing")]
+        let data_id = self.id.clone().into_inner();
+        let resolve = async move {
+            // race the local resource notifier against the set of tasks
+            //
+            // if there are local resources, we just return the fallback immediately
+            //
+            // otherwise, we want to wait for resources to load before trying to resolve the body
+            //
+            // this is *less efficient* than just resolving the body
+            // however, it means that you can use reactive accesses to resources/async derived
+            // inside component props, at any level, and have those picked up by Suspense, and
+            // that it will wait for those to resolve
+            select! {
+                // if there are local resources, bail
+                // this will only have fired by this point for local resources accessed
+                // *synchronously*
+                _ = local_rx => {
+                    let sc = Owner::current_shared_context().expect("no shared context");
+                    sc.set_incomplete_chunk(self.id);
+                    None
+                }
+                _ = tasks_rx => {
+                    let children = {
+                        let mut children_lock = children.lock().or_poisoned();
+                        children_lock.take().expect("children should not be removed until we render here")
+                    };
+
+                    // if we ran this earlier, reactive reads would always be registered as None
+                    // this is fine in the case where we want to use Suspend and .await on some future
+                    // but in situations like a <For each=|| some_resource.snapshot()/> we actually
+                    // want to be able to 1) synchronously read a resource's value, but still 2) wait
+                    // for it to load before we render everything
+                    let mut children = Box::pin(children.resolve().fuse());
+
+                    // we continue racing the children against the "do we have any local
+                    // resources?" Future
+                    select! {
+                        _ = local_rx => {
+                            let sc = Owner::current_shared_context().expect("no shared context");
+                            sc.set_incomplete_chunk(self.id);
+                            None
+                        }
+                        children = children => {
+                            // clean up the (now useless) effect
+                            eff.dispose();
 
-                                Some(OwnedView::new_with_owner(children, owner))
-                            }
+                            Some(OwnedView::new_with_owner(children, owner))
                         }
                     }
                 }
-            },
-        )));
+            }
+        };
+        // time spent waiting for this boundary shows up as a child of the request's span
+        #[cfg(feature = "tracing")]
+        let resolve = tracing::Instrument::instrument(
+            resolve,
+            tracing::debug_span!("suspense", id = ?data_id),
+        );
+        let mut fut =
+            Box::pin(ScopedFuture::new(ErrorHookFuture::new(resolve)));
         match fut.as_mut().now_or_never() {
             Some(Some(resolved)) => {
                 Either::<Fal, _>::Right(resolved)
@@ -0,0 +1,362 @@
+//! Effects, signals, and actions that debounce, throttle, or defer their updates.
+//!
+//! These wait using the timer of the current async [`Executor`], which is `setTimeout` in the
+//! browser. On the server, where the page is rendered once and
+//! effects do not run, debounced and throttled signals simply follow their source.
+//!
+//! ```rust,no_run
+//! use leptos::prelude::*;
+//! use std::time::Duration;
+//!
+//! #[component]
+//! fn Search() -> impl IntoView {
+//!     let query = RwSignal::new(String::new());
+//!     // only updates once the user has stopped typing for 300ms
+//!     let debounced = query.debounce(Duration::from_millis(300));
+//!
+//!     Effect::debounced(Duration::from_millis(300), move || {
+//!         leptos::logging::log!("searching for {}", query.get());
+//!     });
+//!
+//!     view! {
+//!         <input bind:value=query/>
+//!         <p>"Results for " {debounced}</p>
+//!     }
+//! }
+//! ```
+
+use any_spawner::Executor;
+use futures::channel::oneshot;
+use futures::future::select;
+use leptos_dom::helpers::{
+    is_browser, is_server, request_idle_callback_with_handle,
+    IdleCallbackHandle,
+};
+use or_poisoned::OrPoisoned;
+use reactive_graph::{
+    actions::{Action, ActionAbortHandle, ArcAction, DispatchPolicy},
+    effect::{with_effect_priority, Effect, EffectFunction, EffectPriority},
+    owner::{on_cleanup, LocalStorage},
+    signal::ArcRwSignal,
+    traits::{Get, GetUntracked, Set},
+    wrappers::read::Signal,
+};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+/// Returns a future that resolves once the given duration has passed, using the timer of the
+/// current [`Executor`].
+fn sleep(duration: Duration) -> impl Future<Output = ()> {
+    Executor::sleep(duration)
+}
+
+/// Creates effects that wait for their dependencies to stop changing.
+pub trait DebouncedEffect: Sized {
+    /// Creates an effect that runs once on the next “tick,” and then runs again only once its
+    /// dependencies have not changed for `delay`.
+    ///
+    /// See [`Effect::debounced_with`] for details.
+    fn debounced<T, M>(
+        delay: Duration,
+        fun: impl EffectFunction<T, M> + 'static,
+    ) -> Self
+    where
+        T: 'static;
+}
+
+impl DebouncedEffect for Effect<LocalStorage> {
+    fn debounced<T, M>(
+        delay: Duration,
+        fun: impl EffectFunction<T, M> + 'static,
+    ) -> Self
+    where
+        T: 'static,
+    {
+        Effect::debounced_with(move || sleep(delay), fun)
+    }
+}
+
+/// How long an idle effect waits for the browser to become idle before running anyway.
+const IDLE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Returns a future that resolves once the browser is idle, or once [`IDLE_TIMEOUT`] has
+/// passed, whichever comes first.
+///
+/// The handle of the idle callback is kept in `pending` until it has been called, so that it can
+/// be cancelled.
+fn idle(
+    pending: &Arc<Mutex<Option<IdleCallbackHandle>>>,
+) -> impl Future<Output = ()> {
+    let (tx, rx) = oneshot::channel::<()>();
+    let in_browser = cfg!(target_arch = "wasm32") && is_browser();
+    // requestIdleCallback is not supported in every browser
+    let supported = in_browser && {
+        let tx = Arc::new(Mutex::new(Some(tx)));
+        let handle = request_idle_callback_with_handle({
+            let pending = Arc::clone(pending);
+            move || {
+                pending.lock().or_poisoned().take();
+                if let Some(tx) = tx.lock().or_poisoned().take() {
+                    _ = tx.send(());
+                }
+            }
+        });
+        if let Ok(handle) = handle {
+            *pending.lock().or_poisoned() = Some(handle);
+        }
+        handle.is_ok()
+    };
+    async move {
+        if supported {
+            _ = select(rx, Box::pin(sleep(IDLE_TIMEOUT))).await;
+        } else if in_browser {
+            sleep(Duration::ZERO).await;
+        }
+    }
+}
+
+/// Creates effects that only run when the browser is idle.
+pub trait IdleEffect: Sized {
+    /// Creates an effect that waits until the browser is idle before it runs, using
+    /// [`requestIdleCallback`](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback),
+    /// and runs again in the next idle period after its dependencies change.
+    ///
+    /// This is useful for non-urgent work, like saving a draft or prefetching data. If the
+    /// browser does not become idle within one second, or does not support
+    /// `requestIdleCallback`, the effect runs anyway. The effect also has
+    /// [`EffectPriority::Idle`], so it yields to other effects that are waiting to run.
+    ///
+    /// See [`Effect::new_scheduled`] for details.
+    fn new_idle<T, M>(fun: impl EffectFunction<T, M> + 'static) -> Self
+    where
+        T: 'static;
+}
+
+impl IdleEffect for Effect<LocalStorage> {
+    fn new_idle<T, M>(fun: impl EffectFunction<T, M> + 'static) -> Self
+    where
+        T: 'static,
+    {
+        let pending = Arc::new(Mutex::new(None::<IdleCallbackHandle>));
+        on_cleanup({
+            let pending = Arc::clone(&pending);
+            move || {
+                if let Some(handle) = pending.lock().or_poisoned().take() {
+                    handle.cancel();
+                }
+            }
+        });
+        with_effect_priority(EffectPriority::Idle, || {
+            Effect::new_scheduled(move || idle(&pending), fun)
+        })
+    }
+}
+
+/// Creates signals that limit how often a reactive value updates.
+pub trait TimedSignalExt<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Returns a signal that only updates once this value has not changed for `delay`.
+    ///
+    /// This is useful for work that should only happen once the user has stopped typing, like
+    /// search-as-you-type.
+    fn debounce(&self, delay: Duration) -> Signal<T>;
+
+    /// Returns a signal that updates at most once every `delay`.
+    ///
+    /// The first change is applied immediately. Further changes made within `delay` are
+    /// skipped, except for the most recent one, which is applied once `delay` has passed.
+    fn throttle(&self, delay: Duration) -> Signal<T>;
+}
+
+impl<S, T> TimedSignalExt<T> for S
+where
+    S: Get<Value = T> + GetUntracked<Value = T> + Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+{
+    fn debounce(&self, delay: Duration) -> Signal<T> {
+        let source = self.clone();
+        if is_server() {
+            return Signal::derive(move || source.get());
+        }
+
+        let output = ArcRwSignal::new(source.get_untracked());
+        let version = Arc::new(AtomicUsize::new(0));
+        Effect::watch(
+            move || source.get(),
+            {
+                let output = output.clone();
+                move |value: &T, _, _| {
+                    let this_version =
+                        version.fetch_add(1, Ordering::Relaxed) + 1;
+                    let version = Arc::clone(&version);
+                    let output = output.clone();
+                    let value = value.clone();
+                    Executor::spawn_local(async move {
+                        sleep(delay).await;
+                        // only apply the latest value
+                        if version.load(Ordering::Relaxed) == this_version {
+                            output.set(value);
+                        }
+                    });
+                }
+            },
+            false,
+        );
+        output.into()
+    }
+
+    fn throttle(&self, delay: Duration) -> Signal<T> {
+        let source = self.clone();
+        if is_server() {
+            return Signal::derive(move || source.get());
+        }
+
+        let output = ArcRwSignal::new(source.get_untracked());
+        let state = Arc::new(Mutex::new(ThrottleState::<T>::default()));
+        Effect::watch(
+            move || source.get(),
+            {
+                let output = output.clone();
+                move |value: &T, _, _| {
+                    let mut guard = state.lock().or_poisoned();
+                    if guard.active {
+                        guard.pending = Some(value.clone());
+                        return;
+                    }
+                    guard.active = true;
+                    drop(guard);
+                    output.set(value.clone());
+
+                    let state = Arc::clone(&state);
+                    let output = output.clone();
+                    Executor::spawn_local(async move {
+                        loop {
+                            sleep(delay).await;
+                            let pending = {
+                                let mut guard = state.lock().or_poisoned();
+                                let pending = guard.pending.take();
+                                guard.active = pending.is_some();
+                                pending
+                            };
+                            match pending {
+                                Some(value) => output.set(value),
+                                None => break,
+                            }
+                        }
+                    });
+                }
+            },
+            false,
+        );
+        output.into()
+    }
+}
+
+/// Creates [`DispatchPolicy`]s with a fixed delay.
+pub trait TimedDispatchPolicy {
+    /// Runs the action once it has not been dispatched again for `delay`, with the most recent
+    /// input.
+    ///
+    /// See [`DispatchPolicy::debounce_with`] for details.
+    fn debounce(delay: Duration) -> Self;
+
+    /// Runs the action at most once every `delay`.
+    ///
+    /// See [`DispatchPolicy::throttle_with`] for details.
+    fn throttle(delay: Duration) -> Self;
+}
+
+impl TimedDispatchPolicy for DispatchPolicy {
+    fn debounce(delay: Duration) -> Self {
+        DispatchPolicy::debounce_with(move || sleep(delay))
+    }
+
+    fn throttle(delay: Duration) -> Self {
+        DispatchPolicy::throttle_with(move || sleep(delay))
+    }
+}
+
+/// Dispatches actions once they have not been dispatched again for some time.
+pub trait DebouncedDispatch<I> {
+    /// Dispatches the action once `delay` has passed, unless this is called again before then,
+    /// in which case only the most recent input is used.
+    ///
+    /// This is useful for actions that are dispatched on every keystroke, like autosaving a
+    /// draft or a live search. See [`ArcAction::dispatch_debounced_with`] for details.
+    ///
+    /// ```rust,no_run
+    /// use leptos::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[component]
+    /// fn Editor() -> impl IntoView {
+    ///     let save = ServerAction::<SaveDraft>::new();
+    ///     let on_input = move |ev| {
+    ///         let text = event_target_value(&ev);
+    ///         save.dispatch_debounced(SaveDraft { text }, Duration::from_millis(500));
+    ///     };
+    ///
+    ///     view! { <textarea on:input=on_input/> }
+    /// }
+    ///
+    /// #[server]
+    /// async fn save_draft(text: String) -> Result<(), ServerFnError> {
+    ///     Ok(())
+    /// }
+    /// ```
+    fn dispatch_debounced(
+        &self,
+        input: I,
+        delay: Duration,
+    ) -> ActionAbortHandle;
+}
+
+impl<I, O> DebouncedDispatch<I> for ArcAction<I, O>
+where
+    I: 'static,
+    O: 'static,
+{
+    fn dispatch_debounced(
+        &self,
+        input: I,
+        delay: Duration,
+    ) -> ActionAbortHandle {
+        self.dispatch_debounced_with(input, sleep(delay))
+    }
+}
+
+impl<I, O> DebouncedDispatch<I> for Action<I, O>
+where
+    I: 'static,
+    O: 'static,
+{
+    fn dispatch_debounced(
+        &self,
+        input: I,
+        delay: Duration,
+    ) -> ActionAbortHandle {
+        self.dispatch_debounced_with(input, sleep(delay))
+    }
+}
+
+struct ThrottleState<T> {
+    active: bool,
+    pending: Option<T>,
+}
+
+impl<T> Default for ThrottleState<T> {
+    fn default() -> Self {
+        Self {
+            active: false,
+            pending: None,
+        }
+    }
+}
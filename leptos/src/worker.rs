@@ -0,0 +1,281 @@
+//! Signals that are shared between the main thread and a web worker.
+//!
+//! Heavy computations can be moved into a
+//! [web worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API) so that they
+//! do not block rendering. A [`WorkerBridge`] relays values across the worker boundary using
+//! `postMessage`: one side publishes to a [`WorkerChannel`], and the other side receives each
+//! value, either as a signal or with a callback. Values are serialized as JSON.
+//!
+//! Channels are declared once, in code that is shared by the app and the worker, so that both
+//! sides agree on the name and type of each value.
+//!
+//! ```rust
+//! use leptos::{
+//!     prelude::*,
+//!     worker::{WorkerBridge, WorkerChannel},
+//! };
+//!
+//! pub const INPUT: WorkerChannel<u64> = WorkerChannel::new("input");
+//! pub const RESULT: WorkerChannel<u64> = WorkerChannel::new("result");
+//!
+//! // in the app
+//! #[component]
+//! fn Fibonacci(worker: web_sys::Worker) -> impl IntoView {
+//!     let bridge = WorkerBridge::new(worker);
+//!     let n = RwSignal::new(30_u64);
+//!     bridge.send_signal(INPUT, n);
+//!     let result = bridge.signal(RESULT);
+//!
+//!     view! {
+//!         <input
+//!             type="number"
+//!             prop:value=move || n.get().to_string()
+//!             on:input:target=move |ev| {
+//!                 if let Ok(value) = ev.target().value().parse() {
+//!                     n.set(value);
+//!                 }
+//!             }
+//!         />
+//!         <p>{move || result.get().map(|n| n.to_string())}</p>
+//!     }
+//! }
+//!
+//! // in the worker
+//! fn worker_main() {
+//!     let bridge = WorkerBridge::from_worker_scope();
+//!     bridge.subscribe(INPUT, {
+//!         let bridge = bridge.clone();
+//!         move |n| bridge.publish(RESULT, &fib(n))
+//!     });
+//! }
+//! # fn fib(n: u64) -> u64 { n }
+//! ```
+
+use reactive_graph::{
+    effect::Effect,
+    owner::on_cleanup,
+    signal::{ArcRwSignal, ReadSignal},
+    traits::{Get, Set},
+};
+use send_wrapper::SendWrapper;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::marker::PhantomData;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+/// The name and type of a value that is sent across a [`WorkerBridge`].
+///
+/// Declare channels as constants in code that is shared by the app and the worker.
+pub struct WorkerChannel<T> {
+    name: &'static str,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<T> WorkerChannel<T> {
+    /// Declares a channel with the given name, which must be unique within a bridge.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            ty: PhantomData,
+        }
+    }
+
+    /// The name of the channel.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for WorkerChannel<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WorkerChannel<T> {}
+
+impl<T> core::fmt::Debug for WorkerChannel<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("WorkerChannel").field(&self.name).finish()
+    }
+}
+
+#[derive(Clone)]
+enum BridgeTarget {
+    Worker(web_sys::Worker),
+    Scope(web_sys::DedicatedWorkerGlobalScope),
+}
+
+/// One end of a connection between the main thread and a web worker.
+///
+/// On the main thread, create it from the [`Worker`](web_sys::Worker) with [`WorkerBridge::new`].
+/// Inside the worker, create it with [`WorkerBridge::from_worker_scope`].
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Clone)]
+pub struct WorkerBridge {
+    target: SendWrapper<BridgeTarget>,
+}
+
+impl core::fmt::Debug for WorkerBridge {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WorkerBridge").finish_non_exhaustive()
+    }
+}
+
+impl WorkerBridge {
+    /// Connects to a worker from the main thread.
+    pub fn new(worker: web_sys::Worker) -> Self {
+        Self {
+            target: SendWrapper::new(BridgeTarget::Worker(worker)),
+        }
+    }
+
+    /// Connects to the main thread from inside a dedicated worker.
+    ///
+    /// ## Panics
+    /// Panics if it is not called inside a dedicated worker.
+    pub fn from_worker_scope() -> Self {
+        let scope = web_sys::js_sys::global()
+            .dyn_into::<web_sys::DedicatedWorkerGlobalScope>()
+            .expect("WorkerBridge::from_worker_scope called outside a worker");
+        Self {
+            target: SendWrapper::new(BridgeTarget::Scope(scope)),
+        }
+    }
+
+    /// Sends a value to the other side of the bridge.
+    pub fn publish<T: Serialize>(&self, channel: WorkerChannel<T>, value: &T) {
+        let Some(message) = encode(channel, value) else {
+            crate::debug_warn!(
+                "could not serialize value for worker channel {:?}",
+                channel.name
+            );
+            return;
+        };
+        let message = JsValue::from_str(&message);
+        _ = match &*self.target {
+            BridgeTarget::Worker(worker) => worker.post_message(&message),
+            BridgeTarget::Scope(scope) => scope.post_message(&message),
+        };
+    }
+
+    /// Calls `cb` with each value that the other side of the bridge publishes to the channel.
+    ///
+    /// The listener is removed when the current reactive owner is cleaned up. If there is no
+    /// owner, as is often the case inside a worker, it stays active as long as the bridge's
+    /// worker does.
+    pub fn subscribe<T: DeserializeOwned>(
+        &self,
+        channel: WorkerChannel<T>,
+        cb: impl Fn(T) + 'static,
+    ) {
+        let listener = Closure::<dyn Fn(web_sys::MessageEvent)>::new(
+            move |ev: web_sys::MessageEvent| {
+                if let Some(value) = ev
+                    .data()
+                    .as_string()
+                    .and_then(|data| decode(channel, &data))
+                {
+                    cb(value);
+                }
+            },
+        )
+        .into_js_value();
+        let target = self.event_target();
+        _ = target.add_event_listener_with_callback(
+            "message",
+            listener.unchecked_ref(),
+        );
+        let handle = SendWrapper::new((target, listener));
+        on_cleanup(move || {
+            let (target, listener) = handle.take();
+            _ = target.remove_event_listener_with_callback(
+                "message",
+                listener.unchecked_ref(),
+            );
+        });
+    }
+
+    /// Returns a signal containing the latest value that the other side of the bridge has
+    /// published to the channel, or `None` if it has not published one yet.
+    pub fn signal<T>(&self, channel: WorkerChannel<T>) -> ReadSignal<Option<T>>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        let signal = ArcRwSignal::new(None);
+        self.subscribe(channel, {
+            let signal = signal.clone();
+            move |value| signal.set(Some(value))
+        });
+        signal.read_only().into()
+    }
+
+    /// Publishes the current value of `source` to the channel, and publishes it again whenever
+    /// it changes.
+    pub fn send_signal<T>(
+        &self,
+        channel: WorkerChannel<T>,
+        source: impl Get<Value = T> + 'static,
+    ) where
+        T: Serialize + 'static,
+    {
+        let bridge = self.clone();
+        Effect::watch(
+            move || source.get(),
+            move |value, _, _| bridge.publish(channel, value),
+            true,
+        );
+    }
+
+    fn event_target(&self) -> web_sys::EventTarget {
+        match &*self.target {
+            BridgeTarget::Worker(worker) => worker.clone().unchecked_into(),
+            BridgeTarget::Scope(scope) => scope.clone().unchecked_into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope<V> {
+    channel: String,
+    value: V,
+}
+
+fn encode<T: Serialize>(
+    channel: WorkerChannel<T>,
+    value: &T,
+) -> Option<String> {
+    serde_json::to_string(&Envelope {
+        channel: channel.name.to_string(),
+        value,
+    })
+    .ok()
+}
+
+fn decode<T: DeserializeOwned>(
+    channel: WorkerChannel<T>,
+    data: &str,
+) -> Option<T> {
+    let envelope =
+        serde_json::from_str::<Envelope<serde_json::Value>>(data).ok()?;
+    if envelope.channel != channel.name {
+        return None;
+    }
+    serde_json::from_value(envelope.value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, WorkerChannel};
+
+    const COUNT: WorkerChannel<u32> = WorkerChannel::new("count");
+    const NAME: WorkerChannel<String> = WorkerChannel::new("name");
+
+    #[test]
+    fn messages_are_routed_by_channel() {
+        let message = encode(COUNT, &42).unwrap();
+        assert_eq!(decode(COUNT, &message), Some(42));
+        assert_eq!(decode(NAME, &message), None);
+        assert_eq!(decode(COUNT, "not json"), None);
+    }
+}
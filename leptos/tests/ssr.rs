@@ -205,3 +205,119 @@ fn ssr_option() {
 
     assert_eq!(rendered.to_html(), "<option></option>");
 }
+
+#[cfg(feature = "ssr")]
+#[test]
+fn ssr_static_children_are_marked() {
+    use leptos::prelude::*;
+
+    let (value, _) = signal(0);
+    let rendered: View<HtmlElement<_, _, _>> = view! {
+        <p>
+            "Hello, " <b>"world"</b> "!"
+            <span>{move || value.get()}</span>
+            "Bye."
+        </p>
+    };
+
+    assert_eq!(
+        rendered.to_html(),
+        "<p><!--io-->Hello, <b>world</b>!<!--ic--><span>0</span>Bye.</p>"
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn hydration_scripts_use_asset_manifest() {
+    use leptos::{hydration::AssetFileNames, prelude::*};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let site_root = tmp.path();
+    std::fs::create_dir_all(site_root.join("pkg")).unwrap();
+    std::fs::write(
+        site_root.join("pkg/manifest.json"),
+        r#"{
+            "js": { "file": "app.abc.js", "integrity": "sha384-js" },
+            "wasm": { "file": "app.def.wasm", "integrity": "sha384-wasm" }
+        }"#,
+    )
+    .unwrap();
+    let options = LeptosOptions::builder()
+        .output_name("app")
+        .site_root(site_root.to_str().unwrap())
+        .asset_manifest("manifest.json")
+        .build();
+
+    let names = AssetFileNames::new(&options);
+    assert_eq!(names.js, "app.abc");
+    assert_eq!(names.wasm, "app.def");
+    assert_eq!(names.css, "app");
+    assert_eq!(names.css_integrity, None);
+
+    let html = view! { <HydrationScripts options=options.clone()/> }.to_html();
+    assert!(html.contains(
+        "<link rel=\"modulepreload\" href=\"/pkg/app.abc.js\" \
+         integrity=\"sha384-js\""
+    ));
+    assert!(html.contains("href=\"/pkg/app.def.wasm\""));
+    assert!(html.contains("integrity=\"sha384-wasm\""));
+
+    // a rebuild while the server is running writes a new manifest, which is read again
+    let manifest = site_root.join("pkg/manifest.json");
+    std::fs::write(&manifest, r#"{ "js": { "file": "app.ghi.js" } }"#).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&manifest)
+        .unwrap()
+        .set_modified(
+            std::time::SystemTime::now() + std::time::Duration::from_secs(1),
+        )
+        .unwrap();
+    if cfg!(debug_assertions) {
+        assert_eq!(AssetFileNames::new(&options).js, "app.ghi");
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn for_store_renders_each_item_in_the_store() {
+    use leptos::prelude::*;
+    use reactive_stores::Store;
+
+    #[derive(Store)]
+    struct TodoList {
+        #[store(key: usize = |todo| todo.id)]
+        todos: Vec<Todo>,
+    }
+
+    #[derive(Store)]
+    struct Todo {
+        id: usize,
+        label: String,
+    }
+
+    let store = Store::new(TodoList {
+        todos: vec![
+            Todo {
+                id: 1,
+                label: "Write".into(),
+            },
+            Todo {
+                id: 2,
+                label: "Test".into(),
+            },
+        ],
+    });
+    let rendered: View<HtmlElement<_, _, _>> = view! {
+        <ul>
+            <ForStore each=store.todos() let:todo>
+                <li>{todo.label().get_untracked()}</li>
+            </ForStore>
+        </ul>
+    };
+
+    assert_eq!(
+        rendered.to_html(),
+        "<ul><li>Write</li><li>Test</li><!></ul>"
+    );
+}
@@ -1,8 +1,10 @@
 #![forbid(unsafe_code)]
 
 pub mod errors;
+mod loader;
 
 use crate::errors::LeptosConfigError;
+pub use crate::loader::{ConfigLoader, LiveOptions};
 use config::{Case, Config, File, FileFormat};
 use regex::Regex;
 use std::{
@@ -87,6 +89,18 @@ pub struct LeptosOptions {
     #[builder(default)]
     #[serde(default)]
     pub reload_ws_protocol: ReloadWSProtocol,
+    /// The path under which the app is served, like `/app`, when it is deployed in a
+    /// sub-directory behind a proxy.
+    ///
+    /// This is read when the server starts, so the same build can be deployed at different paths.
+    /// It is used as the default `root` for `HydrationScripts`, and can be passed to
+    /// `leptos_router::components::set_base_path` and
+    /// `server_fn::client::set_server_fn_path_prefix` to configure the router and server functions.
+    ///
+    /// Defaults to `None`, i.e., the app is served at `/`.
+    #[builder(default, setter(strip_option, into))]
+    #[serde(default)]
+    pub site_base_path: Option<String>,
     /// The path of a custom 404 Not Found page to display when statically serving content.
     ///
     /// Defaults to `site_root/404.html`.
@@ -140,6 +154,40 @@ pub struct LeptosOptions {
     #[builder(default)]
     #[serde(default)]
     pub server_fn_mod_path: bool,
+    /// If `true`, server-rendered pages list the app's JS, WASM and CSS files in `Link` preload
+    /// headers, so that the browser can start loading them as soon as the headers arrive, while
+    /// the rest of the page is still being streamed.
+    ///
+    /// The server does not send a `103 Early Hints` response itself, but proxies and CDNs that
+    /// support [Early Hints](https://developer.chrome.com/docs/web-platform/early-hints) can
+    /// generate one from these headers.
+    ///
+    /// Defaults to `false`.
+    #[builder(default)]
+    #[serde(default)]
+    pub preload_link_headers: bool,
+    /// The file name of the asset manifest produced by the build, in the `site_pkg_dir`.
+    ///
+    /// The manifest lists the hashed file names of the app's JS, WASM and CSS files, and their
+    /// [subresource integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity)
+    /// hashes:
+    ///
+    /// ```json
+    /// {
+    ///   "js": { "file": "app.1a2b3c.js", "integrity": "sha384-..." },
+    ///   "wasm": { "file": "app.4d5e6f.wasm", "integrity": "sha384-..." },
+    ///   "css": { "file": "app.7a8b9c.css", "integrity": "sha384-..." }
+    /// }
+    /// ```
+    ///
+    /// If it is set, the file names in the manifest are used instead of the
+    /// [`hash_file`](Self::hash_file), and the app's scripts and stylesheet are loaded with
+    /// their `integrity` attributes.
+    ///
+    /// Defaults to `None`.
+    #[builder(default, setter(strip_option, into))]
+    #[serde(default)]
+    pub asset_manifest: Option<Arc<str>>,
 }
 
 impl LeptosOptions {
@@ -196,6 +244,12 @@ impl LeptosOptions {
         path
     }
 
+    /// Returns a [`ConfigLoader`], which loads options from layered sources: the defaults, then a
+    /// file, then environment variables, and then values set in code.
+    pub fn loader() -> ConfigLoader {
+        ConfigLoader::new()
+    }
+
     fn try_from_env() -> Result<Self, LeptosConfigError> {
         let output_name = env_w_default(
             "LEPTOS_OUTPUT_NAME",
@@ -230,6 +284,7 @@ impl LeptosOptions {
             reload_ws_protocol: ws_from_str(
                 env_w_default("LEPTOS_RELOAD_WS_PROTOCOL", "ws")?.as_str(),
             )?,
+            site_base_path: env_wo_default("LEPTOS_SITE_BASE_PATH")?,
             not_found_path: env_w_default("LEPTOS_NOT_FOUND_PATH", "/404")?
                 .into(),
             hash_file: env_w_default("LEPTOS_HASH_FILE_NAME", "hash.txt")?
@@ -239,6 +294,13 @@ impl LeptosOptions {
             disable_server_fn_hash: env_wo_default("DISABLE_SERVER_FN_HASH")?
                 .is_some(),
             server_fn_mod_path: env_wo_default("SERVER_FN_MOD_PATH")?.is_some(),
+            preload_link_headers: env_w_default(
+                "LEPTOS_PRELOAD_LINK_HEADERS",
+                "false",
+            )?
+            .parse()?,
+            asset_manifest: env_wo_default("LEPTOS_ASSET_MANIFEST")?
+                .map(Into::into),
         })
     }
 }
@@ -452,6 +514,27 @@ impl TryFrom<String> for ReloadWSProtocol {
 pub fn get_config_from_str(
     text: &str,
 ) -> Result<LeptosOptions, LeptosConfigError> {
+    let toml = metadata_section(text)?;
+    let settings = Config::builder()
+        // Read the "default" configuration file
+        .add_source(File::from_str(&toml, FileFormat::Toml))
+        // Layer on the environment-specific values.
+        // Add in settings from environment variables (with a prefix of LEPTOS)
+        // E.g. `LEPTOS_RELOAD_PORT=5001 would set `LeptosOptions.reload_port`
+        .add_source(
+            config::Environment::with_prefix("LEPTOS")
+                .convert_case(Case::Kebab),
+        )
+        .build()?;
+
+    settings
+        .try_deserialize()
+        .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))
+}
+
+/// Finds the Leptos metadata section of a `Cargo.toml`, and returns it with its settings at the
+/// root level.
+fn metadata_section(text: &str) -> Result<String, LeptosConfigError> {
     let re: Regex = Regex::new(r"(?m)^\[package.metadata.leptos\]").unwrap();
     let re_workspace: Regex =
         Regex::new(r"(?m)^\[\[workspace.metadata.leptos\]\]").unwrap();
@@ -476,22 +559,7 @@ pub fn get_config_from_str(
     let newlines = text[..start].matches('\n').count();
     let input = "\n".repeat(newlines) + &text[start..];
     // so the settings will be interpreted as root level settings
-    let toml = input.replace(metadata_name, "");
-    let settings = Config::builder()
-        // Read the "default" configuration file
-        .add_source(File::from_str(&toml, FileFormat::Toml))
-        // Layer on the environment-specific values.
-        // Add in settings from environment variables (with a prefix of LEPTOS)
-        // E.g. `LEPTOS_RELOAD_PORT=5001 would set `LeptosOptions.reload_port`
-        .add_source(
-            config::Environment::with_prefix("LEPTOS")
-                .convert_case(Case::Kebab),
-        )
-        .build()?;
-
-    settings
-        .try_deserialize()
-        .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))
+    Ok(input.replace(metadata_name, ""))
 }
 
 /// Loads [`LeptosOptions`] from a Cargo.toml with layered overrides. If an env var is specified, like `LEPTOS_ENV`,
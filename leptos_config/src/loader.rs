@@ -0,0 +1,259 @@
+use crate::{
+    errors::LeptosConfigError, metadata_section, Env, LeptosOptions,
+    ReloadWSProtocol,
+};
+use config::{Case, Config, File, FileFormat, Value};
+use std::{
+    fs,
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// Loads [`LeptosOptions`] from layered sources, for deployments that cannot configure the app
+/// with `Cargo.toml` metadata or environment variables alone.
+///
+/// Each layer overrides the ones before it, whatever order the methods are called in:
+/// 1. the default values,
+/// 2. a file, if one was added with [`file`](Self::file) or [`file_str`](Self::file_str),
+/// 3. `LEPTOS_*` environment variables, if [`env_vars`](Self::env_vars) was called,
+/// 4. values set in code, with the typed setters such as [`site_addr`](Self::site_addr).
+///
+/// ```
+/// use leptos_config::LeptosOptions;
+///
+/// let options = LeptosOptions::loader()
+///     .file_str("output-name = \"app\"\nsite-root = \"site\"")
+///     .site_root("/srv/app")
+///     .load()
+///     .unwrap();
+/// assert_eq!(&*options.output_name, "app");
+/// assert_eq!(&*options.site_root, "/srv/app");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ConfigLoader {
+    file: Option<FileSource>,
+    env_vars: bool,
+    overrides: Vec<(&'static str, Value)>,
+}
+
+#[derive(Clone, Debug)]
+enum FileSource {
+    Path(PathBuf),
+    Str(String),
+}
+
+impl ConfigLoader {
+    /// Creates a loader with only the default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads options from a TOML file, which is read again each time the options are loaded.
+    ///
+    /// This can be a `Cargo.toml` with a `[package.metadata.leptos]` section, or a file that
+    /// only contains Leptos options.
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = Some(FileSource::Path(path.into()));
+        self
+    }
+
+    /// Reads options from the contents of a TOML file. See [`file`](Self::file).
+    pub fn file_str(mut self, text: impl Into<String>) -> Self {
+        self.file = Some(FileSource::Str(text.into()));
+        self
+    }
+
+    /// Reads options from `LEPTOS_*` environment variables, such as `LEPTOS_SITE_ADDR`.
+    pub fn env_vars(mut self) -> Self {
+        self.env_vars = true;
+        self
+    }
+
+    fn set(mut self, key: &'static str, value: impl Into<Value>) -> Self {
+        self.overrides.retain(|(k, _)| *k != key);
+        self.overrides.push((key, value.into()));
+        self
+    }
+
+    /// Sets [`LeptosOptions::output_name`].
+    pub fn output_name(self, output_name: impl Into<String>) -> Self {
+        self.set("output-name", output_name.into())
+    }
+
+    /// Sets [`LeptosOptions::site_root`].
+    pub fn site_root(self, site_root: impl Into<String>) -> Self {
+        self.set("site-root", site_root.into())
+    }
+
+    /// Sets [`LeptosOptions::site_pkg_dir`].
+    pub fn site_pkg_dir(self, site_pkg_dir: impl Into<String>) -> Self {
+        self.set("site-pkg-dir", site_pkg_dir.into())
+    }
+
+    /// Sets [`LeptosOptions::env`].
+    pub fn env(self, env: Env) -> Self {
+        let env = match env {
+            Env::PROD => "PROD",
+            Env::DEV => "DEV",
+        };
+        self.set("env", env)
+    }
+
+    /// Sets [`LeptosOptions::site_addr`].
+    pub fn site_addr(self, site_addr: SocketAddr) -> Self {
+        self.set("site-addr", site_addr.to_string())
+    }
+
+    /// Sets [`LeptosOptions::reload_port`].
+    pub fn reload_port(self, reload_port: u32) -> Self {
+        self.set("reload-port", i64::from(reload_port))
+    }
+
+    /// Sets [`LeptosOptions::reload_external_port`].
+    pub fn reload_external_port(self, reload_external_port: u32) -> Self {
+        self.set("reload-external-port", i64::from(reload_external_port))
+    }
+
+    /// Sets [`LeptosOptions::reload_ws_protocol`].
+    pub fn reload_ws_protocol(self, protocol: ReloadWSProtocol) -> Self {
+        let protocol = match protocol {
+            ReloadWSProtocol::WS => "WS",
+            ReloadWSProtocol::WSS => "WSS",
+        };
+        self.set("reload-ws-protocol", protocol)
+    }
+
+    /// Sets [`LeptosOptions::site_base_path`].
+    pub fn site_base_path(self, site_base_path: impl Into<String>) -> Self {
+        self.set("site-base-path", site_base_path.into())
+    }
+
+    /// Sets [`LeptosOptions::not_found_path`].
+    pub fn not_found_path(self, not_found_path: impl Into<String>) -> Self {
+        self.set("not-found-path", not_found_path.into())
+    }
+
+    /// Sets [`LeptosOptions::hash_file`].
+    pub fn hash_file(self, hash_file: impl Into<String>) -> Self {
+        self.set("hash-file", hash_file.into())
+    }
+
+    /// Sets [`LeptosOptions::hash_files`].
+    pub fn hash_files(self, hash_files: bool) -> Self {
+        self.set("hash-files", hash_files)
+    }
+
+    /// Sets [`LeptosOptions::server_fn_prefix`].
+    pub fn server_fn_prefix(self, server_fn_prefix: impl Into<String>) -> Self {
+        self.set("server-fn-prefix", server_fn_prefix.into())
+    }
+
+    /// Sets [`LeptosOptions::disable_server_fn_hash`].
+    pub fn disable_server_fn_hash(self, disable: bool) -> Self {
+        self.set("disable-server-fn-hash", disable)
+    }
+
+    /// Sets [`LeptosOptions::server_fn_mod_path`].
+    pub fn server_fn_mod_path(self, server_fn_mod_path: bool) -> Self {
+        self.set("server-fn-mod-path", server_fn_mod_path)
+    }
+
+    /// Sets [`LeptosOptions::preload_link_headers`].
+    pub fn preload_link_headers(self, preload_link_headers: bool) -> Self {
+        self.set("preload-link-headers", preload_link_headers)
+    }
+
+    /// Sets [`LeptosOptions::asset_manifest`].
+    pub fn asset_manifest(self, asset_manifest: impl Into<String>) -> Self {
+        self.set("asset-manifest", asset_manifest.into())
+    }
+
+    /// Loads the options from every layer.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either:
+    /// - the file was not found;
+    /// - a layer could not be read, or the options could not be deserialized from them (for
+    ///   example, because no layer sets `output-name`).
+    pub fn load(&self) -> Result<LeptosOptions, LeptosConfigError> {
+        let mut builder = Config::builder();
+
+        if let Some(file) = &self.file {
+            let text = match file {
+                FileSource::Path(path) => fs::read_to_string(path)
+                    .map_err(|_| LeptosConfigError::ConfigNotFound)?,
+                FileSource::Str(text) => text.clone(),
+            };
+            // files without a metadata section only contain Leptos options
+            let toml = metadata_section(&text).unwrap_or(text);
+            builder =
+                builder.add_source(File::from_str(&toml, FileFormat::Toml));
+        }
+        if self.env_vars {
+            builder = builder.add_source(
+                config::Environment::with_prefix("LEPTOS")
+                    .convert_case(Case::Kebab),
+            );
+        }
+        for (key, value) in &self.overrides {
+            builder = builder.set_override(*key, value.clone())?;
+        }
+
+        builder
+            .build()?
+            .try_deserialize()
+            .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))
+    }
+
+    /// Loads the options, keeping the loader so that they can be reloaded while the app is
+    /// running.
+    pub fn load_live(self) -> Result<LiveOptions, LeptosConfigError> {
+        let options = self.load()?;
+        Ok(LiveOptions {
+            loader: self,
+            current: Arc::new(RwLock::new(options)),
+        })
+    }
+}
+
+/// [`LeptosOptions`] that can be reloaded while the app is running. Created with
+/// [`ConfigLoader::load_live`].
+///
+/// Only the options that are safe to change without restarting the server are reloaded:
+/// [`env`](LeptosOptions::env), [`not_found_path`](LeptosOptions::not_found_path),
+/// [`preload_link_headers`](LeptosOptions::preload_link_headers),
+/// [`reload_external_port`](LeptosOptions::reload_external_port), and
+/// [`reload_ws_protocol`](LeptosOptions::reload_ws_protocol). Server integrations keep the
+/// copy of the options that they were given, so use [`get`](Self::get) wherever options are
+/// read for each request, such as in the shell of the app.
+#[derive(Clone, Debug)]
+pub struct LiveOptions {
+    loader: ConfigLoader,
+    current: Arc<RwLock<LeptosOptions>>,
+}
+
+impl LiveOptions {
+    /// Returns the current options.
+    pub fn get(&self) -> LeptosOptions {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Loads every layer again, updates the options that can be reloaded, and returns the new
+    /// options. If loading fails, the current options are kept.
+    pub fn reload(&self) -> Result<LeptosOptions, LeptosConfigError> {
+        let new = self.loader.load()?;
+        let mut current =
+            self.current.write().unwrap_or_else(PoisonError::into_inner);
+        current.env = new.env;
+        current.not_found_path = new.not_found_path;
+        current.preload_link_headers = new.preload_link_headers;
+        current.reload_external_port = new.reload_external_port;
+        current.reload_ws_protocol = new.reload_ws_protocol;
+        Ok(current.clone())
+    }
+}
@@ -227,6 +227,51 @@ fn leptos_options_builder_default() {
     assert_eq!(conf.reload_external_port, None);
 }
 
+#[test]
+fn site_base_path_from_env() {
+    let config = temp_env::with_vars(
+        [
+            ("LEPTOS_OUTPUT_NAME", Some("app-test")),
+            ("LEPTOS_SITE_BASE_PATH", Some("/app")),
+        ],
+        || get_configuration(None).unwrap().leptos_options,
+    );
+    assert_eq!(config.site_base_path.as_deref(), Some("/app"));
+
+    let conf = LeptosOptions::builder().output_name("app-test").build();
+    assert_eq!(conf.site_base_path, None);
+}
+
+#[test]
+fn preload_link_headers_from_env() {
+    let config = temp_env::with_vars(
+        [
+            ("LEPTOS_OUTPUT_NAME", Some("app-test")),
+            ("LEPTOS_PRELOAD_LINK_HEADERS", Some("true")),
+        ],
+        || get_configuration(None).unwrap().leptos_options,
+    );
+    assert!(config.preload_link_headers);
+
+    let conf = LeptosOptions::builder().output_name("app-test").build();
+    assert!(!conf.preload_link_headers);
+}
+
+#[test]
+fn asset_manifest_from_env() {
+    let config = temp_env::with_vars(
+        [
+            ("LEPTOS_OUTPUT_NAME", Some("app-test")),
+            ("LEPTOS_ASSET_MANIFEST", Some("manifest.json")),
+        ],
+        || get_configuration(None).unwrap().leptos_options,
+    );
+    assert_eq!(config.asset_manifest.as_deref(), Some("manifest.json"));
+
+    let conf = LeptosOptions::builder().output_name("app-test").build();
+    assert_eq!(conf.asset_manifest, None);
+}
+
 #[test]
 fn environment_variable_override() {
     // first check without variables set
@@ -308,3 +353,55 @@ env = "{env_value}"
 
     assert_eq!(config_from_file.env, config_from_env.env);
 }
+
+#[test]
+fn loader_layers_file_env_and_code() {
+    let config = temp_env::with_vars(
+        [
+            ("LEPTOS_SITE_ROOT", Some("env_site")),
+            ("LEPTOS_SITE_PKG_DIR", Some("env_pkg")),
+        ],
+        || {
+            LeptosOptions::loader()
+                .site_pkg_dir("code_pkg")
+                .env_vars()
+                .file_str(CARGO_TOML_CONTENT_OK)
+                .load()
+                .unwrap()
+        },
+    );
+
+    // from the file
+    assert_eq!(config.output_name.as_ref(), "app-test");
+    // the environment overrides the file
+    assert_eq!(config.site_root.as_ref(), "env_site");
+    // code overrides the environment
+    assert_eq!(config.site_pkg_dir.as_ref(), "code_pkg");
+    // defaults
+    assert_eq!(config.hash_file.as_ref(), "hash.txt");
+
+    let err = LeptosOptions::loader().load();
+    assert!(err.is_err(), "output-name has no default");
+}
+
+#[test]
+fn live_options_reload_select_fields() {
+    let file = NamedTempFile::new().unwrap();
+    std::fs::write(&file, "output-name = \"app\"\nsite-root = \"a\"").unwrap();
+    let live = LeptosOptions::loader()
+        .file(file.path())
+        .load_live()
+        .unwrap();
+    assert!(!live.get().preload_link_headers);
+
+    std::fs::write(
+        &file,
+        "output-name = \"app\"\nsite-root = \"b\"\npreload-link-headers = true",
+    )
+    .unwrap();
+    let reloaded = live.reload().unwrap();
+    assert!(reloaded.preload_link_headers);
+    assert!(live.get().preload_link_headers);
+    // options that need a restart are kept
+    assert_eq!(live.get().site_root.as_ref(), "a");
+}
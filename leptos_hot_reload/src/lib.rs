@@ -16,7 +16,7 @@ use std::{
 use syn::{
     spanned::Spanned,
     visit::{self, Visit},
-    Macro,
+    ItemFn, Macro,
 };
 use walkdir::WalkDir;
 
@@ -30,6 +30,8 @@ pub const HOT_RELOAD_JS: &str = include_str!("patch.js");
 pub struct ViewMacros {
     // keyed by original location identifier
     views: Arc<RwLock<HashMap<Utf8PathBuf, Vec<MacroInvocation>>>>,
+    // the signatures of the components defined in each file
+    components: Arc<RwLock<HashMap<Utf8PathBuf, Vec<ComponentSignature>>>>,
 }
 
 impl ViewMacros {
@@ -43,6 +45,7 @@ impl ViewMacros {
     /// Will return `Err` if the path is not UTF-8 path or the contents of the file cannot be parsed.
     pub fn update_from_paths<T: AsRef<Path>>(&self, paths: &[T]) -> Result<()> {
         let mut views = HashMap::new();
+        let mut components = HashMap::new();
 
         for path in paths {
             for entry in WalkDir::new(path).into_iter().flatten() {
@@ -50,15 +53,16 @@ impl ViewMacros {
                     let path: PathBuf = entry.path().into();
                     let path = Utf8PathBuf::try_from(path)?;
                     if path.extension() == Some("rs") || path.ends_with(".rs") {
-                        let macros = Self::parse_file(&path)?;
-                        let entry = views.entry(path.clone()).or_default();
-                        *entry = macros;
+                        let (macros, signatures) = Self::parse(&path)?;
+                        views.insert(path.clone(), macros);
+                        components.insert(path, signatures);
                     }
                 }
             }
         }
 
         *self.views.write().or_poisoned() = views;
+        *self.components.write().or_poisoned() = components;
 
         Ok(())
     }
@@ -67,6 +71,12 @@ impl ViewMacros {
     ///
     /// Will return `Err` if the contents of the file cannot be parsed.
     pub fn parse_file(path: &Utf8PathBuf) -> Result<Vec<MacroInvocation>> {
+        Self::parse(path).map(|(views, _)| views)
+    }
+
+    fn parse(
+        path: &Utf8PathBuf,
+    ) -> Result<(Vec<MacroInvocation>, Vec<ComponentSignature>)> {
         let mut file = File::open(path)?;
         let mut content = String::new();
         file.read_to_string(&mut content)?;
@@ -74,6 +84,12 @@ impl ViewMacros {
 
         let mut visitor = ViewMacroVisitor::default();
         visitor.visit_file(&ast);
+        let components = visitor
+            .components
+            .iter()
+            .copied()
+            .filter_map(ComponentSignature::from_item)
+            .collect();
         let mut views = Vec::new();
         for view in visitor.views {
             let span = view.span();
@@ -93,15 +109,34 @@ impl ViewMacros {
                 views.push(MacroInvocation { id, template });
             }
         }
-        Ok(views)
+        Ok((views, components))
     }
 
+    /// Diffs the views in the file against their last known state, and returns the patches that
+    /// update the page to match them.
+    ///
+    /// Patches only touch the parts of the page that changed, so the values of signals and the
+    /// state of islands are kept. Returns `None` if the file cannot be hot-reloaded and the page
+    /// should be fully reloaded instead: either because it was not known, because views were
+    /// added or removed, or because the props of a component defined in it changed.
+    ///
     /// # Errors
     ///
     /// Will return `Err` if the contents of the file cannot be parsed.
     pub fn patch(&self, path: &Utf8PathBuf) -> Result<Option<Patches>> {
-        let new_views = Self::parse_file(path)?;
+        let (new_views, new_components) = Self::parse(path)?;
+        let components_changed = self
+            .components
+            .write()
+            .or_poisoned()
+            .insert(path.clone(), new_components.clone())
+            .is_some_and(|components| components != new_components);
         let mut lock = self.views.write().or_poisoned();
+        if components_changed {
+            // callers of the component have been compiled against its old props
+            lock.insert(path.clone(), new_views);
+            return Ok(None);
+        }
         let diffs = match lock.get(path) {
             None => return Ok(None),
             Some(current_views) => {
@@ -151,12 +186,46 @@ impl core::fmt::Debug for MacroInvocation {
     }
 }
 
+/// The name and props of a `#[component]` or `#[island]` function.
+///
+/// A view can be patched in place only as long as the components in its file keep the same
+/// signature. Changing it changes the props that other views were compiled against, so the page
+/// has to be fully reloaded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ComponentSignature {
+    name: String,
+    signature: String,
+}
+
+impl ComponentSignature {
+    fn from_item(item: &ItemFn) -> Option<Self> {
+        let attr = item.attrs.iter().find(|attr| {
+            attr.path().segments.last().is_some_and(|segment| {
+                segment.ident == "component" || segment.ident == "island"
+            })
+        })?;
+        let sig = &item.sig;
+        let (generics, inputs) = (&sig.generics, &sig.inputs);
+        Some(Self {
+            name: sig.ident.to_string(),
+            signature: quote::quote!(#attr #generics (#inputs)).to_string(),
+        })
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct ViewMacroVisitor<'a> {
     views: Vec<&'a Macro>,
+    components: Vec<&'a ItemFn>,
 }
 
 impl<'ast> Visit<'ast> for ViewMacroVisitor<'ast> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.components.push(node);
+
+        visit::visit_item_fn(self, node);
+    }
+
     fn visit_macro(&mut self, node: &'ast Macro) {
         let ident = node.path.get_ident().map(ToString::to_string);
         if ident == Some("view".to_string()) {
@@ -176,3 +245,40 @@ pub fn span_to_stable_id(path: impl AsRef<Path>, line: usize) -> String {
         .replace(['/', '\\'], "-");
     format!("{file}-{line}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ViewMacros;
+    use camino::Utf8PathBuf;
+
+    #[test]
+    fn reloads_fully_only_when_component_props_change() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let path = Utf8PathBuf::try_from(dir.join("app.rs")).unwrap();
+        let write = |prop: &str, text: &str| {
+            std::fs::write(
+                &path,
+                format!(
+                    "#[component]\nfn Greeting({prop}: String) -> impl \
+                     IntoView {{\n    view! {{ <p>{text}</p> }}\n}}\n"
+                ),
+            )
+            .unwrap();
+        };
+
+        write("name", "Hello");
+        let macros = ViewMacros::new();
+        macros.update_from_paths(&[dir]).unwrap();
+
+        write("name", "Goodbye");
+        assert!(macros.patch(&path).unwrap().is_some());
+
+        write("greeting", "Goodbye");
+        assert!(macros.patch(&path).unwrap().is_none());
+
+        // the new props are known once the page has been reloaded
+        write("greeting", "Hello");
+        assert!(macros.patch(&path).unwrap().is_some());
+    }
+}
@@ -1,5 +1,8 @@
 console.log("[HOT RELOADING] Connected to server.");
 function patch(json) {
+  // patches move and replace nodes in place, so that signals and islands keep their state, but
+  // moving a node blurs it, so focus and selection are restored once the patches have applied
+  const focused = saveFocus();
   try {
     const views = JSON.parse(json);
     for (const [id, patches] of views) {
@@ -83,6 +86,13 @@ function patch(json) {
             for (const attr of oldNode.attributes) {
               newElement.setAttribute(attr.name, attr.value);
             }
+            // keep whatever has been typed into form fields
+            if ("value" in oldNode && "value" in newElement) {
+              newElement.value = oldNode.value;
+            }
+            if ("checked" in oldNode && "checked" in newElement) {
+              newElement.checked = oldNode.checked;
+            }
             for (const childNode of child.node.childNodes) {
               newElement.appendChild(childNode);
             }
@@ -223,6 +233,37 @@ function patch(json) {
   } catch (e) {
     console.warn("[HOT RELOADING] Error: ", e);
   }
+  restoreFocus(focused);
+
+  function saveFocus() {
+    const node = document.activeElement;
+    if (!node || node == document.body) {
+      return undefined;
+    }
+    let selection;
+    try {
+      if (typeof node.selectionStart == "number") {
+        selection = [node.selectionStart, node.selectionEnd];
+      }
+    } catch (_) {
+      // not every kind of input has a selection
+    }
+    return { node, selection };
+  }
+
+  function restoreFocus(focused) {
+    if (
+      !focused ||
+      !focused.node.isConnected ||
+      document.activeElement == focused.node
+    ) {
+      return;
+    }
+    focused.node.focus();
+    if (focused.selection) {
+      focused.node.setSelectionRange(...focused.selection);
+    }
+  }
 
   function fromReplacementNode(node, actualChildren) {
     if (node.Html) {
@@ -255,18 +296,9 @@ function patch(json) {
           const range = new Range();
           range.setStartBefore(child.start);
           range.setEndAfter(child.end);
-          // okay this is somewhat silly
-          // if we do cloneContents() here to return it,
-          // we strip away the event listeners
-          // if we're moving just one object, this is less than ideal
-          // so I'm actually going to *extract* them, then clone and reinsert
-          /* const toReinsert = range.cloneContents();
-					if (child.end.nextSibling) {
-						child.end.parentNode.insertBefore(toReinsert, child.end.nextSibling);
-					} else {
-						child.end.parentNode.appendChild(toReinsert);
-					} */
-          childNode = range.cloneContents();
+          // move the existing nodes rather than cloning them: clones lose their event
+          // listeners, and the reactive system and islands only update the original nodes
+          childNode = range.extractContents();
         }
         return childNode;
       } else {
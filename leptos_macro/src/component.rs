@@ -21,7 +21,10 @@ use syn::{
 pub struct Model {
     is_transparent: bool,
     is_lazy: bool,
+    lazy_group: Option<syn::LitStr>,
+    is_deferred: bool,
     island: Option<String>,
+    hydration_trigger: Option<TokenStream>,
     docs: Docs,
     unknown_attrs: UnknownAttrs,
     vis: Visibility,
@@ -69,7 +72,10 @@ impl Parse for Model {
         Ok(Self {
             is_transparent: false,
             is_lazy: false,
+            lazy_group: None,
+            is_deferred: false,
             island: None,
+            hydration_trigger: None,
             docs,
             unknown_attrs,
             vis: item.vis.clone(),
@@ -144,7 +150,10 @@ impl ToTokens for Model {
         let Self {
             is_transparent,
             is_lazy,
+            lazy_group,
+            is_deferred,
             island,
+            hydration_trigger,
             docs,
             unknown_attrs,
             vis,
@@ -302,7 +311,17 @@ impl ToTokens for Model {
         });
 
         let island_serialize_props = if is_island_with_other_props {
+            let assert_props = props
+                .iter()
+                .filter(|prop| prop.name.ident != "children")
+                .map(|Prop { ty, .. }| {
+                    quote_spanned! {ty.span()=>
+                        ::leptos::component::assert_island_prop::<#ty>();
+                    }
+                })
+                .collect::<TokenStream>();
             quote! {
+                #assert_props
                 let _leptos_ser_props = ::leptos::serde_json::to_string(&props).expect("couldn't serialize island props");
             }
         } else {
@@ -315,6 +334,14 @@ impl ToTokens for Model {
         } else {
             quote! {}
         };
+        let island_hydration_trigger = hydration_trigger
+            .as_ref()
+            .map(|trigger| {
+                quote! {
+                    .with_hydration_trigger(#trigger)
+                }
+            })
+            .unwrap_or_default();
 
         let body_name = unmodified_fn_name_from_fn_name(&body_name);
         let body_expr = if is_island {
@@ -362,12 +389,31 @@ impl ToTokens for Model {
         // add island wrapper if island
         let component = if is_island {
             let hydrate_fn_name = hydrate_fn_name.as_ref().unwrap();
-            quote! {
-                ::leptos::tachys::html::islands::Island::new(
-                    stringify!(#hydrate_fn_name),
-                    #component
-                )
-                #island_serialized_props
+            if *is_deferred {
+                // the contents of a deferred island are only rendered when the island runtime
+                // asks for them
+                quote! {
+                    ::leptos::tachys::html::islands::Island::new(
+                        stringify!(#hydrate_fn_name),
+                        if ::leptos::hydration::should_defer_island() {
+                            ::leptos::either::Either::Left(())
+                        } else {
+                            ::leptos::either::Either::Right(#component)
+                        }
+                    )
+                    #island_serialized_props
+                    #island_hydration_trigger
+                    .deferred()
+                }
+            } else {
+                quote! {
+                    ::leptos::tachys::html::islands::Island::new(
+                        stringify!(#hydrate_fn_name),
+                        #component
+                    )
+                    #island_serialized_props
+                    #island_hydration_trigger
+                }
             }
         } else {
             component
@@ -526,8 +572,14 @@ impl ToTokens for Model {
             let deserialize_island_props = if is_island_with_other_props {
                 quote! {
                     let props = el.dataset().get(::leptos::wasm_bindgen::intern("props"))
-                        .and_then(|data| ::leptos::serde_json::from_str::<#props_serialized_name>(&data).ok())
-                        .expect("could not deserialize props");
+                        .expect(concat!("missing props for island <", stringify!(#name), "/>"));
+                    let props = match ::leptos::serde_json::from_str::<#props_serialized_name>(&props) {
+                        Ok(props) => props,
+                        Err(e) => panic!(
+                            "could not deserialize props for island <{}/>: {e}",
+                            stringify!(#name)
+                        ),
+                    };
                 }
             } else {
                 quote! {}
@@ -535,19 +587,25 @@ impl ToTokens for Model {
 
             let hydrate_fn_name = hydrate_fn_name.as_ref().unwrap();
 
+            let measure_name = format!("island:{name}");
             let hydrate_fn_inner = quote! {
+                let measure = ::leptos::hydration::HydrationMeasure::start(#measure_name);
                 #deserialize_island_props
                 let island = #name(#island_props);
                 let state = island.hydrate_from_position::<true>(&el, ::leptos::tachys::view::Position::Current);
                 // TODO better cleanup
                 std::mem::forget(state);
+                measure.finish();
             };
             if *is_lazy {
                 let outer_name =
                     Ident::new(&format!("{name}_loader"), name.span());
+                let lazy_args = lazy_group
+                    .as_ref()
+                    .map(|group| quote! { (group = #group) });
 
                 quote! {
-                    #[::leptos::prelude::lazy]
+                    #[::leptos::prelude::lazy #lazy_args]
                     #[allow(non_snake_case)]
                     fn #outer_name (el: ::leptos::web_sys::HtmlElement) {
                         #hydrate_fn_inner
@@ -647,12 +705,68 @@ impl Model {
         self
     }
 
+    pub fn with_lazy_group(mut self, lazy_group: Option<syn::LitStr>) -> Self {
+        self.lazy_group = lazy_group;
+
+        self
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_deferred(mut self, is_deferred: bool) -> Self {
+        self.is_deferred = is_deferred;
+
+        self
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub fn with_island(mut self, island: Option<String>) -> Self {
         self.island = island;
 
         self
     }
+
+    pub fn with_hydration_trigger(
+        mut self,
+        hydration_trigger: Option<TokenStream>,
+    ) -> Self {
+        self.hydration_trigger = hydration_trigger;
+
+        self
+    }
+}
+
+/// Converts the `hydrate = "..."` argument of `#[island]` into a `HydrationTrigger`.
+pub fn hydration_trigger_to_tokens(trigger: &LitStr) -> TokenStream {
+    let value = trigger.value();
+    let value = value.trim();
+    let variant = match value {
+        "load" => quote! { Load },
+        "visible" => quote! { Visible },
+        "idle" => quote! { Idle },
+        "interaction" => quote! { Interaction },
+        _ => match value
+            .strip_prefix("media(")
+            .and_then(|query| query.strip_suffix(')'))
+        {
+            Some(query) if !query.trim().is_empty() => {
+                // `media(min-width: 800px)` and `media((min-width: 800px))` are both accepted
+                let query = query.trim();
+                let query = if query.starts_with('(') {
+                    query.to_string()
+                } else {
+                    format!("({query})")
+                };
+                quote! { Media(#query) }
+            }
+            _ => abort!(
+                trigger,
+                "unknown island hydration trigger `{}`", value;
+                help = "expected one of \"load\", \"visible\", \"idle\", \
+                        \"interaction\", or \"media(<query>)\""
+            ),
+        },
+    };
+    quote! { ::leptos::tachys::html::islands::HydrationTrigger::#variant }
 }
 
 /// A model that is more lenient in case of a syntax error in the function body,
@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Expr, Fields, LitStr};
+
+pub fn form_data_impl(ast: &DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        ast.generics.split_for_impl();
+
+    let Data::Struct(data) = &ast.data else {
+        return syn::Error::new(
+            ast.span(),
+            "FormData can only be derived for structs",
+        )
+        .to_compile_error();
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return syn::Error::new(
+            data.fields.span(),
+            "FormData can only be derived for structs with named fields",
+        )
+        .to_compile_error();
+    };
+
+    let mut idents = Vec::new();
+    let mut parsers = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("expected named struct fields");
+        let ty = &field.ty;
+        let span = field.span();
+
+        let mut field_name =
+            ident.to_string().trim_start_matches("r#").to_owned();
+        let mut validate = None::<Expr>;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("form"))
+        {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    field_name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("validate") {
+                    validate = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `rename` or `validate`"))
+                }
+            });
+            if let Err(e) = parsed {
+                return e.to_compile_error();
+            }
+        }
+
+        let validate = validate.map(|validate| {
+            quote! {
+                ::leptos::form::macro_helpers::validate(
+                    &value, &key, #validate, &mut __errors
+                );
+            }
+        });
+        parsers.push(quote_spanned! {
+            span=> let #ident = {
+                let key = ::leptos::form::macro_helpers::field_name(__name, #field_name);
+                match ::leptos::form::macro_helpers::Wrapper::<#ty>::__from_fields(__fields, &key) {
+                    ::core::result::Result::Ok(value) => {
+                        #validate
+                        ::core::option::Option::Some(value)
+                    }
+                    ::core::result::Result::Err(e) => {
+                        __errors.extend(e);
+                        ::core::option::Option::None
+                    }
+                }
+            };
+        });
+        idents.push(ident);
+    }
+
+    quote! {
+        impl #impl_generics ::leptos::form::FormData for #name #ty_generics #where_clause {
+            // the arguments are prefixed, as they share a scope with the struct's field names
+            fn from_fields(
+                __fields: &::leptos::form::FormFields,
+                __name: &str,
+            ) -> ::core::result::Result<Self, ::leptos::form::FormErrors> {
+                use ::leptos::form::macro_helpers::Fallback as _;
+
+                let mut __errors = ::leptos::form::FormErrors::new();
+                #(#parsers)*
+                match (#(#idents,)*) {
+                    (#(::core::option::Option::Some(#idents),)*) if __errors.is_empty() => {
+                        ::core::result::Result::Ok(Self { #(#idents,)* })
+                    }
+                    _ => ::core::result::Result::Err(__errors),
+                }
+            }
+        }
+    }
+}
@@ -10,10 +10,34 @@ use std::{
 use syn::{parse_macro_input, parse_quote, ItemFn, ReturnType, Stmt};
 
 pub fn lazy_impl(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
-    let name = if !args.is_empty() {
-        Some(parse_macro_input!(args as syn::Ident))
-    } else {
+    // `#[lazy(group = "name")]` puts every function with the same group name
+    // in the same WASM chunk, rather than giving each function its own
+    let mut group = None;
+    let name = if args.is_empty() {
         None
+    } else if let Ok(syn::MetaNameValue {
+        path,
+        value:
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(group_name),
+                ..
+            }),
+        ..
+    }) = syn::parse::<syn::MetaNameValue>(args.clone())
+    {
+        if !path.is_ident("group") {
+            abort!(path, "only `group = \"...\"` is supported");
+        }
+        let ident = group_name.parse::<syn::Ident>().unwrap_or_else(|_| {
+            abort!(
+                group_name,
+                "the group name should be a valid Rust identifier"
+            )
+        });
+        group = Some(ident.to_string());
+        Some(ident)
+    } else {
+        Some(parse_macro_input!(args as syn::Ident))
     };
 
     let fun = syn::parse::<ItemFn>(s).unwrap_or_else(|e| {
@@ -30,11 +54,15 @@ pub fn lazy_impl(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
     });
 
     let (unique_name, unique_name_str) = {
-        let span = proc_macro::Span::call_site();
-        let location = (span.line(), span.start().column(), span.file());
-
         let mut hasher = DefaultHasher::new();
-        location.hash(&mut hasher);
+        if let Some(group) = &group {
+            // functions in a group share a name, which is unique to this crate
+            (std::env::var("CARGO_PKG_NAME").ok(), group).hash(&mut hasher);
+        } else {
+            let span = proc_macro::Span::call_site();
+            let location = (span.line(), span.start().column(), span.file());
+            location.hash(&mut hasher);
+        }
         let hash = hasher.finish();
 
         let unique_name_str = format!("{converted_name}_{hash}");
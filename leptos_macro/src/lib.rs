@@ -17,8 +17,12 @@ use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenTree};
 use quote::{quote, ToTokens};
 use std::str::FromStr;
-use syn::{parse_macro_input, spanned::Spanned, token::Pub, Visibility};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, token::Pub,
+    Token, Visibility,
+};
 
+mod form_data;
 mod params;
 mod view;
 use crate::component::unmodified_fn_name_from_fn_name;
@@ -576,7 +580,7 @@ pub fn component(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
         false
     };
 
-    component_macro(s, is_transparent, false, None)
+    component_macro(s, is_transparent, false, None, false, None, None)
 }
 
 /// Defines a component as an interactive island when you are using the
@@ -597,7 +601,44 @@ pub fn component(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
 /// Only code included in an island itself is compiled to WASM. This means:
 /// 1. `children` can be provided from a server `#[component]` to an `#[island]`
 /// without the island needing to be able to hydrate them.
-/// 2. Props can be passed from the server to an island.
+/// 2. Props can be passed from the server to an island. Any prop type that implements
+/// `serde::Serialize` and `serde::Deserialize` can be used: props are serialized as JSON into
+/// the island's `data-props` attribute and deserialized when the island hydrates. Using any
+/// other type is a compile error.
+///
+/// ## Hydration Triggers
+/// By default, an island hydrates as soon as the WASM module has loaded. Islands that are below
+/// the fold or rarely used can defer hydration with `#[island(hydrate = "...")]`, so that they
+/// do not cost anything at startup:
+/// - `"visible"` hydrates the island when it first scrolls into the viewport.
+/// - `"idle"` hydrates the island when the browser is idle.
+/// - `"interaction"` hydrates the island the first time the user hovers over, focuses, or
+///   touches it.
+/// - `"media(<query>)"`, e.g. `"media(min-width: 800px)"`, hydrates the island once the media
+///   query matches.
+///
+/// This can be combined with `lazy`, as in `#[island(lazy, hydrate = "visible")]`, to also
+/// defer loading the island's code until it is needed.
+///
+/// ## Code Splitting
+/// By default, the code for every island is compiled into a single WASM binary. With
+/// `#[island(lazy)]`, an island's code is split into a separate WASM chunk instead, which is
+/// only loaded once the island hydrates, so pages that do not use the island never download
+/// it. Islands that are usually used together can share a chunk with
+/// `#[island(lazy = "group_name")]`: the whole group is loaded when the first of them
+/// hydrates. This requires a build that splits the WASM binary, such as `cargo leptos --split`;
+/// see [`lazy`](macro@lazy) for details.
+///
+/// ## Server Islands
+/// `#[island(defer)]` leaves the island's contents out of the page, rendering it as an empty
+/// `<leptos-island>` placeholder instead. When the island's hydration trigger fires, the island
+/// runtime requests the page again with a `Leptos-Deferred-Islands` header; the server then
+/// renders every deferred island in full, and the runtime swaps in the island's HTML and
+/// hydrates it. This allows per-user content, like a cart or a greeting, inside a page that
+/// can otherwise be cached and shared by every user.
+///
+/// Deferred islands are matched by their position among the deferred islands on the page,
+/// so the same deferred islands should be rendered in both requests.
 ///
 /// ## Present Limitations
 /// A few noteworthy limitations, at the moment:
@@ -653,31 +694,80 @@ pub fn component(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
 #[proc_macro_error2::proc_macro_error]
 #[proc_macro_attribute]
 pub fn island(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
-    let (is_transparent, is_lazy) = if !args.is_empty() {
-        let arg = parse_macro_input!(args as syn::Ident);
-
-        if arg != "transparent" && arg != "lazy" {
-            abort!(
+    let args = parse_macro_input!(
+        args with Punctuated::<syn::Meta, Token![,]>::parse_terminated
+    );
+    let mut is_transparent = false;
+    let mut is_lazy = false;
+    let mut lazy_group = None;
+    let mut is_deferred = false;
+    let mut hydration_trigger = None;
+    for arg in args {
+        match &arg {
+            syn::Meta::Path(path) if path.is_ident("transparent") => {
+                is_transparent = true;
+            }
+            syn::Meta::Path(path) if path.is_ident("lazy") => {
+                is_lazy = true;
+            }
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(group),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("lazy") => {
+                is_lazy = true;
+                lazy_group = Some(group.clone());
+            }
+            syn::Meta::Path(path) if path.is_ident("defer") => {
+                is_deferred = true;
+            }
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(trigger),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("hydrate") => {
+                hydration_trigger =
+                    Some(component::hydration_trigger_to_tokens(trigger));
+            }
+            _ => abort!(
                 arg,
-                "only `transparent` or `lazy` are supported";
-                help = "try `#[island(transparent)]`, `#[island(lazy)]`, or `#[island]`"
-            );
+                "only `transparent`, `lazy`, `lazy = \"...\"`, `defer`, or \
+                 `hydrate = \"...\"` are supported";
+                help = "try `#[island(transparent)]`, `#[island(lazy)]`, \
+                        `#[island(lazy = \"group\")]`, `#[island(defer)]`, `#[island(hydrate = \"visible\")]`, \
+                        or `#[island]`"
+            ),
         }
-
-        (arg == "transparent", arg == "lazy")
-    } else {
-        (false, false)
-    };
+    }
 
     let island_src = s.to_string();
-    component_macro(s, is_transparent, is_lazy, Some(island_src))
+    component_macro(
+        s,
+        is_transparent,
+        is_lazy,
+        lazy_group,
+        is_deferred,
+        Some(island_src),
+        hydration_trigger,
+    )
 }
 
 fn component_macro(
     s: TokenStream,
     is_transparent: bool,
     is_lazy: bool,
+    lazy_group: Option<syn::LitStr>,
+    is_deferred: bool,
     island: Option<String>,
+    hydration_trigger: Option<proc_macro2::TokenStream>,
 ) -> TokenStream {
     let mut dummy = syn::parse::<DummyModel>(s.clone());
     let parse_result = syn::parse::<component::Model>(s);
@@ -686,7 +776,10 @@ fn component_macro(
         let expanded = model
             .is_transparent(is_transparent)
             .is_lazy(is_lazy)
+            .with_lazy_group(lazy_group)
+            .is_deferred(is_deferred)
             .with_island(island)
+            .with_hydration_trigger(hydration_trigger)
             .into_token_stream();
         if !matches!(unexpanded.vis, Visibility::Public(_)) {
             unexpanded.vis = Visibility::Public(Pub {
@@ -970,6 +1063,22 @@ pub fn params_derive(
     }
 }
 
+/// Derives `leptos::form::FormData`, which parses the fields of a submitted form into a struct,
+/// with a separate error for each field that is missing, could not be parsed, or is invalid.
+///
+/// Each field can be renamed with `#[form(rename = "...")]`, and validated once it has been
+/// parsed with `#[form(validate = path::to_fn)]`. See the docs of `FormData` for the types of
+/// fields that are supported.
+#[proc_macro_derive(FormData, attributes(form))]
+pub fn form_data_derive(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match syn::parse(input) {
+        Ok(ast) => form_data::form_data_impl(&ast).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// Generates a `slice` into a struct with a default getter and setter.
 ///
 /// Can be used to access deeply nested fields within a global state object.
@@ -1047,6 +1156,10 @@ pub fn memo(input: TokenStream) -> TokenStream {
 ///
 /// This should be used in tandem with a suitable build process, such as `cargo leptos --split`.
 ///
+/// By default, each `#[lazy]` function is split into its own WASM chunk. Functions that are
+/// usually needed together can be grouped into a single chunk with
+/// `#[lazy(group = "name")]`, so that they are loaded with a single request.
+///
 /// ```rust
 /// # use leptos_macro::lazy;
 ///
@@ -1061,6 +1174,11 @@ pub fn memo(input: TokenStream) -> TokenStream {
 ///     "Hello, lazy async world!".to_string()
 /// }
 ///
+/// #[lazy(group = "settings")]
+/// fn lazy_grouped_function() -> String {
+///     "Loaded with every other function in the `settings` group".to_string()
+/// }
+///
 /// async fn use_lazy_functions() {
 ///     // synchronous function has been converted to async
 ///     let value1 = lazy_synchronous_function().await;
@@ -0,0 +1,183 @@
+use rstml::node::{KVAttributeValue, KeyedAttribute};
+use syn::{Expr, ExprLit, Lit};
+
+/// The roles defined by WAI-ARIA 1.2.
+///
+/// This must contain the same roles as `tachys::html::attribute::aria::AriaRole`, which is
+/// checked by the tests below.
+const ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "mark",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Emits an error if a string literal passed to `role` contains a role that is not
+/// defined by WAI-ARIA, to catch typos like `role="buton"` at compile time.
+pub(crate) fn check_role(attr: &KeyedAttribute) {
+    if attr.key.to_string() != "role" {
+        return;
+    }
+    let Some(value) = attr.possible_value.to_value() else {
+        return;
+    };
+    let KVAttributeValue::Expr(Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    })) = &value.value
+    else {
+        return;
+    };
+    // roles are a space-separated list, where later roles are fallbacks
+    for role in lit.value().split_whitespace() {
+        if is_known_role(role) {
+            continue;
+        }
+        match closest_role(role) {
+            Some(suggestion) => proc_macro_error2::emit_error!(
+                lit.span(),
+                "unknown ARIA role `{}`", role;
+                help = "did you mean `{}`?", suggestion
+            ),
+            None => proc_macro_error2::emit_error!(
+                lit.span(),
+                "unknown ARIA role `{}`", role;
+                help = "see https://www.w3.org/TR/wai-aria-1.2/#role_definitions"
+            ),
+        }
+    }
+}
+
+fn is_known_role(role: &str) -> bool {
+    // DPUB-ARIA and Graphics ARIA roles are defined by separate modules
+    ROLES.contains(&role)
+        || role.starts_with("doc-")
+        || role.starts_with("graphics-")
+}
+
+fn closest_role(role: &str) -> Option<&'static str> {
+    ROLES
+        .iter()
+        .map(|known| (edit_distance(role, known), *known))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] =
+                (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggests_close_roles() {
+        assert!(is_known_role("button"));
+        assert!(is_known_role("doc-chapter"));
+        assert!(!is_known_role("buton"));
+        assert_eq!(closest_role("buton"), Some("button"));
+        assert_eq!(closest_role("navigaton"), Some("navigation"));
+        assert_eq!(closest_role("widgetish"), None);
+    }
+
+    #[test]
+    fn roles_match_aria_role() {
+        use leptos::tachys::html::attribute::aria::AriaRole;
+
+        let variants = AriaRole::ALL
+            .iter()
+            .map(AriaRole::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(ROLES, variants);
+    }
+}
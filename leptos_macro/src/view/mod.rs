@@ -1,3 +1,4 @@
+mod aria;
 mod component_builder;
 mod slot_helper;
 mod utils;
@@ -303,8 +304,36 @@ fn inert_element_to_tokens(
     escape_text: bool,
     global_class: Option<&TokenTree>,
 ) -> TokenStream {
+    let html = inert_html([node], escape_text, global_class);
+
+    quote! {
+        ::leptos::tachys::html::InertElement::new(#html)
+    }
+}
+
+/// Renders a run of sibling nodes that are all known to be static, so that they can be
+/// hydrated in a single step.
+fn inert_nodes_to_tokens(
+    nodes: &[Node<impl CustomNode>],
+    global_class: Option<&TokenTree>,
+) -> TokenStream {
+    let html = inert_html(nodes, true, global_class);
+
+    quote! {
+        ::leptos::tachys::html::InertNodes::new(#html)
+    }
+}
+
+fn inert_html<'a, T: CustomNode + 'a>(
+    nodes: impl IntoIterator<Item = &'a Node<T>>,
+    escape_text: bool,
+    global_class: Option<&'a TokenTree>,
+) -> InertElementBuilder<'a> {
     let mut html = InertElementBuilder::new(global_class);
-    let mut nodes = VecDeque::from([Item::Node(node, escape_text)]);
+    let mut nodes = nodes
+        .into_iter()
+        .map(|node| Item::Node(node, escape_text))
+        .collect::<VecDeque<_>>();
 
     while let Some(current) = nodes.pop_front() {
         match current {
@@ -347,6 +376,7 @@ fn inert_element_to_tokens(
 
                         for attr in node.attributes() {
                             if let NodeAttribute::Attribute(attr) = attr {
+                                aria::check_role(attr);
                                 let attr_name = attr.key.to_string();
                                 // trim r# from raw identifiers like r#as
                                 let attr_name =
@@ -397,10 +427,7 @@ fn inert_element_to_tokens(
     }
 
     html.finish();
-
-    quote! {
-        ::leptos::tachys::html::InertElement::new(#html)
-    }
+    html
 }
 
 /// # Note
@@ -461,6 +488,7 @@ fn inert_svg_element_to_tokens(
 
                         for attr in node.attributes() {
                             if let NodeAttribute::Attribute(attr) = attr {
+                                aria::check_role(attr);
                                 let attr_name = attr.key.to_string();
                                 // trim r# from raw identifiers like r#as
                                 let attr_name =
@@ -524,6 +552,7 @@ fn element_children_to_tokens(
     global_class: Option<&TokenTree>,
     view_marker: Option<&str>,
     disable_inert_html: bool,
+    group_inert_nodes: bool,
 ) -> Option<TokenStream> {
     let children = children_to_tokens(
         nodes,
@@ -531,7 +560,7 @@ fn element_children_to_tokens(
         parent_slots,
         global_class,
         view_marker,
-        false,
+        ChildrenOf::Element { group_inert_nodes },
         disable_inert_html,
     );
     if children.is_empty() {
@@ -590,7 +619,7 @@ fn fragment_to_tokens(
         parent_slots,
         global_class,
         view_marker,
-        true,
+        ChildrenOf::Fragment,
         disable_inert_html,
     );
     if children.is_empty() {
@@ -623,15 +652,31 @@ fn fragment_to_tokens(
     }
 }
 
+/// Where a list of children is rendered.
+#[derive(Clone, Copy)]
+enum ChildrenOf {
+    /// The top level of a view or fragment.
+    Fragment,
+    /// An element, whose consecutive static children may be rendered together.
+    Element { group_inert_nodes: bool },
+}
+
 fn children_to_tokens(
     nodes: &mut [Node<impl CustomNode>],
     parent_type: TagType,
     parent_slots: Option<&mut HashMap<String, Vec<TokenStream>>>,
     global_class: Option<&TokenTree>,
     view_marker: Option<&str>,
-    top_level: bool,
+    children_of: ChildrenOf,
     disable_inert_html: bool,
 ) -> Vec<TokenStream> {
+    let top_level = matches!(children_of, ChildrenOf::Fragment);
+    let group_inert_nodes = matches!(
+        children_of,
+        ChildrenOf::Element {
+            group_inert_nodes: true
+        }
+    );
     if nodes.len() == 1 {
         match node_to_tokens(
             &mut nodes[0],
@@ -647,20 +692,35 @@ fn children_to_tokens(
         }
     } else {
         let mut slots = HashMap::new();
-        let nodes = nodes
-            .iter_mut()
-            .filter_map(|node| {
-                node_to_tokens(
-                    node,
+        let mut tokens = Vec::new();
+        let mut idx = 0;
+        while idx < nodes.len() {
+            // consecutive static nodes are rendered together, so that hydration can skip
+            // over all of them at once
+            let run = if group_inert_nodes && !disable_inert_html {
+                inert_run_len(&nodes[idx..])
+            } else {
+                0
+            };
+            if run > 0 {
+                tokens.push(inert_nodes_to_tokens(
+                    &nodes[idx..idx + run],
+                    global_class,
+                ));
+                idx += run;
+            } else {
+                tokens.extend(node_to_tokens(
+                    &mut nodes[idx],
                     TagType::Unknown,
                     Some(&mut slots),
                     global_class,
                     view_marker,
                     top_level,
                     disable_inert_html,
-                )
-            })
-            .collect();
+                ));
+                idx += 1;
+            }
+        }
         if let Some(parent_slots) = parent_slots {
             for (slot, mut values) in slots.drain() {
                 parent_slots
@@ -669,7 +729,44 @@ fn children_to_tokens(
                     .or_insert(values);
             }
         }
-        nodes
+        tokens
+    }
+}
+
+/// Returns the length of the run of static nodes at the start of `nodes`, if it contains at
+/// least two nodes that will be rendered, or `0` otherwise.
+fn inert_run_len(nodes: &[Node<impl CustomNode>]) -> usize {
+    let mut len = 0;
+    let mut rendered = 0;
+    for node in nodes {
+        match node {
+            Node::Comment(_) => {}
+            Node::Text(_) | Node::RawText(_) => rendered += 1,
+            Node::Element(el) => {
+                let name = el.name().to_string();
+                let is_static = if is_svg_element(&name) && name != "svg" {
+                    false
+                } else if el.attributes().is_empty() && el.children.is_empty() {
+                    !is_component_node(el)
+                        && !is_spread_marker(el)
+                        && !matches!(el.name(), NodeName::Block(_))
+                        && !is_math_ml_element(&name)
+                } else {
+                    is_inert_element(node)
+                };
+                if !is_static {
+                    break;
+                }
+                rendered += 1;
+            }
+            _ => break,
+        }
+        len += 1;
+    }
+    if rendered >= 2 {
+        len
+    } else {
+        0
     }
 }
 
@@ -938,6 +1035,11 @@ pub(crate) fn element_to_tokens(
             quote_spanned! { node.name().span() => ::leptos::tachys::svg::#name() }
         } else if is_math_ml_element(&tag) {
             parent_type = TagType::Math;
+            let name = if tag == "annotation-xml" {
+                Ident::new("annotation_xml", name.span()).to_token_stream()
+            } else {
+                name.to_token_stream()
+            };
             quote_spanned! { node.name().span() => ::leptos::tachys::mathml::#name() }
         } else if is_ambiguous_element(&tag) {
             match parent_type {
@@ -956,7 +1058,7 @@ pub(crate) fn element_to_tokens(
                     quote_spanned! { node.name().span() => ::leptos::tachys::svg::#name() }
                 }
                 TagType::Math => {
-                    quote_spanned! { node.name().span() => ::leptos::tachys::math::#name() }
+                    quote_spanned! { node.name().span() => ::leptos::tachys::mathml::#name() }
                 }
             }
         } else {
@@ -991,6 +1093,10 @@ pub(crate) fn element_to_tokens(
         });
 
         let self_closing = is_self_closing(node);
+        // static children are grouped as HTML, so not inside elements whose text is raw
+        let group_inert_nodes = matches!(parent_type, TagType::Html)
+            && !is_custom
+            && !matches!(tag.as_str(), "script" | "style" | "textarea");
         let children = if !self_closing {
             element_children_to_tokens(
                 &mut node.children,
@@ -999,6 +1105,7 @@ pub(crate) fn element_to_tokens(
                 global_class,
                 view_marker,
                 disable_inert_html,
+                group_inert_nodes,
             )
         } else {
             if !node.children.is_empty() {
@@ -1082,6 +1189,7 @@ fn attribute_to_tokens(
                 }
             }),
         NodeAttribute::Attribute(node) => {
+            aria::check_role(node);
             let name = node.key.to_string();
             if name == "node_ref" {
                 let node_ref = match &node.key {
@@ -1316,6 +1424,10 @@ pub(crate) fn two_way_binding_to_tokens(
         quote! {
             .bind(leptos::tachys::reactive_graph::bind::#ident, #value)
         }
+    } else if name == "innerText" || name == "innerHTML" {
+        quote! {
+            .bind_content(::leptos::tachys::reactive_graph::bind::#ident, #value)
+        }
     } else {
         quote! {
             .bind(::leptos::attr::#ident, #value)
@@ -1481,7 +1593,8 @@ fn prop_to_tokens(
 }
 
 fn is_custom_element(tag: &str) -> bool {
-    tag.contains('-')
+    // annotation-xml is a MathML element, and is not a valid custom element name
+    tag.contains('-') && tag != "annotation-xml"
 }
 
 fn is_self_closing(node: &NodeElement<impl CustomNode>) -> bool {
@@ -1570,6 +1683,7 @@ fn is_math_ml_element(tag: &str) -> bool {
     // Keep list alphabetized for binary search
     [
         "annotation",
+        "annotation-xml",
         "maction",
         "math",
         "menclose",
@@ -0,0 +1,64 @@
+use leptos::form::{FormData, FormErrors, FormFields};
+
+#[derive(PartialEq, Debug, FormData)]
+struct Order {
+    name: String,
+    #[form(rename = "qty", validate = at_least_one)]
+    quantity: u32,
+    gift: bool,
+    note: Option<String>,
+    tags: Vec<String>,
+    address: Address,
+}
+
+#[derive(PartialEq, Debug, FormData)]
+struct Address {
+    city: String,
+    postcode: Option<u32>,
+}
+
+fn at_least_one(quantity: &u32) -> Result<(), &'static str> {
+    if *quantity > 0 {
+        Ok(())
+    } else {
+        Err("Order at least one.")
+    }
+}
+
+#[test]
+fn form_data_parses_nested_fields() {
+    let fields = FormFields::from_urlencoded(
+        "name=Leptos&qty=2&gift=on&tags=a&tags=b&address%5Bcity%5D=Paris",
+    );
+    assert_eq!(
+        Order::from_form(&fields).unwrap(),
+        Order {
+            name: "Leptos".into(),
+            quantity: 2,
+            gift: true,
+            note: None,
+            tags: vec!["a".into(), "b".into()],
+            address: Address {
+                city: "Paris".into(),
+                postcode: None,
+            },
+        }
+    );
+}
+
+#[test]
+fn form_data_collects_errors_for_each_field() {
+    let fields = FormFields::from_urlencoded("qty=0&address[postcode]=75O01");
+    assert_eq!(
+        Order::from_form(&fields).unwrap_err(),
+        FormErrors::from([
+            ("name".into(), "This field is required.".into()),
+            ("qty".into(), "Order at least one.".into()),
+            ("address[city]".into(), "This field is required.".into()),
+            (
+                "address[postcode]".into(),
+                "invalid digit found in string".into()
+            ),
+        ])
+    );
+}
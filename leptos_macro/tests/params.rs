@@ -26,3 +26,74 @@ fn params_test() {
         user_info
     );
 }
+
+#[derive(PartialEq, Debug)]
+struct Slug(String);
+
+#[derive(Debug)]
+struct InvalidSlug;
+
+impl std::fmt::Display for InvalidSlug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid slug")
+    }
+}
+
+impl std::error::Error for InvalidSlug {}
+
+impl leptos_router::params::FromParam for Slug {
+    type Error = InvalidSlug;
+
+    fn from_param(value: &str) -> Result<Self, Self::Error> {
+        if value.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+            Ok(Slug(value.to_string()))
+        } else {
+            Err(InvalidSlug)
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Params)]
+struct FileInfo {
+    slug: Slug,
+    page: Option<u32>,
+    rest: Vec<String>,
+}
+
+#[test]
+fn params_splat_and_custom_from_param() {
+    let mut map = leptos_router::params::ParamsMap::new();
+    map.insert("slug", "hello-world".to_owned());
+    map.insert("rest", "a/b/c".to_owned());
+    let info = FileInfo::from_map(&map).unwrap();
+    assert_eq!(
+        FileInfo {
+            slug: Slug("hello-world".to_owned()),
+            page: None,
+            rest: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+        },
+        info
+    );
+}
+
+#[test]
+fn params_conversion_error_names_param() {
+    use leptos_router::params::ParamsError;
+
+    let mut map = leptos_router::params::ParamsMap::new();
+    map.insert("slug", "hello-world".to_owned());
+    map.insert("page", "two".to_owned());
+    let err = FileInfo::from_map(&map).unwrap_err();
+    assert!(matches!(
+        &err,
+        ParamsError::InvalidParam { name, value, .. }
+            if name == "page" && value == "two"
+    ));
+
+    let mut map = leptos_router::params::ParamsMap::new();
+    map.insert("slug", "Not A Slug".to_owned());
+    assert!(matches!(
+        FileInfo::from_map(&map).unwrap_err(),
+        ParamsError::InvalidParam { name, .. } if name == "slug"
+    ));
+}
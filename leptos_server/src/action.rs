@@ -1,5 +1,5 @@
 use reactive_graph::{
-    actions::{Action, ArcAction},
+    actions::{Action, ArcAction, DispatchPolicy},
     owner::use_context,
     traits::DefinedAt,
 };
@@ -73,6 +73,13 @@ where
             defined_at: Location::caller(),
         }
     }
+
+    /// Sets the [`DispatchPolicy`] that limits how often the server function is called when
+    /// the action is dispatched many times in quick succession.
+    pub fn with_dispatch_policy(mut self, policy: DispatchPolicy) -> Self {
+        self.inner = self.inner.with_dispatch_policy(policy);
+        self
+    }
 }
 
 impl<S> Deref for ArcServerAction<S>
@@ -161,6 +168,21 @@ where
             defined_at: Location::caller(),
         }
     }
+
+    /// Sets the [`DispatchPolicy`] that limits how often the server function is called when
+    /// the action is dispatched many times in quick succession.
+    ///
+    /// ```rust,ignore
+    /// use leptos::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let autosave = ServerAction::<SaveDraft>::new()
+    ///     .with_dispatch_policy(DispatchPolicy::debounce(Duration::from_millis(500)));
+    /// ```
+    pub fn with_dispatch_policy(self, policy: DispatchPolicy) -> Self {
+        self.inner.with_dispatch_policy(policy);
+        self
+    }
 }
 
 impl<S> Clone for ServerAction<S>
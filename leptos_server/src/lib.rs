@@ -12,6 +12,8 @@ mod multi_action;
 pub use multi_action::*;
 mod once_resource;
 pub use once_resource::*;
+mod optimistic;
+pub use optimistic::*;
 mod resource;
 pub use resource::*;
 mod shared;
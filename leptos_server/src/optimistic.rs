@@ -0,0 +1,520 @@
+use crate::ServerMultiAction;
+use reactive_graph::{
+    actions::ArcSubmission,
+    owner::StoredValue,
+    signal::ArcReadSignal,
+    traits::{Get, GetUntracked, UpdateValue, With, WithUntracked},
+};
+use server_fn::ServerFn;
+use std::fmt::{self, Debug};
+
+/// Merges the submissions of a [`ServerMultiAction`] into a list of items that has been loaded
+/// from the server, so that new items can be shown as soon as they are submitted.
+///
+/// Every submission that is still waiting for the server is listed as
+/// [`Pending`](OptimisticItem::Pending), after the loaded items. A submission that fails is
+/// listed as [`Failed`](OptimisticItem::Failed), with its error, until it is retried or
+/// dismissed.
+///
+/// A submission that succeeds stays pending until the loaded items have been reloaded, and are
+/// expected to include it. For this, the resource that loads them should track the action's
+/// [`version`](reactive_graph::actions::MultiAction::version), so that it refetches each time
+/// a submission succeeds, and return the version it was loaded for along with the items.
+///
+/// ```rust,ignore
+/// let add_todo = ServerMultiAction::<AddTodo>::new();
+/// let todos = Resource::new(
+///     move || add_todo.version().get(),
+///     |version| async move { get_todos().await.map(|todos| (version, todos)) },
+/// );
+/// let list = OptimisticList::new(add_todo);
+///
+/// view! {
+///     <MultiActionForm action=add_todo>
+///         <input type="text" name="title"/>
+///     </MultiActionForm>
+///     <Transition>
+///         <ul>
+///             {move || Suspend::new(async move {
+///                 let (version, todos) = todos.await.unwrap_or_default();
+///                 list.items(version, todos)
+///                     .into_iter()
+///                     .map(|item| match item {
+///                         OptimisticItem::Loaded(todo) => view! { <li>{todo.title}</li> }.into_any(),
+///                         OptimisticItem::Pending(input) => {
+///                             view! { <li class="pending">{input.title}</li> }.into_any()
+///                         }
+///                         OptimisticItem::Failed(failed) => view! {
+///                             <li class="failed">
+///                                 {failed.input().map(|input| input.title.clone())}
+///                                 <button on:click=move |_| failed.retry()>"Retry"</button>
+///                             </li>
+///                         }
+///                         .into_any(),
+///                     })
+///                     .collect_view()
+///             })}
+///         </ul>
+///     </Transition>
+/// }
+/// ```
+pub struct OptimisticList<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    action: ServerMultiAction<S>,
+    submissions: StoredValue<Tracked<S>>,
+}
+
+impl<S> Clone for OptimisticList<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for OptimisticList<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+}
+
+impl<S> Debug for OptimisticList<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptimisticList").finish_non_exhaustive()
+    }
+}
+
+impl<S> OptimisticList<S>
+where
+    S: ServerFn + Clone + Send + Sync + 'static,
+    S::Output: Send + Sync + 'static,
+    S::Error: Clone + Send + Sync + 'static,
+{
+    /// Creates a list that merges the submissions of the action.
+    ///
+    /// Submissions can be dispatched with [`dispatch`](Self::dispatch), or by the action itself
+    /// (for example, from a `<MultiActionForm/>`). In that case, the input of a submission is
+    /// remembered once it has been listed as pending, so that it can be retried if it fails.
+    #[track_caller]
+    pub fn new(action: ServerMultiAction<S>) -> Self {
+        Self {
+            action,
+            submissions: StoredValue::new(Tracked::default()),
+        }
+    }
+
+    /// The action whose submissions are listed.
+    pub fn action(&self) -> ServerMultiAction<S> {
+        self.action
+    }
+
+    /// Submits a new item.
+    pub fn dispatch(&self, input: S) {
+        let submissions = self.action.submissions();
+        let count = submissions.with_untracked(Vec::len);
+        self.action.dispatch(input.clone());
+        // the submission is only added if the action actually runs
+        if let Some(submission) = submissions
+            .with_untracked(|submissions| submissions.get(count).cloned())
+        {
+            self.submissions
+                .update_value(|tracked| tracked.remember(&submission, input));
+        }
+    }
+
+    /// Lists the loaded items, followed by the submissions that are pending or have failed.
+    ///
+    /// `version` is the [`version`](reactive_graph::actions::MultiAction::version) of the
+    /// action for which the items were loaded. Submissions that succeeded after that version are
+    /// listed as pending, because the loaded items do not include them yet.
+    ///
+    /// This tracks the action's submissions, so it should be called in a reactive context, such
+    /// as the view that renders the list.
+    pub fn items<T>(
+        &self,
+        version: usize,
+        loaded: impl IntoIterator<Item = T>,
+    ) -> Vec<OptimisticItem<T, S>> {
+        let current = self.action.version().get_untracked();
+        let submitted = self.action.submissions().with(|submissions| {
+            self.submissions
+                .try_update_value(|tracked| {
+                    tracked.reconcile(submissions, current, version)
+                })
+                .unwrap_or_default()
+        });
+
+        loaded
+            .into_iter()
+            .map(OptimisticItem::Loaded)
+            .chain(submitted.into_iter().map(|submitted| match submitted {
+                Submitted::Pending(input) => OptimisticItem::Pending(input),
+                Submitted::Failed {
+                    input,
+                    error,
+                    submission,
+                } => OptimisticItem::Failed(FailedItem {
+                    input,
+                    error,
+                    submission,
+                    list: *self,
+                }),
+            }))
+            .collect()
+    }
+}
+
+type SubmissionOf<S> =
+    ArcSubmission<S, Result<<S as ServerFn>::Output, <S as ServerFn>::Error>>;
+
+// the submissions of the action that have been seen, by the identity of their `canceled` signal:
+// the submissions themselves only keep their inputs until they resolve, but succeeded items need
+// them until they have been reloaded, and failed items need them to retry
+struct Tracked<I> {
+    seen: Vec<(ArcReadSignal<bool>, Seen<I>)>,
+}
+
+impl<I> Default for Tracked<I> {
+    fn default() -> Self {
+        Self { seen: Vec::new() }
+    }
+}
+
+struct Seen<I> {
+    input: I,
+    // the version of the action once the submission had succeeded
+    succeeded_at: Option<usize>,
+}
+
+enum Submitted<I, O, E> {
+    Pending(I),
+    Failed {
+        input: Option<I>,
+        error: E,
+        submission: ArcSubmission<I, Result<O, E>>,
+    },
+}
+
+impl<I> Tracked<I>
+where
+    I: Clone + Send + Sync + 'static,
+{
+    fn remember<O>(&mut self, submission: &ArcSubmission<I, O>, input: I)
+    where
+        O: 'static,
+    {
+        let canceled = submission.canceled();
+        if !self.seen.iter().any(|(seen, _)| *seen == canceled) {
+            self.seen.push((
+                canceled,
+                Seen {
+                    input,
+                    succeeded_at: None,
+                },
+            ));
+        }
+    }
+
+    fn reconcile<O, E>(
+        &mut self,
+        submissions: &[ArcSubmission<I, Result<O, E>>],
+        current: usize,
+        loaded: usize,
+    ) -> Vec<Submitted<I, O, E>>
+    where
+        O: Send + Sync + 'static,
+        E: Clone + Send + Sync + 'static,
+    {
+        let mut pending = Vec::new();
+        let mut failed = Vec::new();
+
+        for submission in submissions {
+            if submission.canceled().get() {
+                continue;
+            }
+            if submission.pending().get() {
+                if let Some(input) = submission.input().get() {
+                    self.remember(submission, input.clone());
+                    pending.push(Submitted::Pending(input));
+                }
+                continue;
+            }
+            let canceled = submission.canceled();
+            let seen = self
+                .seen
+                .iter_mut()
+                .find(|(seen, _)| *seen == canceled)
+                .map(|(_, seen)| seen);
+            submission.value().with(|value| match value {
+                Some(Ok(_)) => {
+                    if let Some(seen) = seen {
+                        let succeeded_at =
+                            *seen.succeeded_at.get_or_insert(current);
+                        if loaded < succeeded_at {
+                            pending
+                                .push(Submitted::Pending(seen.input.clone()));
+                        }
+                    }
+                }
+                Some(Err(error)) => failed.push(Submitted::Failed {
+                    input: seen.map(|seen| seen.input.clone()),
+                    error: error.clone(),
+                    submission: submission.clone(),
+                }),
+                None => {}
+            });
+        }
+
+        // forget the submissions that are no longer in the list
+        self.seen.retain(|(canceled, _)| {
+            submissions
+                .iter()
+                .any(|submission| submission.canceled() == *canceled)
+        });
+
+        pending.extend(failed);
+        pending
+    }
+}
+
+/// An item in an [`OptimisticList`].
+pub enum OptimisticItem<T, S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    /// An item that has been loaded from the server.
+    Loaded(T),
+    /// An item that has been submitted, and is waiting for the server to respond or for the
+    /// loaded items to be reloaded.
+    Pending(S),
+    /// An item whose submission failed.
+    Failed(FailedItem<S>),
+}
+
+impl<T, S> Clone for OptimisticItem<T, S>
+where
+    T: Clone,
+    S: ServerFn + Clone + 'static,
+    S::Output: 'static,
+    S::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Loaded(item) => Self::Loaded(item.clone()),
+            Self::Pending(input) => Self::Pending(input.clone()),
+            Self::Failed(failed) => Self::Failed(failed.clone()),
+        }
+    }
+}
+
+impl<T, S> Debug for OptimisticItem<T, S>
+where
+    T: Debug,
+    S: ServerFn + Debug + 'static,
+    S::Output: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Loaded(item) => f.debug_tuple("Loaded").field(item).finish(),
+            Self::Pending(input) => {
+                f.debug_tuple("Pending").field(input).finish()
+            }
+            Self::Failed(failed) => {
+                f.debug_tuple("Failed").field(failed).finish()
+            }
+        }
+    }
+}
+
+/// A submission to an [`OptimisticList`] that failed, which can be retried or dismissed.
+pub struct FailedItem<S>
+where
+    S: ServerFn + 'static,
+    S::Output: 'static,
+{
+    input: Option<S>,
+    error: S::Error,
+    submission: SubmissionOf<S>,
+    list: OptimisticList<S>,
+}
+
+impl<S> Clone for FailedItem<S>
+where
+    S: ServerFn + Clone + 'static,
+    S::Output: 'static,
+    S::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+            error: self.error.clone(),
+            submission: self.submission.clone(),
+            list: self.list,
+        }
+    }
+}
+
+impl<S> Debug for FailedItem<S>
+where
+    S: ServerFn + Debug + 'static,
+    S::Output: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FailedItem")
+            .field("input", &self.input)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> FailedItem<S>
+where
+    S: ServerFn + Clone + Send + Sync + 'static,
+    S::Output: Send + Sync + 'static,
+    S::Error: Clone + Send + Sync + 'static,
+{
+    /// The input that was submitted, if it is known.
+    pub fn input(&self) -> Option<&S> {
+        self.input.as_ref()
+    }
+
+    /// The error with which the submission failed.
+    pub fn error(&self) -> &S::Error {
+        &self.error
+    }
+
+    /// Removes the item from the list.
+    pub fn dismiss(&self) {
+        self.submission.cancel();
+    }
+
+    /// Removes the item from the list, and submits its input again. Does nothing if the input
+    /// is not known.
+    pub fn retry(&self) {
+        if let Some(input) = self.input.clone() {
+            self.dismiss();
+            self.list.dispatch(input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Submitted, Tracked};
+    use any_spawner::Executor;
+    use reactive_graph::{
+        actions::ArcMultiAction,
+        owner::Owner,
+        traits::{GetUntracked, WithUntracked},
+    };
+
+    type Action = ArcMultiAction<String, Result<(), String>>;
+
+    fn action() -> Action {
+        ArcMultiAction::new(|title: &String| {
+            let failed = title == "fail";
+            async move {
+                if failed {
+                    Err("failed".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    fn listed(
+        tracked: &mut Tracked<String>,
+        action: &Action,
+        loaded: usize,
+    ) -> Vec<String> {
+        let current = action.version().get_untracked();
+        action
+            .submissions()
+            .with_untracked(|submissions| {
+                tracked.reconcile(submissions, current, loaded)
+            })
+            .into_iter()
+            .map(|submitted| match submitted {
+                Submitted::Pending(input) => format!("pending {input}"),
+                Submitted::Failed { input, error, .. } => {
+                    format!("failed {} ({error})", input.unwrap_or_default())
+                }
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn succeeded_items_are_kept_until_reloaded() {
+        _ = Executor::init_tokio();
+        let owner = Owner::new();
+        owner.set();
+
+        let action = action();
+        let mut tracked = Tracked::default();
+        action.dispatch("a".to_string());
+        assert_eq!(listed(&mut tracked, &action, 0), ["pending a"]);
+
+        Executor::tick().await;
+        assert_eq!(action.version().get_untracked(), 1);
+        // the items loaded before the submission succeeded do not include it
+        assert_eq!(listed(&mut tracked, &action, 0), ["pending a"]);
+        // the items reloaded after it succeeded do
+        assert!(listed(&mut tracked, &action, 1).is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_items_keep_their_input() {
+        _ = Executor::init_tokio();
+        let owner = Owner::new();
+        owner.set();
+
+        let action = action();
+        let mut tracked = Tracked::default();
+        action.dispatch("fail".to_string());
+        action.dispatch("b".to_string());
+        assert_eq!(
+            listed(&mut tracked, &action, 0),
+            ["pending fail", "pending b"]
+        );
+
+        Executor::tick().await;
+        assert_eq!(
+            listed(&mut tracked, &action, 0),
+            ["pending b", "failed fail (failed)"]
+        );
+        assert_eq!(listed(&mut tracked, &action, 2), ["failed fail (failed)"]);
+    }
+
+    #[tokio::test]
+    async fn dismissed_items_are_not_listed() {
+        _ = Executor::init_tokio();
+        let owner = Owner::new();
+        owner.set();
+
+        let action = action();
+        let mut tracked = Tracked::default();
+        action.dispatch("fail".to_string());
+        Executor::tick().await;
+
+        let submission = action
+            .submissions()
+            .with_untracked(|submissions| submissions[0].clone());
+        tracked.remember(&submission, "fail".to_string());
+        assert_eq!(listed(&mut tracked, &action, 1), ["failed fail (failed)"]);
+
+        submission.cancel();
+        assert!(listed(&mut tracked, &action, 1).is_empty());
+    }
+}
@@ -310,16 +310,24 @@ where
         });
         let fun = {
             let source = source.clone();
+            #[cfg(feature = "tracing")]
+            let id = id.clone();
             move || {
                 let (_, source) = source.get();
                 let fut = fetcher(source);
-                async move {
+                let fut = async move {
                     if IS_SUPPRESSING_RESOURCE_LOAD.load(Ordering::Relaxed) {
                         pending().await
                     } else {
                         fut.await
                     }
-                }
+                };
+                #[cfg(feature = "tracing")]
+                let fut = tracing::Instrument::instrument(
+                    fut,
+                    tracing::debug_span!("resource", id = ?id),
+                );
+                fut
             }
         };
 
@@ -1,4 +1,7 @@
-use crate::ServerMetaContext;
+use crate::{
+    root_attrs::{ClassOwners, MergedClass, ScopedAttributes},
+    use_head, ServerMetaContext,
+};
 use leptos::{
     attr::{any_attribute::AnyAttribute, NextAttribute},
     component, html,
@@ -12,14 +15,21 @@ use leptos::{
             RenderHtml,
         },
     },
+    text_prop::TextProp,
     IntoView,
 };
 
 /// A component to set metadata on the document’s `<body>` element from
 /// within the application.
 ///
-/// This component takes no props, but can take any number of spread attributes
-/// following the `{..}` operator.
+/// This component can take any number of spread attributes following the `{..}` operator.
+/// When the component is unmounted, the attributes it set go back to the values they had before
+/// it was mounted.
+///
+/// Classes should be set with the `class` prop, rather than as a spread attribute. Class names
+/// set by different `<Body/>` components are merged, rather than replacing one another, and each
+/// one is removed once no mounted component sets it, so that (for example) a theme provider and
+/// a modal can both add classes to the `<body>`.
 ///
 /// ```
 /// use leptos::prelude::*;
@@ -45,11 +55,21 @@ use leptos::{
 /// }
 /// ```
 #[component]
-pub fn Body() -> impl IntoView {
-    BodyView { attributes: () }
+pub fn Body(
+    /// Class names to add to the `<body>`, which are merged with those set by other components.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+) -> impl IntoView {
+    BodyView {
+        class,
+        classes: use_head().body_classes,
+        attributes: (),
+    }
 }
 
 struct BodyView<At> {
+    class: Option<TextProp>,
+    classes: ClassOwners,
     attributes: At,
 }
 
@@ -57,7 +77,11 @@ struct BodyViewState<At>
 where
     At: Attribute,
 {
+    // dropped in order, so that attributes are restored once their effects have stopped
     attributes: At::State,
+    class: Option<MergedClass>,
+    classes: ClassOwners,
+    _scoped: ScopedAttributes,
 }
 
 impl<At> Render for BodyView<At>
@@ -68,13 +92,27 @@ where
 
     fn build(self) -> Self::State {
         let el = document().body().expect("there to be a <body> element");
+        let scoped = ScopedAttributes::snapshot(&el, self.attributes.keys());
         let attributes = self.attributes.build(&el);
-
-        BodyViewState { attributes }
+        let class = self
+            .class
+            .map(|class| MergedClass::new(&el, self.classes.clone(), class));
+
+        BodyViewState {
+            attributes,
+            class,
+            classes: self.classes,
+            _scoped: scoped,
+        }
     }
 
     fn rebuild(self, state: &mut Self::State) {
         self.attributes.rebuild(&mut state.attributes);
+        // the new class names are added before the old ones are removed
+        state.class = self.class.map(|class| {
+            let el = document().body().expect("there to be a <body> element");
+            MergedClass::new(&el, state.classes.clone(), class)
+        });
     }
 }
 
@@ -93,6 +131,8 @@ where
         Self::Output<NewAttr>: RenderHtml,
     {
         BodyView {
+            class: self.class,
+            classes: self.classes,
             attributes: self.attributes.add_any_attr(attr),
         }
     }
@@ -113,6 +153,8 @@ where
 
     async fn resolve(self) -> Self::AsyncOutput {
         BodyView {
+            class: self.class,
+            classes: self.classes,
             attributes: self.attributes.resolve().await,
         }
     }
@@ -134,6 +176,9 @@ where
             if !buf.is_empty() {
                 _ = meta.body.send(buf);
             }
+            if let Some(class) = self.class {
+                _ = meta.body_class.send(class.get().into_owned());
+            }
         }
     }
 
@@ -143,13 +188,25 @@ where
         _position: &PositionState,
     ) -> Self::State {
         let el = document().body().expect("there to be a <body> element");
+        // the server-rendered values already include these attributes
+        let scoped = ScopedAttributes::unknown(&el, self.attributes.keys());
         let attributes = self.attributes.hydrate::<FROM_SERVER>(&el);
-
-        BodyViewState { attributes }
+        let class = self
+            .class
+            .map(|class| MergedClass::new(&el, self.classes.clone(), class));
+
+        BodyViewState {
+            attributes,
+            class,
+            classes: self.classes,
+            _scoped: scoped,
+        }
     }
 
     fn into_owned(self) -> Self::Owned {
         BodyView {
+            class: self.class,
+            classes: self.classes,
             attributes: self.attributes.into_cloneable_owned(),
         }
     }
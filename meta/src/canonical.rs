@@ -0,0 +1,110 @@
+use crate::register;
+use leptos::{
+    component, tachys::html::element::link, text_prop::TextProp, IntoView,
+};
+use std::sync::Arc;
+
+/// Injects a `<link rel="canonical">` into the document head, which tells search engines the
+/// preferred URL of the current page.
+///
+/// Like other `leptos_meta` components, the link is removed when the component is unmounted, so
+/// each page of an app can set its own canonical URL.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Post(slug: String) -> impl IntoView {
+///     view! {
+///       <Canonical href=format!("https://example.com/posts/{slug}")/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Canonical(
+    /// The canonical URL of the page.
+    #[prop(into)]
+    href: TextProp,
+) -> impl IntoView {
+    register(link().rel("canonical").href(move || href.get()))
+}
+
+/// A function that returns the URL of a page in a given locale, given the locale and the path of
+/// the page.
+#[derive(Clone)]
+pub struct LocaleHref(Arc<LocaleHrefFn>);
+
+type LocaleHrefFn = dyn Fn(&str, &str) -> String + Send + Sync;
+
+impl<F> From<F> for LocaleHref
+where
+    F: Fn(&str, &str) -> String + Send + Sync + 'static,
+{
+    #[inline(always)]
+    fn from(f: F) -> LocaleHref {
+        LocaleHref(Arc::new(f))
+    }
+}
+
+/// Injects a `<link rel="alternate" hreflang="...">` into the document head for each locale in
+/// which the current page is available, so that search engines can link to the version in the
+/// user's language.
+///
+/// The URL for each locale is built from the current path by the `href` function. Because `path`
+/// can be reactive, the links are kept up to date as the user navigates, and they are removed
+/// when the component is unmounted.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_meta_context();
+///     // usually the path of the current route, like `use_location().pathname`
+///     let path = RwSignal::new("/about".to_string());
+///
+///     view! {
+///       <AlternateLinks
+///         path=move || path.get()
+///         locales=&["en", "de", "fr"]
+///         href=|locale: &str, path: &str| format!("https://example.com/{locale}{path}")
+///         default_locale="en"
+///       />
+///     }
+/// }
+/// ```
+#[component]
+pub fn AlternateLinks(
+    /// The path of the current page, without any locale prefix.
+    #[prop(into)]
+    path: TextProp,
+    /// The locales in which the page is available, like `"en"` or `"pt-BR"`.
+    locales: &'static [&'static str],
+    /// Returns the URL of the page in a given locale, given the locale and the `path`.
+    #[prop(into)]
+    href: LocaleHref,
+    /// If set, also adds a `hreflang="x-default"` link to the page in this locale, for users
+    /// whose language does not match any of the `locales`.
+    #[prop(optional)]
+    default_locale: Option<&'static str>,
+) -> impl IntoView {
+    let alternate = move |hreflang: &'static str, locale: &'static str| {
+        let path = path.clone();
+        let href = href.clone();
+        register(
+            link()
+                .rel("alternate")
+                .hreflang(hreflang)
+                .href(move || (href.0)(locale, &path.get())),
+        )
+    };
+
+    let links = locales
+        .iter()
+        .map(|locale| alternate(locale, locale))
+        .collect::<Vec<_>>();
+    let default = default_locale.map(|locale| alternate("x-default", locale));
+    (links, default)
+}
@@ -0,0 +1,113 @@
+use crate::{register, OrDefaultNonce};
+use leptos::{
+    component, oco::Oco, tachys::html::element::meta, text_prop::TextProp,
+    IntoView,
+};
+
+/// The `http-equiv` value of a Content Security Policy `<meta>` tag.
+const CSP_HTTP_EQUIV: &str = "Content-Security-Policy";
+
+/// Injects a `<meta http-equiv="Content-Security-Policy">` into the document head.
+///
+/// If `add_nonce` is set, the `nonce` feature is enabled and a [`Nonce`](leptos::nonce::Nonce)
+/// has been provided for the current response, `'nonce-…'` is added to the `script-src` and
+/// `style-src` directives of the policy (or to `default-src`, if neither is set). This is the
+/// same nonce that is used for the inline scripts Leptos adds while streaming, and by
+/// [`Script`](crate::Script) and [`Style`](crate::Style), so the policy and the page always match.
+///
+/// Browsers ignore `'unsafe-inline'` in a directive that also allows a nonce, so any inline
+/// scripts or styles without the nonce, including `style` attributes, will be blocked. The nonce
+/// is only known on the server, and browsers only apply the policy when the page is first
+/// loaded, so changes to `policy` after that have no effect.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///
+///     view! {
+///       <ContentSecurityPolicy
+///         policy="default-src 'self'; script-src 'strict-dynamic' 'wasm-unsafe-eval'; style-src 'self'"
+///         add_nonce=true
+///       />
+///       <Style>"body { color: blue; }"</Style>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ContentSecurityPolicy(
+    /// The policy, as a list of directives separated by `;`.
+    #[prop(into)]
+    policy: TextProp,
+    /// Whether to add the current nonce to the policy.
+    #[prop(optional)]
+    add_nonce: bool,
+) -> impl IntoView {
+    let nonce = add_nonce
+        .then(|| None::<Oco<'static, str>>.or_default_nonce())
+        .flatten();
+    register(
+        meta()
+            .http_equiv(CSP_HTTP_EQUIV)
+            .content(move || with_nonce(policy.get(), nonce.as_deref())),
+    )
+}
+
+/// Adds the nonce as an allowed source to the script and style directives of a policy.
+fn with_nonce(
+    policy: Oco<'static, str>,
+    nonce: Option<&str>,
+) -> Oco<'static, str> {
+    let Some(nonce) = nonce else {
+        return policy;
+    };
+    let source = format!("'nonce-{nonce}'");
+
+    let mut directives = policy
+        .split(';')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let name_of = |directive: &str| {
+        directive
+            .split_ascii_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    let mut found = false;
+    for directive in &mut directives {
+        if matches!(
+            name_of(directive).as_str(),
+            "script-src" | "script-src-elem" | "style-src" | "style-src-elem"
+        ) {
+            found = true;
+            add_source(directive, &source);
+        }
+    }
+    // without script or style directives, these fall back to default-src
+    if !found {
+        if let Some(directive) = directives
+            .iter_mut()
+            .find(|directive| name_of(directive) == "default-src")
+        {
+            add_source(directive, &source);
+        }
+    }
+    directives.join("; ").into()
+}
+
+fn add_source(directive: &mut String, source: &str) {
+    // 'none' cannot be combined with other sources
+    if directive.split_ascii_whitespace().any(|s| s == "'none'") {
+        return;
+    }
+    if !directive.split_ascii_whitespace().any(|s| s == source) {
+        directive.push(' ');
+        directive.push_str(source);
+    }
+}
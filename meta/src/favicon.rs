@@ -0,0 +1,46 @@
+use crate::register;
+use leptos::{
+    component, tachys::html::element::link, text_prop::TextProp, IntoView,
+};
+
+/// Injects a `<link rel="icon">` into the document head, setting the icon shown in the browser
+/// tab.
+///
+/// The `href` can be reactive, in which case the icon is updated when it changes. This can be
+/// used to show a badge with the number of unread messages, for example.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Inbox() -> impl IntoView {
+///     let unread = RwSignal::new(0);
+///
+///     view! {
+///       <Favicon href=move || {
+///           if unread.get() > 0 { "/favicon-unread.svg" } else { "/favicon.svg" }
+///       } type_="image/svg+xml"/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Favicon(
+    /// The URL of the icon.
+    #[prop(into)]
+    href: TextProp,
+    /// The MIME type of the icon, like `"image/png"` or `"image/svg+xml"`.
+    #[prop(optional, into)]
+    type_: Option<TextProp>,
+    /// The sizes of the icon, like `"32x32"`, or `"any"` for a vector icon.
+    #[prop(optional, into)]
+    sizes: Option<TextProp>,
+) -> impl IntoView {
+    register(
+        link()
+            .rel("icon")
+            .href(move || href.get())
+            .r#type(type_.map(|v| move || v.get()))
+            .sizes(sizes.map(|v| move || v.get())),
+    )
+}
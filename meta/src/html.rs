@@ -1,4 +1,7 @@
-use crate::ServerMetaContext;
+use crate::{
+    root_attrs::{ClassOwners, MergedClass, ScopedAttributes},
+    use_head, ServerMetaContext,
+};
 use leptos::{
     attr::{any_attribute::AnyAttribute, NextAttribute},
     component, html,
@@ -12,14 +15,21 @@ use leptos::{
             RenderHtml,
         },
     },
+    text_prop::TextProp,
     IntoView,
 };
 
 /// A component to set metadata on the document’s `<html>` element from
 /// within the application.
 ///
-/// This component takes no props, but can take any number of spread attributes
-/// following the `{..}` operator.
+/// This component can take any number of spread attributes following the `{..}` operator.
+/// When the component is unmounted, the attributes it set go back to the values they had before
+/// it was mounted.
+///
+/// Classes should be set with the `class` prop, rather than as a spread attribute. Class names
+/// set by different `<Html/>` components are merged, rather than replacing one another, and each
+/// one is removed once no mounted component sets it, so that (for example) a theme provider and
+/// a modal can both add classes to the `<html>`.
 ///
 /// ```
 /// use leptos::prelude::*;
@@ -42,11 +52,21 @@ use leptos::{
 /// }
 /// ```
 #[component]
-pub fn Html() -> impl IntoView {
-    HtmlView { attributes: () }
+pub fn Html(
+    /// Class names to add to the `<html>`, which are merged with those set by other components.
+    #[prop(optional, into)]
+    class: Option<TextProp>,
+) -> impl IntoView {
+    HtmlView {
+        class,
+        classes: use_head().html_classes,
+        attributes: (),
+    }
 }
 
 struct HtmlView<At> {
+    class: Option<TextProp>,
+    classes: ClassOwners,
     attributes: At,
 }
 
@@ -54,7 +74,11 @@ struct HtmlViewState<At>
 where
     At: Attribute,
 {
+    // dropped in order, so that attributes are restored once their effects have stopped
     attributes: At::State,
+    class: Option<MergedClass>,
+    classes: ClassOwners,
+    _scoped: ScopedAttributes,
 }
 
 impl<At> Render for HtmlView<At>
@@ -67,14 +91,29 @@ where
         let el = document()
             .document_element()
             .expect("there to be a <html> element");
-
+        let scoped = ScopedAttributes::snapshot(&el, self.attributes.keys());
         let attributes = self.attributes.build(&el);
-
-        HtmlViewState { attributes }
+        let class = self
+            .class
+            .map(|class| MergedClass::new(&el, self.classes.clone(), class));
+
+        HtmlViewState {
+            attributes,
+            class,
+            classes: self.classes,
+            _scoped: scoped,
+        }
     }
 
     fn rebuild(self, state: &mut Self::State) {
         self.attributes.rebuild(&mut state.attributes);
+        // the new class names are added before the old ones are removed
+        state.class = self.class.map(|class| {
+            let el = document()
+                .document_element()
+                .expect("there to be a <html> element");
+            MergedClass::new(&el, state.classes.clone(), class)
+        });
     }
 }
 
@@ -93,6 +132,8 @@ where
         Self::Output<NewAttr>: RenderHtml,
     {
         HtmlView {
+            class: self.class,
+            classes: self.classes,
             attributes: self.attributes.add_any_attr(attr),
         }
     }
@@ -113,6 +154,8 @@ where
 
     async fn resolve(self) -> Self::AsyncOutput {
         HtmlView {
+            class: self.class,
+            classes: self.classes,
             attributes: self.attributes.resolve().await,
         }
     }
@@ -134,6 +177,9 @@ where
             if !buf.is_empty() {
                 _ = meta.html.send(buf);
             }
+            if let Some(class) = self.class {
+                _ = meta.html_class.send(class.get().into_owned());
+            }
         }
     }
 
@@ -145,14 +191,25 @@ where
         let el = document()
             .document_element()
             .expect("there to be a <html> element");
-
+        // the server-rendered values already include these attributes
+        let scoped = ScopedAttributes::unknown(&el, self.attributes.keys());
         let attributes = self.attributes.hydrate::<FROM_SERVER>(&el);
-
-        HtmlViewState { attributes }
+        let class = self
+            .class
+            .map(|class| MergedClass::new(&el, self.classes.clone(), class));
+
+        HtmlViewState {
+            attributes,
+            class,
+            classes: self.classes,
+            _scoped: scoped,
+        }
     }
 
     fn into_owned(self) -> Self::Owned {
         HtmlView {
+            class: self.class,
+            classes: self.classes,
             attributes: self.attributes.into_cloneable_owned(),
         }
     }
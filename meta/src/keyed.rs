@@ -0,0 +1,393 @@
+use crate::{document_head, use_head, MetaContext, ServerMetaContext};
+use leptos::{
+    attr::{any_attribute::AnyAttribute, Attribute},
+    prelude::CustomAttribute,
+    reactive::{
+        effect::RenderEffect,
+        owner::{use_context, Owner},
+    },
+    tachys::{
+        html::element::meta,
+        hydration::Cursor,
+        view::{
+            add_attr::AddAnyAttr, Mountable, Position, PositionState, Render,
+            RenderHtml,
+        },
+    },
+    text_prop::TextProp,
+};
+use or_poisoned::OrPoisoned;
+use send_wrapper::SendWrapper;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, RwLock,
+    },
+};
+
+/// Identifies a `<meta>` tag of which there should only be one in the `<head>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum MetaKey {
+    /// A tag with a `name` attribute, like `description`.
+    Name(String),
+    /// A tag with a `property` attribute, like `og:title`.
+    Property(String),
+}
+
+impl MetaKey {
+    fn attr(&self) -> &'static str {
+        match self {
+            MetaKey::Name(_) => "name",
+            MetaKey::Property(_) => "property",
+        }
+    }
+
+    fn value(&self) -> &str {
+        match self {
+            MetaKey::Name(value) | MetaKey::Property(value) => value,
+        }
+    }
+}
+
+type KeyedMetaId = u32;
+
+struct KeyedMeta {
+    id: KeyedMetaId,
+    key: MetaKey,
+    priority: i32,
+    depth: usize,
+    content: TextProp,
+}
+
+/// The `<meta>` tag that is currently in the `<head>` for a key, along with the effect that keeps
+/// its `content` up to date.
+struct AppliedMeta {
+    el: web_sys::Element,
+    _effect: RenderEffect<()>,
+}
+
+/// Contains the current state of `<meta>` tags that are identified by their `name` or `property`.
+///
+/// Each key is only rendered once. If several components set the same key, the one with the
+/// highest priority wins. Among those with the same priority, the most deeply-nested one wins,
+/// and then the one that was added last. When the winner is unmounted, the next one is applied.
+#[derive(Clone, Default)]
+pub(crate) struct KeyedMetaContext {
+    id: Arc<AtomicU32>,
+    entries: Arc<RwLock<Vec<KeyedMeta>>>,
+    applied: Arc<Mutex<HashMap<MetaKey, SendWrapper<AppliedMeta>>>>,
+}
+
+impl core::fmt::Debug for KeyedMetaContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("KeyedMetaContext").finish()
+    }
+}
+
+impl KeyedMetaContext {
+    fn next_id(&self) -> KeyedMetaId {
+        self.id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn push(&self, entry: KeyedMeta) {
+        self.entries.write().or_poisoned().push(entry);
+    }
+
+    fn update(&self, id: KeyedMetaId, content: TextProp) -> Option<MetaKey> {
+        let mut entries = self.entries.write().or_poisoned();
+        let entry = entries.iter_mut().find(|entry| entry.id == id)?;
+        entry.content = content;
+        Some(entry.key.clone())
+    }
+
+    fn remove(&self, id: KeyedMetaId) -> Option<KeyedMeta> {
+        let mut entries = self.entries.write().or_poisoned();
+        let pos = entries.iter().position(|entry| entry.id == id)?;
+        Some(entries.remove(pos))
+    }
+
+    fn winner(&self, key: &MetaKey) -> Option<TextProp> {
+        self.entries
+            .read()
+            .or_poisoned()
+            .iter()
+            .filter(|entry| &entry.key == key)
+            .max_by_key(|entry| (entry.priority, entry.depth, entry.id))
+            .map(|entry| entry.content.clone())
+    }
+
+    /// Updates the `<meta>` tag in the `<head>` to match the current winner for this key.
+    fn apply(&self, key: &MetaKey) {
+        let winner = self.winner(key);
+        let mut applied = self.applied.lock().or_poisoned();
+        let prev = applied.remove(key).map(SendWrapper::take);
+        let Some(content) = winner else {
+            if let Some(prev) = prev {
+                prev.el.remove();
+            }
+            return;
+        };
+
+        let el = match prev {
+            Some(prev) => prev.el,
+            None => find_or_create(key),
+        };
+        let effect = RenderEffect::new({
+            let el = el.clone();
+            move |_| {
+                _ = el.set_attribute("content", &content.get());
+            }
+        });
+        applied.insert(
+            key.clone(),
+            SendWrapper::new(AppliedMeta {
+                el,
+                _effect: effect,
+            }),
+        );
+    }
+
+    /// Renders the winning tag for each key as HTML.
+    pub(crate) fn to_html(&self) -> String {
+        let mut keys = Vec::new();
+        for entry in self.entries.read().or_poisoned().iter() {
+            if !keys.contains(&entry.key) {
+                keys.push(entry.key.clone());
+            }
+        }
+        keys.into_iter()
+            .filter_map(|key| {
+                let content = self.winner(&key)?.get();
+                Some(
+                    meta()
+                        .attr(key.attr(), key.value().to_string())
+                        .attr("content", content.to_string())
+                        .to_html(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Finds the server-rendered tag for this key, or creates a new one.
+fn find_or_create(key: &MetaKey) -> web_sys::Element {
+    let head = document_head();
+    let tags = head.get_elements_by_tag_name("meta");
+    let existing =
+        (0..tags.length())
+            .filter_map(|idx| tags.item(idx))
+            .find(|tag| {
+                tag.get_attribute(key.attr()).as_deref() == Some(key.value())
+            });
+    existing.unwrap_or_else(|| {
+        let el = leptos::tachys::dom::document()
+            .create_element("meta")
+            .unwrap();
+        _ = el.set_attribute(key.attr(), key.value());
+        _ = head.append_child(&el);
+        el
+    })
+}
+
+/// How deeply the current component is nested, used to let inner components override outer ones.
+fn owner_depth() -> usize {
+    let mut depth = 0;
+    let mut owner = Owner::current();
+    while let Some(this) = owner {
+        depth += 1;
+        owner = this.parent();
+    }
+    depth
+}
+
+/// Registers a `<meta>` tag of which only one should be rendered for its key.
+pub(crate) fn keyed_meta(
+    key: MetaKey,
+    content: TextProp,
+    priority: i32,
+) -> KeyedMetaView {
+    let meta = use_head();
+    let id = meta.keyed.next_id();
+    let depth = owner_depth();
+    KeyedMetaView {
+        id,
+        key,
+        priority,
+        depth,
+        meta,
+        content: Some(content),
+    }
+}
+
+pub(crate) struct KeyedMetaView {
+    id: KeyedMetaId,
+    key: MetaKey,
+    priority: i32,
+    depth: usize,
+    meta: MetaContext,
+    content: Option<TextProp>,
+}
+
+pub(crate) struct KeyedMetaViewState {
+    id: KeyedMetaId,
+    meta: MetaContext,
+    // this is only Some(_) while unmounted, and holds the entry until dropped or remounted
+    entry: Option<KeyedMeta>,
+}
+
+impl Drop for KeyedMetaViewState {
+    fn drop(&mut self) {
+        // restores the tag that was overridden by this one, if any
+        if let Some(entry) = self.meta.keyed.remove(self.id) {
+            self.meta.keyed.apply(&entry.key);
+        }
+    }
+}
+
+impl KeyedMetaView {
+    fn into_entry(self) -> (KeyedMetaViewState, Option<KeyedMeta>) {
+        let KeyedMetaView {
+            id,
+            key,
+            priority,
+            depth,
+            meta,
+            content,
+        } = self;
+        let entry = content.map(|content| KeyedMeta {
+            id,
+            key,
+            priority,
+            depth,
+            content,
+        });
+        (
+            KeyedMetaViewState {
+                id,
+                meta,
+                entry: None,
+            },
+            entry,
+        )
+    }
+}
+
+impl Render for KeyedMetaView {
+    type State = KeyedMetaViewState;
+
+    fn build(self) -> Self::State {
+        // the entry is pushed when the view is mounted
+        let (mut state, entry) = self.into_entry();
+        state.entry = entry;
+        state
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let Some(content) = self.content else {
+            return;
+        };
+        if let Some(entry) = state.entry.as_mut() {
+            entry.content = content;
+        } else if let Some(key) = state.meta.keyed.update(state.id, content) {
+            state.meta.keyed.apply(&key);
+        }
+    }
+}
+
+impl AddAnyAttr for KeyedMetaView {
+    type Output<SomeNewAttr: Attribute> = KeyedMetaView;
+
+    fn add_any_attr<NewAttr: Attribute>(
+        self,
+        _attr: NewAttr,
+    ) -> Self::Output<NewAttr>
+    where
+        Self::Output<NewAttr>: RenderHtml,
+    {
+        self
+    }
+}
+
+impl RenderHtml for KeyedMetaView {
+    type AsyncOutput = Self;
+    type Owned = Self;
+
+    const MIN_LENGTH: usize = 0;
+    const EXISTS: bool = false;
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn to_html_with_buf(
+        self,
+        _buf: &mut String,
+        _position: &mut Position,
+        _escape: bool,
+        _mark_branches: bool,
+        _extra_attrs: Vec<AnyAttribute>,
+    ) {
+        // keyed tags are rendered by the server integration once the first chunk is ready, so
+        // all the tags that set this key can be compared first
+        if let Some(cx) = use_context::<ServerMetaContext>() {
+            let (_, entry) = self.into_entry();
+            if let Some(entry) = entry {
+                cx.keyed.push(entry);
+            }
+        }
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        _cursor: &Cursor,
+        _position: &PositionState,
+    ) -> Self::State {
+        // the tag is rendered outside the hydrated part of the <head>, so it is found by its
+        // key, and mount() is not called when hydrating
+        let (state, entry) = self.into_entry();
+        if let Some(entry) = entry {
+            let key = entry.key.clone();
+            state.meta.keyed.push(entry);
+            state.meta.keyed.apply(&key);
+        }
+        state
+    }
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
+impl Mountable for KeyedMetaViewState {
+    fn unmount(&mut self) {
+        if let Some(entry) = self.meta.keyed.remove(self.id) {
+            self.meta.keyed.apply(&entry.key);
+            self.entry = Some(entry);
+        }
+    }
+
+    fn mount(
+        &mut self,
+        _parent: &leptos::tachys::renderer::types::Element,
+        _marker: Option<&leptos::tachys::renderer::types::Node>,
+    ) {
+        // there is no element to be mounted here: "mounting" means that this tag takes part in
+        // choosing the tag that is rendered in the <head> for its key
+        if let Some(entry) = self.entry.take() {
+            let key = entry.key.clone();
+            self.meta.keyed.push(entry);
+            self.meta.keyed.apply(&key);
+        }
+    }
+
+    fn insert_before_this(&self, _child: &mut dyn Mountable) -> bool {
+        false
+    }
+
+    fn elements(&self) -> Vec<leptos::tachys::renderer::types::Element> {
+        vec![]
+    }
+}
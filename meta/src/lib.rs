@@ -43,6 +43,7 @@
 //! **Important Note:** If you’re using server-side rendering, you should enable `ssr`.
 
 use futures::{Stream, StreamExt};
+use keyed::KeyedMetaContext;
 use leptos::{
     attr::{any_attribute::AnyAttribute, NextAttribute},
     component,
@@ -63,32 +64,49 @@ use leptos::{
     },
     IntoView,
 };
+use or_poisoned::OrPoisoned;
+use root_attrs::{insert_classes, ClassOwners};
 use send_wrapper::SendWrapper;
 use std::{
+    collections::HashSet,
     fmt::Debug,
     sync::{
         mpsc::{channel, Receiver, Sender},
-        Arc, LazyLock,
+        Arc, LazyLock, Mutex,
     },
 };
 use wasm_bindgen::JsCast;
 use web_sys::HtmlHeadElement;
 
 mod body;
+mod canonical;
+mod csp;
+mod favicon;
 mod html;
+mod keyed;
 mod link;
 mod meta_tags;
+mod resource_hints;
+mod root_attrs;
 mod script;
+mod social;
 mod style;
 mod stylesheet;
+mod theme_color;
 mod title;
 pub use body::*;
+pub use canonical::*;
+pub use csp::*;
+pub use favicon::*;
 pub use html::*;
 pub use link::*;
 pub use meta_tags::*;
+pub use resource_hints::*;
 pub use script::*;
+pub use social::*;
 pub use style::*;
 pub use stylesheet::*;
+pub use theme_color::*;
 pub use title::*;
 
 /// Contains the current state of meta tags. To access it, you can use [`use_head`].
@@ -99,6 +117,12 @@ pub use title::*;
 pub struct MetaContext {
     /// Metadata associated with the `<title>` element.
     pub(crate) title: TitleContext,
+    /// `<meta>` tags of which only one is rendered for each `name` or `property`.
+    pub(crate) keyed: KeyedMetaContext,
+    /// Class names added to the `<html>` element.
+    pub(crate) html_classes: ClassOwners,
+    /// Class names added to the `<body>` element.
+    pub(crate) body_classes: ClassOwners,
     /// The hydration cursor for the location in the `<head>` for arbitrary tags will be rendered.
     pub(crate) cursor: Arc<LazyLock<SendWrapper<Cursor>>>,
 }
@@ -145,6 +169,9 @@ impl Default for MetaContext {
         let cursor = Arc::new(LazyLock::new(build_cursor));
         Self {
             title: Default::default(),
+            keyed: Default::default(),
+            html_classes: Default::default(),
+            body_classes: Default::default(),
             cursor,
         }
     }
@@ -162,13 +189,23 @@ impl Default for MetaContext {
 pub struct ServerMetaContext {
     /// Metadata associated with the `<title>` element.
     pub(crate) title: TitleContext,
+    /// `<meta>` tags of which only one is rendered for each `name` or `property`.
+    pub(crate) keyed: KeyedMetaContext,
     /// Attributes for the `<html>` element.
     pub(crate) html: Sender<String>,
+    /// Class names for the `<html>` element, which are merged into one `class` attribute.
+    pub(crate) html_class: Sender<String>,
     /// Attributes for the `<body>` element.
     pub(crate) body: Sender<String>,
+    /// Class names for the `<body>` element, which are merged into one `class` attribute.
+    pub(crate) body_class: Sender<String>,
     /// Arbitrary elements to be added to the `<head>` as HTML.
     #[allow(unused)] // used in SSR
     pub(crate) elements: Sender<String>,
+    /// `<script>` elements with a `src`, which are only added to the `<head>` once.
+    #[allow(unused)] // used in SSR
+    pub(crate) shared_scripts: Sender<String>,
+    shared_script_srcs: Arc<Mutex<HashSet<String>>>,
 }
 
 /// Allows you to access `<head>` content that was inserted via [`ServerMetaContext`].
@@ -177,32 +214,59 @@ pub struct ServerMetaContext {
 #[derive(Debug)]
 pub struct ServerMetaContextOutput {
     pub(crate) title: TitleContext,
+    keyed: KeyedMetaContext,
     html: Receiver<String>,
+    html_class: Receiver<String>,
     body: Receiver<String>,
+    body_class: Receiver<String>,
     elements: Receiver<String>,
+    shared_scripts: Receiver<String>,
 }
 
 impl ServerMetaContext {
     /// Creates an empty [`ServerMetaContext`].
     pub fn new() -> (ServerMetaContext, ServerMetaContextOutput) {
         let title = TitleContext::default();
+        let keyed = KeyedMetaContext::default();
         let (html_tx, html_rx) = channel();
+        let (html_class_tx, html_class_rx) = channel();
         let (body_tx, body_rx) = channel();
+        let (body_class_tx, body_class_rx) = channel();
         let (elements_tx, elements_rx) = channel();
+        let (shared_scripts_tx, shared_scripts_rx) = channel();
         let tx = ServerMetaContext {
             title: title.clone(),
+            keyed: keyed.clone(),
             html: html_tx,
+            html_class: html_class_tx,
             body: body_tx,
+            body_class: body_class_tx,
             elements: elements_tx,
+            shared_scripts: shared_scripts_tx,
+            shared_script_srcs: Default::default(),
         };
         let rx = ServerMetaContextOutput {
             title,
+            keyed,
             html: html_rx,
+            html_class: html_class_rx,
             body: body_rx,
+            body_class: body_class_rx,
             elements: elements_rx,
+            shared_scripts: shared_scripts_rx,
         };
         (tx, rx)
     }
+
+    /// Records that a shared `<script>` with this `src` is being added to the `<head>`, returning
+    /// `false` if one has already been added.
+    #[allow(unused)] // used in SSR
+    pub(crate) fn add_shared_script(&self, src: &str) -> bool {
+        self.shared_script_srcs
+            .lock()
+            .or_poisoned()
+            .insert(src.to_string())
+    }
 }
 
 impl ServerMetaContextOutput {
@@ -234,16 +298,28 @@ impl ServerMetaContextOutput {
 
         // collect all registered meta tags
         let meta_buf = self.elements.try_iter().collect::<String>();
+        // shared scripts and keyed meta tags are not hydrated
+        let mut unhydrated_tags =
+            self.shared_scripts.try_iter().collect::<String>();
+        unhydrated_tags.push_str(&self.keyed.to_html());
 
         // get HTML strings for `<html>` and `<body>`
         let html_attrs = self.html.try_iter().collect::<String>();
         let body_attrs = self.body.try_iter().collect::<String>();
+        let html_classes = self.html_class.try_iter().collect::<Vec<_>>();
+        let body_classes = self.body_class.try_iter().collect::<Vec<_>>();
 
-        let mut modified_chunk = if title_len == 0 && meta_buf.is_empty() {
+        let mut modified_chunk = if title_len == 0
+            && meta_buf.is_empty()
+            && unhydrated_tags.is_empty()
+        {
             first_chunk
         } else {
             let mut buf = String::with_capacity(
-                first_chunk.len() + title_len + meta_buf.len(),
+                first_chunk.len()
+                    + title_len
+                    + meta_buf.len()
+                    + unhydrated_tags.len(),
             );
             let head_loc = first_chunk
                 .find("</head>")
@@ -256,7 +332,14 @@ impl ServerMetaContextOutput {
                 });
             let (before_marker, after_marker) =
                 first_chunk.split_at_mut(marker_loc);
-            buf.push_str(before_marker);
+            // these go before the marker, as they are not hydrated
+            let unhydrated_tags_loc = before_marker
+                .strip_suffix("<!--HEAD-->")
+                .map(str::len)
+                .unwrap_or(before_marker.len());
+            buf.push_str(&before_marker[..unhydrated_tags_loc]);
+            buf.push_str(&unhydrated_tags);
+            buf.push_str(&before_marker[unhydrated_tags_loc..]);
             buf.push_str(&meta_buf);
             if let Some(title) = title {
                 buf.push_str("<title>");
@@ -283,6 +366,9 @@ impl ServerMetaContextOutput {
             }
         }
 
+        insert_classes(&mut modified_chunk, "<html", &html_classes);
+        insert_classes(&mut modified_chunk, "<body", &body_classes);
+
         futures::stream::once(async move { modified_chunk }).chain(stream)
     }
 }
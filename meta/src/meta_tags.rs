@@ -1,6 +1,10 @@
-use crate::register;
+use crate::{
+    keyed::{keyed_meta, MetaKey},
+    register,
+};
 use leptos::{
     component,
+    either::Either,
     prelude::{CustomAttribute, GlobalAttributes},
     tachys::html::element::meta,
     text_prop::TextProp,
@@ -27,6 +31,36 @@ use leptos::{
 ///   }
 /// }
 /// ```
+///
+/// ## Overriding tags
+///
+/// Some tags, like `og:image` or `article:tag`, can appear several times, so every `<Meta/>` is
+/// rendered by default. Set `keyed` to render only one tag for its `name` or `property`. If
+/// several keyed `<Meta/>` components set the same one, the one with the highest `priority` is
+/// used. If they have the same `priority`, the
+/// most deeply-nested component is used, so a page can override a tag that is set by a layout.
+/// When that component is unmounted, the tag goes back to the value set by the next one.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Layout(children: Children) -> impl IntoView {
+///   view! {
+///     <Meta name="description" content="A Leptos fan site." keyed=true/>
+///     {children()}
+///   }
+/// }
+///
+/// #[component]
+/// fn Post() -> impl IntoView {
+///   view! {
+///     // overrides the description set by the layout while this page is shown
+///     <Meta name="description" content="A post about Leptos." keyed=true/>
+///   }
+/// }
+/// ```
 #[component]
 pub fn Meta(
     /// The [`charset`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta#attr-charset) attribute.
@@ -47,8 +81,32 @@ pub fn Meta(
     /// The [`content`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta#attr-content) attribute.
     #[prop(optional, into)]
     content: Option<TextProp>,
+    /// Whether to render only one tag for this `name` or `property`, replacing any other keyed
+    /// tags that set the same one.
+    #[prop(optional)]
+    keyed: bool,
+    /// If several keyed tags set the same `name` or `property`, the one with the highest priority
+    /// is rendered. Defaults to `0`.
+    #[prop(optional)]
+    priority: i32,
 ) -> impl IntoView {
-    register(
+    // the key is read once, when the component is created
+    let key = match (keyed, &name, &property) {
+        (true, Some(name), None) => {
+            Some(MetaKey::Name(name.get().into_owned()))
+        }
+        (true, None, Some(property)) => {
+            Some(MetaKey::Property(property.get().into_owned()))
+        }
+        _ => None,
+    };
+    if let (Some(key), Some(content), None, None, None) =
+        (key, &content, &charset, &http_equiv, &itemprop)
+    {
+        return Either::Left(keyed_meta(key, content.clone(), priority));
+    }
+
+    Either::Right(register(
         meta()
             .charset(charset.map(|v| move || v.get()))
             .name(name.map(|v| move || v.get()))
@@ -56,5 +114,5 @@ pub fn Meta(
             .http_equiv(http_equiv.map(|v| move || v.get()))
             .itemprop(itemprop.map(|v| move || v.get()))
             .content(content.map(|v| move || v.get())),
-    )
+    ))
 }
@@ -0,0 +1,219 @@
+use crate::register;
+use leptos::{
+    component, tachys::html::element::link, text_prop::TextProp, IntoView,
+};
+
+/// The kind of resource that is being preloaded or prefetched, which is used as the
+/// [`as`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/rel/preload#what_types_of_content_can_be_preloaded)
+/// attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    /// An audio file.
+    Audio,
+    /// An HTML document that will be embedded in a frame.
+    Document,
+    /// A resource that will be embedded in an `<embed>` element.
+    Embed,
+    /// A resource that will be loaded with `fetch()`.
+    Fetch,
+    /// A font file.
+    Font,
+    /// An image file.
+    Image,
+    /// A resource that will be embedded in an `<object>` element.
+    Object,
+    /// A JavaScript file.
+    Script,
+    /// A CSS stylesheet.
+    Style,
+    /// A WebVTT file.
+    Track,
+    /// A video file.
+    Video,
+    /// A JavaScript web worker or shared worker.
+    Worker,
+}
+
+impl ResourceKind {
+    /// Returns the value of the `as` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResourceKind::Audio => "audio",
+            ResourceKind::Document => "document",
+            ResourceKind::Embed => "embed",
+            ResourceKind::Fetch => "fetch",
+            ResourceKind::Font => "font",
+            ResourceKind::Image => "image",
+            ResourceKind::Object => "object",
+            ResourceKind::Script => "script",
+            ResourceKind::Style => "style",
+            ResourceKind::Track => "track",
+            ResourceKind::Video => "video",
+            ResourceKind::Worker => "worker",
+        }
+    }
+
+    /// Fonts and `fetch()` requests are always made in CORS mode, so a preload for them is only
+    /// used if it also has a `crossorigin` attribute.
+    fn requires_cors(&self) -> bool {
+        matches!(self, ResourceKind::Font | ResourceKind::Fetch)
+    }
+}
+
+/// The value of the
+/// [`crossorigin`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/crossorigin)
+/// attribute of a resource hint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossOrigin {
+    /// Sends CORS requests without credentials.
+    #[default]
+    Anonymous,
+    /// Sends CORS requests with credentials, like cookies.
+    UseCredentials,
+}
+
+impl CrossOrigin {
+    /// Returns the value of the `crossorigin` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CrossOrigin::Anonymous => "anonymous",
+            CrossOrigin::UseCredentials => "use-credentials",
+        }
+    }
+}
+
+/// Injects a `<link rel="preload">` into the document head, which tells the browser to start
+/// fetching a resource that the current page will need soon.
+///
+/// Fonts and `fetch()` requests need a `crossorigin` attribute for the preloaded response to be
+/// used, so `crossorigin="anonymous"` is added to them if `crossorigin` is not set.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///     let hero = RwSignal::new("/images/hero.webp".to_string());
+///
+///     view! {
+///       <Preload href="/fonts/inter.woff2" as_=ResourceKind::Font type_="font/woff2"/>
+///       // the href can be a signal, so the hint follows the current page
+///       <Preload href=move || hero.get() as_=ResourceKind::Image/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Preload(
+    /// The URL of the resource.
+    #[prop(into)]
+    href: TextProp,
+    /// The kind of resource.
+    as_: ResourceKind,
+    /// The MIME type of the resource, which lets the browser skip resources it cannot use.
+    #[prop(optional, into)]
+    type_: Option<TextProp>,
+    /// The CORS mode used to fetch the resource.
+    #[prop(optional)]
+    crossorigin: Option<CrossOrigin>,
+    /// A media query, so that the resource is only preloaded if it matches.
+    #[prop(optional, into)]
+    media: Option<TextProp>,
+) -> impl IntoView {
+    let crossorigin = crossorigin
+        .or_else(|| as_.requires_cors().then_some(CrossOrigin::Anonymous));
+    register(
+        link()
+            .rel("preload")
+            .href(move || href.get())
+            .r#as(as_.as_str())
+            .r#type(type_.map(|v| move || v.get()))
+            .crossorigin(crossorigin.map(|c| c.as_str()))
+            .media(media.map(|v| move || v.get())),
+    )
+}
+
+/// Injects a `<link rel="prefetch">` into the document head, which tells the browser to fetch a
+/// resource that is likely to be needed by a later navigation, when it is idle.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Article() -> impl IntoView {
+///     view! {
+///       <Prefetch href="/pkg/comments.wasm" as_=ResourceKind::Fetch/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Prefetch(
+    /// The URL of the resource.
+    #[prop(into)]
+    href: TextProp,
+    /// The kind of resource.
+    #[prop(optional)]
+    as_: Option<ResourceKind>,
+    /// The CORS mode used to fetch the resource.
+    #[prop(optional)]
+    crossorigin: Option<CrossOrigin>,
+) -> impl IntoView {
+    let crossorigin = crossorigin.or_else(|| {
+        as_.filter(ResourceKind::requires_cors)
+            .map(|_| CrossOrigin::Anonymous)
+    });
+    register(
+        link()
+            .rel("prefetch")
+            .href(move || href.get())
+            .r#as(as_.map(|as_| as_.as_str()))
+            .crossorigin(crossorigin.map(|c| c.as_str())),
+    )
+}
+
+/// Injects a `<link rel="preconnect">` into the document head, which tells the browser to open a
+/// connection to another origin before any resources are requested from it.
+///
+/// Set `crossorigin` if the resources from that origin will be fetched in CORS mode, like fonts,
+/// as CORS requests use a separate connection. Set `dns_prefetch` to also add a
+/// `<link rel="dns-prefetch">`, for browsers that do not support `preconnect`.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///
+///     view! {
+///       <Preconnect href="https://fonts.gstatic.com" crossorigin=CrossOrigin::Anonymous dns_prefetch=true/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Preconnect(
+    /// The origin to connect to.
+    #[prop(into)]
+    href: TextProp,
+    /// The CORS mode of the requests that will be made to the origin.
+    #[prop(optional)]
+    crossorigin: Option<CrossOrigin>,
+    /// Whether to also add a `<link rel="dns-prefetch">` for the origin.
+    #[prop(optional)]
+    dns_prefetch: bool,
+) -> impl IntoView {
+    let dns_prefetch = dns_prefetch.then(|| {
+        let href = href.clone();
+        register(link().rel("dns-prefetch").href(move || href.get()))
+    });
+    let preconnect = register(
+        link()
+            .rel("preconnect")
+            .href(move || href.get())
+            .crossorigin(crossorigin.map(|c| c.as_str())),
+    );
+    (preconnect, dns_prefetch)
+}
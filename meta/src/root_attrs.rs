@@ -0,0 +1,230 @@
+//! Shared logic for [`Html`](crate::Html) and [`Body`](crate::Body), which set attributes on
+//! elements that are shared by the whole app.
+
+use leptos::{
+    attr::NamedAttributeKey, reactive::effect::RenderEffect,
+    tachys::renderer::types::Element, text_prop::TextProp,
+};
+use or_poisoned::OrPoisoned;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// The class names that have been added to an element, with the number of components that
+/// added each one.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClassOwners(Arc<Mutex<HashMap<String, usize>>>);
+
+impl ClassOwners {
+    fn add(&self, el: &Element, name: &str) {
+        let mut owners = self.0.lock().or_poisoned();
+        let count = owners.entry(name.to_string()).or_default();
+        if *count == 0 {
+            _ = el.class_list().add_1(name);
+        }
+        *count += 1;
+    }
+
+    fn remove(&self, el: &Element, name: &str) {
+        let mut owners = self.0.lock().or_poisoned();
+        if let Some(count) = owners.get_mut(name) {
+            *count -= 1;
+            if *count == 0 {
+                owners.remove(name);
+                _ = el.class_list().remove_1(name);
+            }
+        }
+    }
+}
+
+fn class_names(class: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for name in class.split_whitespace() {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Class names that one component adds to an element. Other class names on the element are left
+/// alone, and these are removed once no other component uses them.
+pub(crate) struct MergedClass {
+    el: Element,
+    owners: ClassOwners,
+    effect: RenderEffect<Vec<String>>,
+}
+
+impl MergedClass {
+    pub(crate) fn new(
+        el: &Element,
+        owners: ClassOwners,
+        class: TextProp,
+    ) -> Self {
+        let effect = RenderEffect::new({
+            let el = el.clone();
+            let owners = owners.clone();
+            move |prev: Option<Vec<String>>| {
+                let names = class_names(&class.get());
+                let prev = prev.unwrap_or_default();
+                for name in names.iter().filter(|name| !prev.contains(name)) {
+                    owners.add(&el, name);
+                }
+                for name in prev.iter().filter(|name| !names.contains(name)) {
+                    owners.remove(&el, name);
+                }
+                names
+            }
+        });
+        MergedClass {
+            el: el.clone(),
+            owners,
+            effect,
+        }
+    }
+}
+
+impl Drop for MergedClass {
+    fn drop(&mut self) {
+        if let Some(names) = self.effect.take_value() {
+            for name in names {
+                self.owners.remove(&self.el, &name);
+            }
+        }
+    }
+}
+
+/// The values that a set of attributes had before a component set them, which are restored when
+/// the component is dropped.
+pub(crate) struct ScopedAttributes {
+    el: Element,
+    previous: Vec<(String, Option<String>)>,
+}
+
+impl ScopedAttributes {
+    /// Records the current values of the attributes with these keys.
+    pub(crate) fn snapshot(el: &Element, keys: Vec<NamedAttributeKey>) -> Self {
+        let previous = keys
+            .into_iter()
+            .filter_map(|key| match key {
+                NamedAttributeKey::Attribute(name) => Some(name.into_owned()),
+                _ => None,
+            })
+            .map(|name| {
+                let value = el.get_attribute(&name);
+                (name, value)
+            })
+            .collect();
+        ScopedAttributes {
+            el: el.clone(),
+            previous,
+        }
+    }
+
+    /// Records that the attributes with these keys should be removed, because their previous
+    /// values are not known.
+    pub(crate) fn unknown(el: &Element, keys: Vec<NamedAttributeKey>) -> Self {
+        let mut this = Self::snapshot(el, keys);
+        for (_, value) in &mut this.previous {
+            *value = None;
+        }
+        this
+    }
+}
+
+impl Drop for ScopedAttributes {
+    fn drop(&mut self) {
+        // restored in reverse, in case the same attribute was set more than once
+        for (name, value) in self.previous.drain(..).rev() {
+            _ = match value {
+                Some(value) => self.el.set_attribute(&name, &value),
+                None => self.el.remove_attribute(&name),
+            };
+        }
+    }
+}
+
+/// Adds class names to the opening tag of an element in an HTML string, merging them with any
+/// `class` attribute that is already there.
+pub(crate) fn insert_classes(html: &mut String, tag: &str, classes: &[String]) {
+    let mut names = Vec::<&str>::new();
+    for name in classes.iter().flat_map(|class| class.split_whitespace()) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    if names.is_empty() {
+        return;
+    }
+    // escaped in the same way as other attribute values
+    let names = names.join(" ").replace('&', "&amp;").replace('"', "&quot;");
+
+    let Some(start) = find_tag(html, tag) else {
+        return;
+    };
+    match class_value_start(html, start + tag.len()) {
+        Some((value, true)) => html.insert_str(value, &format!("{names} ")),
+        // an unquoted value can't contain spaces, so it is quoted along with the new names
+        Some((value, false)) => {
+            let value_end = html[value..]
+                .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                .map(|end| value + end)
+                .unwrap_or(html.len());
+            html.insert(value_end, '"');
+            html.insert_str(value, &format!("\"{names} "));
+        }
+        None => {
+            let insert_pos = start + tag.len();
+            html.insert_str(insert_pos, &format!(" class=\"{names}\""));
+        }
+    }
+}
+
+/// Finds the opening tag `tag`, like `<body`, which must be followed by the end of the tag name.
+fn find_tag(html: &str, tag: &str) -> Option<usize> {
+    html.match_indices(tag)
+        .map(|(start, _)| start)
+        .find(|start| {
+            html[start + tag.len()..].chars().next().is_some_and(|c| {
+                c.is_ascii_whitespace() || c == '>' || c == '/'
+            })
+        })
+}
+
+/// Finds the start of the value of the `class` attribute of the tag whose attributes begin at
+/// `pos`, and whether it is quoted, skipping over the values of other attributes.
+fn class_value_start(html: &str, mut pos: usize) -> Option<(usize, bool)> {
+    let bytes = html.as_bytes();
+    let mut quote = None;
+    while pos < bytes.len() {
+        let byte = bytes[pos];
+        match quote {
+            Some(q) if byte == q => quote = None,
+            Some(_) => {}
+            None if byte == b'"' || byte == b'\'' => quote = Some(byte),
+            None if byte == b'>' => return None,
+            None if byte.is_ascii_whitespace() => {
+                let name = &html[pos + 1..];
+                let is_class = name
+                    .get(..5)
+                    .is_some_and(|name| name.eq_ignore_ascii_case("class"));
+                let rest = name.get(5..).unwrap_or_default();
+                let value = rest.trim_start();
+                if is_class && value.starts_with('=') {
+                    let eq = html.len() - value.len();
+                    let value = html[eq + 1..].trim_start();
+                    let value_start = html.len() - value.len();
+                    return match value.as_bytes().first() {
+                        Some(b'"' | b'\'') => Some((value_start + 1, true)),
+                        Some(_) => Some((value_start, false)),
+                        None => None,
+                    };
+                }
+            }
+            None => {}
+        }
+        pos += 1;
+    }
+    None
+}
@@ -1,7 +1,20 @@
-use crate::{register, OrDefaultNonce};
+use crate::{document_head, register, OrDefaultNonce};
 use leptos::{
-    component, oco::Oco, prelude::*, tachys::html::element::script, IntoView,
+    component,
+    either::Either,
+    oco::Oco,
+    prelude::*,
+    tachys::html::element::{script, HtmlElement, Script as ScriptTag},
+    IntoView,
 };
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// Set on a `<script>` once it has loaded, so that components that use the same script later
+/// know that it is ready.
+const LOADED_ATTR: &str = "data-leptos-loaded";
+
+/// Set on a `<script>` that was added in the browser, rather than rendered on the server.
+const ADDED_ATTR: &str = "data-leptos-added";
 
 /// Injects an [`HTMLScriptElement`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLScriptElement) into the document
 /// head, accepting any of the valid attributes for that tag.
@@ -23,6 +36,31 @@ use leptos::{
 ///     }
 /// }
 /// ```
+///
+/// ## External scripts
+///
+/// A script with a `src` is only added to the `<head>` once, no matter how many `<Script/>`
+/// components use it. It is not removed when the component is unmounted, so navigating away from
+/// a page and back again does not load and run a third-party script a second time. `on_load` is
+/// called once the script has loaded, including when it had already been loaded by another
+/// component.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Map() -> impl IntoView {
+///     view! {
+///       <Script
+///         src="https://maps.example.com/embed.js"
+///         async_="true"
+///         on_load=|| leptos::logging::log!("map script loaded")
+///       />
+///       <div id="map"></div>
+///     }
+/// }
+/// ```
 #[component]
 pub fn Script(
     /// An ID for the `<script>` tag.
@@ -61,24 +99,134 @@ pub fn Script(
     /// The [`blocking`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/script#attr-blocking) attribute.
     #[prop(optional, into)]
     blocking: Option<Oco<'static, str>>,
+    /// Loads the script as a JavaScript module, by setting `type="module"`. This is ignored if
+    /// `type_` is set.
+    #[prop(optional)]
+    module: bool,
+    /// Called once the script at `src` has loaded.
+    #[prop(optional, into)]
+    on_load: Option<Callback<()>>,
+    /// Called if the script at `src` fails to load.
+    #[prop(optional, into)]
+    on_error: Option<Callback<()>>,
     /// The content of the `<script>` tag.
     #[prop(optional)]
     children: Option<Children>,
 ) -> impl IntoView {
-    register(
-        script()
-            .id(id)
-            .r#async(async_)
-            .crossorigin(crossorigin)
-            .defer(defer)
-            .fetchpriority(fetchpriority)
-            .integrity(integrity)
-            .nomodule(nomodule)
-            .nonce(nonce.or_default_nonce())
-            .referrerpolicy(referrerpolicy)
-            .src(src)
-            .r#type(type_)
-            .blocking(blocking)
-            .child(children.map(|c| c())),
-    )
+    let type_ = type_.or_else(|| module.then_some(Oco::Borrowed("module")));
+    let shared_src = src.clone();
+    let el = script()
+        .id(id)
+        .r#async(async_)
+        .crossorigin(crossorigin)
+        .defer(defer)
+        .fetchpriority(fetchpriority)
+        .integrity(integrity)
+        .nomodule(nomodule)
+        .nonce(nonce.or_default_nonce())
+        .referrerpolicy(referrerpolicy)
+        .src(src)
+        .r#type(type_)
+        .blocking(blocking)
+        .child(children.map(|c| c()));
+    match shared_src {
+        Some(src) => {
+            add_shared_script(el, &src, on_load, on_error);
+            Either::Left(())
+        }
+        None => Either::Right(register(el)),
+    }
+}
+
+/// Adds a `<script>` with the given `src` to the `<head>`, unless it has already been added.
+fn add_shared_script<At, Ch>(
+    el: HtmlElement<ScriptTag, At, Ch>,
+    src: &str,
+    on_load: Option<Callback<()>>,
+    on_error: Option<Callback<()>>,
+) where
+    HtmlElement<ScriptTag, At, Ch>: RenderHtml,
+{
+    // on the server, shared scripts are rendered outside the part of the <head> that is
+    // hydrated, as they are never removed
+    #[cfg(feature = "ssr")]
+    if let Some(cx) = use_context::<crate::ServerMetaContext>() {
+        if cx.add_shared_script(src) {
+            _ = cx.shared_scripts.send(el.to_html());
+        }
+        return;
+    }
+    if is_server() {
+        return;
+    }
+
+    let head = document_head();
+    let scripts = head.get_elements_by_tag_name("script");
+    let existing = (0..scripts.length())
+        .filter_map(|idx| scripts.item(idx))
+        .find(|script| script.get_attribute("src").as_deref() == Some(src));
+    let script = match existing {
+        Some(script) => script,
+        None => {
+            let mut state = el.build();
+            state.mount(&head, None);
+            let script = state.elements().remove(0);
+            // never unmounted, as the script cannot be unloaded once it has run
+            std::mem::forget(state);
+            _ = script.set_attribute(ADDED_ATTR, "");
+            mark_loaded_on_load(&script);
+            script
+        }
+    };
+    let from_server = !script.has_attribute(ADDED_ATTR);
+
+    if script.has_attribute(LOADED_ATTR)
+        || (from_server && server_script_has_loaded(&script))
+    {
+        if let Some(on_load) = on_load {
+            on_load.try_run(());
+        }
+        return;
+    }
+    for (event, callback) in [("load", on_load), ("error", on_error)] {
+        if let Some(callback) = callback {
+            let handler = Closure::once_into_js(move || {
+                callback.try_run(());
+            });
+            // a server-rendered script may already have fired its own `load` event, but it
+            // always fires before the window's
+            if from_server && event == "load" {
+                _ = window().add_event_listener_with_callback(
+                    event,
+                    handler.unchecked_ref(),
+                );
+            } else {
+                _ = script.add_event_listener_with_callback(
+                    event,
+                    handler.unchecked_ref(),
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether a server-rendered script has loaded by the time the app is hydrated.
+///
+/// Scripts without `async` run before the document has been parsed, which is before the app is
+/// hydrated. `async` scripts delay the window's `load` event, so they have all loaded once the
+/// document is complete.
+fn server_script_has_loaded(script: &web_sys::Element) -> bool {
+    !script.has_attribute("async")
+        || document().ready_state() == "complete"
+}
+
+fn mark_loaded_on_load(script: &web_sys::Element) {
+    let handler = Closure::once_into_js({
+        let script = script.clone();
+        move || {
+            _ = script.set_attribute(LOADED_ATTR, "");
+        }
+    });
+    _ = script
+        .add_event_listener_with_callback("load", handler.unchecked_ref());
 }
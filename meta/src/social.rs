@@ -0,0 +1,158 @@
+use crate::keyed::{keyed_meta, MetaKey};
+use leptos::{component, text_prop::TextProp, IntoView};
+
+/// Injects the [Open Graph](https://ogp.me/) `<meta property="og:...">` tags that describe how
+/// a page looks when it is shared, for each of the props that is set.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn BlogPost() -> impl IntoView {
+///     view! {
+///       <OgMeta
+///         title="Announcing Leptos 1.0"
+///         description="What's new in this release."
+///         kind="article"
+///         url="https://leptos.dev/blog/1.0"
+///         image="https://leptos.dev/blog/1.0/cover.png"
+///         image_alt="The Leptos logo"
+///       />
+///     }
+/// }
+/// ```
+#[component]
+pub fn OgMeta(
+    /// The `og:title` of the page.
+    #[prop(optional, into)]
+    title: Option<TextProp>,
+    /// The `og:description` of the page.
+    #[prop(optional, into)]
+    description: Option<TextProp>,
+    /// The `og:type` of the page, like `"website"` or `"article"`.
+    #[prop(optional, into)]
+    kind: Option<TextProp>,
+    /// The canonical `og:url` of the page.
+    #[prop(optional, into)]
+    url: Option<TextProp>,
+    /// The URL of an `og:image` that represents the page.
+    #[prop(optional, into)]
+    image: Option<TextProp>,
+    /// A description of the image, as `og:image:alt`.
+    #[prop(optional, into)]
+    image_alt: Option<TextProp>,
+    /// The `og:site_name` of the site the page is part of.
+    #[prop(optional, into)]
+    site_name: Option<TextProp>,
+    /// The `og:locale` of the page, like `"en_US"`.
+    #[prop(optional, into)]
+    locale: Option<TextProp>,
+) -> impl IntoView {
+    (
+        property_tag("og:title", title),
+        property_tag("og:description", description),
+        property_tag("og:type", kind),
+        property_tag("og:url", url),
+        property_tag("og:image", image),
+        property_tag("og:image:alt", image_alt),
+        property_tag("og:site_name", site_name),
+        property_tag("og:locale", locale),
+    )
+}
+
+/// The type of a [Twitter/X card](https://developer.x.com/en/docs/x-for-websites/cards/overview/abouts-cards).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TwitterCard {
+    /// A title, description, and small thumbnail image.
+    #[default]
+    Summary,
+    /// A title, description, and large image.
+    SummaryLargeImage,
+    /// A link to download a mobile app.
+    App,
+    /// An embedded video or audio player.
+    Player,
+}
+
+impl TwitterCard {
+    /// The value of the `twitter:card` meta tag for this type of card.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TwitterCard::Summary => "summary",
+            TwitterCard::SummaryLargeImage => "summary_large_image",
+            TwitterCard::App => "app",
+            TwitterCard::Player => "player",
+        }
+    }
+}
+
+/// Injects the `<meta name="twitter:...">` tags that describe how a page looks when it is shared
+/// on Twitter/X, for each of the props that is set.
+///
+/// Twitter/X falls back to the [Open Graph](OgMeta) tags for a title, description, or image that
+/// is not set here.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn BlogPost() -> impl IntoView {
+///     view! {
+///       <TwitterMeta
+///         card=TwitterCard::SummaryLargeImage
+///         site="@leptos_rs"
+///         image="https://leptos.dev/blog/1.0/cover.png"
+///       />
+///     }
+/// }
+/// ```
+#[component]
+pub fn TwitterMeta(
+    /// The type of card. Defaults to [`TwitterCard::Summary`].
+    #[prop(optional)]
+    card: TwitterCard,
+    /// The `twitter:title` of the page.
+    #[prop(optional, into)]
+    title: Option<TextProp>,
+    /// The `twitter:description` of the page.
+    #[prop(optional, into)]
+    description: Option<TextProp>,
+    /// The URL of a `twitter:image` that represents the page.
+    #[prop(optional, into)]
+    image: Option<TextProp>,
+    /// A description of the image, as `twitter:image:alt`.
+    #[prop(optional, into)]
+    image_alt: Option<TextProp>,
+    /// The `@username` of the website, as `twitter:site`.
+    #[prop(optional, into)]
+    site: Option<TextProp>,
+    /// The `@username` of the author of the page, as `twitter:creator`.
+    #[prop(optional, into)]
+    creator: Option<TextProp>,
+) -> impl IntoView {
+    (
+        name_tag("twitter:card", Some(card.as_str().into())),
+        name_tag("twitter:title", title),
+        name_tag("twitter:description", description),
+        name_tag("twitter:image", image),
+        name_tag("twitter:image:alt", image_alt),
+        name_tag("twitter:site", site),
+        name_tag("twitter:creator", creator),
+    )
+}
+
+fn property_tag(
+    property: &'static str,
+    content: Option<TextProp>,
+) -> impl IntoView {
+    content.map(|content| {
+        keyed_meta(MetaKey::Property(property.to_string()), content, 0)
+    })
+}
+
+fn name_tag(name: &'static str, content: Option<TextProp>) -> impl IntoView {
+    content
+        .map(|content| keyed_meta(MetaKey::Name(name.to_string()), content, 0))
+}
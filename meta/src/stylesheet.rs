@@ -1,7 +1,7 @@
 use crate::register;
 use leptos::{
-    attr::global::GlobalAttributes, component, prelude::LeptosOptions,
-    tachys::html::element::link, IntoView,
+    attr::global::GlobalAttributes, component, hydration::AssetFileNames,
+    prelude::LeptosOptions, tachys::html::element::link, IntoView,
 };
 
 /// Injects an [`HTMLLinkElement`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLinkElement) into the document
@@ -40,6 +40,9 @@ pub fn Stylesheet(
 
 /// Injects an [`HTMLLinkElement`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLinkElement) into the document head that loads a `cargo-leptos`-hashed stylesheet.
 ///
+/// If an [`asset_manifest`](LeptosOptions::asset_manifest) is configured, the file name and
+/// `integrity` attribute are read from it.
+///
 /// This should only be used in the application’s server-side `shell` function, as
 /// [`LeptosOptions`] is not available in the browser. Unlike other `leptos_meta` components, it
 /// will render the `<link>` it creates exactly where it is called.
@@ -54,36 +57,17 @@ pub fn HashedStylesheet(
     #[prop(optional, into)]
     root: Option<String>,
 ) -> impl IntoView {
-    let mut css_file_name = options.output_name.to_string();
-    if options.hash_files {
-        let hash_path = std::env::current_exe()
-            .map(|path| {
-                path.parent().map(|p| p.to_path_buf()).unwrap_or_default()
-            })
-            .unwrap_or_default()
-            .join(options.hash_file.as_ref());
-        if hash_path.exists() {
-            let hashes = std::fs::read_to_string(&hash_path)
-                .expect("failed to read hash file");
-            for line in hashes.lines() {
-                let line = line.trim();
-                if !line.is_empty() {
-                    if let Some((file, hash)) = line.split_once(':') {
-                        if file == "css" {
-                            css_file_name
-                                .push_str(&format!(".{}", hash.trim()));
-                        }
-                    }
-                }
-            }
-        }
-    }
-    css_file_name.push_str(".css");
+    let AssetFileNames {
+        css: css_file_name,
+        css_integrity,
+        ..
+    } = AssetFileNames::new(&options);
     let pkg_path = &options.site_pkg_dir;
     let root = root.unwrap_or_default();
 
     link()
         .id(id)
         .rel("stylesheet")
-        .href(format!("{root}/{pkg_path}/{css_file_name}"))
+        .integrity(css_integrity)
+        .href(format!("{root}/{pkg_path}/{css_file_name}.css"))
 }
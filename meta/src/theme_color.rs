@@ -0,0 +1,46 @@
+use crate::register;
+use leptos::{
+    component, prelude::CustomAttribute, tachys::html::element::meta,
+    text_prop::TextProp, IntoView,
+};
+
+/// Injects a `<meta name="theme-color">` into the document head, setting the color that the
+/// browser uses for its interface around the page.
+///
+/// The `content` can be reactive, so the color follows the app's theme. To use a different color
+/// depending on the user's color scheme, add one `<ThemeColor/>` for each scheme, with a `media`
+/// query.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp() -> impl IntoView {
+///     provide_meta_context();
+///     let dark = RwSignal::new(false);
+///
+///     view! {
+///       <ThemeColor content=move || if dark.get() { "#111827" } else { "#ffffff" }/>
+///       // or, to follow the operating system's color scheme
+///       <ThemeColor content="#ffffff" media="(prefers-color-scheme: light)"/>
+///       <ThemeColor content="#111827" media="(prefers-color-scheme: dark)"/>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeColor(
+    /// The color, as any CSS color value.
+    #[prop(into)]
+    content: TextProp,
+    /// A media query, so that the color is only used if it matches.
+    #[prop(optional, into)]
+    media: Option<TextProp>,
+) -> impl IntoView {
+    register(
+        meta()
+            .name("theme-color")
+            .content(move || content.get())
+            .attr("media", media.map(|v| move || v.get())),
+    )
+}
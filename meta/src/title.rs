@@ -159,20 +159,47 @@ impl TitleContext {
             .map(|n| n.1.get());
 
         title.map(|title| {
-            if let Some(formatter) =
-                self.formatter_stack.read().or_poisoned().last()
-            {
-                (formatter.1 .0)(title.into_owned()).into()
-            } else {
-                title
+            let formatter_stack = self.formatter_stack.read().or_poisoned();
+            let mut formatters = formatter_stack.iter().rev();
+            let Some((_, innermost)) = formatters.next() else {
+                return title;
+            };
+
+            let mut title = (innermost.format)(title.into_owned());
+            // templates are also wrapped by any formatters further up the tree
+            let mut composes = innermost.composes;
+            for (_, formatter) in formatters {
+                if !composes {
+                    break;
+                }
+                title = (formatter.format)(title);
+                composes = formatter.composes;
             }
+            title.into()
         })
     }
 }
 
 /// A function that is applied to the text value before setting `document.title`.
-#[repr(transparent)]
-pub struct Formatter(Box<dyn Fn(String) -> String + Send + Sync>);
+pub struct Formatter {
+    format: Box<dyn Fn(String) -> String + Send + Sync>,
+    composes: bool,
+}
+
+impl Formatter {
+    /// Creates a formatter from a template, in which every `%s` is replaced with the text value.
+    ///
+    /// Unlike other formatters, the result of a template is also passed to the formatter of the
+    /// nearest `<Title/>` further up the tree, if there is one, so that nested templates
+    /// compose.
+    pub fn template(template: impl Into<String>) -> Self {
+        let template = template.into();
+        Formatter {
+            format: Box::new(move |text| template.replace("%s", &text)),
+            composes: true,
+        }
+    }
+}
 
 impl<F> From<F> for Formatter
 where
@@ -180,7 +207,10 @@ where
 {
     #[inline(always)]
     fn from(f: F) -> Formatter {
-        Formatter(Box::new(f))
+        Formatter {
+            format: Box::new(f),
+            composes: false,
+        }
     }
 }
 
@@ -189,6 +219,11 @@ where
 /// The `title` and `formatter` can be set independently of one another. For example, you can create a root-level
 /// `<Title formatter=.../>` that will wrap each of the text values of `<Title/>` components created lower in the tree.
 ///
+/// Only the formatter of the nearest `<Title/>` is used. A `template`, in which `%s` is replaced with the text,
+/// works like a formatter, but its result is also passed through the template or formatter of the next `<Title/>`
+/// up the tree, so that each level of nesting can add to the title. When a nested `<Title/>` is unmounted, the
+/// title is restored to the value set by the remaining ones.
+///
 /// ```
 /// use leptos::prelude::*;
 /// use leptos_meta::*;
@@ -223,16 +258,31 @@ where
 ///       </main>
 ///     }
 /// }
+///
+/// #[component]
+/// fn Docs() -> impl IntoView {
+///     view! {
+///       <Title template="%s | Docs"/>
+///       <Title text="Getting Started"/> // sets title to "Getting Started | Docs — Leptos Online"
+///     }
+/// }
 /// ```
 #[component]
 pub fn Title(
     /// A function that will be applied to any text value before it’s set as the title.
     #[prop(optional, into)]
     mut formatter: Option<Formatter>,
+    /// A template for the title, in which `%s` is replaced with the text value, like
+    /// `"%s – My App"`. Nested templates compose. This is ignored if a `formatter` is set.
+    #[prop(optional, into)]
+    template: Option<String>,
     /// Sets the current `document.title`.
     #[prop(optional, into)]
     mut text: Option<TextProp>,
 ) -> impl IntoView {
+    if formatter.is_none() {
+        formatter = template.map(Formatter::template);
+    }
     let meta = use_head();
     let server_ctx = use_context::<ServerMetaContext>();
     let id = meta.title.next_id();
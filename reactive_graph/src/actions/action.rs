@@ -5,18 +5,29 @@ use crate::{
     owner::{ArcStoredValue, ArenaItem, Owner},
     send_wrapper_ext::SendOption,
     signal::{ArcMappedSignal, ArcRwSignal, MappedSignal, RwSignal},
-    traits::{DefinedAt, Dispose, Get, GetUntracked, GetValue, Update, Write},
+    traits::{
+        DefinedAt, Dispose, Get, GetUntracked, GetValue, Update, UpdateValue,
+        WithValue, Write,
+    },
     unwrap_signal,
 };
 use any_spawner::Executor;
-use futures::{channel::oneshot, select, FutureExt};
+use futures::{
+    channel::oneshot,
+    future::{AbortHandle, Abortable},
+    select, FutureExt,
+};
 use send_wrapper::SendWrapper;
 use std::{
+    fmt::Debug,
     future::Future,
     ops::{Deref, DerefMut},
     panic::Location,
     pin::Pin,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 /// An action runs some asynchronous code when you dispatch a new value to it, and gives you
@@ -102,6 +113,8 @@ pub struct ArcAction<I, O> {
     value: ArcRwSignal<SendOption<O>>,
     version: ArcRwSignal<usize>,
     dispatched: ArcStoredValue<usize>,
+    calls: ArcStoredValue<Vec<InFlightCall>>,
+    queue: ArcStoredValue<DispatchQueue<I>>,
     #[allow(clippy::complexity)]
     action_fn: Arc<
         dyn Fn(&I) -> Pin<Box<dyn Future<Output = O> + Send>> + Send + Sync,
@@ -118,6 +131,8 @@ impl<I, O> Clone for ArcAction<I, O> {
             value: self.value.clone(),
             version: self.version.clone(),
             dispatched: self.dispatched.clone(),
+            calls: self.calls.clone(),
+            queue: self.queue.clone(),
             action_fn: self.action_fn.clone(),
             #[cfg(any(debug_assertions, leptos_debuginfo))]
             defined_at: self.defined_at,
@@ -207,6 +222,8 @@ where
             value: ArcRwSignal::new(SendOption::new(value)),
             version: Default::default(),
             dispatched: Default::default(),
+            calls: Default::default(),
+            queue: Default::default(),
             action_fn: Arc::new(move |input| {
                 let fut = untrack(|| action_fn(input));
                 match weak_owner.as_ref().and_then(|w| w.upgrade()) {
@@ -234,11 +251,158 @@ where
     }
 }
 
+type DelayFn = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ()>>> + Send + Sync>;
+
+/// Limits how often an action runs when it is dispatched many times in quick succession, for
+/// example by an autosave or a live search, without any timer code where it is dispatched.
+///
+/// A policy is set when the action is created, with [`ArcAction::with_dispatch_policy`] or
+/// [`Action::with_dispatch_policy`], and applies to each later call to `dispatch`. Calls that
+/// are delayed are spawned on the current thread.
+///
+/// The delay is given as a function that returns a future, so that any timer can be used.
+/// `leptos::timing` provides `DispatchPolicy::debounce` and `DispatchPolicy::throttle`, which
+/// take a [`Duration`](std::time::Duration) instead.
+#[derive(Clone)]
+pub struct DispatchPolicy {
+    mode: DispatchMode,
+    delay: DelayFn,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DispatchMode {
+    Debounce,
+    Throttle,
+}
+
+impl Debug for DispatchPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DispatchPolicy")
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DispatchPolicy {
+    /// Runs the action once it has not been dispatched again until the future returned by
+    /// `delay` resolves, with the most recent input. Earlier inputs are dropped.
+    pub fn debounce_with<Fut>(
+        delay: impl Fn() -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self {
+            mode: DispatchMode::Debounce,
+            delay: Arc::new(move || Box::pin(delay())),
+        }
+    }
+
+    /// Runs the action at most once until the future returned by `delay` resolves.
+    ///
+    /// The first dispatch runs immediately. The dispatches made while waiting are coalesced,
+    /// so that only the most recent of them runs once the delay has passed.
+    pub fn throttle_with<Fut>(
+        delay: impl Fn() -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self {
+            mode: DispatchMode::Throttle,
+            delay: Arc::new(move || Box::pin(delay())),
+        }
+    }
+}
+
+/// The calls that are waiting to be run under an action's [`DispatchPolicy`].
+struct DispatchQueue<I> {
+    policy: Option<DispatchPolicy>,
+    /// Increased by each debounced call, so that only the most recent one runs.
+    generation: usize,
+    /// Whether a throttled call has run, and the delay after it has not passed yet.
+    throttling: bool,
+    /// The most recent throttled call that is waiting for the delay to pass.
+    pending: SendOption<(I, oneshot::Receiver<()>)>,
+}
+
+impl<I> Default for DispatchQueue<I> {
+    fn default() -> Self {
+        Self {
+            policy: None,
+            generation: 0,
+            throttling: false,
+            pending: SendOption::new_local(None),
+        }
+    }
+}
+
 /// A handle that allows aborting an in-flight action. It is returned from [`Action::dispatch`] or
 /// [`ArcAction::dispatch`].
 #[derive(Debug)]
 pub struct ActionAbortHandle(oneshot::Sender<()>);
 
+/// The future of a single dispatched call.
+type BoxedCall<O> = Pin<Box<dyn Future<Output = O> + Send>>;
+
+/// A dispatched call that has not completed yet, which can be aborted by [`ArcAction::abort`].
+struct InFlightCall {
+    abort: AbortHandle,
+    /// Set once the call has either completed or been aborted, by whichever happens first.
+    settled: Arc<AtomicBool>,
+}
+
+impl<I, O> ArcAction<I, O>
+where
+    I: 'static,
+    O: 'static,
+{
+    /// Registers a new call, returning the future that runs it and its `settled` flag.
+    fn track_call(
+        &self,
+        fut: BoxedCall<O>,
+    ) -> (Abortable<BoxedCall<O>>, Arc<AtomicBool>) {
+        let (abort, registration) = AbortHandle::new_pair();
+        let settled = Arc::new(AtomicBool::new(false));
+        self.calls.update_value(|calls| {
+            calls.retain(|call| !call.settled.load(Ordering::Relaxed));
+            calls.push(InFlightCall {
+                abort,
+                settled: Arc::clone(&settled),
+            });
+        });
+        (Abortable::new(fut, registration), settled)
+    }
+
+    /// Aborts every call that has been dispatched and has not completed yet.
+    ///
+    /// The action's value is not updated, and [`pending`](Self::pending) is reset as soon as
+    /// this is called. The dispatched `Future`s are dropped rather than polled again: a server
+    /// function call made from the browser cancels its request with an
+    /// [`AbortController`](https://developer.mozilla.org/en-US/docs/Web/API/AbortController)
+    /// when it is dropped, but other side effects may still run (see
+    /// [`ActionAbortHandle::abort`]).
+    ///
+    /// This is useful for a search that is replaced by a new one, or for a long-running
+    /// operation that the user cancels.
+    #[track_caller]
+    pub fn abort(&self) {
+        let calls = self
+            .calls
+            .try_update_value(std::mem::take)
+            .unwrap_or_default();
+        for call in calls {
+            if !call.settled.swap(true, Ordering::Relaxed) {
+                call.abort.abort();
+                self.in_flight.update(|n| *n = n.saturating_sub(1));
+            }
+        }
+        if self.in_flight.get_untracked() == 0 {
+            self.input.try_update(|inp| **inp = None);
+        }
+    }
+}
+
 impl ActionAbortHandle {
     /// Aborts the action.
     ///
@@ -258,11 +422,23 @@ where
     O: Send + Sync + 'static,
 {
     /// Calls the `async` function with a reference to the input type as its argument.
+    ///
+    /// If the action has a [`DispatchPolicy`], the call may be delayed or coalesced with other
+    /// calls, and is then spawned on the current thread.
     #[track_caller]
     pub fn dispatch(&self, input: I) -> ActionAbortHandle {
-        let (abort_tx, mut abort_rx) = oneshot::channel();
+        let (abort_tx, abort_rx) = oneshot::channel();
+        match self.queue.with_value(|queue| queue.policy.clone()) {
+            Some(policy) => self.schedule(input, abort_rx, policy),
+            None => self.run(input, abort_rx),
+        }
+        ActionAbortHandle(abort_tx)
+    }
+
+    fn run(&self, input: I, mut abort_rx: oneshot::Receiver<()>) {
         if !is_suppressing_resource_load() {
-            let mut fut = (self.action_fn)(&input).fuse();
+            let (fut, settled) = self.track_call((self.action_fn)(&input));
+            let mut fut = fut.fuse();
 
             // Update the state before loading
             self.in_flight.update(|n| *n += 1);
@@ -280,15 +456,21 @@ where
                     select! {
                         // if the abort message has been sent, bail and do nothing
                         _ = abort_rx => {
-                            in_flight.update(|n| *n = n.saturating_sub(1));
+                            if !settled.swap(true, Ordering::Relaxed) {
+                                in_flight.update(|n| *n = n.saturating_sub(1));
+                            }
                         },
-                        // otherwise, update the value
+                        // otherwise, update the value, unless every call has been aborted
                         result = fut => {
-                            in_flight.update(|n| *n = n.saturating_sub(1));
-                            let is_latest = dispatched.get_value() <= current_version;
-                            if is_latest {
-                                version.update(|n| *n += 1);
-                                value.update(|n| **n = Some(result));
+                            if let Ok(result) = result {
+                                if !settled.swap(true, Ordering::Relaxed) {
+                                    in_flight.update(|n| *n = n.saturating_sub(1));
+                                    let is_latest = dispatched.get_value() <= current_version;
+                                    if is_latest {
+                                        version.update(|n| *n += 1);
+                                        value.update(|n| **n = Some(result));
+                                    }
+                                }
                             }
                         }
                     }
@@ -298,8 +480,6 @@ where
                 }
             });
         }
-
-        ActionAbortHandle(abort_tx)
     }
 }
 
@@ -310,11 +490,122 @@ where
 {
     /// Calls the `async` function with a reference to the input type as its argument,
     /// ensuring that it is spawned on the current thread.
+    ///
+    /// If the action has a [`DispatchPolicy`], the call may be delayed or coalesced with other
+    /// calls.
     #[track_caller]
     pub fn dispatch_local(&self, input: I) -> ActionAbortHandle {
-        let (abort_tx, mut abort_rx) = oneshot::channel();
+        let (abort_tx, abort_rx) = oneshot::channel();
+        match self.queue.with_value(|queue| queue.policy.clone()) {
+            Some(policy) => self.schedule(input, abort_rx, policy),
+            None => self.run_local(input, abort_rx),
+        }
+        ActionAbortHandle(abort_tx)
+    }
+
+    /// Calls the `async` function once `delay` has passed, unless this is called again before
+    /// then, in which case only the most recent input is used. This is useful for actions that
+    /// are dispatched on every keystroke, like autosaving a draft.
+    ///
+    /// This ignores the action's [`DispatchPolicy`], and spawns the call on the current thread.
+    /// `leptos::timing` provides a `dispatch_debounced` method that takes a [`Duration`](std::time::Duration).
+    #[track_caller]
+    pub fn dispatch_debounced_with(
+        &self,
+        input: I,
+        delay: impl Future<Output = ()> + 'static,
+    ) -> ActionAbortHandle {
+        let (abort_tx, abort_rx) = oneshot::channel();
+        self.debounce(input, abort_rx, Box::pin(delay));
+        ActionAbortHandle(abort_tx)
+    }
+
+    /// Sets the [`DispatchPolicy`] that applies to every later call to
+    /// [`dispatch`](Self::dispatch) or [`dispatch_local`](Self::dispatch_local).
+    pub fn with_dispatch_policy(self, policy: DispatchPolicy) -> Self {
+        self.queue.update_value(|queue| queue.policy = Some(policy));
+        self
+    }
+
+    fn schedule(
+        &self,
+        input: I,
+        abort_rx: oneshot::Receiver<()>,
+        policy: DispatchPolicy,
+    ) {
+        match policy.mode {
+            DispatchMode::Debounce => {
+                self.debounce(input, abort_rx, (policy.delay)())
+            }
+            DispatchMode::Throttle => {
+                let throttling = self
+                    .queue
+                    .try_update_value(|queue| {
+                        if queue.throttling {
+                            *queue.pending = Some((input, abort_rx));
+                            None
+                        } else {
+                            queue.throttling = true;
+                            Some((input, abort_rx))
+                        }
+                    })
+                    .flatten();
+                // the first call runs immediately, and the latest of the calls made while
+                // waiting runs once the delay has passed
+                let Some((input, abort_rx)) = throttling else {
+                    return;
+                };
+                self.run_local(input, abort_rx);
+                let this = self.clone();
+                Executor::spawn_local(async move {
+                    loop {
+                        (policy.delay)().await;
+                        let pending = this
+                            .queue
+                            .try_update_value(|queue| {
+                                let pending = (*queue.pending).take();
+                                queue.throttling = pending.is_some();
+                                pending
+                            })
+                            .flatten();
+                        match pending {
+                            Some((input, abort_rx)) => {
+                                this.run_local(input, abort_rx)
+                            }
+                            None => break,
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    fn debounce(
+        &self,
+        input: I,
+        abort_rx: oneshot::Receiver<()>,
+        delay: Pin<Box<dyn Future<Output = ()>>>,
+    ) {
+        let Some(generation) = self.queue.try_update_value(|queue| {
+            queue.generation += 1;
+            queue.generation
+        }) else {
+            return;
+        };
+        let this = self.clone();
+        Executor::spawn_local(async move {
+            delay.await;
+            // only the most recent call runs
+            if this.queue.with_value(|queue| queue.generation) == generation {
+                this.run_local(input, abort_rx);
+            }
+        });
+    }
+
+    fn run_local(&self, input: I, mut abort_rx: oneshot::Receiver<()>) {
         if !is_suppressing_resource_load() {
-            let mut fut = (self.action_fn)(&input).fuse();
+            let (fut, settled) = self.track_call((self.action_fn)(&input));
+            let mut fut = fut.fuse();
 
             // Update the state before loading
             self.in_flight.update(|n| *n += 1);
@@ -332,15 +623,21 @@ where
                     select! {
                         // if the abort message has been sent, bail and do nothing
                         _ = abort_rx => {
-                            in_flight.update(|n| *n = n.saturating_sub(1));
+                            if !settled.swap(true, Ordering::Relaxed) {
+                                in_flight.update(|n| *n = n.saturating_sub(1));
+                            }
                         },
-                        // otherwise, update the value
+                        // otherwise, update the value, unless every call has been aborted
                         result = fut => {
-                            in_flight.update(|n| *n = n.saturating_sub(1));
-                            let is_latest = dispatched.get_value() <= current_version;
-                            if is_latest {
-                                version.update(|n| *n += 1);
-                                value.update(|n| **n = Some(result));
+                            if let Ok(result) = result {
+                                if !settled.swap(true, Ordering::Relaxed) {
+                                    in_flight.update(|n| *n = n.saturating_sub(1));
+                                    let is_latest = dispatched.get_value() <= current_version;
+                                    if is_latest {
+                                        version.update(|n| *n += 1);
+                                        value.update(|n| **n = Some(result));
+                                    }
+                                }
                             }
                         }
                     }
@@ -350,7 +647,6 @@ where
                 }
             });
         }
-        ActionAbortHandle(abort_tx)
     }
 }
 
@@ -389,6 +685,8 @@ where
             value: ArcRwSignal::new(SendOption::new_local(value)),
             version: Default::default(),
             dispatched: Default::default(),
+            calls: Default::default(),
+            queue: Default::default(),
             action_fn: Arc::new(move |input| {
                 let fut = untrack(|| action_fn(input));
                 match weak_owner.as_ref().and_then(|w| w.upgrade()) {
@@ -980,6 +1278,103 @@ where
     }
 }
 
+impl<I, O> Action<I, O>
+where
+    I: 'static,
+    O: 'static,
+{
+    /// Calls the `async` function once `delay` has passed, unless this is called again before
+    /// then, in which case only the most recent input is used.
+    ///
+    /// See [`ArcAction::dispatch_debounced_with`] for details.
+    #[track_caller]
+    pub fn dispatch_debounced_with(
+        &self,
+        input: I,
+        delay: impl Future<Output = ()> + 'static,
+    ) -> ActionAbortHandle {
+        self.inner
+            .try_get_value()
+            .map(|inner| inner.dispatch_debounced_with(input, delay))
+            .unwrap_or_else(unwrap_signal!(self))
+    }
+
+    /// Sets the [`DispatchPolicy`] that applies to every later call to
+    /// [`dispatch`](Self::dispatch) or [`dispatch_local`](Self::dispatch_local).
+    ///
+    /// ```rust
+    /// # use reactive_graph::actions::*;
+    /// # use reactive_graph::prelude::*;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// # let _guard = reactive_graph::diagnostics::SpecialNonReactiveZone::enter();
+    /// use std::time::Duration;
+    ///
+    /// let autosave = Action::new(|draft: &String| {
+    ///     let draft = draft.to_owned();
+    ///     async move { draft }
+    /// })
+    /// .with_dispatch_policy(DispatchPolicy::debounce_with(|| {
+    ///     tokio::time::sleep(Duration::from_millis(10))
+    /// }));
+    ///
+    /// autosave.dispatch("H".to_string());
+    /// autosave.dispatch("He".to_string());
+    /// autosave.dispatch("Hello".to_string());
+    /// # tokio::time::sleep(Duration::from_millis(30)).await;
+    ///
+    /// // only the last draft was saved
+    /// assert_eq!(autosave.version().get(), 1);
+    /// assert_eq!(autosave.value().get(), Some("Hello".to_string()));
+    /// # }).await;
+    /// # });
+    /// ```
+    pub fn with_dispatch_policy(self, policy: DispatchPolicy) -> Self {
+        if let Some(inner) = self.inner.try_get_value() {
+            _ = inner.with_dispatch_policy(policy);
+        }
+        self
+    }
+
+    /// Aborts every call that has been dispatched and has not completed yet.
+    ///
+    /// See [`ArcAction::abort`] for details.
+    ///
+    /// ```rust
+    /// # use reactive_graph::actions::*;
+    /// # use reactive_graph::prelude::*;
+    /// # tokio_test::block_on(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// # let _guard = reactive_graph::diagnostics::SpecialNonReactiveZone::enter();
+    /// let search = Action::new(|query: &String| {
+    ///     let query = query.to_owned();
+    ///     async move {
+    ///         tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    ///         query.len()
+    ///     }
+    /// });
+    /// let pending = search.pending();
+    ///
+    /// search.dispatch("leptos".to_string());
+    /// assert_eq!(pending.get(), true);
+    ///
+    /// search.abort();
+    /// assert_eq!(pending.get(), false);
+    ///
+    /// # tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    /// // the aborted call never sets the value
+    /// assert_eq!(search.value().get(), None);
+    /// # });
+    /// ```
+    #[track_caller]
+    pub fn abort(&self) {
+        self.inner
+            .try_with_value(|inner| inner.abort())
+            .unwrap_or_else(unwrap_signal!(self))
+    }
+}
+
 impl<I, O> Action<I, O>
 where
     I: 'static,
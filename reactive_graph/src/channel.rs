@@ -8,7 +8,7 @@ use std::{
     task::{Context, Poll},
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct Sender(Arc<Inner>);
 
 #[derive(Debug)]
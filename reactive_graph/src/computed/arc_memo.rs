@@ -159,6 +159,11 @@ where
                 weak.as_ptr() as usize,
                 Weak::clone(weak) as Weak<dyn Subscriber + Send + Sync>,
             );
+            #[cfg(debug_assertions)]
+            crate::graph::record_node(
+                &subscriber,
+                crate::graph::GraphNodeKind::Computed,
+            );
 
             MemoInner::new(Arc::new(fun), subscriber)
         });
@@ -183,6 +188,72 @@ where
     }
 }
 
+impl<T, S> ArcMemo<T, S>
+where
+    S: Storage<T>,
+{
+    /// Creates a [`WeakMemo`] that refers to this memo without keeping it alive.
+    pub fn downgrade(&self) -> WeakMemo<T, S> {
+        WeakMemo {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: self.defined_at,
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+/// A weak reference to an [`ArcMemo`], which does not keep the memo or its value alive.
+///
+/// This is useful for long-lived caches or registries that refer to memos owned by components.
+/// Use [`upgrade`](WeakMemo::upgrade) to access the memo, if it still exists.
+pub struct WeakMemo<T, S = SyncStorage>
+where
+    S: Storage<T>,
+{
+    #[cfg(any(debug_assertions, leptos_debuginfo))]
+    defined_at: &'static Location<'static>,
+    inner: Weak<MemoInner<T, S>>,
+}
+
+impl<T, S> WeakMemo<T, S>
+where
+    S: Storage<T>,
+{
+    /// Returns the memo, if any strong reference to it still exists.
+    pub fn upgrade(&self) -> Option<ArcMemo<T, S>> {
+        Some(ArcMemo {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: self.defined_at,
+            inner: self.inner.upgrade()?,
+        })
+    }
+}
+
+impl<T, S> Clone for WeakMemo<T, S>
+where
+    S: Storage<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: self.defined_at,
+            inner: Weak::clone(&self.inner),
+        }
+    }
+}
+
+impl<T, S> Debug for WeakMemo<T, S>
+where
+    S: Storage<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeakMemo")
+            .field("type", &std::any::type_name::<T>())
+            .field("data", &Weak::as_ptr(&self.inner))
+            .finish()
+    }
+}
+
 impl<T, S> DefinedAt for ArcMemo<T, S>
 where
     S: Storage<T>,
@@ -1,6 +1,6 @@
 use super::{
     inner::{ArcAsyncDerivedInner, AsyncDerivedState},
-    AsyncDerivedReadyFuture, ScopedFuture,
+    AsyncDerivedReadyFuture, AsyncState, ScopedFuture,
 };
 #[cfg(feature = "sandboxed-arenas")]
 use crate::owner::Sandboxed;
@@ -235,7 +235,8 @@ macro_rules! spawn_derived {
             state: AsyncDerivedState::Clean,
             version: 0,
             suspenses: Vec::new(),
-            pending_suspenses: Vec::new()
+            pending_suspenses: Vec::new(),
+            loading_trigger: ArcTrigger::new(),
         }));
         let value = Arc::new(AsyncRwLock::new($initial));
         let wakers = Arc::new(RwLock::new(Vec::new()));
@@ -249,6 +250,11 @@ macro_rules! spawn_derived {
             loading: Arc::new(AtomicBool::new(!is_ready)),
         };
         let any_subscriber = this.to_any_subscriber();
+        #[cfg(debug_assertions)]
+        crate::graph::record_node(
+            &any_subscriber,
+            crate::graph::GraphNodeKind::Computed,
+        );
         let initial_fut = if $should_track {
             owner.with_cleanup(|| {
                 any_subscriber
@@ -364,6 +370,8 @@ macro_rules! spawn_derived {
 
                                     // generate and assign new value
                                     loading.store(true, Ordering::Relaxed);
+                                    let loading_trigger = inner.read().or_poisoned().loading_trigger.clone();
+                                    loading_trigger.notify();
 
                                     let this_version = {
                                         let mut guard = inner.write().or_poisoned();
@@ -597,6 +605,74 @@ impl<T: 'static> ArcAsyncDerived<T> {
     }
 }
 
+impl<T: Clone + 'static> ArcAsyncDerived<T> {
+    /// Returns the current [`AsyncState`], tracking both the value and whether it is loading.
+    ///
+    /// Unlike reading the value, this does not register with a `Suspense`, and it
+    /// distinguishes the first load ([`AsyncState::Loading`]) from revalidation
+    /// ([`AsyncState::Reloading`]).
+    #[track_caller]
+    pub fn state(&self) -> AsyncState<T> {
+        self.track_state(|value| value.cloned().map(Ok))
+    }
+
+    fn track_state<U, E>(
+        &self,
+        fun: impl FnOnce(Option<&T>) -> Option<Result<U, E>>,
+    ) -> AsyncState<U, E> {
+        let loading_trigger =
+            self.inner.read().or_poisoned().loading_trigger.clone();
+        loading_trigger.track();
+        self.track();
+        let loading = self.loading.load(Ordering::Relaxed);
+        let value = self.value.blocking_read();
+        AsyncState::new(fun(value.as_ref()), loading)
+    }
+}
+
+impl<T, E> ArcAsyncDerived<Result<T, E>>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    /// Returns the current [`AsyncState`], tracking both the value and whether it is loading.
+    ///
+    /// If the async computation returns an error, the state is [`AsyncState::Error`].
+    ///
+    /// ```rust
+    /// # use reactive_graph::computed::*;
+    /// # use reactive_graph::signal::*;
+    /// # use reactive_graph::prelude::*;
+    /// # tokio_test::block_on(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// let id = RwSignal::new(1);
+    /// let user = ArcAsyncDerived::new(move || async move {
+    ///     // stands in for a request that takes some time
+    ///     any_spawner::Executor::tick().await;
+    ///     let id = id.get();
+    ///     if id > 0 {
+    ///         Ok(format!("user {id}"))
+    ///     } else {
+    ///         Err("invalid id")
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(user.try_state(), AsyncState::Loading);
+    /// user.ready().await;
+    /// assert_eq!(user.try_state(), AsyncState::Ready("user 1".to_string()));
+    ///
+    /// id.set(0);
+    /// any_spawner::Executor::tick().await;
+    /// user.ready().await;
+    /// assert_eq!(user.try_state(), AsyncState::Error("invalid id"));
+    /// # });
+    /// ```
+    #[track_caller]
+    pub fn try_state(&self) -> AsyncState<T, E> {
+        self.track_state(|value| value.cloned())
+    }
+}
+
 impl<T: 'static> ArcAsyncDerived<T> {
     #[doc(hidden)]
     #[track_caller]
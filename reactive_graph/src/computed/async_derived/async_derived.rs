@@ -1,4 +1,6 @@
-use super::{ArcAsyncDerived, AsyncDerivedReadyFuture, BlockingLock};
+use super::{
+    ArcAsyncDerived, AsyncDerivedReadyFuture, AsyncState, BlockingLock,
+};
 use crate::{
     graph::{
         AnySource, AnySubscriber, ReactiveNode, Source, Subscriber,
@@ -281,6 +283,39 @@ where
     }
 }
 
+impl<T, S> AsyncDerived<T, S>
+where
+    T: Clone + 'static,
+    S: Storage<ArcAsyncDerived<T>>,
+{
+    /// Returns the current [`AsyncState`], tracking both the value and whether it is loading.
+    ///
+    /// See [`ArcAsyncDerived::state`].
+    #[track_caller]
+    pub fn state(&self) -> AsyncState<T> {
+        self.inner
+            .try_with_value(ArcAsyncDerived::state)
+            .unwrap_or(AsyncState::Loading)
+    }
+}
+
+impl<T, E, S> AsyncDerived<Result<T, E>, S>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+    S: Storage<ArcAsyncDerived<Result<T, E>>>,
+{
+    /// Returns the current [`AsyncState`], tracking both the value and whether it is loading.
+    ///
+    /// See [`ArcAsyncDerived::try_state`].
+    #[track_caller]
+    pub fn try_state(&self) -> AsyncState<T, E> {
+        self.inner
+            .try_with_value(ArcAsyncDerived::try_state)
+            .unwrap_or(AsyncState::Loading)
+    }
+}
+
 impl<T, S> Copy for AsyncDerived<T, S> {}
 
 impl<T, S> Clone for AsyncDerived<T, S> {
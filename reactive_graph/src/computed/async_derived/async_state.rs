@@ -0,0 +1,65 @@
+use std::convert::Infallible;
+
+/// The state of an async derived value, as returned by
+/// [`ArcAsyncDerived::state`](super::ArcAsyncDerived::state) and
+/// [`ArcAsyncDerived::try_state`](super::ArcAsyncDerived::try_state).
+///
+/// Unlike reading the value as an `Option<T>`, this distinguishes the first load from
+/// revalidation, so that a view can continue showing the previous value while a new one loads.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AsyncState<T, E = Infallible> {
+    /// The first value is loading, and there is no previous value to show.
+    Loading,
+    /// A new value is loading. Contains the previous value.
+    Reloading(T),
+    /// The value has finished loading.
+    Ready(T),
+    /// The value has finished loading, but returned an error.
+    Error(E),
+}
+
+impl<T, E> AsyncState<T, E> {
+    /// Creates the state from the latest value and whether a new value is currently loading.
+    pub fn new(value: Option<Result<T, E>>, loading: bool) -> Self {
+        match (value, loading) {
+            (Some(Ok(value)), true) => AsyncState::Reloading(value),
+            (Some(Ok(value)), false) => AsyncState::Ready(value),
+            (Some(Err(error)), false) => AsyncState::Error(error),
+            (Some(Err(_)), true) | (None, _) => AsyncState::Loading,
+        }
+    }
+
+    /// Whether a value is currently loading, either for the first time or as a reload.
+    pub fn is_loading(&self) -> bool {
+        matches!(self, AsyncState::Loading | AsyncState::Reloading(_))
+    }
+
+    /// Returns the current value, if any. This is either the previous value while reloading, or
+    /// the value that has finished loading.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            AsyncState::Reloading(value) | AsyncState::Ready(value) => {
+                Some(value)
+            }
+            AsyncState::Loading | AsyncState::Error(_) => None,
+        }
+    }
+
+    /// Returns the error, if the value has finished loading with an error.
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            AsyncState::Error(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Maps the value, if any, to a new value.
+    pub fn map<U>(self, fun: impl FnOnce(T) -> U) -> AsyncState<U, E> {
+        match self {
+            AsyncState::Loading => AsyncState::Loading,
+            AsyncState::Reloading(value) => AsyncState::Reloading(fun(value)),
+            AsyncState::Ready(value) => AsyncState::Ready(fun(value)),
+            AsyncState::Error(error) => AsyncState::Error(error),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
         SubscriberSet,
     },
     owner::Owner,
+    signal::ArcTrigger,
 };
 use or_poisoned::OrPoisoned;
 use std::sync::RwLock;
@@ -24,6 +25,8 @@ pub(crate) struct ArcAsyncDerivedInner {
     pub version: usize,
     pub suspenses: Vec<SuspenseContext>,
     pub pending_suspenses: Vec<TaskHandle>,
+    // notified when a new value starts loading, so that the loading state can be tracked
+    pub loading_trigger: ArcTrigger,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -2,6 +2,7 @@ mod arc_async_derived;
 pub use arc_async_derived::*;
 #[allow(clippy::module_inception)] // not a pub mod, who cares?
 mod async_derived;
+mod async_state;
 mod future_impls;
 mod inner;
 use crate::{
@@ -9,6 +10,7 @@ use crate::{
     owner::Owner,
 };
 pub use async_derived::*;
+pub use async_state::*;
 pub use future_impls::*;
 use futures::Future;
 use pin_project_lite::pin_project;
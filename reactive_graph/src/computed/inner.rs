@@ -137,6 +137,11 @@ where
                 })
             });
 
+            crate::devtools_event!(MemoRecomputed {
+                id: any_subscriber.0,
+                changed
+            });
+
             // Two locks are acquired, so order matters.
             let reactivity_lock = self.reactivity.write().or_poisoned();
             {
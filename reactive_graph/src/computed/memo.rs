@@ -191,7 +191,8 @@ where
     /// allows you to compare the old and new values using any criteria.
     ///
     /// `changed` should be a function that returns `true` if the new value is different from the
-    /// old value.
+    /// old value. This does not require `T: PartialEq`: passing `|_, _| true` will notify
+    /// subscribers every time the memo is recalculated.
     pub fn new_with_compare(
         fun: impl Fn(Option<&T>) -> T + Send + Sync + 'static,
         changed: fn(Option<&T>, Option<&T>) -> bool,
@@ -1,6 +1,6 @@
 use crate::{
     effect::RenderEffect,
-    signal::ArcRwSignal,
+    signal::{ArcReadSignal, ArcRwSignal},
     traits::{Track, Update},
 };
 use or_poisoned::OrPoisoned;
@@ -17,6 +17,10 @@ use std::{
 /// in certain situations (e.g., “set the class `selected` if `selected() == this_row_index`)
 /// because it reduces them from `O(n)` to `O(1)`.
 ///
+/// Each key has its own boolean signal, which is only notified when that key's selected state
+/// flips. You can read it using [`selected`](Selector::selected), or take the signal itself with
+/// [`for_key`](Selector::for_key).
+///
 /// ```
 /// # use reactive_graph::computed::*;
 /// # use reactive_graph::signal::*; let owner = reactive_graph::owner::Owner::new(); owner.set();
@@ -101,12 +105,13 @@ where
                 *v.write().or_poisoned() = Some(next_value.clone());
                 if prev.as_ref() != Some(&next_value) {
                     for (key, signal) in &*subs.read().or_poisoned() {
-                        if f(key, &next_value)
-                            || (prev.is_some()
-                                && f(key, prev.as_ref().unwrap()))
-                        {
-                            signal.update(|n| *n = true);
-                        }
+                        let selected = f(key, &next_value);
+                        // only notify keys whose selected state has flipped
+                        signal.maybe_update(|prev| {
+                            let changed = *prev != selected;
+                            *prev = selected;
+                            changed
+                        });
                     }
                 }
                 next_value
@@ -118,21 +123,35 @@ where
 
     /// Reactively checks whether the given key is selected.
     pub fn selected(&self, key: &T) -> bool {
-        let read = {
-            let sub = self.subs.read().or_poisoned().get(key).cloned();
-            sub.unwrap_or_else(|| {
-                self.subs
-                    .write()
-                    .or_poisoned()
-                    .entry(key.clone())
-                    .or_insert_with(|| ArcRwSignal::new(false))
-                    .clone()
-            })
-        };
-        read.track();
+        self.signal(key).track();
         (self.f)(key, self.v.read().or_poisoned().as_ref().unwrap())
     }
 
+    /// Returns a signal that is `true` while the given key is selected.
+    ///
+    /// The signal is only notified when the key's selected state flips, so that each of many
+    /// rows can subscribe to its own key without rerunning when another row is selected.
+    pub fn for_key(&self, key: &T) -> ArcReadSignal<bool> {
+        self.signal(key).read_only()
+    }
+
+    fn signal(&self, key: &T) -> ArcRwSignal<bool> {
+        let sub = self.subs.read().or_poisoned().get(key).cloned();
+        sub.unwrap_or_else(|| {
+            self.subs
+                .write()
+                .or_poisoned()
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    ArcRwSignal::new((self.f)(
+                        key,
+                        self.v.read().or_poisoned().as_ref().unwrap(),
+                    ))
+                })
+                .clone()
+        })
+    }
+
     /// Removes the listener for the given key.
     pub fn remove(&self, key: &T) {
         let mut subs = self.subs.write().or_poisoned();
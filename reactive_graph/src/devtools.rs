@@ -0,0 +1,118 @@
+//! An optional stream of events that describes changes to the reactive graph as they happen.
+//!
+//! This is intended to be consumed by developer tools (like a browser extension) that visualize
+//! the live reactive graph: which signals exist, how often they are updated, which effects and
+//! memos run in response, and how owners are nested.
+//!
+//! Events are only emitted once a hook has been registered with [`set_devtools_hook`], so the
+//! cost of the `devtools` feature is a single check per event when no tool is listening.
+//!
+//! ```rust
+//! use reactive_graph::{
+//!     devtools::{set_devtools_hook, DevtoolsEvent},
+//!     prelude::*,
+//!     signal::RwSignal,
+//! };
+//! use std::sync::{Arc, Mutex};
+//!
+//! let events = Arc::new(Mutex::new(Vec::new()));
+//! set_devtools_hook({
+//!     let events = Arc::clone(&events);
+//!     move |event| events.lock().unwrap().push(event.clone())
+//! })
+//! .map_err(|_| "a devtools hook has already been set")
+//! .unwrap();
+//!
+//! # let owner = reactive_graph::owner::Owner::new(); owner.set();
+//! let count = RwSignal::new(0);
+//! count.set(1);
+//!
+//! let events = events.lock().unwrap();
+//! assert!(events
+//!     .iter()
+//!     .any(|event| matches!(event, DevtoolsEvent::SignalUpdated { .. })));
+//! ```
+
+use std::sync::OnceLock;
+
+/// An event describing a change to the reactive graph.
+///
+/// Each node is identified by an `id` that is stable for its lifetime. For signals, memos, and
+/// effects, this matches the id of the corresponding [`AnySource`](crate::graph::AnySource) or
+/// [`AnySubscriber`](crate::graph::AnySubscriber), and for owners, their
+/// [`Owner::debug_id`](crate::owner::Owner::debug_id). Ids may be reused after a node is dropped.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "type", rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum DevtoolsEvent {
+    /// A signal was created.
+    SignalCreated {
+        /// The id of the signal.
+        id: usize,
+        /// The location at which the signal was created, in debug mode.
+        defined_at: Option<String>,
+    },
+    /// A signal was updated, and will notify its subscribers.
+    SignalUpdated {
+        /// The id of the signal.
+        id: usize,
+    },
+    /// A memo recalculated its value.
+    MemoRecomputed {
+        /// The id of the memo.
+        id: usize,
+        /// Whether the new value is different from the previous value.
+        changed: bool,
+    },
+    /// An effect ran.
+    EffectRan {
+        /// The id of the effect.
+        id: usize,
+    },
+    /// A reactive owner was created.
+    OwnerCreated {
+        /// The id of the owner.
+        id: usize,
+        /// The id of the parent owner, if any.
+        parent: Option<usize>,
+    },
+    /// A reactive owner was dropped, disposing of everything it owned.
+    OwnerDisposed {
+        /// The id of the owner.
+        id: usize,
+    },
+}
+
+type DevtoolsHook = Box<dyn Fn(&DevtoolsEvent) + Send + Sync>;
+
+static HOOK: OnceLock<DevtoolsHook> = OnceLock::new();
+
+/// Registers the function that receives every [`DevtoolsEvent`].
+///
+/// The hook is called synchronously whenever the graph changes, so it should do as little work
+/// as possible, like forwarding the event over a channel to the tool that displays it.
+///
+/// The hook can only be set once; if one has already been set, the new hook is returned as an
+/// error.
+pub fn set_devtools_hook(
+    hook: impl Fn(&DevtoolsEvent) + Send + Sync + 'static,
+) -> Result<(), DevtoolsHook> {
+    HOOK.set(Box::new(hook))
+}
+
+/// Whether a hook has been registered with [`set_devtools_hook`].
+pub fn is_enabled() -> bool {
+    HOOK.get().is_some()
+}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn emit(event: impl FnOnce() -> DevtoolsEvent) {
+    if let Some(hook) = HOOK.get() {
+        hook(&event());
+    }
+}
@@ -5,11 +5,13 @@ mod effect;
 mod effect_function;
 mod immediate;
 mod inner;
+mod priority;
 mod render_effect;
 
 pub use effect::*;
 pub use effect_function::*;
 pub use immediate::*;
+pub use priority::*;
 pub use render_effect::*;
 
 /// Creates a new render effect, which immediately runs `fun`.
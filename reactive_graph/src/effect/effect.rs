@@ -1,18 +1,25 @@
 use crate::{
     channel::{channel, Receiver},
-    effect::{inner::EffectInner, EffectFunction},
+    effect::{
+        inner::EffectInner, EffectDependencies, EffectFunction, EffectPriority,
+    },
     graph::{
-        AnySubscriber, ReactiveNode, SourceSet, Subscriber, ToAnySubscriber,
-        WithObserver,
+        untrack, AnySubscriber, ReactiveNode, SourceSet, Subscriber,
+        ToAnySubscriber, WithObserver,
     },
     owner::{ArenaItem, LocalStorage, Owner, Storage, SyncStorage},
     traits::Dispose,
 };
 use any_spawner::Executor;
-use futures::StreamExt;
+use futures::{
+    future::{select, Either},
+    StreamExt,
+};
 use or_poisoned::OrPoisoned;
 use std::{
+    future::Future,
     mem,
+    pin::pin,
     sync::{atomic::AtomicBool, Arc, RwLock},
 };
 
@@ -171,6 +178,7 @@ impl Effect<LocalStorage> {
     {
         let inner = cfg!(feature = "effects").then(|| {
             let (mut rx, owner, inner) = effect_base();
+            let priority = EffectPriority::current();
             let value = Arc::new(RwLock::new(None::<T>));
             let mut first_run = true;
 
@@ -180,6 +188,7 @@ impl Effect<LocalStorage> {
 
                 async move {
                     while rx.next().await.is_some() {
+                        let _lane = priority.wait_for_turn().await;
                         if !owner.paused()
                             && (subscriber.with_observer(|| {
                                 subscriber.update_if_necessary()
@@ -187,6 +196,9 @@ impl Effect<LocalStorage> {
                         {
                             first_run = false;
                             subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
 
                             let old_value =
                                 mem::take(&mut *value.write().or_poisoned());
@@ -310,6 +322,42 @@ impl Effect<LocalStorage> {
     /// # }).await;
     /// # });
     /// ```
+    ///
+    /// ## Cleanups and Stopping
+    ///
+    /// The `handler` runs in the effect's own reactive [`Owner`], so any cleanup functions it
+    /// registers with [`on_cleanup`](crate::owner::on_cleanup) run before the handler runs
+    /// again. Calling [`stop`](Effect::stop) on the returned effect stops watching, and runs any
+    /// remaining cleanups once the effect's task has been shut down.
+    ///
+    /// ```
+    /// # use reactive_graph::effect::Effect;
+    /// # use reactive_graph::traits::*;
+    /// # use reactive_graph::signal::signal;
+    /// # use reactive_graph::owner::on_cleanup;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// #
+    /// let (id, set_id) = signal(0);
+    ///
+    /// let watcher = Effect::watch(
+    ///     move || id.get(),
+    ///     move |id, _, _| {
+    ///         let id = *id;
+    ///         // log::debug!("subscribing to {id}");
+    ///         on_cleanup(move || {
+    ///             // log::debug!("unsubscribing from {id}");
+    ///         });
+    ///     },
+    ///     true,
+    /// );
+    ///
+    /// set_id.set(1); // > "unsubscribing from 0", "subscribing to 1"
+    /// watcher.stop(); // > "unsubscribing from 1"
+    /// # }).await;
+    /// # });
+    /// ```
     pub fn watch<D, T>(
         mut dependency_fn: impl FnMut() -> D + 'static,
         mut handler: impl FnMut(&D, Option<&D>, Option<T>) -> T + 'static,
@@ -321,6 +369,7 @@ impl Effect<LocalStorage> {
     {
         let inner = cfg!(feature = "effects").then(|| {
             let (mut rx, owner, inner) = effect_base();
+            let priority = EffectPriority::current();
             let mut first_run = true;
             let dep_value = Arc::new(RwLock::new(None::<D>));
             let watch_value = Arc::new(RwLock::new(None::<T>));
@@ -332,12 +381,16 @@ impl Effect<LocalStorage> {
 
                 async move {
                     while rx.next().await.is_some() {
+                        let _lane = priority.wait_for_turn().await;
                         if !owner.paused()
                             && (subscriber.with_observer(|| {
                                 subscriber.update_if_necessary()
                             }) || first_run)
                         {
                             subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
 
                             let old_dep_value = mem::take(
                                 &mut *dep_value.write().or_poisoned(),
@@ -351,11 +404,13 @@ impl Effect<LocalStorage> {
                             );
 
                             if immediate || !first_run {
-                                let new_watch_value = handler(
-                                    &new_dep_value,
-                                    old_dep_value.as_ref(),
-                                    old_watch_value,
-                                );
+                                let new_watch_value = owner.with(|| {
+                                    handler(
+                                        &new_dep_value,
+                                        old_dep_value.as_ref(),
+                                        old_watch_value,
+                                    )
+                                });
 
                                 *watch_value.write().or_poisoned() =
                                     Some(new_watch_value);
@@ -375,6 +430,222 @@ impl Effect<LocalStorage> {
 
         Self { inner }
     }
+
+    /// Creates an effect that depends only on an explicit list of reactive values, and runs
+    /// again whenever one of them changes.
+    ///
+    /// Unlike [`Effect::new`], nothing that is read inside `fun` is tracked. This is useful when
+    /// the effect calls code that reads signals you do not want it to depend on, like a
+    /// third-party library.
+    ///
+    /// The dependencies can be a tuple, like `(a,)` or `(a, b)`, or an array or vector of
+    /// values of the same type. See [`EffectDependencies`].
+    ///
+    /// ```
+    /// # use reactive_graph::effect::Effect;
+    /// # use reactive_graph::traits::*;
+    /// # use reactive_graph::signal::signal;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// #
+    /// let (a, set_a) = signal(0);
+    /// let (b, set_b) = signal(0);
+    ///
+    /// Effect::new_with_deps((a,), move || {
+    ///     // log::debug!("a = {}, b = {}", a.get(), b.get());
+    /// });
+    ///
+    /// set_a.set(1); // > "a = 1, b = 0"
+    /// set_b.set(1); // (nothing happens)
+    /// # }).await;
+    /// # });
+    /// ```
+    pub fn new_with_deps<T, M>(
+        deps: impl EffectDependencies + 'static,
+        mut fun: impl EffectFunction<T, M> + 'static,
+    ) -> Self
+    where
+        T: 'static,
+    {
+        Effect::watch(
+            move || deps.track_all(),
+            move |_, _, prev| untrack(|| run_in_effect_scope(|| fun.run(prev))),
+            true,
+        )
+    }
+
+    /// Creates an effect that waits until its dependencies have stopped changing before
+    /// running again.
+    ///
+    /// The effect runs once on the next “tick,” like [`Effect::new`]. After that, whenever a
+    /// dependency changes, it waits for the future returned by `delay` to resolve. If another
+    /// dependency changes while it is waiting, it starts waiting again, so that a burst of
+    /// changes only causes the effect to run once.
+    ///
+    /// `delay` will usually return a timer from the async runtime; the `leptos` crate provides
+    /// `Effect::debounced`, which uses browser timers.
+    ///
+    /// ```
+    /// # use reactive_graph::effect::Effect;
+    /// # use reactive_graph::traits::*;
+    /// # use reactive_graph::signal::signal;
+    /// # use std::time::Duration;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// let (query, set_query) = signal(String::new());
+    ///
+    /// Effect::debounced_with(
+    ///     || tokio::time::sleep(Duration::from_millis(250)),
+    ///     move || {
+    ///         // only runs once the query has not changed for 250ms
+    ///         let query = query.get();
+    ///     },
+    /// );
+    /// # }).await;
+    /// # });
+    /// ```
+    pub fn debounced_with<T, M, Fut>(
+        mut delay: impl FnMut() -> Fut + 'static,
+        mut fun: impl EffectFunction<T, M> + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let inner = cfg!(feature = "effects").then(|| {
+            let (mut rx, owner, inner) = effect_base();
+            let value = Arc::new(RwLock::new(None::<T>));
+            let mut first_run = true;
+
+            Executor::spawn_local({
+                let value = Arc::clone(&value);
+                let subscriber = inner.to_any_subscriber();
+
+                async move {
+                    while rx.next().await.is_some() {
+                        // restart the delay each time a dependency changes
+                        if !first_run {
+                            loop {
+                                let delay = pin!(delay());
+                                match select(rx.next(), delay).await {
+                                    Either::Left((Some(_), _)) => continue,
+                                    Either::Left((None, _)) => return,
+                                    Either::Right(_) => break,
+                                }
+                            }
+                        }
+
+                        if !owner.paused()
+                            && (subscriber.with_observer(|| {
+                                subscriber.update_if_necessary()
+                            }) || first_run)
+                        {
+                            first_run = false;
+                            subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
+
+                            let old_value =
+                                mem::take(&mut *value.write().or_poisoned());
+                            let new_value = owner.with_cleanup(|| {
+                                subscriber.with_observer(|| {
+                                    run_in_effect_scope(|| fun.run(old_value))
+                                })
+                            });
+                            *value.write().or_poisoned() = Some(new_value);
+                        }
+                    }
+                }
+            });
+
+            ArenaItem::new_with_storage(Some(inner))
+        });
+
+        Self { inner }
+    }
+
+    /// Creates an effect that waits for the future returned by `schedule` to resolve each time
+    /// before it runs, including the first time.
+    ///
+    /// Changes to its dependencies that happen while it is waiting are batched into a single
+    /// run. This allows non-urgent work to be deferred, for example until the browser is idle;
+    /// the `leptos` crate provides `Effect::new_idle`, which uses `requestIdleCallback`.
+    ///
+    /// ```
+    /// # use reactive_graph::effect::Effect;
+    /// # use reactive_graph::traits::*;
+    /// # use reactive_graph::signal::signal;
+    /// # use std::time::Duration;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    /// # any_spawner::Executor::init_tokio(); let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// let (draft, set_draft) = signal(String::new());
+    ///
+    /// Effect::new_scheduled(
+    ///     || tokio::time::sleep(Duration::from_millis(10)),
+    ///     move || {
+    ///         // save the draft once the scheduler allows
+    ///         let draft = draft.get();
+    ///     },
+    /// );
+    /// # }).await;
+    /// # });
+    /// ```
+    pub fn new_scheduled<T, M, Fut>(
+        mut schedule: impl FnMut() -> Fut + 'static,
+        mut fun: impl EffectFunction<T, M> + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let inner = cfg!(feature = "effects").then(|| {
+            let (mut rx, owner, inner) = effect_base();
+            let priority = EffectPriority::current();
+            let value = Arc::new(RwLock::new(None::<T>));
+            let mut first_run = true;
+
+            Executor::spawn_local({
+                let value = Arc::clone(&value);
+                let subscriber = inner.to_any_subscriber();
+
+                async move {
+                    while rx.next().await.is_some() {
+                        schedule().await;
+                        let _lane = priority.wait_for_turn().await;
+
+                        if !owner.paused()
+                            && (subscriber.with_observer(|| {
+                                subscriber.update_if_necessary()
+                            }) || first_run)
+                        {
+                            first_run = false;
+                            subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
+
+                            let old_value =
+                                mem::take(&mut *value.write().or_poisoned());
+                            let new_value = owner.with_cleanup(|| {
+                                subscriber.with_observer(|| {
+                                    run_in_effect_scope(|| fun.run(old_value))
+                                })
+                            });
+                            *value.write().or_poisoned() = Some(new_value);
+                        }
+                    }
+                }
+            });
+
+            ArenaItem::new_with_storage(Some(inner))
+        });
+
+        Self { inner }
+    }
 }
 
 impl Effect<SyncStorage> {
@@ -407,6 +678,7 @@ impl Effect<SyncStorage> {
         T: Send + Sync + 'static,
     {
         let (mut rx, owner, inner) = effect_base();
+        let priority = EffectPriority::current();
         let mut first_run = true;
         let value = Arc::new(RwLock::new(None::<T>));
 
@@ -416,6 +688,7 @@ impl Effect<SyncStorage> {
 
             async move {
                 while rx.next().await.is_some() {
+                    let _lane = priority.wait_for_turn().await;
                     if !owner.paused()
                         && (subscriber
                             .with_observer(|| subscriber.update_if_necessary())
@@ -423,6 +696,7 @@ impl Effect<SyncStorage> {
                     {
                         first_run = false;
                         subscriber.clear_sources(&subscriber);
+                        crate::devtools_event!(EffectRan { id: subscriber.0 });
 
                         let old_value =
                             mem::take(&mut *value.write().or_poisoned());
@@ -458,6 +732,7 @@ impl Effect<SyncStorage> {
         T: Send + Sync + 'static,
     {
         let (mut rx, owner, inner) = effect_base();
+        let priority = EffectPriority::current();
         let mut first_run = true;
         let dep_value = Arc::new(RwLock::new(None::<D>));
         let watch_value = Arc::new(RwLock::new(None::<T>));
@@ -470,12 +745,16 @@ impl Effect<SyncStorage> {
 
                 async move {
                     while rx.next().await.is_some() {
+                        let _lane = priority.wait_for_turn().await;
                         if !owner.paused()
                             && (subscriber.with_observer(|| {
                                 subscriber.update_if_necessary()
                             }) || first_run)
                         {
                             subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
 
                             let old_dep_value = mem::take(
                                 &mut *dep_value.write().or_poisoned(),
@@ -489,11 +768,13 @@ impl Effect<SyncStorage> {
                             );
 
                             if immediate || !first_run {
-                                let new_watch_value = handler(
-                                    &new_dep_value,
-                                    old_dep_value.as_ref(),
-                                    old_watch_value,
-                                );
+                                let new_watch_value = owner.with(|| {
+                                    handler(
+                                        &new_dep_value,
+                                        old_dep_value.as_ref(),
+                                        old_watch_value,
+                                    )
+                                });
 
                                 *watch_value.write().or_poisoned() =
                                     Some(new_watch_value);
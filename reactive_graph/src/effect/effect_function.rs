@@ -1,3 +1,5 @@
+use crate::traits::Track;
+
 /// Trait to enable effect functions that have zero or one parameter
 pub trait EffectFunction<T, M> {
     /// Call this to execute the function. In case the actual function has no parameters
@@ -29,3 +31,54 @@ where
         self()
     }
 }
+
+/// A fixed list of reactive values that an effect depends on, used by
+/// [`Effect::new_with_deps`](super::Effect::new_with_deps).
+///
+/// This is implemented for tuples of up to twelve values that can be tracked, like `(a,)` or
+/// `(a, b, c)`, as well as for arrays and vectors of a single type of value.
+pub trait EffectDependencies {
+    /// Subscribes to each of the dependencies in the current reactive scope.
+    fn track_all(&self);
+}
+
+impl<T: Track, const N: usize> EffectDependencies for [T; N] {
+    fn track_all(&self) {
+        for dep in self {
+            dep.track();
+        }
+    }
+}
+
+impl<T: Track> EffectDependencies for Vec<T> {
+    fn track_all(&self) {
+        for dep in self {
+            dep.track();
+        }
+    }
+}
+
+macro_rules! impl_effect_dependencies_for_tuple {
+    ($($ty:ident),*) => {
+        impl<$($ty: Track),*> EffectDependencies for ($($ty,)*) {
+            #[allow(non_snake_case)]
+            fn track_all(&self) {
+                let ($($ty,)*) = self;
+                $($ty.track();)*
+            }
+        }
+    };
+}
+
+impl_effect_dependencies_for_tuple!(A);
+impl_effect_dependencies_for_tuple!(A, B);
+impl_effect_dependencies_for_tuple!(A, B, C);
+impl_effect_dependencies_for_tuple!(A, B, C, D);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F, G);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F, G, H);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_effect_dependencies_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -1,10 +1,12 @@
 use crate::{
+    channel::Sender,
     graph::{AnySubscriber, ReactiveNode, ToAnySubscriber},
     owner::on_cleanup,
     traits::{DefinedAt, Dispose},
 };
 use or_poisoned::OrPoisoned;
 use std::{
+    mem,
     panic::Location,
     sync::{Arc, Mutex, RwLock},
 };
@@ -147,43 +149,91 @@ impl DefinedAt for ImmediateEffect {
     }
 }
 
-/// Defers any [ImmediateEffect]s from running until the end of the function.
+/// Defers notifying effects until the end of the function, so that updating several signals
+/// at once causes each effect to run only once, after all of them have been updated.
 ///
-/// NOTE: this affects only [ImmediateEffect]s, not other effects.
+/// This affects every kind of effect:
+/// - [ImmediateEffect]s, which would otherwise run synchronously after each update, run once at
+///   the end of the batch.
+/// - [`Effect`](super::Effect)s and [`RenderEffect`](super::RenderEffect)s are not scheduled
+///   until the end of the batch, even if another thread polls them in the meantime.
 ///
-/// NOTE: this is rarely needed, but it is useful for example when multiple signals
-/// need to be updated atomically (for example a double-bound signal tree).
+/// Memos are lazy, so reading a memo inside the batch still returns an up-to-date value.
+/// Nested batches are merged into the outermost one. A batch only applies to the current thread,
+/// so it does not defer effects that are notified by other threads, like concurrent requests
+/// during server rendering.
+///
+/// ```
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::signal::RwSignal;
+/// # use reactive_graph::effect::{batch, ImmediateEffect};
+/// # use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+/// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+/// let a = RwSignal::new(0);
+/// let b = RwSignal::new(0);
+/// let runs = Arc::new(AtomicUsize::new(0));
+/// let _effect = ImmediateEffect::new_isomorphic({
+///     let runs = Arc::clone(&runs);
+///     move || {
+///         _ = a.get() + b.get();
+///         runs.fetch_add(1, Ordering::Relaxed);
+///     }
+/// });
+///
+/// batch(|| {
+///     a.set(1);
+///     b.set(1);
+/// });
+/// // one run on creation, and one for the batch
+/// assert_eq!(runs.load(Ordering::Relaxed), 2);
+/// ```
 pub fn batch<T>(f: impl FnOnce() -> T) -> T {
     struct ExecuteOnDrop;
     impl Drop for ExecuteOnDrop {
         fn drop(&mut self) {
-            let effects = {
-                let mut batch = inner::BATCH.write().or_poisoned();
-                batch.take().unwrap().into_inner().expect("lock poisoned")
-            };
+            let effects =
+                inner::BATCH.with_borrow_mut(|batch| batch.take().unwrap());
+            let notifications = inner::DEFERRED.with_borrow_mut(mem::take);
             // TODO: Should we skip the effects if it's panicking?
             for effect in effects {
                 effect.update_if_necessary();
             }
+            for mut observer in notifications {
+                observer.notify();
+            }
         }
     }
     let mut execute_on_drop = None;
-    {
-        let mut batch = inner::BATCH.write().or_poisoned();
+    inner::BATCH.with_borrow_mut(|batch| {
         if batch.is_none() {
             execute_on_drop = Some(ExecuteOnDrop);
+            *batch = Some(Default::default());
         } else {
             // Nested batching has no effect.
         }
-        *batch = Some(batch.take().unwrap_or_default());
-    }
+    });
     let ret = f();
     drop(execute_on_drop);
     ret
 }
 
+/// If a [batch] is running, stores the notification for an async effect until it ends.
+///
+/// Returns `true` if the notification was deferred.
+pub(crate) fn defer_notification(observer: &Sender) -> bool {
+    if inner::BATCH.with_borrow(Option::is_some) {
+        inner::DEFERRED.with_borrow_mut(|deferred| {
+            deferred.insert(observer.clone());
+        });
+        true
+    } else {
+        false
+    }
+}
+
 mod inner {
     use crate::{
+        channel::Sender,
         graph::{
             AnySource, AnySubscriber, ReactiveNode, ReactiveNodeState,
             SourceSet, Subscriber, ToAnySubscriber, WithObserver,
@@ -195,15 +245,22 @@ mod inner {
     use indexmap::IndexSet;
     use or_poisoned::OrPoisoned;
     use std::{
+        cell::RefCell,
         panic::Location,
         sync::{Arc, RwLock, Weak},
         thread::{self, ThreadId},
     };
 
-    /// Only the [super::batch] function ever writes to the outer RwLock.
-    /// While the effects will write to the inner one.
-    pub(super) static BATCH: RwLock<Option<RwLock<IndexSet<AnySubscriber>>>> =
-        RwLock::new(None);
+    thread_local! {
+        /// Only the [super::batch] function ever starts or ends a batch.
+        /// While it is running, the effects add themselves to it.
+        pub(super) static BATCH: RefCell<Option<IndexSet<AnySubscriber>>> =
+            const { RefCell::new(None) };
+
+        /// Notifications for async effects that were marked dirty during a [super::batch].
+        pub(super) static DEFERRED: RefCell<IndexSet<Sender>> =
+            RefCell::new(IndexSet::new());
+    }
 
     /// Handles subscription logic for effects.
     ///
@@ -321,12 +378,16 @@ mod inner {
             };
 
             {
-                if let Some(batch) = &*BATCH.read().or_poisoned() {
-                    let mut batch = batch.write().or_poisoned();
-                    let subscriber =
-                        self.read().or_poisoned().any_subscriber.clone();
-
-                    batch.insert(subscriber);
+                let batched = BATCH.with_borrow_mut(|batch| match batch {
+                    Some(batch) => {
+                        let subscriber =
+                            self.read().or_poisoned().any_subscriber.clone();
+                        batch.insert(subscriber);
+                        true
+                    }
+                    None => false,
+                });
+                if batched {
                     return needs_update;
                 }
             }
@@ -355,6 +416,10 @@ mod inner {
 
                 drop(guard);
 
+                crate::devtools_event!(EffectRan {
+                    id: any_subscriber.0
+                });
+
                 // We execute the effect.
                 // Note that *this could happen in parallel across threads*.
                 owner.with_cleanup(|| any_subscriber.with_observer(|| fun()));
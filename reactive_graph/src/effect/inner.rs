@@ -1,3 +1,4 @@
+use super::immediate::defer_notification;
 use crate::{
     channel::Sender,
     graph::{
@@ -46,13 +47,18 @@ impl ReactiveNode for RwLock<EffectInner> {
     }
 
     fn mark_check(&self) {
-        self.write().or_poisoned().observer.notify()
+        let mut lock = self.write().or_poisoned();
+        if !defer_notification(&lock.observer) {
+            lock.observer.notify()
+        }
     }
 
     fn mark_dirty(&self) {
         let mut lock = self.write().or_poisoned();
         lock.dirty = true;
-        lock.observer.notify()
+        if !defer_notification(&lock.observer) {
+            lock.observer.notify()
+        }
     }
 }
 
@@ -0,0 +1,152 @@
+use or_poisoned::OrPoisoned;
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+};
+
+/// The priority with which an effect or render effect runs, when many effects are waiting to
+/// run at the same time.
+///
+/// Effects run asynchronously, after the signals they depend on have changed. When a large
+/// update causes many effects to rerun, an effect only begins to run once every effect waiting
+/// in a higher-priority lane has run, yielding to the executor in the meantime. This allows
+/// low-priority work (like logging, analytics, or updating offscreen content) to make way for
+/// effects that respond to user input.
+///
+/// Effects and render effects take the priority set with [`with_effect_priority`] when they are
+/// created. By default, they are [`Normal`](EffectPriority::Normal).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EffectPriority {
+    /// Runs as soon as possible. Use this for effects that respond directly to user input.
+    UserBlocking,
+    /// Runs once all user-blocking effects that are waiting have run.
+    #[default]
+    Normal,
+    /// Runs once all other effects that are waiting have run.
+    Idle,
+}
+
+thread_local! {
+    static CURRENT: Cell<EffectPriority> = const { Cell::new(EffectPriority::Normal) };
+}
+
+static LANES: Mutex<Lanes> = Mutex::new(Lanes {
+    waiting: [0; 3],
+    blocked: Vec::new(),
+});
+
+struct Lanes {
+    /// The number of effects in each lane that have been notified, but have not yet run.
+    waiting: [usize; 3],
+    /// Effects that are waiting for a higher-priority lane to empty.
+    blocked: Vec<Waker>,
+}
+
+/// Runs `fun`, giving every effect and render effect created inside it the given priority.
+///
+/// ```
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::effect::{with_effect_priority, Effect, EffectPriority};
+/// # use reactive_graph::signal::RwSignal;
+/// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+/// let count = RwSignal::new(0);
+///
+/// with_effect_priority(EffectPriority::Idle, || {
+///     Effect::new(move || {
+///         // send count.get() to an analytics service
+///     });
+/// });
+/// ```
+pub fn with_effect_priority<T>(
+    priority: EffectPriority,
+    fun: impl FnOnce() -> T,
+) -> T {
+    let prev = CURRENT.with(|current| current.replace(priority));
+    let value = fun();
+    CURRENT.with(|current| current.set(prev));
+    value
+}
+
+impl EffectPriority {
+    /// The priority that effects created now will be given.
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    fn lane(self) -> usize {
+        self as usize
+    }
+
+    /// Waits until this effect can run, and returns a guard that marks it as no longer waiting
+    /// once it is dropped.
+    pub(crate) async fn wait_for_turn(self) -> LaneGuard {
+        LANES.lock().or_poisoned().waiting[self.lane()] += 1;
+        let guard = LaneGuard(self);
+
+        // idle effects always yield once, so that other effects that have been notified at the
+        // same time have a chance to register as waiting
+        if self == EffectPriority::Idle {
+            YieldNow(false).await;
+        }
+        HigherLanesEmpty(self).await;
+
+        guard
+    }
+}
+
+pub(crate) struct LaneGuard(EffectPriority);
+
+impl Drop for LaneGuard {
+    fn drop(&mut self) {
+        let blocked = {
+            let mut lanes = LANES.lock().or_poisoned();
+            let waiting = &mut lanes.waiting[self.0.lane()];
+            *waiting -= 1;
+            if *waiting == 0 {
+                std::mem::take(&mut lanes.blocked)
+            } else {
+                Vec::new()
+            }
+        };
+        // each of them checks again whether it can run now
+        for waker in blocked {
+            waker.wake();
+        }
+    }
+}
+
+/// Resolves once no effect is waiting in a lane with a higher priority.
+struct HigherLanesEmpty(EffectPriority);
+
+impl Future for HigherLanesEmpty {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut lanes = LANES.lock().or_poisoned();
+        if lanes.waiting[..self.0.lane()].iter().all(|n| *n == 0) {
+            Poll::Ready(())
+        } else {
+            lanes.blocked.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
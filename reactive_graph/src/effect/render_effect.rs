@@ -1,6 +1,6 @@
 use crate::{
     channel::channel,
-    effect::inner::EffectInner,
+    effect::{inner::EffectInner, EffectPriority},
     graph::{
         AnySubscriber, ReactiveNode, SourceSet, Subscriber, ToAnySubscriber,
         WithObserver,
@@ -157,6 +157,7 @@ where
         }
 
         let (owner, inner, mut rx) = prep();
+        let priority = EffectPriority::current();
 
         let value = Arc::new(RwLock::new(None::<T>));
 
@@ -164,6 +165,7 @@ where
         {
             let _ = initial_value;
             let _ = owner;
+            let _ = priority;
             let _ = &mut rx;
             let _ = fun;
         }
@@ -215,6 +217,7 @@ where
                 move |prev| fun.call((prev,))
             };
 
+            crate::devtools_event!(EffectRan { id: subscriber.0 });
             *value.write().or_poisoned() = Some(
                 owner.with(|| subscriber.with_observer(|| fun(initial_value))),
             );
@@ -224,12 +227,16 @@ where
 
                 async move {
                     while rx.next().await.is_some() {
+                        let _lane = priority.wait_for_turn().await;
                         if !owner.paused()
                             && subscriber.with_observer(|| {
                                 subscriber.update_if_necessary()
                             })
                         {
                             subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
 
                             let old_value =
                                 mem::take(&mut *value.write().or_poisoned());
@@ -264,6 +271,7 @@ where
         }
 
         let (owner, inner, mut rx) = prep();
+        let priority = EffectPriority::current();
 
         let value = Arc::new(RwLock::new(None::<T>));
 
@@ -271,6 +279,7 @@ where
         {
             drop(initial_value);
             let _ = owner;
+            let _ = priority;
             let _ = &mut rx;
             let _ = &mut fun;
         }
@@ -281,6 +290,7 @@ where
 
             let subscriber = inner.to_any_subscriber();
 
+            crate::devtools_event!(EffectRan { id: subscriber.0 });
             let initial = subscriber
                 .with_observer(|| ScopedFuture::new(initial_value))
                 .await;
@@ -291,12 +301,16 @@ where
 
                 async move {
                     while rx.next().await.is_some() {
+                        let _lane = priority.wait_for_turn().await;
                         if !owner.paused()
                             && subscriber.with_observer(|| {
                                 subscriber.update_if_necessary()
                             })
                         {
                             subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
 
                             let old_value =
                                 mem::take(&mut *value.write().or_poisoned());
@@ -346,14 +360,17 @@ where
             let (observer, mut rx) = channel();
             let value = Arc::new(RwLock::new(None::<T>));
             let owner = Owner::new();
+            let priority = EffectPriority::current();
             let inner = Arc::new(RwLock::new(EffectInner {
                 dirty: false,
                 observer,
                 sources: SourceSet::new(),
             }));
 
-            let initial_value = owner
-                .with(|| inner.to_any_subscriber().with_observer(|| fun(None)));
+            let subscriber = inner.to_any_subscriber();
+            crate::devtools_event!(EffectRan { id: subscriber.0 });
+            let initial_value =
+                owner.with(|| subscriber.with_observer(|| fun(None)));
             *value.write().or_poisoned() = Some(initial_value);
 
             crate::spawn({
@@ -362,12 +379,16 @@ where
 
                 async move {
                     while rx.next().await.is_some() {
+                        let _lane = priority.wait_for_turn().await;
                         if !owner.paused()
                             && subscriber.with_observer(|| {
                                 subscriber.update_if_necessary()
                             })
                         {
                             subscriber.clear_sources(&subscriber);
+                            crate::devtools_event!(EffectRan {
+                                id: subscriber.0
+                            });
 
                             let old_value =
                                 mem::take(&mut *value.write().or_poisoned());
@@ -3,10 +3,18 @@
 
 mod node;
 mod sets;
+#[cfg(debug_assertions)]
+mod snapshot;
 mod source;
 mod subscriber;
 
 pub use node::*;
 pub(crate) use sets::*;
+#[cfg(debug_assertions)]
+pub(crate) use snapshot::{record_edge, record_node, remove_edge};
+#[cfg(debug_assertions)]
+pub use snapshot::{
+    record_graph, GraphNode, GraphNodeKind, GraphOwner, GraphSnapshot,
+};
 pub use source::*;
 pub use subscriber::*;
@@ -38,6 +38,8 @@ impl SourceSet {
 
     pub fn clear_sources(&mut self, subscriber: &AnySubscriber) {
         for source in self.take() {
+            #[cfg(debug_assertions)]
+            super::remove_edge(&source, subscriber);
             source.remove_subscriber(subscriber);
         }
     }
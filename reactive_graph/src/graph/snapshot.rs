@@ -0,0 +1,375 @@
+//! Exports the dependency graph between signals, memos, and effects, for debugging.
+
+use super::{AnySource, AnySubscriber};
+use crate::owner::Owner;
+use indexmap::IndexSet;
+use or_poisoned::OrPoisoned;
+use rustc_hash::{FxHashMap, FxHasher};
+use std::{
+    fmt::Write,
+    hash::BuildHasherDefault,
+    panic::Location,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock, Weak,
+    },
+};
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+
+#[derive(Default)]
+struct Registry {
+    sources: FxHashMap<usize, (AnySource, &'static str)>,
+    subscribers: FxHashMap<usize, AnySubscriber>,
+    // the kind of each node that has said what it is when it was created
+    kinds: FxHashMap<usize, (AnySubscriber, GraphNodeKind)>,
+    edges: IndexSet<(usize, usize), BuildHasherDefault<FxHasher>>,
+}
+
+fn registry() -> &'static Mutex<Registry> {
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Starts or stops recording the dependencies between reactive nodes, so that they can be
+/// exported with [`GraphSnapshot::capture`].
+///
+/// Only dependencies that are tracked while recording is enabled will appear in a snapshot, so
+/// this should be called before the part of the app you want to inspect is created.
+///
+/// This is only available in debug builds.
+pub fn record_graph(enabled: bool) {
+    RECORDING.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        *registry().lock().or_poisoned() = Registry::default();
+    }
+}
+
+#[inline(always)]
+pub(crate) fn record_edge(
+    source: &AnySource,
+    type_name: &'static str,
+    subscriber: &AnySubscriber,
+) {
+    if RECORDING.load(Ordering::Relaxed) {
+        let mut registry = registry().lock().or_poisoned();
+        registry
+            .sources
+            .entry(source.0)
+            .or_insert_with(|| (source.clone(), type_name));
+        registry
+            .subscribers
+            .entry(subscriber.0)
+            .or_insert_with(|| subscriber.clone());
+        registry.edges.insert((source.0, subscriber.0));
+    }
+}
+
+/// Records the kind of a node that both tracks and can be tracked, like a memo, when it is
+/// created, so that it is not mistaken for an effect before anything has read from it.
+#[inline(always)]
+pub(crate) fn record_node(subscriber: &AnySubscriber, kind: GraphNodeKind) {
+    if RECORDING.load(Ordering::Relaxed) {
+        registry()
+            .lock()
+            .or_poisoned()
+            .kinds
+            .insert(subscriber.0, (subscriber.clone(), kind));
+    }
+}
+
+#[inline(always)]
+pub(crate) fn remove_edge(source: &AnySource, subscriber: &AnySubscriber) {
+    if RECORDING.load(Ordering::Relaxed) {
+        registry()
+            .lock()
+            .or_poisoned()
+            .edges
+            .shift_remove(&(source.0, subscriber.0));
+    }
+}
+
+/// The role of a node in the reactive graph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GraphNodeKind {
+    /// A node that is only read from, like a signal or trigger.
+    Source,
+    /// A node that both reads from other nodes and is read from, like a memo.
+    Computed,
+    /// A node that only reads from other nodes, like an effect.
+    Effect,
+}
+
+impl GraphNodeKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            GraphNodeKind::Source => "source",
+            GraphNodeKind::Computed => "computed",
+            GraphNodeKind::Effect => "effect",
+        }
+    }
+}
+
+/// A signal, memo, or effect in a [`GraphSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphNode {
+    /// An identifier for the node, which is unique while the node is alive.
+    pub id: usize,
+    /// The role of the node in the graph.
+    pub kind: GraphNodeKind,
+    /// The type of the node, if it has been read from.
+    pub type_name: Option<&'static str>,
+    /// The location at which the node was created, if it has been read from.
+    pub defined_at: Option<&'static Location<'static>>,
+}
+
+/// A reactive owner in a [`GraphSnapshot`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GraphOwner {
+    /// The owner's [`debug_id`](Owner::debug_id).
+    pub id: usize,
+    /// The [`debug_id`](Owner::debug_id) of the owner's parent, if any.
+    pub parent: Option<usize>,
+}
+
+/// The state of the reactive graph at a particular moment: which nodes exist, which nodes each
+/// node currently depends on, and how reactive owners are nested.
+///
+/// This is useful for understanding why an effect or component reruns more often than
+/// expected. It can be rendered with [Graphviz](https://graphviz.org/) using
+/// [`to_dot`](GraphSnapshot::to_dot), or passed to other tools using
+/// [`to_json`](GraphSnapshot::to_json).
+///
+/// ```
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::{computed::Memo, signal::RwSignal};
+/// use reactive_graph::graph::{record_graph, GraphSnapshot};
+/// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+///
+/// record_graph(true);
+///
+/// let count = RwSignal::new(1);
+/// let double = Memo::new(move |_| count.get() * 2);
+/// double.get();
+///
+/// let graph = GraphSnapshot::capture();
+/// assert_eq!(graph.edges.len(), 1);
+/// println!("{}", graph.to_dot());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphSnapshot {
+    /// The nodes that have been tracked since recording began, and are still alive.
+    pub nodes: Vec<GraphNode>,
+    /// Each current dependency, as a `(source, subscriber)` pair of node ids.
+    pub edges: Vec<(usize, usize)>,
+    /// The owner tree containing the current owner, starting at its root.
+    pub owners: Vec<GraphOwner>,
+}
+
+impl GraphSnapshot {
+    /// Captures the current dependency graph. Dependencies are only available if they were
+    /// tracked after calling [`record_graph`].
+    pub fn capture() -> Self {
+        let mut registry = registry().lock().or_poisoned();
+
+        // drop any nodes that have been disposed
+        registry
+            .sources
+            .retain(|_, (source, _)| source.1.strong_count() > 0);
+        registry
+            .subscribers
+            .retain(|_, subscriber| subscriber.1.strong_count() > 0);
+        registry
+            .kinds
+            .retain(|_, (subscriber, _)| subscriber.1.strong_count() > 0);
+        let Registry {
+            sources,
+            subscribers,
+            kinds,
+            edges,
+        } = &mut *registry;
+        let kind_of = |id: &usize| {
+            kinds.get(id).map(|(_, kind)| *kind).unwrap_or_else(|| {
+                if !subscribers.contains_key(id) {
+                    GraphNodeKind::Source
+                } else if sources.contains_key(id) {
+                    GraphNodeKind::Computed
+                } else {
+                    GraphNodeKind::Effect
+                }
+            })
+        };
+        edges.retain(|(source, subscriber)| {
+            sources.contains_key(source) && subscribers.contains_key(subscriber)
+        });
+
+        let mut nodes = sources
+            .iter()
+            .map(|(id, (source, type_name))| GraphNode {
+                id: *id,
+                kind: kind_of(id),
+                type_name: Some(*type_name),
+                defined_at: Some(source.2),
+            })
+            .collect::<Vec<_>>();
+        nodes.extend(
+            subscribers
+                .keys()
+                .filter(|id| !sources.contains_key(id))
+                .map(|id| GraphNode {
+                    id: *id,
+                    kind: kind_of(id),
+                    type_name: None,
+                    defined_at: None,
+                }),
+        );
+        nodes.sort_by_key(|node| node.id);
+
+        Self {
+            nodes,
+            edges: edges.iter().copied().collect(),
+            owners: Owner::current().map(owner_tree).unwrap_or_default(),
+        }
+    }
+
+    /// Renders the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language.
+    ///
+    /// Dependencies are drawn as solid arrows from each source to its subscribers, and owners
+    /// are drawn as dashed arrows from each parent to its children.
+    pub fn to_dot(&self) -> String {
+        let mut buf = String::from("digraph reactive_graph {\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                GraphNodeKind::Source => "ellipse",
+                GraphNodeKind::Computed => "diamond",
+                GraphNodeKind::Effect => "box",
+            };
+            _ = write!(buf, "  n{} [shape={shape}, label=\"", node.id);
+            escape(&mut buf, &node_label(node));
+            buf.push_str("\"];\n");
+        }
+        for (source, subscriber) in &self.edges {
+            _ = writeln!(buf, "  n{source} -> n{subscriber};");
+        }
+        for owner in &self.owners {
+            _ = writeln!(buf, "  o{} [shape=point];", owner.id);
+            if let Some(parent) = owner.parent {
+                _ = writeln!(
+                    buf,
+                    "  o{parent} -> o{} [style=dashed];",
+                    owner.id
+                );
+            }
+        }
+        buf.push_str("}\n");
+        buf
+    }
+
+    /// Renders the graph as JSON, with `nodes`, `edges`, and `owners` fields.
+    pub fn to_json(&self) -> String {
+        let mut buf = String::from("{\"nodes\":[");
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if idx > 0 {
+                buf.push(',');
+            }
+            _ = write!(
+                buf,
+                "{{\"id\":{},\"kind\":\"{}\",\"type_name\":",
+                node.id,
+                node.kind.as_str()
+            );
+            json_string(&mut buf, node.type_name);
+            buf.push_str(",\"defined_at\":");
+            json_string(
+                &mut buf,
+                node.defined_at.map(ToString::to_string).as_deref(),
+            );
+            buf.push('}');
+        }
+        buf.push_str("],\"edges\":[");
+        for (idx, (source, subscriber)) in self.edges.iter().enumerate() {
+            if idx > 0 {
+                buf.push(',');
+            }
+            _ = write!(
+                buf,
+                "{{\"source\":{source},\"subscriber\":{subscriber}}}"
+            );
+        }
+        buf.push_str("],\"owners\":[");
+        for (idx, owner) in self.owners.iter().enumerate() {
+            if idx > 0 {
+                buf.push(',');
+            }
+            let parent = owner
+                .parent
+                .map(|parent| parent.to_string())
+                .unwrap_or_else(|| "null".to_string());
+            _ = write!(buf, "{{\"id\":{},\"parent\":{parent}}}", owner.id);
+        }
+        buf.push_str("]}");
+        buf
+    }
+}
+
+fn node_label(node: &GraphNode) -> String {
+    let mut label = node.type_name.unwrap_or(node.kind.as_str()).to_string();
+    if let Some(defined_at) = node.defined_at {
+        _ = write!(label, "\n{defined_at}");
+    }
+    label
+}
+
+fn owner_tree(owner: Owner) -> Vec<GraphOwner> {
+    // walk up to the root, then collect every descendant
+    let mut root = owner;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+
+    let mut owners = Vec::new();
+    let mut stack = vec![(root, None)];
+    while let Some((owner, parent)) = stack.pop() {
+        let id = owner.debug_id();
+        owners.push(GraphOwner { id, parent });
+        let children = owner
+            .inner
+            .read()
+            .or_poisoned()
+            .children
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+        stack.extend(children.into_iter().map(|inner| {
+            let child = Owner {
+                inner,
+                #[cfg(feature = "hydration")]
+                shared_context: owner.shared_context.clone(),
+            };
+            (child, Some(id))
+        }));
+    }
+    owners
+}
+
+fn escape(buf: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            c => buf.push(c),
+        }
+    }
+}
+
+fn json_string(buf: &mut String, value: Option<&str>) {
+    match value {
+        Some(value) => {
+            buf.push('"');
+            escape(buf, value);
+            buf.push('"');
+        }
+        None => buf.push_str("null"),
+    }
+}
@@ -2,7 +2,11 @@ use super::{node::ReactiveNode, AnySource};
 #[cfg(debug_assertions)]
 use crate::diagnostics::SpecialNonReactiveZone;
 use core::{fmt::Debug, hash::Hash};
-use std::{cell::RefCell, mem, sync::Weak};
+use std::{
+    cell::{Cell, RefCell},
+    mem,
+    sync::Weak,
+};
 
 thread_local! {
     static OBSERVER: RefCell<Option<ObserverState>> = const { RefCell::new(None) };
@@ -142,6 +146,80 @@ pub fn untrack_with_diagnostics<T>(fun: impl FnOnce() -> T) -> T {
     fun()
 }
 
+/// Wraps a function so that every reactive value read while it runs is untracked.
+///
+/// This is the equivalent of calling [`untrack`] each time the function is called, and is useful
+/// for derived computations that read many signals but should not subscribe to any of them.
+///
+/// ```rust
+/// # use reactive_graph::computed::*;
+/// # use reactive_graph::signal::*;
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::graph::untracked;
+/// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+/// let (a, set_a) = signal(1);
+/// let (b, set_b) = signal(2);
+/// let sum = untracked(move || a.get() + b.get());
+///
+/// let c = Memo::new(move |_| sum());
+/// assert_eq!(c.get(), 3);
+/// set_a.set(2);
+/// set_b.set(3);
+/// // hasn't updated, because neither signal was tracked
+/// assert_eq!(c.get(), 3);
+/// ```
+pub fn untracked<T>(fun: impl Fn() -> T) -> impl Fn() -> T {
+    move || untrack(&fun)
+}
+
+thread_local! {
+    static UNTRACKED_WRITES: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Debug)]
+struct ResetUntrackedWritesOnDrop(bool);
+
+impl Drop for ResetUntrackedWritesOnDrop {
+    fn drop(&mut self) {
+        UNTRACKED_WRITES.set(self.0);
+    }
+}
+
+/// Runs the given function without notifying subscribers of any signals that are updated
+/// inside it.
+///
+/// This is the equivalent of using `set_untracked` or `update_untracked` for every write in the
+/// function. Reads inside the function are still tracked as usual.
+///
+/// ```rust
+/// # use reactive_graph::computed::*;
+/// # use reactive_graph::signal::*;
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::graph::untrack_writes;
+/// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+/// let (a, set_a) = signal(0);
+/// let (b, set_b) = signal(0);
+/// let c = Memo::new(move |_| a.get() + b.get());
+/// assert_eq!(c.get(), 0);
+///
+/// untrack_writes(move || {
+///     set_a.set(1);
+///     set_b.set(1);
+/// });
+/// // hasn't updated, because the memo was not notified
+/// assert_eq!(c.get(), 0);
+/// assert_eq!(a.get_untracked(), 1);
+/// ```
+pub fn untrack_writes<T>(fun: impl FnOnce() -> T) -> T {
+    let _prev = ResetUntrackedWritesOnDrop(UNTRACKED_WRITES.replace(true));
+    fun()
+}
+
+/// Whether writes are currently inside [`untrack_writes`], and should not notify subscribers.
+pub(crate) fn writes_are_untracked() -> bool {
+    UNTRACKED_WRITES.get()
+}
+
 /// Converts a [`Subscriber`] to a type-erased [`AnySubscriber`].
 pub trait ToAnySubscriber {
     /// Converts this type to its type-erased equivalent.
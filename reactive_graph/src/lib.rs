@@ -77,6 +77,8 @@ use std::{fmt::Arguments, future::Future};
 pub mod actions;
 pub(crate) mod channel;
 pub mod computed;
+#[cfg(feature = "devtools")]
+pub mod devtools;
 pub mod diagnostics;
 pub mod effect;
 pub mod graph;
@@ -108,6 +110,15 @@ pub mod prelude {
     };
 }
 
+/// Emits a [`devtools::DevtoolsEvent`] if the `devtools` feature is enabled.
+macro_rules! devtools_event {
+    ($($event:tt)*) => {
+        #[cfg(feature = "devtools")]
+        $crate::devtools::emit(|| $crate::devtools::DevtoolsEvent::$($event)*);
+    };
+}
+pub(crate) use devtools_event;
+
 // TODO remove this, it's just useful while developing
 #[allow(unused)]
 #[doc(hidden)]
@@ -162,29 +162,39 @@ impl Owner {
             })
             .unwrap_or((None, None));
         let this = Self {
-            inner: Arc::new(RwLock::new(OwnerInner {
-                parent: parent.clone(),
-                nodes: Default::default(),
-                contexts: Default::default(),
-                cleanups: Default::default(),
-                children: Default::default(),
-                #[cfg(feature = "sandboxed-arenas")]
-                arena: parent
-                    .as_ref()
-                    .and_then(|parent| parent.upgrade())
-                    .map(|parent| parent.read().or_poisoned().arena.clone())
-                    .unwrap_or_default(),
-                paused: false,
-            })),
+            inner: Arc::new_cyclic(|_weak| {
+                RwLock::new(OwnerInner {
+                    #[cfg(feature = "devtools")]
+                    id: _weak.as_ptr() as usize,
+                    parent: parent.clone(),
+                    nodes: Default::default(),
+                    contexts: Default::default(),
+                    cleanups: Default::default(),
+                    children: Default::default(),
+                    #[cfg(feature = "sandboxed-arenas")]
+                    arena: parent
+                        .as_ref()
+                        .and_then(|parent| parent.upgrade())
+                        .map(|parent| parent.read().or_poisoned().arena.clone())
+                        .unwrap_or_default(),
+                    paused: false,
+                })
+            }),
             #[cfg(feature = "hydration")]
             shared_context,
         };
         if let Some(parent) = parent.and_then(|n| n.upgrade()) {
-            parent
-                .write()
-                .or_poisoned()
-                .children
-                .push(Arc::downgrade(&this.inner));
+            crate::devtools_event!(OwnerCreated {
+                id: this.debug_id(),
+                parent: Some(Arc::as_ptr(&parent) as usize),
+            });
+            let mut parent = parent.write().or_poisoned();
+            parent.children.push(Arc::downgrade(&this.inner));
+        } else {
+            crate::devtools_event!(OwnerCreated {
+                id: this.debug_id(),
+                parent: None
+            });
         }
         this
     }
@@ -200,19 +210,27 @@ impl Owner {
         shared_context: Option<Arc<dyn SharedContext + Send + Sync>>,
     ) -> Self {
         let this = Self {
-            inner: Arc::new(RwLock::new(OwnerInner {
-                parent: None,
-                nodes: Default::default(),
-                contexts: Default::default(),
-                cleanups: Default::default(),
-                children: Default::default(),
-                #[cfg(feature = "sandboxed-arenas")]
-                arena: Default::default(),
-                paused: false,
-            })),
+            inner: Arc::new_cyclic(|_weak| {
+                RwLock::new(OwnerInner {
+                    #[cfg(feature = "devtools")]
+                    id: _weak.as_ptr() as usize,
+                    parent: None,
+                    nodes: Default::default(),
+                    contexts: Default::default(),
+                    cleanups: Default::default(),
+                    children: Default::default(),
+                    #[cfg(feature = "sandboxed-arenas")]
+                    arena: Default::default(),
+                    paused: false,
+                })
+            }),
             #[cfg(feature = "hydration")]
             shared_context,
         };
+        crate::devtools_event!(OwnerCreated {
+            id: this.debug_id(),
+            parent: None
+        });
         this.set();
         this
     }
@@ -244,20 +262,28 @@ impl Owner {
         let arena = inner.arena.clone();
         let paused = inner.paused;
         let child = Self {
-            inner: Arc::new(RwLock::new(OwnerInner {
-                parent,
-                nodes: Default::default(),
-                contexts: Default::default(),
-                cleanups: Default::default(),
-                children: Default::default(),
-                #[cfg(feature = "sandboxed-arenas")]
-                arena,
-                paused,
-            })),
+            inner: Arc::new_cyclic(|_weak| {
+                RwLock::new(OwnerInner {
+                    #[cfg(feature = "devtools")]
+                    id: _weak.as_ptr() as usize,
+                    parent,
+                    nodes: Default::default(),
+                    contexts: Default::default(),
+                    cleanups: Default::default(),
+                    children: Default::default(),
+                    #[cfg(feature = "sandboxed-arenas")]
+                    arena,
+                    paused,
+                })
+            }),
             #[cfg(feature = "hydration")]
             shared_context: self.shared_context.clone(),
         };
         inner.children.push(Arc::downgrade(&child.inner));
+        crate::devtools_event!(OwnerCreated {
+            id: child.debug_id(),
+            parent: Some(self.debug_id()),
+        });
         child
     }
 
@@ -495,6 +521,9 @@ pub fn on_cleanup(fun: impl FnOnce() + Send + Sync + 'static) {
 
 #[derive(Default)]
 pub(crate) struct OwnerInner {
+    /// The owner's [`debug_id`](Owner::debug_id), which is reported when it is disposed.
+    #[cfg(feature = "devtools")]
+    id: usize,
     pub parent: Option<Weak<RwLock<OwnerInner>>>,
     nodes: Vec<NodeId>,
     pub contexts: FxHashMap<TypeId, Box<dyn Any + Send + Sync>>,
@@ -518,6 +547,8 @@ impl Debug for OwnerInner {
 
 impl Drop for OwnerInner {
     fn drop(&mut self) {
+        crate::devtools_event!(OwnerDisposed { id: self.id });
+
         for child in std::mem::take(&mut self.children) {
             if let Some(child) = child.upgrade() {
                 child.cleanup();
@@ -3,8 +3,15 @@ use or_poisoned::OrPoisoned;
 use std::{
     any::{Any, TypeId},
     collections::VecDeque,
+    sync::{Arc, OnceLock},
 };
 
+/// A default value for a context type, which is only created the first time it is used.
+struct ContextDefault<T> {
+    value: OnceLock<T>,
+    init: Box<dyn Fn() -> T + Send + Sync>,
+}
+
 impl Owner {
     fn provide_context<T: Send + Sync + 'static>(&self, value: T) {
         self.inner
@@ -15,7 +22,12 @@ impl Owner {
     }
 
     fn use_context<T: Clone + 'static>(&self) -> Option<T> {
-        self.with_context(Clone::clone)
+        self.with_context(Clone::clone).or_else(|| {
+            // fall back to a default, if one has been provided
+            let default =
+                self.with_context::<Arc<ContextDefault<T>>, _>(Arc::clone)?;
+            Some(default.value.get_or_init(|| (default.init)()).clone())
+        })
     }
 
     fn take_context<T: 'static>(&self) -> Option<T> {
@@ -206,6 +218,39 @@ pub fn provide_context<T: Send + Sync + 'static>(value: T) {
     }
 }
 
+/// Provides a default value for the context of type `T`, which will be used by
+/// [`use_context`] and [`expect_context`] in the current reactive [`Owner`] and its
+/// descendants whenever no value of type `T` has been provided with [`provide_context`].
+///
+/// The default is created lazily, the first time it is needed, and is then shared by every
+/// caller. Once a default has been provided, `use_context::<T>()` will always return `Some(_)`,
+/// so it is safe to use [`expect_context`]. Values that are provided explicitly still take
+/// precedence, so nested overrides work as usual.
+///
+/// ```rust
+/// # use reactive_graph::owner::*;
+/// # let owner = Owner::new(); owner.set();
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Theme(&'static str);
+///
+/// provide_context_with_default(|| Theme("light"));
+/// assert_eq!(expect_context::<Theme>(), Theme("light"));
+///
+/// owner.child().with(|| {
+///     // a value provided explicitly overrides the default
+///     provide_context(Theme("dark"));
+///     assert_eq!(expect_context::<Theme>(), Theme("dark"));
+/// });
+/// ```
+pub fn provide_context_with_default<T: Send + Sync + 'static>(
+    default: impl Fn() -> T + Send + Sync + 'static,
+) {
+    provide_context(Arc::new(ContextDefault {
+        value: OnceLock::new(),
+        init: Box::new(default),
+    }));
+}
+
 /// Extracts a context value of type `T` from the reactive system.
 ///
 /// This traverses the reactive ownership graph, beginning from the current reactive
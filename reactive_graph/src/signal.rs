@@ -6,6 +6,7 @@ mod arc_rw;
 mod arc_trigger;
 mod arc_write;
 pub mod guards;
+mod history;
 mod mapped;
 mod read;
 mod rw;
@@ -18,6 +19,7 @@ pub use arc_read::*;
 pub use arc_rw::*;
 pub use arc_trigger::*;
 pub use arc_write::*;
+pub use history::*;
 pub use mapped::*;
 pub use read::*;
 pub use rw::*;
@@ -12,7 +12,7 @@ use core::fmt::{Debug, Formatter, Result};
 use std::{
     hash::Hash,
     panic::Location,
-    sync::{Arc, RwLock},
+    sync::{Arc, RwLock, Weak},
 };
 
 /// A reference-counted signal that can be read from or written to.
@@ -98,6 +98,7 @@ pub struct ArcRwSignal<T> {
     pub(crate) defined_at: &'static Location<'static>,
     pub(crate) value: Arc<RwLock<T>>,
     pub(crate) inner: Arc<RwLock<SubscriberSet>>,
+    pub(crate) changed: Option<fn(&T, &T) -> bool>,
 }
 
 impl<T> Clone for ArcRwSignal<T> {
@@ -108,10 +109,69 @@ impl<T> Clone for ArcRwSignal<T> {
             defined_at: self.defined_at,
             value: Arc::clone(&self.value),
             inner: Arc::clone(&self.inner),
+            changed: self.changed,
         }
     }
 }
 
+/// A weak reference to an [`ArcRwSignal`], which does not keep the signal's value alive.
+///
+/// This is useful for long-lived caches or registries that refer to signals owned by
+/// components, which would otherwise keep each component's state alive after it has been
+/// unmounted. Use [`upgrade`](WeakSignal::upgrade) to access the signal, if it still exists.
+///
+/// ```
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::signal::*;
+/// let count = ArcRwSignal::new(0);
+/// let weak = count.downgrade();
+/// assert_eq!(weak.upgrade().map(|count| count.get()), Some(0));
+///
+/// drop(count);
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub struct WeakSignal<T> {
+    #[cfg(any(debug_assertions, leptos_debuginfo))]
+    defined_at: &'static Location<'static>,
+    value: Weak<RwLock<T>>,
+    inner: Weak<RwLock<SubscriberSet>>,
+    changed: Option<fn(&T, &T) -> bool>,
+}
+
+impl<T> WeakSignal<T> {
+    /// Returns the signal, if any strong reference to it still exists.
+    pub fn upgrade(&self) -> Option<ArcRwSignal<T>> {
+        Some(ArcRwSignal {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: self.defined_at,
+            value: self.value.upgrade()?,
+            inner: self.inner.upgrade()?,
+            changed: self.changed,
+        })
+    }
+}
+
+impl<T> Clone for WeakSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: self.defined_at,
+            value: Weak::clone(&self.value),
+            inner: Weak::clone(&self.inner),
+            changed: self.changed,
+        }
+    }
+}
+
+impl<T> Debug for WeakSignal<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("WeakSignal")
+            .field("type", &std::any::type_name::<T>())
+            .field("value", &Weak::as_ptr(&self.value))
+            .finish()
+    }
+}
+
 impl<T> Debug for ArcRwSignal<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.debug_struct("ArcRwSignal")
@@ -153,11 +213,60 @@ impl<T> ArcRwSignal<T> {
     )]
     #[track_caller]
     pub fn new(value: T) -> Self {
-        Self {
+        Self::new_with_changed(value, None)
+    }
+
+    /// Creates a new signal with a custom comparison function.
+    ///
+    /// When the signal is [`set`](crate::traits::Set::set), `changed` is called with the previous
+    /// value and the new value, and subscribers are only notified if it returns `true`. This can
+    /// be used to skip updates for values that are expensive to compare, or to compare floats
+    /// with a tolerance. Updates made in place, like [`update`](crate::traits::Update::update),
+    /// always notify subscribers.
+    ///
+    /// ```
+    /// # use reactive_graph::prelude::*;
+    /// # use reactive_graph::signal::*; let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// let position = ArcRwSignal::new_with_compare(1.0_f64, |prev, next| {
+    ///     (prev - next).abs() > 0.01
+    /// });
+    /// position.set(1.001); // does not notify subscribers
+    /// position.set(2.0); // notifies subscribers
+    /// # assert_eq!(position.get(), 2.0);
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all)
+    )]
+    #[track_caller]
+    pub fn new_with_compare(value: T, changed: fn(&T, &T) -> bool) -> Self {
+        Self::new_with_changed(value, Some(changed))
+    }
+
+    #[track_caller]
+    fn new_with_changed(value: T, changed: Option<fn(&T, &T) -> bool>) -> Self {
+        let this = Self {
             #[cfg(any(debug_assertions, leptos_debuginfo))]
             defined_at: Location::caller(),
             value: Arc::new(RwLock::new(value)),
             inner: Arc::new(RwLock::new(SubscriberSet::new())),
+            changed,
+        };
+        crate::devtools_event!(SignalCreated {
+            id: Arc::as_ptr(&this.inner) as usize,
+            defined_at: this.defined_at().map(ToString::to_string),
+        });
+        this
+    }
+
+    /// Creates a [`WeakSignal`] that refers to this signal without keeping its value alive.
+    pub fn downgrade(&self) -> WeakSignal<T> {
+        WeakSignal {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: self.defined_at,
+            value: Arc::downgrade(&self.value),
+            inner: Arc::downgrade(&self.inner),
+            changed: self.changed,
         }
     }
 
@@ -180,6 +289,7 @@ impl<T> ArcRwSignal<T> {
             defined_at: Location::caller(),
             value: Arc::clone(&self.value),
             inner: Arc::clone(&self.inner),
+            changed: self.changed,
         }
     }
 
@@ -202,6 +312,7 @@ impl<T> ArcRwSignal<T> {
                 defined_at: Location::caller(),
                 value: read.value,
                 inner: read.inner,
+                changed: write.changed,
             })
         } else {
             None
@@ -276,4 +387,8 @@ impl<T: 'static> Write for ArcRwSignal<T> {
     fn try_write_untracked(&self) -> Option<UntrackedWriteGuard<Self::Value>> {
         UntrackedWriteGuard::try_new(Arc::clone(&self.value))
     }
+
+    fn value_changed(&self, prev: &Self::Value, next: &Self::Value) -> bool {
+        self.changed.is_none_or(|changed| changed(prev, next))
+    }
 }
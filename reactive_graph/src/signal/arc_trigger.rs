@@ -22,11 +22,16 @@ impl ArcTrigger {
     /// Creates a new trigger.
     #[track_caller]
     pub fn new() -> Self {
-        Self {
+        let this = Self {
             #[cfg(any(debug_assertions, leptos_debuginfo))]
             defined_at: Location::caller(),
             inner: Default::default(),
-        }
+        };
+        crate::devtools_event!(SignalCreated {
+            id: Arc::as_ptr(&this.inner) as usize,
+            defined_at: this.defined_at().map(ToString::to_string),
+        });
+        this
     }
 }
 
@@ -58,6 +58,7 @@ pub struct ArcWriteSignal<T> {
     pub(crate) defined_at: &'static Location<'static>,
     pub(crate) value: Arc<RwLock<T>>,
     pub(crate) inner: Arc<RwLock<SubscriberSet>>,
+    pub(crate) changed: Option<fn(&T, &T) -> bool>,
 }
 
 impl<T> Clone for ArcWriteSignal<T> {
@@ -68,6 +69,7 @@ impl<T> Clone for ArcWriteSignal<T> {
             defined_at: self.defined_at,
             value: Arc::clone(&self.value),
             inner: Arc::clone(&self.inner),
+            changed: self.changed,
         }
     }
 }
@@ -145,4 +147,8 @@ impl<T: 'static> Write for ArcWriteSignal<T> {
     fn try_write_untracked(&self) -> Option<UntrackedWriteGuard<Self::Value>> {
         UntrackedWriteGuard::try_new(Arc::clone(&self.value))
     }
+
+    fn value_changed(&self, prev: &Self::Value, next: &Self::Value) -> bool {
+        self.changed.is_none_or(|changed| changed(prev, next))
+    }
 }
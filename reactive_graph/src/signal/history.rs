@@ -0,0 +1,180 @@
+use super::RwSignal;
+use crate::traits::{GetUntracked, Set, Update, With, WithUntracked};
+use std::collections::VecDeque;
+
+/// Wraps a signal (or a store field) to record its previous values, so that changes can be
+/// undone and redone.
+///
+/// Every change made through [`set`](History::set) or [`update`](History::update) first saves
+/// the current value as an undo point. Changes made directly to the underlying signal are not
+/// recorded unless you call [`checkpoint`](History::checkpoint) before making them. At most
+/// `depth` undo points are kept; the oldest is dropped when the limit is reached.
+///
+/// Whether an undo or redo is available is reactive, so it can be used to disable buttons.
+///
+/// ```
+/// # use reactive_graph::prelude::*;
+/// # use reactive_graph::signal::{History, RwSignal};
+/// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+/// let text = RwSignal::new(String::new());
+/// let history = History::new(text, 100);
+///
+/// history.set("Hello".to_string());
+/// history.update(|text| text.push_str(", world!"));
+/// assert_eq!(text.get(), "Hello, world!");
+///
+/// history.undo();
+/// assert_eq!(text.get(), "Hello");
+/// history.undo();
+/// assert_eq!(text.get(), "");
+/// assert!(!history.can_undo());
+///
+/// history.redo();
+/// assert_eq!(text.get(), "Hello");
+/// ```
+pub struct History<T: 'static, S> {
+    signal: S,
+    state: RwSignal<HistoryState<T>>,
+}
+
+struct HistoryState<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    depth: usize,
+}
+
+impl<T, S: Clone> Clone for History<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            state: self.state,
+        }
+    }
+}
+
+impl<T, S: Copy> Copy for History<T, S> {}
+
+impl<T, S> History<T, S>
+where
+    T: Send + Sync + 'static,
+    S: GetUntracked<Value = T> + Set<Value = T> + Update<Value = T>,
+{
+    /// Starts recording the history of `signal`, keeping at most `depth` undo points.
+    #[track_caller]
+    pub fn new(signal: S, depth: usize) -> Self {
+        Self {
+            signal,
+            state: RwSignal::new(HistoryState {
+                undo: VecDeque::new(),
+                redo: Vec::new(),
+                depth,
+            }),
+        }
+    }
+
+    /// Returns the signal whose history is being recorded.
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// Saves the current value as an undo point, and clears the redo stack.
+    pub fn checkpoint(&self) {
+        let Some(value) = self.signal.try_get_untracked() else {
+            return;
+        };
+        self.state.update(|state| {
+            if state.depth == 0 {
+                return;
+            }
+            if state.undo.len() == state.depth {
+                state.undo.pop_front();
+            }
+            state.undo.push_back(value);
+            state.redo.clear();
+        });
+    }
+
+    /// Saves an undo point, then sets the signal to a new value.
+    pub fn set(&self, value: T) {
+        self.checkpoint();
+        self.signal.set(value);
+    }
+
+    /// Saves an undo point, then updates the signal by applying a closure.
+    pub fn update(&self, fun: impl FnOnce(&mut T)) {
+        self.checkpoint();
+        self.signal.update(fun);
+    }
+
+    /// Restores the most recent undo point. Returns `false` if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        let Some(current) = self.signal.try_get_untracked() else {
+            return false;
+        };
+        let previous = self
+            .state
+            .try_maybe_update(|state| match state.undo.pop_back() {
+                Some(previous) => {
+                    state.redo.push(current);
+                    (true, Some(previous))
+                }
+                None => (false, None),
+            })
+            .flatten();
+        match previous {
+            Some(previous) => {
+                self.signal.set(previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the most recently undone change. Returns `false` if there was nothing to
+    /// redo.
+    pub fn redo(&self) -> bool {
+        let Some(current) = self.signal.try_get_untracked() else {
+            return false;
+        };
+        let next = self
+            .state
+            .try_maybe_update(|state| match state.redo.pop() {
+                Some(next) => {
+                    state.undo.push_back(current);
+                    (true, Some(next))
+                }
+                None => (false, None),
+            })
+            .flatten();
+        match next {
+            Some(next) => {
+                self.signal.set(next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether there is a change that can be undone. This is reactive.
+    pub fn can_undo(&self) -> bool {
+        self.state.with(|state| !state.undo.is_empty())
+    }
+
+    /// Whether there is a change that can be redone. This is reactive.
+    pub fn can_redo(&self) -> bool {
+        self.state.with(|state| !state.redo.is_empty())
+    }
+
+    /// The number of undo points currently saved.
+    pub fn undo_len(&self) -> usize {
+        self.state.with_untracked(|state| state.undo.len())
+    }
+
+    /// Discards every undo and redo point.
+    pub fn clear(&self) {
+        self.state.update(|state| {
+            state.undo.clear();
+            state.redo.clear();
+        });
+    }
+}
@@ -4,6 +4,7 @@ use super::{
     ArcReadSignal, ArcRwSignal, ArcWriteSignal, ReadSignal, WriteSignal,
 };
 use crate::{
+    computed::create_slice,
     graph::{ReactiveNode, SubscriberSet},
     owner::{ArenaItem, FromLocal, LocalStorage, Storage, SyncStorage},
     signal::guards::{UntrackedWriteGuard, WriteGuard},
@@ -12,6 +13,7 @@ use crate::{
         UntrackableGuard, Write,
     },
     unwrap_signal,
+    wrappers::{read::Signal, write::SignalSetter},
 };
 use core::fmt::Debug;
 use guardian::ArcRwLockWriteGuardian;
@@ -124,6 +126,63 @@ where
     pub fn new(value: T) -> Self {
         Self::new_with_storage(value)
     }
+
+    /// Creates a new signal with a custom comparison function, which is called with the
+    /// previous and new values when the signal is set. Subscribers are only notified if it
+    /// returns `true`.
+    ///
+    /// See [`ArcRwSignal::new_with_compare`] for details.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all)
+    )]
+    #[track_caller]
+    pub fn new_with_compare(value: T, changed: fn(&T, &T) -> bool) -> Self {
+        Self {
+            #[cfg(any(debug_assertions, leptos_debuginfo))]
+            defined_at: Location::caller(),
+            inner: ArenaItem::new_with_storage(ArcRwSignal::new_with_compare(
+                value, changed,
+            )),
+        }
+    }
+
+    /// Creates a readable and writable “slice” of the signal, focused on one part of its value.
+    ///
+    /// The getter is memoized, so reading the slice only reruns when the part of the value
+    /// that it returns has changed. Setting the slice updates the original signal by applying
+    /// the setter. This is the same as [`create_slice`]. It makes it possible to bind many inputs to fields of a single, larger
+    /// state object without creating a separate signal for each field.
+    ///
+    /// ```
+    /// # use reactive_graph::prelude::*;
+    /// # use reactive_graph::signal::RwSignal;
+    /// # let owner = reactive_graph::owner::Owner::new(); owner.set();
+    /// #[derive(Default)]
+    /// struct Form {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// let form = RwSignal::new(Form::default());
+    /// let (name, set_name) =
+    ///     form.slice(|form| form.name.clone(), |form, name| form.name = name);
+    ///
+    /// set_name.set("Alice".to_string());
+    /// assert_eq!(name.get(), "Alice");
+    /// assert_eq!(form.with(|form| form.name.clone()), "Alice");
+    /// ```
+    #[track_caller]
+    pub fn slice<O>(
+        &self,
+        getter: impl Fn(&T) -> O + Copy + Send + Sync + 'static,
+        setter: impl Fn(&mut T, O) + Copy + Send + Sync + 'static,
+    ) -> (Signal<O>, SignalSetter<O>)
+    where
+        O: PartialEq + Send + Sync + 'static,
+    {
+        create_slice(*self, getter, setter)
+    }
 }
 
 impl<T, S> RwSignal<T, S>
@@ -238,6 +297,7 @@ where
                             defined_at: Location::caller(),
                             value: Arc::clone(&read.value),
                             inner: Arc::clone(&read.inner),
+                            changed: write.changed,
                         }),
                     })
                 } else {
@@ -381,6 +441,12 @@ where
             .try_with_value(|n| n.try_write_untracked())
             .flatten()
     }
+
+    fn value_changed(&self, prev: &Self::Value, next: &Self::Value) -> bool {
+        self.inner
+            .try_with_value(|n| Write::value_changed(n, prev, next))
+            .unwrap_or(true)
+    }
 }
 
 impl<T> From<ArcRwSignal<T>> for RwSignal<T>
@@ -10,8 +10,8 @@
 
 use crate::{
     graph::{
-        AnySource, AnySubscriber, ReactiveNode, Source, SubscriberSet,
-        ToAnySource,
+        writes_are_untracked, AnySource, AnySubscriber, ReactiveNode, Source,
+        SubscriberSet, ToAnySource,
     },
     traits::{DefinedAt, IsDisposed},
     unwrap_signal,
@@ -48,7 +48,18 @@ impl DefinedAt for RwLock<SubscriberSet> {
 // Source>
 impl<T: AsSubscriberSet + DefinedAt> ReactiveNode for T {
     fn mark_dirty(&self) {
-        self.mark_subscribers_check();
+        if !writes_are_untracked() {
+            crate::devtools_event!(SignalUpdated {
+                id: self
+                    .as_subscriber_set()
+                    .map(|inner| {
+                        let subs: &RwLock<SubscriberSet> = inner.borrow();
+                        subs as *const _ as usize
+                    })
+                    .unwrap_or_default(),
+            });
+            self.mark_subscribers_check();
+        }
     }
 
     fn mark_check(&self) {}
@@ -115,7 +126,12 @@ where
 
 impl ReactiveNode for RwLock<SubscriberSet> {
     fn mark_dirty(&self) {
-        self.mark_subscribers_check();
+        if !writes_are_untracked() {
+            crate::devtools_event!(SignalUpdated {
+                id: self as *const _ as usize
+            });
+            self.mark_subscribers_check();
+        }
     }
 
     fn mark_check(&self) {}
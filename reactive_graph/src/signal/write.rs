@@ -190,4 +190,10 @@ where
             .try_with_value(|n| n.try_write_untracked())
             .flatten()
     }
+
+    fn value_changed(&self, prev: &Self::Value, next: &Self::Value) -> bool {
+        self.inner
+            .try_with_value(|n| Write::value_changed(n, prev, next))
+            .unwrap_or(true)
+    }
 }
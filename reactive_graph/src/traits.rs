@@ -115,7 +115,14 @@ impl<T: Source + ToAnySource + DefinedAt> Track for T {
         }
 
         if let Some(subscriber) = Observer::get() {
-            subscriber.add_source(self.to_any_source());
+            let source = self.to_any_source();
+            #[cfg(debug_assertions)]
+            crate::graph::record_edge(
+                &source,
+                std::any::type_name::<T>(),
+                &subscriber,
+            );
+            subscriber.add_source(source);
             self.add_subscriber(subscriber);
         } else {
             #[cfg(all(debug_assertions, feature = "effects"))]
@@ -267,6 +274,14 @@ pub trait Write: Sized + DefinedAt + Notify {
         self.try_write_untracked()
             .unwrap_or_else(unwrap_signal!(self))
     }
+
+    /// Whether replacing the previous value with the next value should notify subscribers
+    /// when the signal is [`set`](Set::set).
+    ///
+    /// By default, every update notifies subscribers.
+    fn value_changed(&self, _prev: &Self::Value, _next: &Self::Value) -> bool {
+        true
+    }
 }
 
 /// Give read-only access to a signal's value by reference inside a closure,
@@ -499,6 +514,12 @@ pub trait Update {
         &self,
         fun: impl FnOnce(&mut Self::Value) -> (bool, U),
     ) -> Option<U>;
+
+    /// Whether replacing the previous value with the next value should notify subscribers
+    /// when the signal is [`set`](Set::set).
+    fn value_changed(&self, _prev: &Self::Value, _next: &Self::Value) -> bool {
+        true
+    }
 }
 
 impl<T> Update for T
@@ -520,6 +541,10 @@ where
         drop(lock);
         Some(val)
     }
+
+    fn value_changed(&self, prev: &Self::Value, next: &Self::Value) -> bool {
+        Write::value_changed(self, prev, next)
+    }
 }
 
 /// Updates the value of the signal by replacing it.
@@ -545,7 +570,13 @@ where
 
     #[track_caller]
     fn set(&self, value: Self::Value) {
-        let failed = self.try_update(|n| *n = value).is_none();
+        let failed = self
+            .try_maybe_update(|n| {
+                let changed = self.value_changed(n, &value);
+                *n = value;
+                (changed, ())
+            })
+            .is_none();
 
         #[cfg(any(debug_assertions, leptos_debuginfo))]
         if failed && !self.is_disposed() {
@@ -0,0 +1,73 @@
+use any_spawner::Executor;
+use reactive_graph::{
+    actions::{ArcAction, DispatchPolicy},
+    owner::Owner,
+    prelude::*,
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::task;
+
+fn counting_action(runs: &Arc<AtomicUsize>) -> ArcAction<usize, usize> {
+    let runs = Arc::clone(runs);
+    ArcAction::new(move |input: &usize| {
+        runs.fetch_add(1, Ordering::Relaxed);
+        let input = *input;
+        async move { input }
+    })
+}
+
+fn delay() -> tokio::time::Sleep {
+    tokio::time::sleep(Duration::from_millis(10))
+}
+
+#[tokio::test]
+async fn debounce_runs_once_per_burst() {
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+    task::LocalSet::new()
+        .run_until(async {
+            let runs = Arc::new(AtomicUsize::new(0));
+            let action = counting_action(&runs)
+                .with_dispatch_policy(DispatchPolicy::debounce_with(delay));
+
+            for input in 0..5 {
+                action.dispatch(input);
+            }
+            assert_eq!(runs.load(Ordering::Relaxed), 0);
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert_eq!(runs.load(Ordering::Relaxed), 1);
+            assert_eq!(action.value().get_untracked(), Some(4));
+        })
+        .await
+}
+
+#[tokio::test]
+async fn throttle_runs_first_and_latest_of_a_burst() {
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+    task::LocalSet::new()
+        .run_until(async {
+            let runs = Arc::new(AtomicUsize::new(0));
+            let action = counting_action(&runs)
+                .with_dispatch_policy(DispatchPolicy::throttle_with(delay));
+
+            for input in 0..5 {
+                action.dispatch(input);
+            }
+            assert_eq!(runs.load(Ordering::Relaxed), 1);
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert_eq!(runs.load(Ordering::Relaxed), 2);
+            assert_eq!(action.value().get_untracked(), Some(4));
+        })
+        .await
+}
@@ -1,11 +1,12 @@
 use any_spawner::Executor;
 use reactive_graph::{
-    computed::{ArcAsyncDerived, AsyncDerived},
+    computed::{ArcAsyncDerived, AsyncDerived, AsyncState},
     owner::Owner,
     signal::RwSignal,
     traits::{Get, Read, Set, With, WithUntracked},
 };
-use std::future::pending;
+use futures::{channel::mpsc, lock::Mutex, StreamExt};
+use std::{future::pending, sync::Arc};
 
 #[tokio::test]
 async fn arc_async_derived_calculates_eagerly() {
@@ -137,3 +138,34 @@ async fn async_derived_with_initial() {
     signal2.set(1);
     assert_eq!(derived.await, 2);
 }
+
+#[tokio::test]
+async fn async_derived_state_distinguishes_loading_and_reloading() {
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    // each run waits until it is let through, so that its loading state can be observed
+    let (gate, runs) = mpsc::unbounded::<()>();
+    let runs = Arc::new(Mutex::new(runs));
+    let signal = RwSignal::new(10);
+    let value = AsyncDerived::new(move || {
+        let runs = Arc::clone(&runs);
+        async move {
+            runs.lock().await.next().await;
+            signal.get()
+        }
+    });
+
+    assert_eq!(value.state(), AsyncState::Loading);
+    gate.unbounded_send(()).unwrap();
+    assert_eq!(value.await, 10);
+    assert_eq!(value.state(), AsyncState::Ready(10));
+
+    signal.set(30);
+    Executor::tick().await;
+    assert_eq!(value.state(), AsyncState::Reloading(10));
+    gate.unbounded_send(()).unwrap();
+    assert_eq!(value.await, 30);
+    assert_eq!(value.state(), AsyncState::Ready(30));
+}
@@ -255,3 +255,257 @@ async fn paused_effect_pauses() {
         })
         .await
 }
+
+#[cfg(feature = "effects")]
+#[tokio::test]
+async fn batched_effect_runs_once() {
+    use imports::*;
+    use reactive_graph::{effect::batch, owner::StoredValue};
+
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    task::LocalSet::new()
+        .run_until(async {
+            let a = RwSignal::new(0);
+            let b = RwSignal::new(0);
+
+            let values = StoredValue::new(Vec::new());
+
+            Effect::new(move || {
+                values.write_value().push((a.get(), b.get()));
+            });
+
+            Executor::tick().await;
+            assert_eq!(values.get_value(), vec![(0, 0)]);
+
+            batch(move || {
+                a.set(1);
+                b.set(1);
+                a.set(2);
+            });
+
+            Executor::tick().await;
+            assert_eq!(values.get_value(), vec![(0, 0), (2, 1)]);
+        })
+        .await
+}
+
+#[cfg(feature = "effects")]
+#[tokio::test]
+async fn debounced_effect_runs_once_per_burst() {
+    use imports::*;
+    use std::time::Duration;
+
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    task::LocalSet::new()
+        .run_until(async {
+            let a = RwSignal::new(0);
+            let runs = Arc::new(RwLock::new(Vec::new()));
+
+            Effect::debounced_with(
+                || tokio::time::sleep(Duration::from_millis(20)),
+                {
+                    let runs = Arc::clone(&runs);
+                    move || runs.write().unwrap().push(a.get())
+                },
+            );
+
+            Executor::tick().await;
+            assert_eq!(*runs.read().unwrap(), vec![0]);
+
+            for n in 1..=3 {
+                a.set(n);
+                Executor::tick().await;
+            }
+            assert_eq!(*runs.read().unwrap(), vec![0]);
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert_eq!(*runs.read().unwrap(), vec![0, 3]);
+        })
+        .await
+}
+
+#[cfg(feature = "effects")]
+#[tokio::test]
+async fn selector_only_notifies_flipped_keys() {
+    use imports::*;
+    use reactive_graph::computed::Selector;
+
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    task::LocalSet::new()
+        .run_until(async {
+            let selected = RwSignal::new(0);
+            let selector = Selector::new(move || selected.get());
+
+            let runs = Arc::new(RwLock::new(vec![0; 10]));
+            for row in 0..10 {
+                let is_selected = selector.for_key(&row);
+                Effect::new_sync({
+                    let runs = Arc::clone(&runs);
+                    move |_| {
+                        is_selected.track();
+                        runs.write().unwrap()[row] += 1;
+                    }
+                });
+            }
+            Executor::tick().await;
+            assert_eq!(*runs.read().unwrap(), vec![1; 10]);
+            assert!(selector.for_key(&0).get());
+
+            // the selector's effect runs, then notifies the rows' effects
+            selected.set(3);
+            Executor::tick().await;
+            Executor::tick().await;
+            assert_eq!(
+                *runs.read().unwrap(),
+                vec![2, 1, 1, 2, 1, 1, 1, 1, 1, 1]
+            );
+            assert!(!selector.for_key(&0).get());
+            assert!(selector.for_key(&3).get());
+        })
+        .await;
+}
+
+#[cfg(feature = "effects")]
+#[tokio::test]
+async fn watch_runs_cleanups_between_runs_and_on_stop() {
+    use imports::*;
+    use reactive_graph::owner::on_cleanup;
+
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    task::LocalSet::new()
+        .run_until(async {
+            let a = RwSignal::new(0);
+            let log = Arc::new(RwLock::new(Vec::new()));
+
+            let watcher = Effect::watch(
+                move || a.get(),
+                {
+                    let log = Arc::clone(&log);
+                    move |value, prev, _| {
+                        log.write()
+                            .unwrap()
+                            .push(format!("run {value} {prev:?}"));
+                        let log = Arc::clone(&log);
+                        let value = *value;
+                        on_cleanup(move || {
+                            log.write()
+                                .unwrap()
+                                .push(format!("cleanup {value}"));
+                        });
+                    }
+                },
+                true,
+            );
+            Executor::tick().await;
+            assert_eq!(*log.read().unwrap(), vec!["run 0 None"]);
+
+            a.set(1);
+            Executor::tick().await;
+            assert_eq!(
+                *log.read().unwrap(),
+                vec!["run 0 None", "cleanup 0", "run 1 Some(0)"]
+            );
+
+            watcher.stop();
+            Executor::tick().await;
+            assert_eq!(log.read().unwrap().last().unwrap(), "cleanup 1");
+        })
+        .await;
+}
+
+#[cfg(feature = "effects")]
+#[tokio::test]
+async fn idle_effects_run_after_normal_effects() {
+    use imports::*;
+    use reactive_graph::effect::{with_effect_priority, EffectPriority};
+
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    task::LocalSet::new()
+        .run_until(async {
+            let a = RwSignal::new(0);
+            let order = Arc::new(RwLock::new(Vec::new()));
+
+            // created first, but should run last
+            with_effect_priority(EffectPriority::Idle, || {
+                Effect::new({
+                    let order = Arc::clone(&order);
+                    move || {
+                        a.track();
+                        order.write().unwrap().push("idle");
+                    }
+                });
+            });
+            Effect::new({
+                let order = Arc::clone(&order);
+                move || {
+                    a.track();
+                    order.write().unwrap().push("normal");
+                }
+            });
+
+            for _ in 0..5 {
+                Executor::tick().await;
+            }
+            assert_eq!(*order.read().unwrap(), vec!["normal", "idle"]);
+
+            a.set(1);
+            for _ in 0..5 {
+                Executor::tick().await;
+            }
+            assert_eq!(
+                *order.read().unwrap(),
+                vec!["normal", "idle", "normal", "idle"]
+            );
+        })
+        .await;
+}
+
+#[cfg(feature = "effects")]
+#[tokio::test]
+async fn effect_with_deps_only_tracks_deps() {
+    use imports::*;
+
+    _ = Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+
+    task::LocalSet::new()
+        .run_until(async {
+            let a = RwSignal::new(0);
+            let b = RwSignal::new(0);
+            let runs = Arc::new(RwLock::new(Vec::new()));
+
+            Effect::new_with_deps((a,), {
+                let runs = Arc::clone(&runs);
+                move || {
+                    runs.write().unwrap().push((a.get(), b.get()));
+                }
+            });
+            Executor::tick().await;
+            assert_eq!(*runs.read().unwrap(), vec![(0, 0)]);
+
+            b.set(1);
+            Executor::tick().await;
+            assert_eq!(*runs.read().unwrap(), vec![(0, 0)]);
+
+            a.set(1);
+            Executor::tick().await;
+            assert_eq!(*runs.read().unwrap(), vec![(0, 0), (1, 1)]);
+        })
+        .await;
+}
@@ -0,0 +1,38 @@
+#![cfg(debug_assertions)]
+
+use reactive_graph::{
+    computed::Memo,
+    graph::{record_graph, GraphNodeKind, GraphSnapshot},
+    owner::Owner,
+    prelude::*,
+    signal::RwSignal,
+};
+
+#[test]
+fn snapshot_contains_dependencies() {
+    let owner = Owner::new();
+    owner.set();
+    record_graph(true);
+
+    let count = RwSignal::new(1);
+    let double = Memo::new(move |_| count.get() * 2);
+    assert_eq!(double.get(), 2);
+
+    let graph = GraphSnapshot::capture();
+    let kind = |id| graph.nodes.iter().find(|node| node.id == id).unwrap().kind;
+    assert_eq!(graph.edges.len(), 1);
+    let (source, subscriber) = graph.edges[0];
+    assert_eq!(kind(source), GraphNodeKind::Source);
+    assert_eq!(kind(subscriber), GraphNodeKind::Computed);
+    assert!(!graph.owners.is_empty());
+
+    let dot = graph.to_dot();
+    assert!(dot.contains(&format!("n{source} -> n{subscriber};")));
+    let json = graph.to_json();
+    assert!(json.contains(&format!(
+        "{{\"source\":{source},\"subscriber\":{subscriber}}}"
+    )));
+
+    record_graph(false);
+    assert!(GraphSnapshot::capture().nodes.is_empty());
+}
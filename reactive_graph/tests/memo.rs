@@ -57,6 +57,37 @@ fn arc_memo_readable() {
     assert_eq!(d.read(), 6);
 }
 
+#[test]
+fn signal_with_compare_only_notifies_when_changed() {
+    let owner = Owner::new();
+    owner.set();
+
+    let calculations = Arc::new(RwLock::new(0));
+
+    let a = RwSignal::new_with_compare(1.0_f64, |prev, next| {
+        (prev - next).abs() > 0.01
+    });
+    let b = Memo::new({
+        let calculations = Arc::clone(&calculations);
+        move |_| {
+            *calculations.write().unwrap() += 1;
+            a.get() * 2.0
+        }
+    });
+    assert_eq!(b.get_untracked(), 2.0);
+    assert_eq!(*calculations.read().unwrap(), 1);
+
+    // within the tolerance: the value is updated, but the memo is not notified
+    a.set(1.001);
+    assert_eq!(a.get_untracked(), 1.001);
+    assert_eq!(b.get_untracked(), 2.0);
+    assert_eq!(*calculations.read().unwrap(), 1);
+
+    a.set(2.0);
+    assert_eq!(b.get_untracked(), 4.0);
+    assert_eq!(*calculations.read().unwrap(), 2);
+}
+
 #[test]
 fn memo_doesnt_repeat_calculation_per_get() {
     let owner = Owner::new();
@@ -546,3 +577,20 @@ fn memo_updates_even_if_not_read_until_later() {
     println!("read memo 2");
     assert_eq!(second_memo.get(), false);
 }
+
+#[test]
+fn weak_memo_does_not_keep_memo_alive() {
+    let owner = Owner::new();
+    owner.set();
+
+    let a = RwSignal::new(1);
+    let double = ArcMemo::new(move |_| a.get() * 2);
+    let weak = double.downgrade();
+    assert_eq!(weak.upgrade().map(|memo| memo.get()), Some(2));
+
+    a.set(2);
+    assert_eq!(weak.upgrade().map(|memo| memo.get()), Some(4));
+
+    drop(double);
+    assert!(weak.upgrade().is_none());
+}
@@ -1,6 +1,6 @@
 use reactive_graph::{
     owner::Owner,
-    signal::{arc_signal, signal, ArcRwSignal, RwSignal},
+    signal::{arc_signal, signal, ArcRwSignal, History, RwSignal},
     traits::{
         Dispose, Get, GetUntracked, IntoInner, Read, Set, Update,
         UpdateUntracked, With, WithUntracked, Write,
@@ -140,3 +140,37 @@ fn into_inner_non_arc_signal() {
     b.dispose();
     assert_eq!(a.into_inner(), Some(2));
 }
+
+#[test]
+fn history_undo_and_redo() {
+    let owner = Owner::new();
+    owner.set();
+
+    let a = RwSignal::new(0);
+    let history = History::new(a, 2);
+    assert!(!history.can_undo());
+
+    history.set(1);
+    history.update(|n| *n += 1);
+    history.set(3);
+    // only the two most recent undo points are kept
+    assert_eq!(history.undo_len(), 2);
+
+    assert!(history.undo());
+    assert_eq!(a.get(), 2);
+    assert!(history.undo());
+    assert_eq!(a.get(), 1);
+    assert!(!history.undo());
+    assert_eq!(a.get(), 1);
+
+    assert!(history.redo());
+    assert_eq!(a.get(), 2);
+
+    // changes made directly to the signal are recorded with a checkpoint,
+    // which also clears the redo stack
+    history.checkpoint();
+    a.set(10);
+    assert!(!history.can_redo());
+    assert!(history.undo());
+    assert_eq!(a.get(), 2);
+}
@@ -14,7 +14,7 @@ use reactive_graph::{
     },
 };
 use std::{
-    collections::VecDeque,
+    collections::{HashSet, VecDeque},
     fmt::Debug,
     hash::Hash,
     iter,
@@ -806,6 +806,78 @@ where
             || self.latest_keys(),
         );
     }
+
+    /// Adds, removes, or reorders entries in the collection, without notifying subscribers
+    /// to entries that are still present.
+    ///
+    /// Writing to the collection directly notifies every keyed entry, because any of them might
+    /// have changed. This instead notifies subscribers to the collection as a whole (like an
+    /// iteration over its entries) and subscribers to any entry whose key was removed, which
+    /// makes it possible to add or remove a row in a large table without rerunning every other
+    /// row.
+    ///
+    /// Changes to the value of an existing entry will not be tracked, and should be made
+    /// through [`at_key`](Self::at_key) instead.
+    pub fn update_entries<U>(
+        &self,
+        fun: impl FnOnce(&mut T) -> U,
+    ) -> Option<U> {
+        let path = self.path().into_iter().collect::<StorePath>();
+        let keys = self.keys()?;
+
+        // make sure the keys for the current value are registered, so that
+        // we can tell which of them have been removed
+        self.update_keys();
+
+        let mut parent = self.inner.writer()?;
+        parent.untrack();
+        let value = fun((self.write)(&mut *parent));
+        drop(parent);
+
+        let latest = self.latest_keys();
+        let removed = keys
+            .with_field_keys(
+                path.clone(),
+                |field_keys| {
+                    let removed = {
+                        let current = latest.iter().collect::<HashSet<_>>();
+                        field_keys
+                            .keys
+                            .iter()
+                            .filter(|(key, _)| !current.contains(key))
+                            .map(|(_, (segment, _))| *segment)
+                            .collect::<Vec<_>>()
+                    };
+                    (removed, field_keys.update(latest))
+                },
+                || self.latest_keys(),
+            )
+            .unwrap_or_default();
+
+        // notify the collection and its ancestors, but not the collection's `this` trigger,
+        // which is tracked by every keyed entry
+        let mut ancestor = path.clone();
+        let mut triggers = vec![self.get_trigger(ancestor.clone()).children];
+        while !ancestor.is_empty() {
+            ancestor.pop();
+            triggers.push(self.get_trigger(ancestor.clone()).children);
+        }
+        triggers.reverse();
+
+        for segment in removed {
+            let mut entry_path = path.clone();
+            entry_path.push(segment);
+            let trigger = self.get_trigger(entry_path);
+            triggers.push(trigger.this);
+            triggers.push(trigger.children);
+        }
+
+        for trigger in triggers {
+            trigger.notify();
+        }
+
+        Some(value)
+    }
 }
 
 impl<Inner, Prev, K, T> IntoIterator for KeyedSubfield<Inner, Prev, K, T>
@@ -1456,4 +1528,75 @@ mod tests {
         assert_eq!(b_count.load(Ordering::Relaxed), 2);
         assert_eq!(c_count.load(Ordering::Relaxed), 2);
     }
+
+    #[tokio::test]
+    async fn updating_entries_only_notifies_removed_keys() {
+        _ = any_spawner::Executor::init_tokio();
+
+        let store = Store::new(TodoVec::test_data());
+
+        let whole_count = Arc::new(AtomicUsize::new(0));
+        let a_count = Arc::new(AtomicUsize::new(0));
+        let c_count = Arc::new(AtomicUsize::new(0));
+
+        let whole = store.todos();
+        let a = AtKeyed::new(store.todos(), 10);
+        let c = AtKeyed::new(store.todos(), 12);
+
+        Effect::new_sync({
+            let whole_count = Arc::clone(&whole_count);
+            move || {
+                for todo in whole {
+                    todo.track();
+                }
+                whole_count.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        Effect::new_sync({
+            let a_count = Arc::clone(&a_count);
+            move || {
+                a.track();
+                a_count.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        Effect::new_sync({
+            let c_count = Arc::clone(&c_count);
+            move || {
+                c.track();
+                c_count.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        tick().await;
+        assert_eq!(whole_count.load(Ordering::Relaxed), 1);
+        assert_eq!(a_count.load(Ordering::Relaxed), 1);
+        assert_eq!(c_count.load(Ordering::Relaxed), 1);
+
+        // adding and removing entries notifies the collection and removed entries
+        store.todos().update_entries(|todos| {
+            todos.retain(|todo| todo.id != 12);
+            todos.insert(0, Todo::new(13, "New"));
+        });
+        assert_eq!(
+            store.todos().get_untracked(),
+            vec![Todo::new(13, "New"), Todo::new(10, "A"), Todo::new(11, "B")]
+        );
+        tick().await;
+        assert_eq!(whole_count.load(Ordering::Relaxed), 2);
+        assert_eq!(a_count.load(Ordering::Relaxed), 1);
+        assert_eq!(c_count.load(Ordering::Relaxed), 2);
+
+        // remaining entries still update after being moved
+        a.label().set("Bar".into());
+        assert_eq!(
+            store.todos().get_untracked(),
+            vec![
+                Todo::new(13, "New"),
+                Todo::new(10, "Bar"),
+                Todo::new(11, "B")
+            ]
+        );
+        tick().await;
+        assert_eq!(a_count.load(Ordering::Relaxed), 2);
+    }
 }
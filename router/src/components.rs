@@ -11,8 +11,8 @@ use crate::{
     navigate::NavigateOptions,
     nested_router::NestedRoutesView,
     resolve_path::resolve_path,
-    ChooseView, MatchNestedRoutes, NestedRoute, PossibleRouteMatch, RouteDefs,
-    SsrMode,
+    ChooseView, MatchNestedRoutes, NamedViews, NestedRoute, PossibleRouteMatch,
+    RedirectStatus, RouteDefs, ServerRedirectOptions, SsrMode,
 };
 use either_of::EitherOf3;
 use leptos::{children, prelude::*};
@@ -26,7 +26,7 @@ use std::{
     borrow::Cow,
     fmt::{Debug, Display},
     mem,
-    sync::Arc,
+    sync::{Arc, OnceLock},
     time::Duration,
 };
 
@@ -51,9 +51,30 @@ where
     }
 }
 
+static BASE_PATH: OnceLock<Cow<'static, str>> = OnceLock::new();
+
+/// Sets the base path used by any [`Router`] that does not have an explicit `base`.
+///
+/// Unlike the `base` prop, this can be read from configuration or an environment variable when
+/// the app starts, which allows the same build to be deployed at `/` or under a sub-directory
+/// like `/app`. It should be set to the same value on the server and in the browser, before the
+/// app is rendered or hydrated. It can only be set once; later calls are ignored.
+pub fn set_base_path(base: impl Into<Cow<'static, str>>) {
+    let mut base = base.into();
+    if base.ends_with('/') {
+        base = Cow::Owned(base.trim_end_matches('/').to_string());
+    }
+    _ = BASE_PATH.set(base);
+}
+
+/// Returns the base path set with [`set_base_path`], if any.
+pub fn base_path() -> Option<&'static str> {
+    BASE_PATH.get().map(|base| base.as_ref())
+}
+
 #[component(transparent)]
 pub fn Router<Chil>(
-    /// The base URL for the router. Defaults to `""`.
+    /// The base URL for the router. Defaults to the value set with [`set_base_path`], or `""`.
     #[prop(optional, into)]
     base: Option<Cow<'static, str>>,
     /// A signal that will be set while the navigation process is underway.
@@ -71,6 +92,8 @@ pub fn Router<Chil>(
 where
     Chil: IntoView,
 {
+    let base = base.or_else(|| base_path().map(Cow::Borrowed));
+
     #[cfg(feature = "ssr")]
     let (location_provider, current_url, redirect_hook) = {
         let req = use_context::<RequestUrl>().expect("no RequestUrl provided");
@@ -222,6 +245,12 @@ pub fn Routes<Defs, FallbackFn, Fallback>(
     /// Whether to use the View Transition API during navigation.
     #[prop(optional)]
     transition: bool,
+    /// If set, client-side navigations start loading the next route's data while the current
+    /// page stays displayed and interactive, and only swap in the new route once its data is
+    /// ready (see [`LazyRoute::ready`](crate::LazyRoute::ready)) or once this much time has
+    /// passed, whichever happens first.
+    #[prop(optional, into)]
+    wait_for_data: Option<Duration>,
     /// The route definitions. This should consist of one or more [`ParentRoute`] or [`Route`]
     /// components.
     children: RouteChildren<Defs>,
@@ -264,6 +293,7 @@ where
             fallback: fallback.clone(),
             set_is_routing,
             transition,
+            wait_for_data,
         }
     }
 }
@@ -275,6 +305,12 @@ pub fn FlatRoutes<Defs, FallbackFn, Fallback>(
     /// Whether to use the View Transition API during navigation.
     #[prop(optional)]
     transition: bool,
+    /// If set, client-side navigations start loading the next route's data while the current
+    /// page stays displayed and interactive, and only swap in the new route once its data is
+    /// ready (see [`LazyRoute::ready`](crate::LazyRoute::ready)) or once this much time has
+    /// passed, whichever happens first.
+    #[prop(optional, into)]
+    wait_for_data: Option<Duration>,
     /// The route definitions. This should consist of one or more [`ParentRoute`] or [`Route`]
     /// components.
     children: RouteChildren<Defs>,
@@ -321,6 +357,7 @@ where
             outer_owner: outer_owner.clone(),
             set_is_routing,
             transition,
+            wait_for_data,
         }
     }
 }
@@ -340,6 +377,9 @@ pub fn Route<Segments, View>(
     /// Defaults to out-of-order streaming.
     #[prop(optional)]
     ssr: SsrMode,
+    /// Views to display in the named [`Outlet`]s of the parent route.
+    #[prop(optional)]
+    named_views: NamedViews,
 ) -> <NestedRoute<Segments, (), (), View> as IntoMaybeErased>::Output
 where
     View: ChooseView + Clone + 'static,
@@ -347,6 +387,7 @@ where
 {
     NestedRoute::new(path, view)
         .ssr_mode(ssr)
+        .named_views(named_views)
         .into_maybe_erased()
 }
 
@@ -367,6 +408,9 @@ pub fn ParentRoute<Segments, View, Children>(
     /// Defaults to out-of-order streaming.
     #[prop(optional)]
     ssr: SsrMode,
+    /// Views to display in the named [`Outlet`]s of the parent route.
+    #[prop(optional)]
+    named_views: NamedViews,
 ) -> <NestedRoute<Segments, Children, (), View> as IntoMaybeErased>::Output
 where
     View: ChooseView + Clone + 'static,
@@ -376,6 +420,7 @@ where
     let children = children.into_inner();
     NestedRoute::new(path, view)
         .ssr_mode(ssr)
+        .named_views(named_views)
         .child(children)
         .into_maybe_erased()
 }
@@ -548,15 +593,32 @@ define_protected_parent_route!(crate::any_nested_route::AnyNestedRoute);
 define_protected_parent_route!(NestedRoute<Segments, Children, (), impl Fn() -> AnyView + Send + Clone>);
 
 /// Redirects the user to a new URL, whether on the client side or on the server
-/// side. If rendered on the server, this sets a `302` status code and sets a `Location`
-/// header. If rendered in the browser, it uses client-side navigation to redirect.
+/// side. If rendered on the server, this sets a `302` status code (or the given `status`)
+/// and sets a `Location` header, along with any additional `headers`. If rendered in the
+/// browser, it uses client-side navigation to redirect.
 /// In either case, it resolves the route relative to the current route. (To use
 /// an absolute path, prefix it with `/`).
 ///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_router::{components::Redirect, RedirectStatus};
+///
+/// #[component]
+/// fn OldBlogPath() -> impl IntoView {
+///     view! {
+///         <Redirect
+///             path="/blog"
+///             status=RedirectStatus::MovedPermanently
+///             headers=vec![("Cache-Control".into(), "max-age=3600".into())]
+///         />
+///     }
+/// }
+/// ```
+///
 /// **Note**: Support for server-side redirects is provided by the server framework
 /// integrations ([`leptos_actix`] and [`leptos_axum`]. If you’re not using one of those
 /// integrations, you should manually provide a way of redirecting on the server
-/// using [`provide_server_redirect`].
+/// using [`provide_server_redirect`] or [`provide_server_redirect_with_options`].
 ///
 /// [`leptos_actix`]: <https://docs.rs/leptos_actix/>
 /// [`leptos_axum`]: <https://docs.rs/leptos_axum/>
@@ -568,6 +630,12 @@ pub fn Redirect<P>(
     #[prop(optional)]
     #[allow(unused)]
     options: Option<NavigateOptions>,
+    /// The status code used for the redirect on the server. Defaults to `302 Found`.
+    #[prop(optional)]
+    status: RedirectStatus,
+    /// Additional headers to be set on the redirect response on the server.
+    #[prop(optional)]
+    headers: Vec<(String, String)>,
 ) where
     P: core::fmt::Display + 'static,
 {
@@ -576,11 +644,14 @@ pub fn Redirect<P>(
 
     // redirect on the server
     if let Some(redirect_fn) = use_context::<ServerRedirectFunction>() {
-        (redirect_fn.f)(&resolve_path(
-            "",
-            &path,
-            Some(&use_matched().get_untracked()),
-        ));
+        (redirect_fn.f)(
+            &resolve_path("", &path, Some(&use_matched().get_untracked())),
+            &ServerRedirectOptions {
+                status,
+                headers,
+                ..Default::default()
+            },
+        );
     }
     // redirect on the client
     else {
@@ -608,9 +679,11 @@ pub fn Redirect<P>(
 /// and [`Redirect`].
 #[derive(Clone)]
 pub struct ServerRedirectFunction {
-    f: Arc<dyn Fn(&str) + Send + Sync>,
+    f: Arc<ServerRedirectFn>,
 }
 
+type ServerRedirectFn = dyn Fn(&str, &ServerRedirectOptions) + Send + Sync;
+
 impl core::fmt::Debug for ServerRedirectFunction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ServerRedirectFunction").finish()
@@ -620,7 +693,20 @@ impl core::fmt::Debug for ServerRedirectFunction {
 /// Provides a function that can be used to redirect the user to another
 /// absolute path, on the server. This should set a `302` status code and an
 /// appropriate `Location` header.
+///
+/// Any status code or headers requested by a [`Redirect`] are ignored. Use
+/// [`provide_server_redirect_with_options`] to respect them.
 pub fn provide_server_redirect(handler: impl Fn(&str) + Send + Sync + 'static) {
+    provide_server_redirect_with_options(move |path, _| handler(path))
+}
+
+/// Provides a function that can be used to redirect the user to another
+/// absolute path, on the server. This should set the status code given in the
+/// [`ServerRedirectOptions`], an appropriate `Location` header, and any
+/// additional headers.
+pub fn provide_server_redirect_with_options(
+    handler: impl Fn(&str, &ServerRedirectOptions) + Send + Sync + 'static,
+) {
     provide_context(ServerRedirectFunction {
         f: Arc::new(handler),
     })
@@ -1,7 +1,7 @@
 use crate::{
     hooks::Matched,
     location::{LocationProvider, Url},
-    matching::{MatchParams, RouteDefs},
+    matching::{choose_view::ready_or_timeout, MatchParams, RouteDefs},
     params::ParamsMap,
     view_transition::start_view_transition,
     ChooseView, MatchInterface, MatchNestedRoutes, PathSegment, RouteList,
@@ -19,7 +19,7 @@ use reactive_graph::{
     transition::AsyncTransition,
     wrappers::write::SignalSetter,
 };
-use std::{cell::RefCell, iter, mem, rc::Rc};
+use std::{cell::RefCell, iter, mem, rc::Rc, time::Duration};
 use tachys::{
     hydration::Cursor,
     reactive_graph::OwnedView,
@@ -39,6 +39,7 @@ pub(crate) struct FlatRoutesView<Loc, Defs, FalFn> {
     pub outer_owner: Owner,
     pub set_is_routing: Option<SignalSetter<bool>>,
     pub transition: bool,
+    pub wait_for_data: Option<Duration>,
 }
 
 /// Retained view state for the flat router.
@@ -197,6 +198,7 @@ where
             outer_owner,
             set_is_routing,
             transition,
+            wait_for_data,
         } = self;
         let url_snapshot = current_url.read_untracked();
 
@@ -296,9 +298,19 @@ where
                     ScopedFuture::new({
                         let state = Rc::clone(state);
                         async move {
+                            if let Some(set_is_routing) = set_is_routing {
+                                set_is_routing.set(true);
+                            }
+
+                            // keep displaying the current route until the next
+                            // route's data is ready
+                            if let Some(timeout) = wait_for_data {
+                                view.preload().await;
+                                ready_or_timeout(view.ready(), timeout).await;
+                            }
+
                             let view = OwnedView::new(
                                 if let Some(set_is_routing) = set_is_routing {
-                                    set_is_routing.set(true);
                                     let value =
                                         AsyncTransition::run(|| view.choose())
                                             .await;
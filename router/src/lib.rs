@@ -142,6 +142,9 @@ mod navigate;
 pub mod nested_router;
 /// Support for maps of parameters in the path or in the query.
 pub mod params;
+mod redirect;
+/// Support for generating a `sitemap.xml` from the route definitions.
+pub mod sitemap;
 mod ssr_mode;
 /// Support for static routing.
 pub mod static_routes;
@@ -152,6 +155,7 @@ pub use leptos_router_macro::{lazy_route, path};
 pub use matching::*;
 pub use method::*;
 pub use navigate::*;
+pub use redirect::*;
 pub use ssr_mode::*;
 
 pub(crate) mod view_transition {
@@ -1,6 +1,8 @@
+use any_spawner::Executor;
 use either_of::*;
-use leptos::prelude::{ArcStoredValue, WriteValue};
-use std::{future::Future, marker::PhantomData};
+use futures::future::select;
+use leptos::prelude::{ArcStoredValue, ReadValue, WriteValue};
+use std::{future::Future, marker::PhantomData, pin::pin, time::Duration};
 use tachys::view::any_view::{AnyView, IntoAny};
 
 pub trait ChooseView
@@ -10,6 +12,23 @@ where
     fn choose(self) -> impl Future<Output = AnyView>;
 
     fn preload(&self) -> impl Future<Output = ()>;
+
+    /// Resolves once the data loaded by [`preload`](ChooseView::preload) is ready to be
+    /// displayed.
+    ///
+    /// This is used to delay committing a client-side navigation until the next route's data
+    /// has loaded. By default, it resolves immediately.
+    fn ready(&self) -> impl Future<Output = ()> {
+        async {}
+    }
+}
+
+/// Waits until `ready` resolves, or until `timeout` has passed, whichever happens first.
+pub(crate) async fn ready_or_timeout(
+    ready: impl Future<Output = ()>,
+    timeout: Duration,
+) {
+    select(pin!(ready), pin!(Executor::sleep(timeout))).await;
 }
 
 impl<F, View> ChooseView for F
@@ -37,6 +56,13 @@ where
         *self.data.write_value() = Some(T::data());
         T::preload().await;
     }
+
+    async fn ready(&self) {
+        let ready = self.data.read_value().as_ref().map(T::ready);
+        if let Some(ready) = ready {
+            ready.await;
+        }
+    }
 }
 
 pub trait LazyRoute: Send + 'static {
@@ -47,6 +73,16 @@ pub trait LazyRoute: Send + 'static {
     fn preload() -> impl Future<Output = ()> {
         async {}
     }
+
+    /// Resolves once the data created in [`data`](LazyRoute::data) is ready to be displayed,
+    /// for example by awaiting the resources it created.
+    ///
+    /// If `<Routes wait_for_data>` is set, client-side navigations to this route keep the
+    /// current page displayed until this resolves (or the timeout passes). By default, it
+    /// resolves immediately.
+    fn ready(&self) -> impl Future<Output = ()> + 'static {
+        async {}
+    }
 }
 
 #[derive(Debug)]
@@ -105,6 +141,13 @@ where
             Either::Right(f) => f.preload().await,
         }
     }
+
+    async fn ready(&self) {
+        match self {
+            Either::Left(f) => f.ready().await,
+            Either::Right(f) => f.ready().await,
+        }
+    }
 }
 
 macro_rules! tuples {
@@ -126,6 +169,12 @@ macro_rules! tuples {
                     $($either::$ty(f) => f.preload().await,)*
                 }
             }
+
+            async fn ready(&self) {
+                match self {
+                    $($either::$ty(f) => f.ready().await,)*
+                }
+            }
         }
     };
 }
@@ -175,3 +224,78 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{
+        channel::oneshot,
+        future::{pending, Shared},
+        FutureExt,
+    };
+    use std::{sync::Mutex, time::Instant};
+
+    static SEND_DATA: Mutex<Option<oneshot::Sender<()>>> = Mutex::new(None);
+
+    struct Gated(Shared<oneshot::Receiver<()>>);
+
+    impl LazyRoute for Gated {
+        fn data() -> Self {
+            let (tx, rx) = oneshot::channel();
+            *SEND_DATA.lock().unwrap() = Some(tx);
+            Self(rx.shared())
+        }
+
+        async fn view(_this: Self) -> AnyView {
+            ().into_any()
+        }
+
+        fn ready(&self) -> impl Future<Output = ()> + 'static {
+            self.0.clone().map(|_| ())
+        }
+    }
+
+    struct NeverReady;
+
+    impl LazyRoute for NeverReady {
+        fn data() -> Self {
+            Self
+        }
+
+        async fn view(_this: Self) -> AnyView {
+            ().into_any()
+        }
+
+        fn ready(&self) -> impl Future<Output = ()> + 'static {
+            pending()
+        }
+    }
+
+    #[test]
+    fn waits_for_the_route_to_be_ready() {
+        let lazy = Lazy::<Gated>::new();
+        futures::executor::block_on(lazy.preload());
+
+        let timeout = Duration::from_secs(5);
+        let start = Instant::now();
+        let mut waiting = pin!(ready_or_timeout(lazy.ready(), timeout));
+        assert!(futures::executor::block_on(async {
+            futures::poll!(waiting.as_mut()).is_pending()
+        }));
+
+        SEND_DATA.lock().unwrap().take().unwrap().send(()).unwrap();
+        futures::executor::block_on(waiting);
+        assert!(start.elapsed() < timeout);
+    }
+
+    #[test]
+    fn stops_waiting_after_the_timeout() {
+        let lazy = Lazy::<NeverReady>::new();
+        futures::executor::block_on(lazy.preload());
+
+        let timeout = Duration::from_millis(20);
+        let start = Instant::now();
+        futures::executor::block_on(ready_or_timeout(lazy.ready(), timeout));
+        assert!(start.elapsed() >= timeout);
+    }
+}
@@ -1,10 +1,13 @@
 #![allow(missing_docs)]
 
 mod any_choose_view;
-mod choose_view;
+pub(crate) mod choose_view;
+mod named_views;
 mod path_segment;
 pub(crate) mod resolve_path;
+pub use any_choose_view::AnyChooseView;
 pub use choose_view::*;
+pub use named_views::*;
 pub use path_segment::*;
 mod horizontal;
 mod nested;
@@ -109,6 +112,11 @@ pub trait MatchInterface {
 
     fn as_matched(&self) -> &str;
 
+    /// The views this match renders into the named outlets of its parent.
+    fn named_views(&self) -> NamedViews {
+        NamedViews::default()
+    }
+
     fn into_view_and_child(self) -> (impl ChooseView, Option<Self::Child>);
 }
 
@@ -156,7 +164,7 @@ pub struct GeneratedRouteData {
 
 #[cfg(test)]
 mod tests {
-    use super::{NestedRoute, ParamSegment, RouteDefs};
+    use super::{NamedViews, NestedRoute, ParamSegment, RouteDefs};
     use crate::{
         matching::MatchParams, MatchInterface, PathSegment, StaticSegment,
         WildcardSegment,
@@ -213,6 +221,36 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn nested_match_carries_named_views() {
+        let routes: RouteDefs<_> = RouteDefs::new(
+            NestedRoute::new(StaticSegment(""), || "Layout").child((
+                NestedRoute::new(StaticSegment("inbox"), || "Messages")
+                    .named_views(
+                        NamedViews::new()
+                            .view("sidebar", || "Folders")
+                            .view("toolbar", || "Compose"),
+                    ),
+                NestedRoute::new(StaticSegment("about"), || "About"),
+            )),
+        );
+
+        let matched = routes.match_route("/inbox").unwrap();
+        assert!(MatchInterface::named_views(&matched).is_empty());
+        let (_, child) = MatchInterface::into_view_and_child(matched);
+        let named_views = MatchInterface::named_views(&child.unwrap());
+        assert_eq!(
+            named_views.names().collect::<Vec<_>>(),
+            vec!["sidebar", "toolbar"]
+        );
+        assert!(named_views.get("sidebar").is_some());
+        assert!(named_views.get("modal").is_none());
+
+        let matched = routes.match_route("/about").unwrap();
+        let (_, child) = MatchInterface::into_view_and_child(matched);
+        assert!(MatchInterface::named_views(&child.unwrap()).is_empty());
+    }
+
     #[test]
     pub fn does_not_match_route_unless_full_param_matches() {
         let routes = RouteDefs::<_>::new((
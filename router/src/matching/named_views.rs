@@ -0,0 +1,93 @@
+use super::{any_choose_view::AnyChooseView, ChooseView};
+use std::fmt::Debug;
+
+/// A set of views that a route renders into the named
+/// [`Outlet`](crate::components::Outlet)s of its parent route, in addition to
+/// the view rendered into the parent's default (unnamed) outlet.
+///
+/// This allows a single route to populate several independent regions of a
+/// layout at once, like the main content, a sidebar, and a modal.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_router::{components::*, path, NamedViews};
+///
+/// #[component]
+/// fn Layout() -> impl IntoView {
+///     view! {
+///         <aside>
+///             <Outlet name="sidebar"/>
+///         </aside>
+///         <main>
+///             <Outlet/>
+///         </main>
+///     }
+/// }
+///
+/// # fn routes() -> impl IntoView {
+/// view! {
+///     <Router>
+///         <Routes fallback=|| "Not found.">
+///             <ParentRoute path=path!("") view=Layout>
+///                 <Route
+///                     path=path!("inbox")
+///                     view=|| "Messages"
+///                     named_views=NamedViews::new().view("sidebar", || "Folders")
+///                 />
+///             </ParentRoute>
+///         </Routes>
+///     </Router>
+/// }
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct NamedViews(Vec<(&'static str, AnyChooseView)>);
+
+impl NamedViews {
+    /// Creates an empty set of named views.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a view for the outlet with the given name, replacing any view
+    /// previously added for that name.
+    pub fn view(mut self, name: &'static str, view: impl ChooseView) -> Self {
+        let view = AnyChooseView::new(view);
+        match self.0.iter_mut().find(|(n, _)| *n == name) {
+            Some(prev) => prev.1 = view,
+            None => self.0.push((name, view)),
+        }
+        self
+    }
+
+    /// Returns the view for the outlet with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&AnyChooseView> {
+        self.0
+            .iter()
+            .find_map(|(n, view)| (*n == name).then_some(view))
+    }
+
+    /// Returns `true` if no named views have been added.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the names of the outlets for which a view was added.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.iter().map(|(name, _)| *name)
+    }
+}
+
+impl Debug for NamedViews {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl PartialEq for NamedViews {
+    fn eq(&self, other: &Self) -> bool {
+        self.names().eq(other.names())
+    }
+}
+
+impl Eq for NamedViews {}
@@ -1,7 +1,7 @@
 #![allow(clippy::type_complexity)]
 use crate::{
     matching::any_choose_view::AnyChooseView, ChooseView, MatchInterface,
-    MatchParams, RouteMatchId,
+    MatchParams, NamedViews, RouteMatchId,
 };
 use std::{borrow::Cow, fmt::Debug};
 use tachys::erased::ErasedLocal;
@@ -12,6 +12,7 @@ pub struct AnyNestedMatch {
     to_params: fn(&ErasedLocal) -> Vec<(Cow<'static, str>, String)>,
     as_id: fn(&ErasedLocal) -> RouteMatchId,
     as_matched: for<'a> fn(&'a ErasedLocal) -> &'a str,
+    named_views: fn(&ErasedLocal) -> NamedViews,
     into_view_and_child:
         fn(ErasedLocal) -> (AnyChooseView, Option<AnyNestedMatch>),
 }
@@ -56,6 +57,13 @@ where
             value.as_matched()
         }
 
+        fn named_views<T: MatchInterface + 'static>(
+            value: &ErasedLocal,
+        ) -> NamedViews {
+            let value = value.get_ref::<T>();
+            value.named_views()
+        }
+
         fn into_view_and_child<T: MatchInterface + 'static>(
             value: ErasedLocal,
         ) -> (AnyChooseView, Option<AnyNestedMatch>) {
@@ -72,6 +80,7 @@ where
             to_params: to_params::<T>,
             as_id: as_id::<T>,
             as_matched: as_matched::<T>,
+            named_views: named_views::<T>,
             into_view_and_child: into_view_and_child::<T>,
         }
     }
@@ -94,6 +103,10 @@ impl MatchInterface for AnyNestedMatch {
         (self.as_matched)(&self.value)
     }
 
+    fn named_views(&self) -> NamedViews {
+        (self.named_views)(&self.value)
+    }
+
     fn into_view_and_child(self) -> (impl ChooseView, Option<Self::Child>) {
         (self.into_view_and_child)(self.value)
     }
@@ -1,6 +1,6 @@
 use super::{
-    IntoChooseViewMaybeErased, MatchInterface, MatchNestedRoutes, PathSegment,
-    PossibleRouteMatch, RouteMatchId,
+    IntoChooseViewMaybeErased, MatchInterface, MatchNestedRoutes, NamedViews,
+    PathSegment, PossibleRouteMatch, RouteMatchId,
 };
 use crate::{ChooseView, GeneratedRouteData, MatchParams, Method, SsrMode};
 use core::{fmt, iter};
@@ -27,6 +27,7 @@ pub struct NestedRoute<Segments, Children, Data, View> {
     view: View,
     methods: HashSet<Method>,
     ssr_mode: SsrMode,
+    named_views: NamedViews,
 }
 
 impl<Segments, Children, Data, View> IntoMaybeErased
@@ -71,6 +72,7 @@ where
             view: self.view.clone(),
             methods: self.methods.clone(),
             ssr_mode: self.ssr_mode.clone(),
+            named_views: self.named_views.clone(),
         }
     }
 }
@@ -96,6 +98,7 @@ impl<Segments, View> NestedRoute<Segments, (), (), View> {
             view: view.into_maybe_erased(),
             methods: [Method::Get].into(),
             ssr_mode: Default::default(),
+            named_views: Default::default(),
         }
     }
 }
@@ -112,6 +115,7 @@ impl<Segments, Data, View> NestedRoute<Segments, (), Data, View> {
             view,
             ssr_mode,
             methods,
+            named_views,
             ..
         } = self;
         NestedRoute {
@@ -122,6 +126,7 @@ impl<Segments, Data, View> NestedRoute<Segments, (), Data, View> {
             view,
             ssr_mode,
             methods,
+            named_views,
         }
     }
 
@@ -129,6 +134,12 @@ impl<Segments, Data, View> NestedRoute<Segments, (), Data, View> {
         self.ssr_mode = ssr_mode;
         self
     }
+
+    /// Sets the views this route renders into the named outlets of its parent.
+    pub fn named_views(mut self, named_views: NamedViews) -> Self {
+        self.named_views = named_views;
+        self
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -141,6 +152,8 @@ pub struct NestedMatch<Child, View> {
     /// The nested route.
     child: Option<Child>,
     view_fn: View,
+    /// Views to be rendered into the parent's named outlets.
+    named_views: NamedViews,
 }
 
 impl<Child, View> fmt::Debug for NestedMatch<Child, View>
@@ -178,6 +191,10 @@ where
         &self.matched
     }
 
+    fn named_views(&self) -> NamedViews {
+        self.named_views.clone()
+    }
+
     fn into_view_and_child(self) -> (impl ChooseView, Option<Self::Child>) {
         (self.view_fn, self.child)
     }
@@ -295,6 +312,7 @@ where
                                 params,
                                 child,
                                 view_fn: self.view.clone(),
+                                named_views: self.named_views.clone(),
                             },
                         )),
                         remaining,
@@ -1,4 +1,6 @@
-use super::{MatchInterface, MatchNestedRoutes, PathSegment, RouteMatchId};
+use super::{
+    MatchInterface, MatchNestedRoutes, NamedViews, PathSegment, RouteMatchId,
+};
 use crate::{ChooseView, GeneratedRouteData, MatchParams};
 use core::iter;
 use either_of::*;
@@ -75,6 +77,10 @@ where
         self.0.as_matched()
     }
 
+    fn named_views(&self) -> NamedViews {
+        self.0.named_views()
+    }
+
     fn into_view_and_child(self) -> (impl ChooseView, Option<Self::Child>) {
         self.0.into_view_and_child()
     }
@@ -139,6 +145,13 @@ where
         }
     }
 
+    fn named_views(&self) -> NamedViews {
+        match self {
+            Either::Left(i) => i.named_views(),
+            Either::Right(i) => i.named_views(),
+        }
+    }
+
     fn into_view_and_child(self) -> (impl ChooseView, Option<Self::Child>) {
         match self {
             Either::Left(i) => {
@@ -267,6 +280,12 @@ macro_rules! tuples {
                 }
             }
 
+            fn named_views(&self) -> NamedViews {
+                match self {
+                    $($either::$ty(i) => i.named_views(),)*
+                }
+            }
+
             fn into_view_and_child(
                 self,
             ) -> (
@@ -2,11 +2,11 @@ use crate::{
     flat_router::MatchedRoute,
     hooks::Matched,
     location::{LocationProvider, Url},
-    matching::RouteDefs,
+    matching::{choose_view::ready_or_timeout, RouteDefs},
     params::ParamsMap,
     view_transition::start_view_transition,
-    ChooseView, MatchInterface, MatchNestedRoutes, MatchParams, PathSegment,
-    RouteList, RouteListing, RouteMatchId,
+    ChooseView, MatchInterface, MatchNestedRoutes, MatchParams, NamedViews,
+    PathSegment, RouteList, RouteListing, RouteMatchId,
 };
 use any_spawner::Executor;
 use either_of::{Either, EitherOf3};
@@ -39,6 +39,7 @@ use std::{
     pin::Pin,
     rc::Rc,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 use tachys::{
     hydration::Cursor,
@@ -61,6 +62,7 @@ pub(crate) struct NestedRoutesView<Loc, Defs, FalFn> {
     pub fallback: FalFn,
     pub set_is_routing: Option<SignalSetter<bool>>,
     pub transition: bool,
+    pub wait_for_data: Option<Duration>,
 }
 
 /// Retained view state for the nested router.
@@ -193,6 +195,7 @@ where
                     &mut full_loaders,
                     &mut state.outlets,
                     self.set_is_routing.is_some(),
+                    self.wait_for_data,
                     0,
                     &self.outer_owner,
                 );
@@ -570,6 +573,7 @@ where
 }
 
 type OutletViewFn = Box<dyn FnMut(Owner) -> Suspend<AnyView> + Send>;
+type NamedOutletViewFn = Box<dyn FnMut(&str, Owner) -> Suspend<AnyView> + Send>;
 
 pub(crate) struct RouteContext {
     id: RouteMatchId,
@@ -579,6 +583,7 @@ pub(crate) struct RouteContext {
     pub matched: ArcRwSignal<String>,
     base: Option<Oco<'static, str>>,
     view_fn: Arc<Mutex<OutletViewFn>>,
+    named_view_fn: Arc<Mutex<NamedOutletViewFn>>,
     owner: Arc<Mutex<Option<Owner>>>,
     preload_owner: Owner,
     child: ChildRoute,
@@ -610,6 +615,7 @@ impl Clone for RouteContext {
             matched: self.matched.clone(),
             base: self.base.clone(),
             view_fn: Arc::clone(&self.view_fn),
+            named_view_fn: Arc::clone(&self.named_view_fn),
             owner: Arc::clone(&self.owner),
             child: self.child.clone(),
             preload_owner: self.preload_owner.clone(),
@@ -637,6 +643,7 @@ trait AddNestedRoute {
         full_loaders: &mut Vec<oneshot::Receiver<Option<Owner>>>,
         outlets: &mut Vec<RouteContext>,
         set_is_routing: bool,
+        wait_for_data: Option<Duration>,
         level: u8,
         outer_owner: &Owner,
     ) -> u8;
@@ -717,6 +724,9 @@ where
             view_fn: Arc::new(Mutex::new(Box::new(|_owner| {
                 Suspend::new(Box::pin(async { ().into_any() }))
             }))),
+            named_view_fn: Arc::new(Mutex::new(Box::new(|_name, _owner| {
+                Suspend::new(Box::pin(async { ().into_any() }))
+            }))),
             base: base.clone(),
             child: ChildRoute(Arc::new(Mutex::new(None))),
             owner: Arc::new(Mutex::new(None)),
@@ -730,12 +740,14 @@ where
         outlets.push(outlet.clone());
 
         // send the initial view through the channel, and recurse through the children
+        let named_views = self.named_views();
         let (view, child) = self.into_view_and_child();
 
         loaders.push(Box::pin(ScopedFuture::new({
             let url = outlet.url.clone();
             let matched = Matched(matched_including_parents);
             let view_fn = Arc::clone(&outlet.view_fn);
+            let named_view_fn = Arc::clone(&outlet.named_view_fn);
             let route_owner = Arc::clone(&outlet.owner);
             let outlet = outlet.clone();
             let params = params_including_parents.clone();
@@ -755,6 +767,13 @@ where
                     })
                     .await;
                 let child = outlet.child.clone();
+                *named_view_fn.lock().or_poisoned() = named_outlet_view_fn(
+                    named_views,
+                    child.clone(),
+                    params.clone(),
+                    url.clone(),
+                    matched.clone(),
+                );
                 *view_fn.lock().or_poisoned() =
                     Box::new(move |owner_where_used| {
                         *route_owner.lock().or_poisoned() =
@@ -820,6 +839,7 @@ where
         full_loaders: &mut Vec<oneshot::Receiver<Option<Owner>>>,
         outlets: &mut Vec<RouteContext>,
         set_is_routing: bool,
+        wait_for_data: Option<Duration>,
         level: u8,
         outer_owner: &Owner,
     ) -> u8 {
@@ -858,6 +878,7 @@ where
                     self.to_params().into_iter().collect::<ParamsMap>();
                 let new_match = self.as_matched().to_owned();
 
+                let named_views = self.named_views();
                 let (view, child) = self.into_view_and_child();
 
                 // if the IDs don't match, everything below in the tree needs to be swapped:
@@ -926,6 +947,7 @@ where
                         let url = current.url.clone();
                         let matched = Matched(matched_including_parents);
                         let view_fn = Arc::clone(&current.view_fn);
+                        let named_view_fn = Arc::clone(&current.named_view_fn);
                         let route_owner = Arc::clone(&current.owner);
                         let child = outlet.child.clone();
                         async move {
@@ -947,9 +969,26 @@ where
                                         } else {
                                             view.preload().await;
                                         }
+                                        // keep displaying the current route until the
+                                        // next route's data is ready
+                                        if let Some(timeout) = wait_for_data {
+                                            ready_or_timeout(
+                                                view.ready(),
+                                                timeout,
+                                            )
+                                            .await;
+                                        }
                                     })
                                 })
                                 .await;
+                            *named_view_fn.lock().or_poisoned() =
+                                named_outlet_view_fn(
+                                    named_views,
+                                    child.clone(),
+                                    params_including_parents.clone(),
+                                    url.clone(),
+                                    matched.clone(),
+                                );
                             *view_fn.lock().or_poisoned() =
                                 Box::new(move |owner_where_used| {
                                     let prev_owner = route_owner
@@ -1038,6 +1077,7 @@ where
                         full_loaders,
                         outlets,
                         set_is_routing,
+                        wait_for_data,
                         level + 1,
                         outer_owner,
                     )
@@ -1092,10 +1132,52 @@ fn top_level_outlet(outlets: &[RouteContext], outer_owner: &Owner) -> AnyView {
     })
 }
 
+/// Creates the function used to render a matched route's named views into the named outlets
+/// of its parent, with the same route context that is provided to its main view.
+fn named_outlet_view_fn(
+    named_views: NamedViews,
+    child: ChildRoute,
+    params: ArcMemo<ParamsMap>,
+    url: ArcRwSignal<Url>,
+    matched: Matched,
+) -> NamedOutletViewFn {
+    Box::new(move |name, owner_where_used| {
+        let view = named_views.get(name).cloned();
+        let child = child.clone();
+        let params = params.clone();
+        let url = url.clone();
+        let matched = matched.clone();
+        owner_where_used.with(|| {
+            Suspend::new(Box::pin(async move {
+                provide_context(child);
+                provide_context(params);
+                provide_context(url);
+                provide_context(matched);
+                let view = match view {
+                    Some(view) => {
+                        SendWrapper::new(ScopedFuture::new(view.choose())).await
+                    }
+                    None => ().into_any(),
+                };
+                OwnedView::new(view).into_any()
+            })
+                as Pin<Box<dyn Future<Output = AnyView> + Send>>)
+        })
+    })
+}
+
 /// Displays the child route nested in a parent route, allowing you to control exactly where
 /// that child route is displayed. Renders nothing if there is no nested child.
+///
+/// A parent route can contain several outlets. The unnamed outlet displays the child route's
+/// `view`, while an outlet with a `name` displays the view the child route registered for that
+/// name with [`NamedViews`], or nothing if it did not register one.
 #[component]
-pub fn Outlet() -> impl RenderHtml
+pub fn Outlet(
+    /// The name of the outlet. If omitted, the child route's main view is displayed.
+    #[prop(optional)]
+    name: Option<&'static str>,
+) -> impl RenderHtml
 where
 {
     let ChildRoute(child) = use_context()
@@ -1105,8 +1187,17 @@ where
     child.map(|child| {
         move || {
             child.trigger.track();
-            let mut view_fn = child.view_fn.lock().or_poisoned();
-            view_fn(outer_owner.child())
+            match name {
+                None => {
+                    let mut view_fn = child.view_fn.lock().or_poisoned();
+                    view_fn(outer_owner.child())
+                }
+                Some(name) => {
+                    let mut named_view_fn =
+                        child.named_view_fn.lock().or_poisoned();
+                    named_view_fn(name, outer_owner.child())
+                }
+            }
         }
     })
 }
@@ -208,6 +208,74 @@ impl Params for () {
     }
 }
 
+/// Converts a single raw parameter value from the URL into a typed value.
+///
+/// This is implemented for every type that implements [`FromStr`], so most
+/// types work out of the box. Implement it directly for types that need custom
+/// parsing logic but do not (or should not) implement [`FromStr`].
+///
+/// ```rust
+/// use leptos_router::params::FromParam;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Slug(String);
+///
+/// #[derive(Debug, thiserror::Error)]
+/// #[error("slugs may only contain lowercase letters and dashes")]
+/// struct InvalidSlug;
+///
+/// impl FromParam for Slug {
+///     type Error = InvalidSlug;
+///
+///     fn from_param(value: &str) -> Result<Self, Self::Error> {
+///         if value.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+///             Ok(Slug(value.to_string()))
+///         } else {
+///             Err(InvalidSlug)
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Slug::from_param("hello-world").unwrap(), Slug("hello-world".into()));
+/// assert!(Slug::from_param("Hello World").is_err());
+/// ```
+pub trait FromParam
+where
+    Self: Sized,
+{
+    /// The error returned when the value cannot be converted.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Converts the raw value.
+    fn from_param(value: &str) -> Result<Self, Self::Error>;
+}
+
+impl<T> FromParam for T
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Error = <T as FromStr>::Err;
+
+    #[inline(always)]
+    fn from_param(value: &str) -> Result<Self, Self::Error> {
+        T::from_str(value)
+    }
+}
+
+/// Converts a raw value into `T`, attaching the name and value of the param to
+/// any error that occurs.
+pub fn parse_param<T>(value: &str, name: &str) -> Result<T, ParamsError>
+where
+    T: FromParam,
+{
+    T::from_param(value).map_err(|e| ParamsError::InvalidParam {
+        name: name.to_string(),
+        value: value.to_string(),
+        error: Arc::new(e),
+    })
+}
+
 /// Converts some parameter value from the URL into a typed parameter with the given name.
 pub trait IntoParam
 where
@@ -220,27 +288,39 @@ where
 
 impl<T> IntoParam for Option<T>
 where
-    T: FromStr,
-    <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    T: FromParam,
 {
     fn into_param(
         value: Option<&str>,
-        _name: &str,
+        name: &str,
     ) -> Result<Self, ParamsError> {
-        match value {
-            None => Ok(None),
-            Some(value) => match T::from_str(value) {
-                Ok(value) => Ok(Some(value)),
-                Err(e) => Err(ParamsError::Params(Arc::new(e))),
-            },
-        }
+        value.map(|value| parse_param(value, name)).transpose()
+    }
+}
+
+/// Collects the segments of a wildcard (splat) param, like `*rest` in
+/// `/files/*rest`, splitting the matched value on `/` and converting each
+/// non-empty segment. A missing param yields an empty `Vec`.
+impl<T> IntoParam for Vec<T>
+where
+    T: FromParam,
+{
+    fn into_param(
+        value: Option<&str>,
+        name: &str,
+    ) -> Result<Self, ParamsError> {
+        value
+            .unwrap_or_default()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| parse_param(segment, name))
+            .collect()
     }
 }
 
 /// Helpers for the `Params` derive macro to allow specialization without nightly.
 pub mod macro_helpers {
-    use crate::params::{IntoParam, ParamsError};
-    use std::{str::FromStr, sync::Arc};
+    use crate::params::{parse_param, FromParam, IntoParam, ParamsError};
 
     /// This struct is never actually created; it just exists so that we can impl associated
     /// functions on it.
@@ -264,8 +344,7 @@ pub mod macro_helpers {
     /// (which it won't if `T` does not implement `IntoParam`)
     pub trait Fallback<T>: Sized
     where
-        T: FromStr,
-        <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+        T: FromParam,
     {
         /// Fallback function in case the inherent impl on the Wrapper struct does not exist for
         /// `T`
@@ -276,19 +355,16 @@ pub mod macro_helpers {
         ) -> Result<T, ParamsError> {
             let value = value
                 .ok_or_else(|| ParamsError::MissingParam(name.to_string()))?;
-            T::from_str(value).map_err(|e| ParamsError::Params(Arc::new(e)))
+            parse_param(value, name)
         }
     }
 
-    impl<T> Fallback<T> for Wrapper<T>
-    where
-        T: FromStr,
-        <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
-    {
-    }
+    impl<T> Fallback<T> for Wrapper<T> where T: FromParam {}
 }
+
 /// Errors that can occur while parsing params using [`Params`].
 #[derive(Error, Debug, Clone)]
+#[non_exhaustive]
 pub enum ParamsError {
     /// A field was missing from the route params.
     #[error("could not find parameter {0}")]
@@ -296,6 +372,17 @@ pub enum ParamsError {
     /// Something went wrong while deserializing a field.
     #[error("failed to deserialize parameters")]
     Params(Arc<dyn std::error::Error + Send + Sync>),
+    /// A param was present, but its value could not be converted into the
+    /// expected type.
+    #[error("could not convert parameter {name} from {value:?}: {error}")]
+    InvalidParam {
+        /// The name of the param.
+        name: String,
+        /// The raw value found in the URL.
+        value: String,
+        /// The underlying conversion error.
+        error: Arc<dyn std::error::Error + Send + Sync>,
+    },
 }
 
 impl PartialEq for ParamsError {
@@ -303,6 +390,18 @@ impl PartialEq for ParamsError {
         match (self, other) {
             (Self::MissingParam(l0), Self::MissingParam(r0)) => l0 == r0,
             (Self::Params(_), Self::Params(_)) => false,
+            (
+                Self::InvalidParam {
+                    name: l_name,
+                    value: l_value,
+                    ..
+                },
+                Self::InvalidParam {
+                    name: r_name,
+                    value: r_value,
+                    ..
+                },
+            ) => l_name == r_name && l_value == r_value,
             _ => false,
         }
     }
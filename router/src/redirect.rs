@@ -0,0 +1,134 @@
+/// The HTTP status code that should be used for a server-side redirect.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RedirectStatus {
+    /// [`301 Moved Permanently`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/301):
+    /// the resource has permanently moved. Clients may change the method to `GET`.
+    MovedPermanently,
+    /// [`302 Found`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/302):
+    /// the resource has temporarily moved. This is the default.
+    #[default]
+    Found,
+    /// [`303 See Other`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/303):
+    /// the result should be fetched from another URL with a `GET` request, typically
+    /// after a form submission.
+    SeeOther,
+    /// [`307 Temporary Redirect`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/307):
+    /// the resource has temporarily moved, and the method and body should not change.
+    TemporaryRedirect,
+    /// [`308 Permanent Redirect`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/308):
+    /// the resource has permanently moved, and the method and body should not change.
+    PermanentRedirect,
+}
+
+impl RedirectStatus {
+    /// The numeric status code.
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            RedirectStatus::MovedPermanently => 301,
+            RedirectStatus::Found => 302,
+            RedirectStatus::SeeOther => 303,
+            RedirectStatus::TemporaryRedirect => 307,
+            RedirectStatus::PermanentRedirect => 308,
+        }
+    }
+
+    /// Whether this redirect indicates that the resource has moved permanently.
+    pub fn is_permanent(&self) -> bool {
+        matches!(
+            self,
+            RedirectStatus::MovedPermanently
+                | RedirectStatus::PermanentRedirect
+        )
+    }
+}
+
+impl TryFrom<u16> for RedirectStatus {
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            301 => Ok(RedirectStatus::MovedPermanently),
+            302 => Ok(RedirectStatus::Found),
+            303 => Ok(RedirectStatus::SeeOther),
+            307 => Ok(RedirectStatus::TemporaryRedirect),
+            308 => Ok(RedirectStatus::PermanentRedirect),
+            _ => Err(value),
+        }
+    }
+}
+
+/// What a server function call made by the client (rather than by a plain HTML `<form>`) does
+/// when the server function redirects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ClientRedirect {
+    /// Navigates to the redirect location with client-side routing, once the server function
+    /// has returned.
+    #[default]
+    Navigate,
+    /// Ignores the redirect, and returns the server function's value as usual.
+    ///
+    /// This is useful for an `<ActionForm/>` whose server function should send users without
+    /// JavaScript/WASM to a confirmation page, while users with it stay on the page and see the
+    /// result of the action.
+    Ignore,
+}
+
+/// Options for a server-side redirect, passed to the function provided with
+/// [`provide_server_redirect_with_options`](crate::components::provide_server_redirect_with_options).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServerRedirectOptions {
+    /// The status code that will be sent with the redirect.
+    pub status: RedirectStatus,
+    /// Additional headers that will be added to the redirect response.
+    pub headers: Vec<(String, String)>,
+    /// What a server function call made by the client does with the redirect. The status code
+    /// and headers are only used for plain HTML `<form>` submissions and ordinary navigations.
+    pub client: ClientRedirect,
+}
+
+impl ServerRedirectOptions {
+    /// Creates options for a redirect with the given status code.
+    pub fn new(status: RedirectStatus) -> Self {
+        Self {
+            status,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a header to the redirect response.
+    pub fn header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets what a server function call made by the client does with the redirect.
+    pub fn client(mut self, client: ClientRedirect) -> Self {
+        self.client = client;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedirectStatus;
+
+    #[test]
+    fn redirect_status_round_trips() {
+        for status in [
+            RedirectStatus::MovedPermanently,
+            RedirectStatus::Found,
+            RedirectStatus::SeeOther,
+            RedirectStatus::TemporaryRedirect,
+            RedirectStatus::PermanentRedirect,
+        ] {
+            assert_eq!(RedirectStatus::try_from(status.as_u16()), Ok(status));
+        }
+        assert_eq!(RedirectStatus::try_from(200), Err(200));
+        assert!(RedirectStatus::PermanentRedirect.is_permanent());
+        assert!(!RedirectStatus::default().is_permanent());
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    static_routes::StaticPath, ExpandOptionals, PathSegment, RouteList,
+};
+use std::fmt::{self, Display, Write};
+
+/// How frequently the page at a [`SitemapEntry`] is likely to change.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeFrequency {
+    /// The page changes each time it is accessed.
+    Always,
+    /// The page changes every hour.
+    Hourly,
+    /// The page changes every day.
+    Daily,
+    /// The page changes every week.
+    Weekly,
+    /// The page changes every month.
+    Monthly,
+    /// The page changes every year.
+    Yearly,
+    /// The page is archived and will not change.
+    Never,
+}
+
+impl Display for ChangeFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChangeFrequency::Always => "always",
+            ChangeFrequency::Hourly => "hourly",
+            ChangeFrequency::Daily => "daily",
+            ChangeFrequency::Weekly => "weekly",
+            ChangeFrequency::Monthly => "monthly",
+            ChangeFrequency::Yearly => "yearly",
+            ChangeFrequency::Never => "never",
+        })
+    }
+}
+
+/// A single `<url>` entry in a [`Sitemap`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SitemapEntry {
+    /// The path of the page, relative to the sitemap's base URL (like `/blog/my-post`).
+    pub path: String,
+    /// The date the page was last modified, in W3C Datetime format (like `2024-01-31`).
+    pub lastmod: Option<String>,
+    /// How frequently the page is likely to change.
+    pub changefreq: Option<ChangeFrequency>,
+    /// The priority of this page relative to other pages on the site, between `0.0` and `1.0`.
+    pub priority: Option<f32>,
+}
+
+impl SitemapEntry {
+    /// Creates an entry for the given path.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the date the page was last modified.
+    pub fn lastmod(mut self, lastmod: impl Into<String>) -> Self {
+        self.lastmod = Some(lastmod.into());
+        self
+    }
+
+    /// Sets how frequently the page is likely to change.
+    pub fn changefreq(mut self, changefreq: ChangeFrequency) -> Self {
+        self.changefreq = Some(changefreq);
+        self
+    }
+
+    /// Sets the priority of this page, clamped between `0.0` and `1.0`.
+    pub fn priority(mut self, priority: f32) -> Self {
+        self.priority = Some(priority.clamp(0.0, 1.0));
+        self
+    }
+}
+
+/// A `sitemap.xml` document, generated from the app's route definitions.
+///
+/// Every route that does not contain any params is included automatically. Routes with params
+/// can be added by prerendering static routes with
+/// [`with_static_routes`](Sitemap::with_static_routes), or by adding entries manually.
+///
+/// ```rust
+/// use leptos_router::{
+///     sitemap::{ChangeFrequency, Sitemap, SitemapEntry},
+///     PathSegment, RouteList, RouteListing,
+/// };
+///
+/// let routes = RouteList::from(vec![
+///     RouteListing::from_path([PathSegment::Static("/".into())]),
+///     RouteListing::from_path([PathSegment::Static("about".into())]),
+///     RouteListing::from_path([
+///         PathSegment::Static("post".into()),
+///         PathSegment::Param("id".into()),
+///     ]),
+/// ]);
+///
+/// let sitemap = Sitemap::from_route_list("https://example.com/", &routes)
+///     .entry(
+///         SitemapEntry::new("/post/hello-world")
+///             .changefreq(ChangeFrequency::Monthly),
+///     );
+/// let xml = sitemap.to_xml();
+/// assert!(xml.contains("<loc>https://example.com/about</loc>"));
+/// assert!(xml.contains("<loc>https://example.com/post/hello-world</loc>"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sitemap {
+    base_url: String,
+    entries: Vec<SitemapEntry>,
+}
+
+impl Sitemap {
+    /// Creates an empty sitemap. `base_url` is the scheme and host (and optionally, a path
+    /// prefix) that will be prepended to the path of each entry.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        Self {
+            base_url,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Creates a sitemap containing every statically-known path in the route list, i.e., every
+    /// route that does not contain any params.
+    pub fn from_route_list(
+        base_url: impl Into<String>,
+        routes: &RouteList,
+    ) -> Self {
+        let mut sitemap = Self::new(base_url);
+        for listing in routes.iter() {
+            for path in listing.path().to_vec().expand_optionals() {
+                let is_static = path.iter().all(|segment| {
+                    matches!(
+                        segment,
+                        PathSegment::Static(_) | PathSegment::Unit
+                    )
+                });
+                if is_static {
+                    for path in StaticPath::new(path).into_paths(None) {
+                        sitemap.push(SitemapEntry::new(path.to_string()));
+                    }
+                }
+            }
+        }
+        sitemap
+    }
+
+    /// Adds every path that will be prerendered for routes using
+    /// [`SsrMode::Static`](crate::SsrMode::Static), including paths with params.
+    pub async fn with_static_routes(mut self, routes: RouteList) -> Self {
+        for path in routes.into_static_paths().await {
+            self.push(SitemapEntry::new(path.to_string()));
+        }
+        self
+    }
+
+    /// Adds an entry, returning the sitemap.
+    pub fn entry(mut self, entry: SitemapEntry) -> Self {
+        self.push(entry);
+        self
+    }
+
+    /// Adds an entry. If an entry with the same path already exists, it is replaced.
+    pub fn push(&mut self, mut entry: SitemapEntry) {
+        if entry.path.is_empty() {
+            entry.path.push('/');
+        } else if !entry.path.starts_with('/') {
+            entry.path.insert(0, '/');
+        }
+        match self.entries.iter_mut().find(|e| e.path == entry.path) {
+            Some(prev) => *prev = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// The entries in this sitemap.
+    pub fn entries(&self) -> &[SitemapEntry] {
+        &self.entries
+    }
+
+    /// Renders the sitemap as an XML document.
+    pub fn to_xml(&self) -> String {
+        let mut buf = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset \
+             xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for entry in &self.entries {
+            buf.push_str("  <url>\n    <loc>");
+            escape_xml(&mut buf, &self.base_url);
+            escape_xml(&mut buf, &entry.path);
+            buf.push_str("</loc>\n");
+            if let Some(lastmod) = &entry.lastmod {
+                buf.push_str("    <lastmod>");
+                escape_xml(&mut buf, lastmod);
+                buf.push_str("</lastmod>\n");
+            }
+            if let Some(changefreq) = entry.changefreq {
+                _ = writeln!(buf, "    <changefreq>{changefreq}</changefreq>");
+            }
+            if let Some(priority) = entry.priority {
+                _ = writeln!(buf, "    <priority>{priority:.1}</priority>");
+            }
+            buf.push_str("  </url>\n");
+        }
+        buf.push_str("</urlset>\n");
+        buf
+    }
+}
+
+impl Display for Sitemap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_xml())
+    }
+}
+
+fn escape_xml(buf: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&apos;"),
+            c => buf.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RouteListing;
+
+    #[test]
+    fn includes_only_static_routes() {
+        let routes = RouteList::from(vec![
+            RouteListing::from_path([PathSegment::Static("".into())]),
+            RouteListing::from_path([
+                PathSegment::Static("blog".into()),
+                PathSegment::OptionalParam("page".into()),
+            ]),
+            RouteListing::from_path([
+                PathSegment::Static("files".into()),
+                PathSegment::Splat("rest".into()),
+            ]),
+        ]);
+        let sitemap = Sitemap::from_route_list("https://example.com/", &routes);
+        let paths = sitemap
+            .entries()
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["/", "/blog"]);
+    }
+
+    #[test]
+    fn renders_escaped_xml() {
+        let sitemap = Sitemap::new("https://example.com")
+            .entry(
+                SitemapEntry::new("search?a=1&b=2")
+                    .lastmod("2024-01-31")
+                    .changefreq(ChangeFrequency::Weekly)
+                    .priority(0.8),
+            )
+            .entry(SitemapEntry::new("/search?a=1&b=2").priority(2.0));
+        assert_eq!(
+            sitemap.to_xml(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset \
+             xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n  \
+             <url>\n    \
+             <loc>https://example.com/search?a=1&amp;b=2</loc>\n    \
+             <priority>1.0</priority>\n  </url>\n</urlset>\n"
+        );
+    }
+}
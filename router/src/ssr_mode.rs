@@ -23,6 +23,10 @@ use crate::static_routes::StaticRoute;
 /// 6. **`Static`**: Renders the page when the server starts up, or incrementally, using the
 ///    configuration provided by a [`StaticRoute`].
 ///
+/// Each `<Route/>` can set its own mode with its `ssr` prop, so a single app can mix static
+/// pages that are regenerated on a schedule (see [`StaticRoute::regenerate_every`]) with
+/// streamed or fully-async pages.
+///
 /// The mode defaults to out-of-order streaming. For a path that includes multiple nested routes, the most
 /// restrictive mode will be used: i.e., if even a single nested route asks for `Async` rendering, the whole initial
 /// request will be rendered `Async`. (`Async` is the most restricted requirement, followed by `InOrder`, `PartiallyBlocked`, and `OutOfOrder`.)
@@ -1,4 +1,5 @@
 use crate::{hooks::RawParamsMap, params::ParamsMap, PathSegment};
+use any_spawner::Executor;
 use futures::{channel::oneshot, stream, Stream, StreamExt};
 use leptos::task::spawn;
 use reactive_graph::{owner::Owner, traits::GetUntracked};
@@ -8,6 +9,7 @@ use std::{
     ops::Deref,
     pin::Pin,
     sync::Arc,
+    time::Duration,
 };
 
 type PinnedFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
@@ -87,6 +89,14 @@ impl StaticRoute {
         self
     }
 
+    /// Regenerates the route every time the given interval has passed, for each set of params.
+    ///
+    /// Each set of params waits on a timer from [`Executor::sleep`], rather than on its own
+    /// thread.
+    pub fn regenerate_every(self, interval: Duration) -> Self {
+        self.regenerate(move |_| every(interval))
+    }
+
     /// Returns a set of params that should be prerendered.
     pub async fn to_prerendered_params(&self) -> Option<StaticParamsMap> {
         match &self.prerender_params {
@@ -128,6 +138,14 @@ impl PartialEq for StaticRoute {
 
 impl Eq for StaticRoute {}
 
+/// Returns a stream that yields once each time the interval has passed.
+fn every(interval: Duration) -> impl Stream<Item = ()> + Send + 'static {
+    stream::unfold((), move |()| async move {
+        Executor::sleep(interval).await;
+        Some(((), ()))
+    })
+}
+
 /// A map of params for static routes.
 #[derive(Debug, Clone, Default)]
 pub struct StaticParamsMap(pub Vec<(String, Vec<String>)>);
@@ -440,4 +458,15 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn regenerate_every_yields_once_per_interval() {
+        let route =
+            StaticRoute::new().regenerate_every(Duration::from_millis(10));
+        let regenerate = route.regenerate.expect("regeneration is set");
+        let ticks = futures::executor::block_on(
+            regenerate(&ParamsMap::new()).take(2).collect::<Vec<_>>(),
+        );
+        assert_eq!(ticks.len(), 2);
+    }
 }
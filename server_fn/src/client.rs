@@ -17,6 +17,31 @@ pub fn get_server_url() -> &'static str {
     ROOT_URL.get().copied().unwrap_or("")
 }
 
+static PATH_PREFIX: OnceLock<String> = OnceLock::new();
+
+/// Set a path prefix that is added, at runtime, in front of the path of every server function.
+///
+/// This allows the same build to be deployed under different sub-directories (like `/` or
+/// `/app`) without recompiling. Requests from the client are sent to the prefixed path, and the
+/// server integrations mount server functions at the prefixed path, so this should be set to the
+/// same value on the server and the client, before any server functions are called or registered.
+/// It can only be set once; later calls are ignored.
+pub fn set_server_fn_path_prefix(prefix: impl Into<String>) {
+    let mut prefix = prefix.into();
+    while prefix.ends_with('/') {
+        prefix.pop();
+    }
+    if !prefix.is_empty() && !prefix.starts_with('/') {
+        prefix.insert(0, '/');
+    }
+    _ = PATH_PREFIX.set(prefix);
+}
+
+/// Returns the path prefix for all server functions, or `""` if none has been set.
+pub fn get_server_fn_path_prefix() -> &'static str {
+    PATH_PREFIX.get().map(String::as_str).unwrap_or("")
+}
+
 /// A client defines a pair of request/response types and the logic to send
 /// and receive them.
 ///
@@ -55,7 +80,7 @@ pub trait Client<Error, InputStreamError = Error, OutputStreamError = Error> {
 #[cfg(feature = "browser")]
 /// Implements [`Client`] for a `fetch` request in the browser.
 pub mod browser {
-    use super::{get_server_url, Client};
+    use super::{get_server_fn_path_prefix, get_server_url, Client};
     use crate::{
         error::{FromServerFnError, IntoAppError, ServerFnErrorErr},
         request::browser::{BrowserRequest, RequestInner},
@@ -129,7 +154,8 @@ pub mod browser {
             {
                 websocket_server_url = format!("wss://{postfix}");
             }
-            let url = format!("{websocket_server_url}{url}");
+            let prefix = get_server_fn_path_prefix();
+            let url = format!("{websocket_server_url}{prefix}{url}");
             SendWrapper::new(async move {
                 let websocket =
                     gloo_net::websocket::futures::WebSocket::open(&url)
@@ -226,7 +252,7 @@ pub mod browser {
 #[cfg(feature = "reqwest")]
 /// Implements [`Client`] for a request made by [`reqwest`].
 pub mod reqwest {
-    use super::{get_server_url, Client};
+    use super::{get_server_fn_path_prefix, get_server_url, Client};
     use crate::{
         error::{FromServerFnError, IntoAppError, ServerFnErrorErr},
         request::reqwest::CLIENT,
@@ -275,7 +301,8 @@ pub mod reqwest {
             {
                 websocket_server_url = format!("wss://{postfix}");
             }
-            let url = format!("{websocket_server_url}{path}");
+            let prefix = get_server_fn_path_prefix();
+            let url = format!("{websocket_server_url}{prefix}{path}");
             let (ws_stream, _) =
                 tokio_tungstenite::connect_async(url).await.map_err(|e| {
                     Error::from_server_fn_error(ServerFnErrorErr::Request(
@@ -6,8 +6,16 @@ use crate::{
 };
 use http::Method;
 use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
 
 /// Pass arguments as a URL-encoded query string of a `GET` request.
+///
+/// All of the `Url` encodings accept the field names an HTML form would use for
+/// nested arguments: `items[0][title]` or `items[0].title` for nested structs,
+/// and `tags[]` for each element of a `Vec`. See [`deserialize_form`].
 pub struct GetUrl;
 
 /// Pass arguments as the URL-encoded body of a `POST` request.
@@ -15,19 +23,35 @@ pub struct PostUrl;
 
 /// Pass arguments as the URL-encoded query string of a `DELETE` request.
 /// **Note**: Browser support for `DELETE` requests without JS/WASM may be poor.
-/// Consider using a `POST` request if functionality without JS/WASM is required.
+/// Consider using a `POST` request if functionality without JS/WASM is required,
+/// or an `<ActionForm/>`, which submits it with a
+/// [method override](crate::method_override) when JS/WASM is unavailable.
 pub struct DeleteUrl;
 
 /// Pass arguments as the URL-encoded body of a `PATCH` request.
 /// **Note**: Browser support for `PATCH` requests without JS/WASM may be poor.
-/// Consider using a `POST` request if functionality without JS/WASM is required.
+/// Consider using a `POST` request if functionality without JS/WASM is required,
+/// or an `<ActionForm/>`, which submits it with a
+/// [method override](crate::method_override) when JS/WASM is unavailable.
 pub struct PatchUrl;
 
 /// Pass arguments as the URL-encoded body of a `PUT` request.
 /// **Note**: Browser support for `PUT` requests without JS/WASM may be poor.
-/// Consider using a `POST` request if functionality without JS/WASM is required.
+/// Consider using a `POST` request if functionality without JS/WASM is required,
+/// or an `<ActionForm/>`, which submits it with a
+/// [method override](crate::method_override) when JS/WASM is unavailable.
 pub struct PutUrl;
 
+/// A `Url` encoding whose arguments can be submitted by an HTML `<form>`: directly for
+/// `POST`, or with a [method override](crate::method_override) for `PUT`, `PATCH`, and
+/// `DELETE`.
+pub trait FormUrlEncoding: Encoding {}
+
+impl FormUrlEncoding for PostUrl {}
+impl FormUrlEncoding for PutUrl {}
+impl FormUrlEncoding for PatchUrl {}
+impl FormUrlEncoding for DeleteUrl {}
+
 impl ContentType for GetUrl {
     const CONTENT_TYPE: &'static str = "application/x-www-form-urlencoded";
 }
@@ -58,11 +82,9 @@ where
 {
     async fn from_req(req: Request) -> Result<Self, E> {
         let string_data = req.as_query().unwrap_or_default();
-        let args = serde_qs::Config::new(5, false)
-            .deserialize_str::<Self>(string_data)
-            .map_err(|e| {
-                ServerFnErrorErr::Args(e.to_string()).into_app_error()
-            })?;
+        let args = deserialize_form::<Self>(string_data).map_err(|e| {
+            ServerFnErrorErr::Args(e.to_string()).into_app_error()
+        })?;
         Ok(args)
     }
 }
@@ -97,11 +119,9 @@ where
 {
     async fn from_req(req: Request) -> Result<Self, E> {
         let string_data = req.try_into_string().await?;
-        let args = serde_qs::Config::new(5, false)
-            .deserialize_str::<Self>(&string_data)
-            .map_err(|e| {
-                ServerFnErrorErr::Args(e.to_string()).into_app_error()
-            })?;
+        let args = deserialize_form::<Self>(&string_data).map_err(|e| {
+            ServerFnErrorErr::Args(e.to_string()).into_app_error()
+        })?;
         Ok(args)
     }
 }
@@ -135,12 +155,18 @@ where
     E: FromServerFnError,
 {
     async fn from_req(req: Request) -> Result<Self, E> {
-        let string_data = req.as_query().unwrap_or_default();
-        let args = serde_qs::Config::new(5, false)
-            .deserialize_str::<Self>(string_data)
-            .map_err(|e| {
-                ServerFnErrorErr::Args(e.to_string()).into_app_error()
-            })?;
+        // a form submitted with a method override sends its arguments in the body
+        let query = req
+            .as_query()
+            .filter(|query| !query.is_empty())
+            .map(str::to_string);
+        let string_data = match query {
+            Some(query) => query,
+            None => req.try_into_string().await?,
+        };
+        let args = deserialize_form::<Self>(&string_data).map_err(|e| {
+            ServerFnErrorErr::Args(e.to_string()).into_app_error()
+        })?;
         Ok(args)
     }
 }
@@ -175,11 +201,9 @@ where
 {
     async fn from_req(req: Request) -> Result<Self, E> {
         let string_data = req.try_into_string().await?;
-        let args = serde_qs::Config::new(5, false)
-            .deserialize_str::<Self>(&string_data)
-            .map_err(|e| {
-                ServerFnErrorErr::Args(e.to_string()).into_app_error()
-            })?;
+        let args = deserialize_form::<Self>(&string_data).map_err(|e| {
+            ServerFnErrorErr::Args(e.to_string()).into_app_error()
+        })?;
         Ok(args)
     }
 }
@@ -214,11 +238,151 @@ where
 {
     async fn from_req(req: Request) -> Result<Self, E> {
         let string_data = req.try_into_string().await?;
-        let args = serde_qs::Config::new(5, false)
-            .deserialize_str::<Self>(&string_data)
-            .map_err(|e| {
-                ServerFnErrorErr::Args(e.to_string()).into_app_error()
-            })?;
+        let args = deserialize_form::<Self>(&string_data).map_err(|e| {
+            ServerFnErrorErr::Args(e.to_string()).into_app_error()
+        })?;
         Ok(args)
     }
 }
+
+/// Deserializes a URL-encoded form body or query string into `T`.
+///
+/// Field names use the bracket notation of [`serde_qs`], so a struct argument
+/// `item` with a `title` field is read from `item[title]`, and a `Vec` argument
+/// `tags` from `tags[0]`, `tags[1]`, and so on. Two conventions used by plain
+/// HTML forms are accepted as well:
+/// - dotted fields, so `items[0].title` is the same as `items[0][title]`
+/// - empty brackets, so each `tags[]` is the next element of `tags`
+///
+/// Within a list of structs, `items[][title]` starts a new element each time a
+/// field repeats, so the inputs of one row can be given in any order.
+pub fn deserialize_form<T>(data: &str) -> Result<T, serde_qs::Error>
+where
+    T: DeserializeOwned,
+{
+    serde_qs::Config::new(5, false)
+        .deserialize_str::<T>(&normalize_form_keys(data))
+}
+
+/// Rewrites dotted and empty-bracket field names into the indexed bracket
+/// notation `serde_qs` expects, leaving the data untouched if there are none.
+fn normalize_form_keys(data: &str) -> Cow<'_, str> {
+    let pairs = url::form_urlencoded::parse(data.as_bytes());
+    if !pairs
+        .clone()
+        .any(|(key, _)| key.contains('.') || key.contains("[]"))
+    {
+        return Cow::Borrowed(data);
+    }
+
+    let mut lists = HashMap::new();
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    for (key, value) in pairs {
+        serializer.append_pair(&normalize_key(&key, &mut lists), &value);
+    }
+    Cow::Owned(serializer.finish())
+}
+
+/// The segments of a field name after its first one, where `None` is `[]`.
+fn key_segments(key: &str) -> (&str, Vec<Option<&str>>) {
+    let base_end = key.find(['[', '.']).unwrap_or(key.len());
+    let (base, mut rest) = key.split_at(base_end);
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(bracketed) = rest.strip_prefix('[') {
+            let end = bracketed.find(']').unwrap_or(bracketed.len());
+            let segment = &bracketed[..end];
+            segments.push((!segment.is_empty()).then_some(segment));
+            rest = bracketed.get(end + 1..).unwrap_or_default();
+        } else {
+            let dotted = rest.strip_prefix('.').unwrap_or(rest);
+            let end = dotted.find(['[', '.']).unwrap_or(dotted.len());
+            segments.push(Some(&dotted[..end]));
+            rest = &dotted[end..];
+        }
+    }
+    (base, segments)
+}
+
+/// Rewrites one field name, numbering each `[]` by how many times the rest of
+/// the name has already been seen under the same list.
+fn normalize_key(
+    key: &str,
+    lists: &mut HashMap<String, (usize, HashSet<String>)>,
+) -> String {
+    let (base, segments) = key_segments(key);
+    let mut normalized = base.to_string();
+    for (idx, segment) in segments.iter().enumerate() {
+        match segment {
+            Some(segment) => {
+                normalized.push('[');
+                normalized.push_str(segment);
+                normalized.push(']');
+            }
+            None => {
+                let rest = segments[idx + 1..]
+                    .iter()
+                    .map(|segment| format!("[{}]", segment.unwrap_or_default()))
+                    .collect::<String>();
+                let (index, seen) =
+                    lists.entry(normalized.clone()).or_default();
+                if !seen.insert(rest.clone()) {
+                    *index += 1;
+                    seen.clear();
+                    seen.insert(rest);
+                }
+                normalized.push_str(&format!("[{index}]"));
+            }
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deserialize_form;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        title: String,
+        done: Option<String>,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Args {
+        tags: Vec<String>,
+        items: Vec<Item>,
+    }
+
+    #[test]
+    fn reads_lists_and_nested_fields_from_form_names() {
+        let expected = Args {
+            tags: vec!["a".into(), "b".into()],
+            items: vec![
+                Item {
+                    title: "first".into(),
+                    done: Some("on".into()),
+                },
+                Item {
+                    title: "second".into(),
+                    done: None,
+                },
+            ],
+        };
+
+        let indexed = "tags%5B0%5D=a&tags%5B1%5D=b&items%5B0%5D%5Btitle%5D=\
+                       first&items%5B0%5D%5Bdone%5D=on&items%5B1%5D%5Btitle%5D=\
+                       second";
+        assert_eq!(deserialize_form::<Args>(indexed).unwrap(), expected);
+
+        let dotted = "tags%5B%5D=a&tags%5B%5D=b&items%5B0%5D.title=first&\
+                      items%5B0%5D.done=on&items%5B1%5D.title=second";
+        assert_eq!(deserialize_form::<Args>(dotted).unwrap(), expected);
+
+        let unindexed = "tags%5B%5D=a&items%5B%5D%5Btitle%5D=first&\
+                         items%5B%5D%5Bdone%5D=on&tags%5B%5D=b&\
+                         items%5B%5D%5Btitle%5D=second";
+        assert_eq!(deserialize_form::<Args>(unindexed).unwrap(), expected);
+    }
+}
@@ -5,8 +5,10 @@ use base64::{engine::general_purpose::URL_SAFE, Engine as _};
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     fmt::{self, Display, Write},
     str::FromStr,
+    time::Duration,
 };
 use throw_error::Error;
 use url::Url;
@@ -162,11 +164,15 @@ impl<E> ViaError<E> for WrapError<E> {
 /// Unlike [`ServerFnErrorErr`], this does not implement [`Error`](trait@std::error::Error).
 /// This means that other error types can easily be converted into it using the
 /// `?` operator.
+///
+/// New variants may be added without a breaking release, so a `match` on it needs a
+/// wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[non_exhaustive]
 pub enum ServerFnError<E = NoCustomError> {
     #[deprecated(
         since = "0.8.0",
@@ -194,6 +200,13 @@ pub enum ServerFnError<E = NoCustomError> {
     Args(String),
     /// Occurs on the server if there's a missing argument.
     MissingArg(String),
+    /// Occurs on the server if the request body is larger than the limit, in bytes.
+    PayloadTooLarge(usize),
+    /// Occurs on the server if the server function did not finish within the time limit.
+    Timeout(Duration),
+    /// Returned by the server function when some of its arguments are invalid, with an error
+    /// for each invalid form field.
+    FieldErrors(FieldErrors),
 }
 
 impl ServerFnError<NoCustomError> {
@@ -242,6 +255,13 @@ where
                     "error deserializing server function arguments: {s}"
                 ),
                 ServerFnError::MissingArg(s) => format!("missing argument {s}"),
+                ServerFnError::PayloadTooLarge(max) => format!(
+                    "request body is larger than the limit of {max} bytes"
+                ),
+                ServerFnError::Timeout(timeout) =>
+                    format!("server function did not finish within {timeout:?}"),
+                ServerFnError::FieldErrors(errors) =>
+                    format!("invalid fields: {errors}"),
                 ServerFnError::Response(s) =>
                     format!("error generating HTTP response: {s}"),
                 ServerFnError::WrappedServerError(e) => format!("{e}"),
@@ -294,6 +314,18 @@ where
             ServerFnError::MissingArg(e) => {
                 write!(&mut buf, "MissingArg|{e}")
             }
+            ServerFnError::PayloadTooLarge(max) => {
+                write!(&mut buf, "PayloadTooLarge|{max}")
+            }
+            ServerFnError::Timeout(timeout) => {
+                write!(&mut buf, "Timeout|{}", timeout.as_millis())
+            }
+            ServerFnError::FieldErrors(errors) => {
+                match serde_json::to_string(errors) {
+                    Ok(json) => write!(&mut buf, "FieldErrors|{json}"),
+                    Err(_) => Err(fmt::Error),
+                }
+            }
         };
 
         match result {
@@ -342,6 +374,19 @@ where
                 }
                 "Args" => Ok(ServerFnError::Args(data.to_string())),
                 "MissingArg" => Ok(ServerFnError::MissingArg(data.to_string())),
+                "PayloadTooLarge" => data
+                    .parse()
+                    .map(ServerFnError::PayloadTooLarge)
+                    .map_err(|_| format!("Invalid limit: {data:?}")),
+                "Timeout" => data
+                    .parse()
+                    .map(|millis| {
+                        ServerFnError::Timeout(Duration::from_millis(millis))
+                    })
+                    .map_err(|_| format!("Invalid timeout: {data:?}")),
+                "FieldErrors" => serde_json::from_str(data)
+                    .map(ServerFnError::FieldErrors)
+                    .map_err(|e| format!("Invalid field errors: {e}")),
                 _ => Err(format!("Unknown error type: {ty}")),
             })
     }
@@ -379,8 +424,28 @@ where
             ServerFnErrorErr::UnsupportedRequestMethod(value) => {
                 ServerFnError::Request(value)
             }
+            ServerFnErrorErr::PayloadTooLarge(max) => {
+                ServerFnError::PayloadTooLarge(max)
+            }
+            ServerFnErrorErr::Timeout(timeout) => {
+                ServerFnError::Timeout(timeout)
+            }
+            ServerFnErrorErr::FieldErrors(errors) => {
+                ServerFnError::FieldErrors(errors)
+            }
+        }
+    }
+
+    fn field_errors(&self) -> Option<&FieldErrors> {
+        match self {
+            ServerFnError::FieldErrors(errors) => Some(errors),
+            _ => None,
         }
     }
+
+    fn is_network_error(&self) -> bool {
+        matches!(self, ServerFnError::Request(_))
+    }
 }
 
 impl<E> std::error::Error for ServerFnError<E>
@@ -397,6 +462,9 @@ where
 }
 
 /// Type for errors that can occur when using server functions. If you need to return a custom error type from a server function, implement `FromServerFnError` for your custom error type.
+///
+/// New variants may be added without a breaking release, so a `match` on it needs a
+/// wildcard arm.
 #[derive(
     thiserror::Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize,
 )]
@@ -404,6 +472,7 @@ where
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[non_exhaustive]
 pub enum ServerFnErrorErr {
     /// Error while trying to register the server function (only occurs in case of poisoned RwLock).
     #[error("error while trying to register the server function: {0}")]
@@ -435,6 +504,121 @@ pub enum ServerFnErrorErr {
     /// Occurs on the server if there is an error creating an HTTP response.
     #[error("error creating response {0}")]
     Response(String),
+    /// Occurs on the server if the request body is larger than the limit, in bytes.
+    #[error("request body is larger than the limit of {0} bytes")]
+    PayloadTooLarge(usize),
+    /// Occurs on the server if the server function did not finish within the time limit.
+    #[error("server function did not finish within {0:?}")]
+    Timeout(Duration),
+    /// Returned by the server function when some of its arguments are invalid, with an error
+    /// for each invalid form field.
+    #[error("invalid fields: {0}")]
+    FieldErrors(FieldErrors),
+}
+
+/// Errors for the individual fields of a form, as a map from each field's name to its error
+/// message.
+///
+/// A server function can return these when some of its arguments are invalid, with
+/// [`ServerFnError::FieldErrors`], or in a custom error type that returns them from
+/// [`FromServerFnError::field_errors`]. `<ActionForm/>` then marks those inputs as invalid,
+/// and exposes the errors keyed by input name.
+///
+/// ```rust
+/// use server_fn::error::{FieldErrors, ServerFnError};
+///
+/// fn check_title(title: &str) -> Result<(), ServerFnError> {
+///     if title.trim().is_empty() {
+///         let errors = FieldErrors::new().with("title", "Enter a title.");
+///         return Err(ServerFnError::FieldErrors(errors));
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[serde(transparent)]
+pub struct FieldErrors(BTreeMap<String, String>);
+
+impl FieldErrors {
+    /// Creates an empty set of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the error for a field, replacing any error it already had.
+    pub fn insert(&mut self, name: impl Into<String>, error: impl ToString) {
+        self.0.insert(name.into(), error.to_string());
+    }
+
+    /// Adds the error for a field, and returns the errors.
+    pub fn with(
+        mut self,
+        name: impl Into<String>,
+        error: impl ToString,
+    ) -> Self {
+        self.insert(name, error);
+        self
+    }
+
+    /// The error for the field with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Whether there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the names of the invalid fields, and their errors.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, error)| (name.as_str(), error.as_str()))
+    }
+
+    /// Returns the map from each field's name to its error.
+    pub fn into_inner(self) -> BTreeMap<String, String> {
+        self.0
+    }
+}
+
+impl From<BTreeMap<String, String>> for FieldErrors {
+    fn from(errors: BTreeMap<String, String>) -> Self {
+        Self(errors)
+    }
+}
+
+impl<N, E> FromIterator<(N, E)> for FieldErrors
+where
+    N: Into<String>,
+    E: ToString,
+{
+    fn from_iter<T: IntoIterator<Item = (N, E)>>(iter: T) -> Self {
+        let mut errors = Self::new();
+        for (name, error) in iter {
+            errors.insert(name, error);
+        }
+        errors
+    }
+}
+
+impl Display for FieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, error)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name}: {error}")?;
+        }
+        Ok(())
+    }
 }
 
 /// Associates a particular server function error with the server function
@@ -568,6 +752,19 @@ pub trait FromServerFnError: std::fmt::Debug + Sized + 'static {
     /// Converts a [`ServerFnErrorErr`] into the application-specific custom error type.
     fn from_server_fn_error(value: ServerFnErrorErr) -> Self;
 
+    /// Returns the errors for individual form fields, if this error carries any. Custom error
+    /// types can override this so that `<ActionForm/>` can mark the invalid fields.
+    fn field_errors(&self) -> Option<&FieldErrors> {
+        None
+    }
+
+    /// Whether the server could not be reached, so the server function was never run. Custom
+    /// error types can override this so that failed calls can be queued and retried once the
+    /// network is available again.
+    fn is_network_error(&self) -> bool {
+        false
+    }
+
     /// Serializes the custom error type to bytes, according to the encoding given by `Self::Encoding`.
     fn ser(&self) -> Bytes {
         Self::Encoder::encode(self).unwrap_or_else(|e| {
@@ -635,3 +832,20 @@ fn assert_from_server_fn_error_impl() {
 
     assert_impl::<ServerFnError>();
 }
+
+#[test]
+fn field_errors_round_trip() {
+    let err = ServerFnError::<NoCustomError>::FieldErrors(
+        [("title", "Enter a title."), ("tags[0]", "Too long.")]
+            .into_iter()
+            .collect(),
+    );
+    let decoded: ServerFnError = FromServerFnError::de(err.ser());
+    assert_eq!(decoded, err);
+    assert_eq!(
+        decoded
+            .field_errors()
+            .and_then(|errors| errors.get("title")),
+        Some("Enter a title.")
+    );
+}
@@ -111,6 +111,10 @@ pub mod codec;
 #[macro_use]
 /// Error types and utilities.
 pub mod error;
+/// Limits on the size of server function requests and the time spent handling them.
+pub mod limits;
+/// Method overrides, which let HTML forms call `PUT`, `PATCH`, and `DELETE` server functions.
+pub mod method_override;
 /// Types to add server middleware to a server function.
 pub mod middleware;
 /// Utilities to allow client-side redirects.
@@ -300,6 +304,9 @@ pub trait ServerFn: Send + Sized {
             .unwrap_or(false);
         #[cfg(feature = "form-redirects")]
         let mut referer = req.referer().as_deref().map(ToOwned::to_owned);
+        #[cfg(feature = "form-redirects")]
+        let redirect_to =
+            req.as_query().and_then(redirect::form_redirect_target);
 
         async move {
             #[allow(unused_variables, unused_mut)]
@@ -334,6 +341,10 @@ pub trait ServerFn: Send + Sized {
                         referer = Some(url.to_string());
                     }
                 }
+                // if it succeeded, and the form asked to be redirected elsewhere, go there
+                else if let Some(redirect_to) = redirect_to {
+                    referer = Some(redirect_to);
+                }
                 // otherwise, strip error info from referer URL, as that means it's from a previous
                 // call
                 else if let Some(referer) = referer.as_mut() {
@@ -1113,10 +1124,16 @@ pub mod axum {
     }
 
     /// Returns the server function at the given path as a service that can be modified.
+    ///
+    /// The path may include the prefix set with
+    /// [`set_server_fn_path_prefix`](crate::client::set_server_fn_path_prefix).
     pub fn get_server_fn_service(
         path: &str,
         method: Method,
     ) -> Option<BoxedService<Request<Body>, Response<Body>>> {
+        let path = path
+            .strip_prefix(crate::client::get_server_fn_path_prefix())
+            .unwrap_or(path);
         let key = (path.into(), method);
         REGISTERED_SERVER_FUNCTIONS
             .read()
@@ -1236,6 +1253,9 @@ pub mod actix {
     }
 
     /// Returns the server function at the given path as a service that can be modified.
+    ///
+    /// The path may include the prefix set with
+    /// [`set_server_fn_path_prefix`](crate::client::set_server_fn_path_prefix).
     pub fn get_server_fn_service(
         path: &str,
         method: &actix_web::http::Method,
@@ -1254,6 +1274,9 @@ pub mod actix {
             ActixMethod::CONNECT => Method::CONNECT,
             _ => unreachable!(),
         };
+        let path = path
+            .strip_prefix(crate::client::get_server_fn_path_prefix())
+            .unwrap_or(path);
         REGISTERED_SERVER_FUNCTIONS
             .read()
             .or_poisoned()
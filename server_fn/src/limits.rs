@@ -0,0 +1,115 @@
+use crate::ServerFn;
+use or_poisoned::OrPoisoned;
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, RwLock},
+    time::Duration,
+};
+
+/// Limits on the size of server function requests, and on how long they are handled for.
+///
+/// The server integrations check these limits in the same way, whichever framework is used.
+/// A request whose body is too large is rejected with
+/// [`ServerFnErrorErr::PayloadTooLarge`](crate::error::ServerFnErrorErr::PayloadTooLarge), and a
+/// server function that runs for too long is cancelled with
+/// [`ServerFnErrorErr::Timeout`](crate::error::ServerFnErrorErr::Timeout). No limits are set by
+/// default.
+///
+/// ```rust,ignore
+/// use server_fn::limits::{set_default_limits, set_limits, ServerFnLimits};
+/// use std::time::Duration;
+///
+/// set_default_limits(
+///     ServerFnLimits::new()
+///         .max_body_size(64 * 1024)
+///         .timeout(Duration::from_secs(10)),
+/// );
+/// // uploads are allowed to be larger, and to take longer
+/// set_limits::<UploadFile>(
+///     ServerFnLimits::new()
+///         .max_body_size(16 * 1024 * 1024)
+///         .timeout(Duration::from_secs(60)),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ServerFnLimits {
+    /// The largest request body that is accepted, in bytes.
+    pub max_body_size: Option<usize>,
+    /// How long a server function can run before it is cancelled.
+    pub timeout: Option<Duration>,
+}
+
+impl ServerFnLimits {
+    /// Creates a set of limits with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the largest request body that is accepted, in bytes.
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = Some(max_body_size);
+        self
+    }
+
+    /// Sets how long a server function can run before it is cancelled.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Whether a request with the given `Content-Length` is larger than the limit.
+    pub fn exceeds_body_size(&self, content_length: Option<usize>) -> bool {
+        matches!(
+            (self.max_body_size, content_length),
+            (Some(max), Some(len)) if len > max
+        )
+    }
+}
+
+static DEFAULT_LIMITS: RwLock<ServerFnLimits> = RwLock::new(ServerFnLimits {
+    max_body_size: None,
+    timeout: None,
+});
+
+static LIMITS: LazyLock<RwLock<HashMap<&'static str, ServerFnLimits>>> =
+    LazyLock::new(Default::default);
+
+/// Sets the limits for every server function that does not have its own limits.
+pub fn set_default_limits(limits: ServerFnLimits) {
+    *DEFAULT_LIMITS.write().or_poisoned() = limits;
+}
+
+/// Sets the limits for one server function, replacing the default limits.
+pub fn set_limits<T: ServerFn>(limits: ServerFnLimits) {
+    LIMITS.write().or_poisoned().insert(T::PATH, limits);
+}
+
+/// Returns the limits for the server function at the given path.
+///
+/// The path may include the prefix set with
+/// [`set_server_fn_path_prefix`](crate::client::set_server_fn_path_prefix).
+pub fn limits_for(path: &str) -> ServerFnLimits {
+    let path = path
+        .strip_prefix(crate::client::get_server_fn_path_prefix())
+        .unwrap_or(path);
+    LIMITS
+        .read()
+        .or_poisoned()
+        .get(path)
+        .copied()
+        .unwrap_or_else(|| *DEFAULT_LIMITS.read().or_poisoned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_the_content_length_against_the_limit() {
+        let limits = ServerFnLimits::new().max_body_size(10);
+        assert!(!limits.exceeds_body_size(Some(10)));
+        assert!(limits.exceeds_body_size(Some(11)));
+        assert!(!limits.exceeds_body_size(None));
+        assert!(!ServerFnLimits::new().exceeds_body_size(Some(usize::MAX)));
+    }
+}
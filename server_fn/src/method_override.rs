@@ -0,0 +1,62 @@
+use http::Method;
+
+/// The name of the hidden form field that sets the method a `POST` request should be handled
+/// as.
+///
+/// Browsers can only submit a `<form>` with `GET` or `POST`, so a form that calls a server
+/// function using `PUT`, `PATCH`, or `DELETE` posts its arguments along with `_method=PUT` (and
+/// so on). `<ActionForm/>` adds this field for these server functions, and the server
+/// integrations route the request to the server function registered for that method.
+pub const METHOD_OVERRIDE_FIELD: &str = "_method";
+
+/// A header that can be set on a `POST` request to handle it as a `PUT`, `PATCH`, or `DELETE`
+/// request, in the same way as the [`METHOD_OVERRIDE_FIELD`].
+///
+/// This is useful for clients (or proxies) that can only send `GET` and `POST` requests.
+pub const METHOD_OVERRIDE_HEADER: &str = "x-http-method-override";
+
+/// Whether a server function that uses this method can be called by a `POST` request with a
+/// method override.
+pub fn accepts_override(method: &Method) -> bool {
+    matches!(*method, Method::PUT | Method::PATCH | Method::DELETE)
+}
+
+/// Parses the value of a method override, which may only be `PUT`, `PATCH`, or `DELETE`.
+pub fn parse_override(value: &str) -> Option<Method> {
+    let method =
+        Method::from_bytes(value.trim().to_ascii_uppercase().as_bytes())
+            .ok()?;
+    accepts_override(&method).then_some(method)
+}
+
+/// Finds the method set by the [`METHOD_OVERRIDE_FIELD`] in a URL-encoded form body.
+pub fn method_override_field(body: &[u8]) -> Option<Method> {
+    url::form_urlencoded::parse(body)
+        .find(|(key, _)| key == METHOD_OVERRIDE_FIELD)
+        .and_then(|(_, value)| parse_override(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_overrides_to_methods_forms_cannot_send() {
+        assert_eq!(parse_override("put"), Some(Method::PUT));
+        assert_eq!(parse_override(" PATCH "), Some(Method::PATCH));
+        assert_eq!(parse_override("Delete"), Some(Method::DELETE));
+        assert_eq!(parse_override("GET"), None);
+        assert_eq!(parse_override("POST"), None);
+        assert_eq!(parse_override("CONNECT"), None);
+    }
+
+    #[test]
+    fn finds_override_in_form_body() {
+        assert_eq!(
+            method_override_field(b"title=Hello&_method=PUT"),
+            Some(Method::PUT)
+        );
+        assert_eq!(method_override_field(b"title=_method%3DPUT"), None);
+        assert_eq!(method_override_field(b"_method=GET"), None);
+    }
+}
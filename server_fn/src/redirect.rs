@@ -9,6 +9,41 @@ use std::sync::OnceLock;
 /// that redirect rather than returning the desired data.
 pub const REDIRECT_HEADER: &str = "serverfnredirect";
 
+/// The name of the query parameter that sets where a plain HTML form submission should be
+/// redirected once the server function has succeeded.
+///
+/// By default, a server function that is called by a `<form>` without JavaScript redirects back
+/// to the page that the form was submitted from. Adding `?__redirect=/some/path` to the
+/// form's `action` redirects to `/some/path` instead. Only paths on the same site are followed.
+pub const FORM_REDIRECT_PARAM: &str = "__redirect";
+
+/// Returns the path given by the [`FORM_REDIRECT_PARAM`] in a query string, if it is a path on
+/// the same site.
+pub fn form_redirect_target(query: &str) -> Option<String> {
+    url::form_urlencoded::parse(query.as_bytes())
+        .find(|(key, _)| key == FORM_REDIRECT_PARAM)
+        .map(|(_, value)| value.into_owned())
+        // only follow paths on this site, so that the parameter can't be used as an open
+        // redirect; browsers remove tabs and newlines from URLs, so `/\t/evil.com` would be
+        // followed as `//evil.com`
+        .filter(|path| {
+            path.starts_with('/')
+                && !path.starts_with("//")
+                && !path.starts_with("/\\")
+                && !path.chars().any(|c| c.is_control() || c.is_whitespace())
+        })
+}
+
+/// Adds a [`FORM_REDIRECT_PARAM`] to a form's `action` URL, so that a plain HTML submission
+/// of that form is redirected to `path` once the server function has succeeded.
+pub fn with_form_redirect(action: &str, path: &str) -> String {
+    let param = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair(FORM_REDIRECT_PARAM, path)
+        .finish();
+    let separator = if action.contains('?') { '&' } else { '?' };
+    format!("{action}{separator}{param}")
+}
+
 /// A function that will be called if a server function returns a `3xx` status
 /// or the [`REDIRECT_HEADER`].
 pub type RedirectHook = Box<dyn Fn(&str) + Send + Sync>;
@@ -31,3 +66,47 @@ pub fn call_redirect_hook(loc: &str) {
         hook(loc)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{form_redirect_target, with_form_redirect};
+
+    #[test]
+    fn reads_form_redirect_target() {
+        assert_eq!(
+            form_redirect_target("__redirect=%2Fthanks%3Fid%3D1"),
+            Some("/thanks?id=1".to_string())
+        );
+        assert_eq!(
+            form_redirect_target("a=1&__redirect=/done"),
+            Some("/done".to_string())
+        );
+        assert_eq!(form_redirect_target("a=1"), None);
+    }
+
+    #[test]
+    fn adds_form_redirect_to_action() {
+        let action = with_form_redirect("/api/add_todo", "/todos?page=2");
+        assert_eq!(action, "/api/add_todo?__redirect=%2Ftodos%3Fpage%3D2");
+        assert_eq!(
+            form_redirect_target(action.split_once('?').unwrap().1),
+            Some("/todos?page=2".to_string())
+        );
+        assert_eq!(
+            with_form_redirect("/api/add_todo?a=1", "/"),
+            "/api/add_todo?a=1&__redirect=%2F"
+        );
+    }
+
+    #[test]
+    fn ignores_off_site_form_redirect_targets() {
+        assert_eq!(form_redirect_target("__redirect=https://evil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=//evil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=/%5Cevil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=/%09/evil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=/%0A/evil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=/%0D%0A/evil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=%20//evil.com"), None);
+        assert_eq!(form_redirect_target("__redirect=/%00/evil.com"), None);
+    }
+}
@@ -1,15 +1,19 @@
 use super::ClientReq;
 use crate::{
-    client::get_server_url,
+    client::{get_server_fn_path_prefix, get_server_url},
     error::{FromServerFnError, ServerFnErrorErr},
 };
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
 pub use gloo_net::http::Request;
+use gloo_net::http::RequestBuilder;
 use http::Method;
 use js_sys::{Reflect, Uint8Array};
 use send_wrapper::SendWrapper;
-use std::ops::{Deref, DerefMut};
+use std::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+};
 use wasm_bindgen::JsValue;
 use wasm_streams::ReadableStream;
 use web_sys::{
@@ -17,6 +21,44 @@ use web_sys::{
     UrlSearchParams,
 };
 
+thread_local! {
+    static DEFAULT_HEADERS: RefCell<Vec<(String, String)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Sets a header that is sent with every server function request made from the browser,
+/// replacing any value previously set for the same header.
+///
+/// This can be used to attach a token (like a CSRF token) to every request.
+pub fn set_default_header(name: impl Into<String>, value: impl Into<String>) {
+    let name = name.into();
+    let value = value.into();
+    DEFAULT_HEADERS.with_borrow_mut(|headers| {
+        match headers
+            .iter_mut()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(&name))
+        {
+            Some((_, existing)) => *existing = value,
+            None => headers.push((name, value)),
+        }
+    });
+}
+
+trait WithDefaultHeaders {
+    /// Adds every header set with [`set_default_header`].
+    fn default_headers(self) -> Self;
+}
+
+impl WithDefaultHeaders for RequestBuilder {
+    fn default_headers(self) -> Self {
+        DEFAULT_HEADERS.with_borrow(|headers| {
+            headers.iter().fold(self, |builder, (name, value)| {
+                builder.header(name, value)
+            })
+        })
+    }
+}
+
 /// A `fetch` request made in the browser.
 #[derive(Debug)]
 pub struct BrowserRequest(pub(crate) SendWrapper<RequestInner>);
@@ -109,10 +151,12 @@ where
     ) -> Result<Self, E> {
         let (abort_ctrl, abort_signal) = abort_signal();
         let server_url = get_server_url();
+        let prefix = get_server_fn_path_prefix();
         let mut url = String::with_capacity(
-            server_url.len() + path.len() + 1 + query.len(),
+            server_url.len() + prefix.len() + path.len() + 1 + query.len(),
         );
         url.push_str(server_url);
+        url.push_str(prefix);
         url.push_str(path);
         url.push('?');
         url.push_str(query);
@@ -133,6 +177,7 @@ where
             }
             .header("Content-Type", content_type)
             .header("Accept", accepts)
+            .default_headers()
             .abort_signal(abort_signal.as_ref())
             .build()
             .map_err(|e| {
@@ -153,8 +198,11 @@ where
     ) -> Result<Self, E> {
         let (abort_ctrl, abort_signal) = abort_signal();
         let server_url = get_server_url();
-        let mut url = String::with_capacity(server_url.len() + path.len());
+        let prefix = get_server_fn_path_prefix();
+        let mut url =
+            String::with_capacity(server_url.len() + prefix.len() + path.len());
         url.push_str(server_url);
+        url.push_str(prefix);
         url.push_str(path);
         Ok(Self(SendWrapper::new(RequestInner {
             request: match method {
@@ -171,6 +219,7 @@ where
             }
             .header("Content-Type", content_type)
             .header("Accept", accepts)
+            .default_headers()
             .abort_signal(abort_signal.as_ref())
             .body(body)
             .map_err(|e| {
@@ -191,8 +240,11 @@ where
     ) -> Result<Self, E> {
         let (abort_ctrl, abort_signal) = abort_signal();
         let server_url = get_server_url();
-        let mut url = String::with_capacity(server_url.len() + path.len());
+        let prefix = get_server_fn_path_prefix();
+        let mut url =
+            String::with_capacity(server_url.len() + prefix.len() + path.len());
         url.push_str(server_url);
+        url.push_str(prefix);
         url.push_str(path);
         let body: &[u8] = &body;
         let body = Uint8Array::from(body).buffer();
@@ -211,6 +263,7 @@ where
             }
             .header("Content-Type", content_type)
             .header("Accept", accepts)
+            .default_headers()
             .abort_signal(abort_signal.as_ref())
             .body(body)
             .map_err(|e| {
@@ -230,8 +283,11 @@ where
     ) -> Result<Self, E> {
         let (abort_ctrl, abort_signal) = abort_signal();
         let server_url = get_server_url();
-        let mut url = String::with_capacity(server_url.len() + path.len());
+        let prefix = get_server_fn_path_prefix();
+        let mut url =
+            String::with_capacity(server_url.len() + prefix.len() + path.len());
         url.push_str(server_url);
+        url.push_str(prefix);
         url.push_str(path);
         Ok(Self(SendWrapper::new(RequestInner {
             request: match method {
@@ -247,6 +303,7 @@ where
                 }
             }
             .header("Accept", accepts)
+            .default_headers()
             .abort_signal(abort_signal.as_ref())
             .body(body.0.take())
             .map_err(|e| {
@@ -292,6 +349,7 @@ where
             }
             .header("Content-Type", content_type)
             .header("Accept", accepts)
+            .default_headers()
             .abort_signal(abort_signal.as_ref())
             .body(url_params)
             .map_err(|e| {
@@ -352,6 +410,11 @@ fn streaming_request(
     let headers = Headers::new()?;
     headers.append("Content-Type", content_type)?;
     headers.append("Accept", accepts)?;
+    DEFAULT_HEADERS.with_borrow(|default_headers| {
+        default_headers
+            .iter()
+            .try_for_each(|(name, value)| headers.append(name, value))
+    })?;
 
     let init = RequestInit::new();
     init.set_headers(&headers);
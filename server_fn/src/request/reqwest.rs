@@ -1,6 +1,6 @@
 use super::ClientReq;
 use crate::{
-    client::get_server_url,
+    client::{get_server_fn_path_prefix, get_server_url},
     error::{FromServerFnError, IntoAppError, ServerFnErrorErr},
 };
 use bytes::Bytes;
@@ -27,7 +27,12 @@ where
         query: &str,
         method: Method,
     ) -> Result<Self, E> {
-        let url = format!("{}{}", get_server_url(), path);
+        let url = format!(
+            "{}{}{}",
+            get_server_url(),
+            get_server_fn_path_prefix(),
+            path
+        );
         let mut url = Url::try_from(url.as_str()).map_err(|e| {
             E::from_server_fn_error(ServerFnErrorErr::Request(e.to_string()))
         })?;
@@ -61,7 +66,12 @@ where
         body: String,
         method: Method,
     ) -> Result<Self, E> {
-        let url = format!("{}{}", get_server_url(), path);
+        let url = format!(
+            "{}{}{}",
+            get_server_url(),
+            get_server_fn_path_prefix(),
+            path
+        );
         match method {
             Method::POST => CLIENT.post(url),
             Method::PUT => CLIENT.put(url),
@@ -86,7 +96,12 @@ where
         body: Bytes,
         method: Method,
     ) -> Result<Self, E> {
-        let url = format!("{}{}", get_server_url(), path);
+        let url = format!(
+            "{}{}{}",
+            get_server_url(),
+            get_server_fn_path_prefix(),
+            path
+        );
         match method {
             Method::POST => CLIENT.post(url),
             Method::PATCH => CLIENT.patch(url),
@@ -157,7 +172,12 @@ where
         body: impl Stream<Item = Bytes> + Send + 'static,
         method: Method,
     ) -> Result<Self, E> {
-        let url = format!("{}{}", get_server_url(), path);
+        let url = format!(
+            "{}{}{}",
+            get_server_url(),
+            get_server_fn_path_prefix(),
+            path
+        );
         let body = Body::wrap_stream(
             body.map(|chunk| Ok(chunk) as Result<Bytes, ServerFnErrorErr>),
         );
@@ -68,3 +68,246 @@ pub fn event_target_checked(ev: &web_sys::Event) -> bool {
         .unchecked_into::<web_sys::HtmlInputElement>()
         .checked()
 }
+
+/// The visibility of an element, as reported by an
+/// [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VisibilityEntry {
+    /// Whether any part of the element is visible in the viewport.
+    pub is_intersecting: bool,
+    /// The fraction of the element that is visible, from `0.0` to `1.0`.
+    pub intersection_ratio: f64,
+}
+
+/// The size of an element's content box, as reported by a
+/// [`ResizeObserver`](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SizeEntry {
+    /// The width of the element's content box, in pixels.
+    pub width: f64,
+    /// The height of the element's content box, in pixels.
+    pub height: f64,
+}
+
+/// A directive that updates `entry` whenever the element enters or leaves the viewport.
+///
+/// The observer is disconnected when the element is unmounted.
+///
+/// ```rust,ignore
+/// let visible = RwSignal::new(VisibilityEntry::default());
+///
+/// view! {
+///     <img use:observe_visibility=visible src=move || {
+///         visible.get().is_intersecting.then_some("/image.png")
+///     }/>
+/// }
+/// ```
+#[cfg(feature = "reactive_graph")]
+pub fn observe_visibility(
+    el: web_sys::Element,
+    entry: reactive_graph::signal::RwSignal<VisibilityEntry>,
+) -> impl FnOnce() {
+    use reactive_graph::traits::Set;
+    use wasm_bindgen::closure::Closure;
+
+    let callback = Closure::<dyn FnMut(js_sys::Array)>::new(
+        move |entries: js_sys::Array| {
+            if let Some(last) = entries.iter().last() {
+                let last =
+                    last.unchecked_into::<web_sys::IntersectionObserverEntry>();
+                entry.set(VisibilityEntry {
+                    is_intersecting: last.is_intersecting(),
+                    intersection_ratio: last.intersection_ratio(),
+                });
+            }
+        },
+    );
+    let observer =
+        web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref())
+            .ok();
+    if let Some(observer) = &observer {
+        observer.observe(&el);
+    }
+
+    move || {
+        if let Some(observer) = observer {
+            observer.disconnect();
+        }
+        drop(callback);
+    }
+}
+
+/// A directive that updates `entry` whenever the size of the element's content box changes.
+///
+/// The observer is disconnected when the element is unmounted.
+///
+/// ```rust,ignore
+/// let size = RwSignal::new(SizeEntry::default());
+///
+/// view! {
+///     <textarea use:observe_size=size/>
+///     <p>{move || format!("{} x {}", size.get().width, size.get().height)}</p>
+/// }
+/// ```
+#[cfg(feature = "reactive_graph")]
+pub fn observe_size(
+    el: web_sys::Element,
+    entry: reactive_graph::signal::RwSignal<SizeEntry>,
+) -> impl FnOnce() {
+    use reactive_graph::traits::Set;
+    use wasm_bindgen::closure::Closure;
+
+    let callback = Closure::<dyn FnMut(js_sys::Array)>::new(
+        move |entries: js_sys::Array| {
+            if let Some(last) = entries.iter().last() {
+                let rect = last
+                    .unchecked_into::<web_sys::ResizeObserverEntry>()
+                    .content_rect();
+                entry.set(SizeEntry {
+                    width: rect.width(),
+                    height: rect.height(),
+                });
+            }
+        },
+    );
+    let observer =
+        web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()).ok();
+    if let Some(observer) = &observer {
+        observer.observe(&el);
+    }
+
+    move || {
+        if let Some(observer) = observer {
+            observer.disconnect();
+        }
+        drop(callback);
+    }
+}
+
+/// A directive that animates the children of an element to their new positions when they are
+/// moved, added, or removed, using the [FLIP](https://aerotwist.com/blog/flip-your-animations/)
+/// technique.
+///
+/// This is most useful on the parent of a keyed list. Whenever the element's children change,
+/// each child that has moved is transformed back to where it was, then animated to its new
+/// position over `duration`.
+///
+/// ```rust,ignore
+/// view! {
+///     <ul use:animate_layout=Duration::from_millis(250)>
+///         <For each=move || items.get() key=|item| item.id let:item>
+///             <li>{item.name}</li>
+///         </For>
+///     </ul>
+/// }
+/// ```
+pub fn animate_layout(
+    el: web_sys::Element,
+    duration: std::time::Duration,
+) -> impl FnOnce() {
+    use wasm_bindgen::closure::Closure;
+
+    let positions = js_sys::WeakMap::new();
+
+    // the element is not in the document yet, so its children are laid out on the next frame
+    let initial = {
+        let el = el.clone();
+        let positions = positions.clone();
+        Closure::once_into_js(move || {
+            update_layout(&el, &positions, None);
+        })
+    };
+    _ = window().request_animation_frame(initial.unchecked_ref());
+
+    let callback = {
+        let el = el.clone();
+        let positions = positions.clone();
+        Closure::<dyn FnMut()>::new(move || {
+            update_layout(&el, &positions, Some(duration));
+        })
+    };
+    let observer =
+        web_sys::MutationObserver::new(callback.as_ref().unchecked_ref()).ok();
+    if let Some(observer) = &observer {
+        let init = web_sys::MutationObserverInit::new();
+        init.set_child_list(true);
+        _ = observer.observe_with_options(&el, &init);
+    }
+
+    move || {
+        if let Some(observer) = observer {
+            observer.disconnect();
+        }
+        drop(callback);
+    }
+}
+
+/// Records the position of each child of `el`, animating any child that has moved since its
+/// position was last recorded.
+fn update_layout(
+    el: &web_sys::Element,
+    positions: &js_sys::WeakMap,
+    duration: Option<std::time::Duration>,
+) {
+    let children = el.children();
+    for idx in 0..children.length() {
+        let Some(child) = children.item(idx) else {
+            continue;
+        };
+        let Some(child) = child.dyn_ref::<HtmlElement>() else {
+            continue;
+        };
+        // offsets are not affected by transforms, so animations that are still running
+        // do not change them
+        let x = f64::from(child.offset_left());
+        let y = f64::from(child.offset_top());
+
+        let prev = positions.get(child);
+        if let (Some(duration), Some(prev)) =
+            (duration, prev.dyn_ref::<js_sys::Array>())
+        {
+            let dx = prev.get(0).as_f64().unwrap_or(x) - x;
+            let dy = prev.get(1).as_f64().unwrap_or(y) - y;
+            if dx != 0.0 || dy != 0.0 {
+                animate_from(child, dx, dy, duration);
+            }
+        }
+        positions.set(child, &js_sys::Array::of2(&x.into(), &y.into()));
+    }
+}
+
+/// Animates `el` from an offset of `(dx, dy)` back to its current position.
+fn animate_from(
+    el: &HtmlElement,
+    dx: f64,
+    dy: f64,
+    duration: std::time::Duration,
+) {
+    let keyframe = |transform: &str| {
+        let keyframe = js_sys::Object::new();
+        _ = js_sys::Reflect::set(
+            &keyframe,
+            &"transform".into(),
+            &transform.into(),
+        );
+        keyframe
+    };
+    let keyframes = js_sys::Array::of2(
+        &keyframe(&format!("translate({dx}px, {dy}px)")),
+        &keyframe("none"),
+    );
+    let options = js_sys::Object::new();
+    _ = js_sys::Reflect::set(
+        &options,
+        &"duration".into(),
+        &(duration.as_secs_f64() * 1000.0).into(),
+    );
+    _ = js_sys::Reflect::set(&options, &"easing".into(), &"ease".into());
+
+    // Element.animate() is called dynamically, because it is behind an unstable web-sys feature
+    if let Ok(animate) = js_sys::Reflect::get(el, &"animate".into())
+        .and_then(|animate| animate.dyn_into::<js_sys::Function>())
+    {
+        _ = animate.call2(el, &keyframes, &options);
+    }
+}
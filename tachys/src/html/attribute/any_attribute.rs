@@ -302,6 +302,26 @@ impl NextAttribute for Vec<AnyAttribute> {
     }
 }
 
+fn remove_attribute(state: AnyAttributeState) {
+    for key in state.keys {
+        match key {
+            NamedAttributeKey::InnerHtml => {
+                Rndr::set_inner_html(&state.el, "");
+            }
+            NamedAttributeKey::Property(prop_name) => {
+                Rndr::set_property(
+                    &state.el,
+                    &prop_name,
+                    &wasm_bindgen::JsValue::UNDEFINED,
+                );
+            }
+            NamedAttributeKey::Attribute(key) => {
+                Rndr::remove_attribute(&state.el, &key);
+            }
+        }
+    }
+}
+
 impl Attribute for Vec<AnyAttribute> {
     const MIN_LENGTH: usize = 0;
 
@@ -374,26 +394,29 @@ impl Attribute for Vec<AnyAttribute> {
 
     fn rebuild(self, state: &mut Self::State) {
         let (el, state) = state;
-        for old in mem::take(state) {
-            for key in old.keys {
-                match key {
-                    NamedAttributeKey::InnerHtml => {
-                        Rndr::set_inner_html(&old.el, "");
-                    }
-                    NamedAttributeKey::Property(prop_name) => {
-                        Rndr::set_property(
-                            &old.el,
-                            &prop_name,
-                            &wasm_bindgen::JsValue::UNDEFINED,
-                        );
-                    }
-                    NamedAttributeKey::Attribute(key) => {
-                        Rndr::remove_attribute(&old.el, &key);
-                    }
+        let mut old_states = mem::take(state).into_iter();
+        for attr in self {
+            // attributes at the same position are updated in place if they are the same
+            // type and set the same keys, rather than being removed and added again
+            let new_state = match old_states.next() {
+                Some(mut old)
+                    if old.type_id == attr.type_id
+                        && old.keys == attr.keys() =>
+                {
+                    attr.rebuild(&mut old);
+                    old
                 }
-            }
+                Some(old) => {
+                    remove_attribute(old);
+                    attr.build(el)
+                }
+                None => attr.build(el),
+            };
+            state.push(new_state);
+        }
+        for old in old_states {
+            remove_attribute(old);
         }
-        *state = self.into_iter().map(|s| s.build(el)).collect();
     }
 
     fn into_cloneable(self) -> Self::Cloneable {
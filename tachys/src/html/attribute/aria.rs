@@ -406,3 +406,159 @@ where
     V: AttributeValue,
 {
 }
+
+attribute_values! {
+    /// A value for the `role` attribute, from the roles defined by
+    /// [WAI-ARIA](https://www.w3.org/TR/wai-aria-1.2/#role_definitions).
+    AriaRole {
+        Alert => "alert",
+        AlertDialog => "alertdialog",
+        Application => "application",
+        Article => "article",
+        Banner => "banner",
+        Blockquote => "blockquote",
+        Button => "button",
+        Caption => "caption",
+        Cell => "cell",
+        Checkbox => "checkbox",
+        Code => "code",
+        ColumnHeader => "columnheader",
+        Combobox => "combobox",
+        Complementary => "complementary",
+        ContentInfo => "contentinfo",
+        Definition => "definition",
+        Deletion => "deletion",
+        Dialog => "dialog",
+        Document => "document",
+        Emphasis => "emphasis",
+        Feed => "feed",
+        Figure => "figure",
+        Form => "form",
+        Generic => "generic",
+        Grid => "grid",
+        GridCell => "gridcell",
+        Group => "group",
+        Heading => "heading",
+        Img => "img",
+        Insertion => "insertion",
+        Link => "link",
+        List => "list",
+        Listbox => "listbox",
+        ListItem => "listitem",
+        Log => "log",
+        Main => "main",
+        Mark => "mark",
+        Marquee => "marquee",
+        Math => "math",
+        Menu => "menu",
+        Menubar => "menubar",
+        MenuItem => "menuitem",
+        MenuItemCheckbox => "menuitemcheckbox",
+        MenuItemRadio => "menuitemradio",
+        Meter => "meter",
+        Navigation => "navigation",
+        None => "none",
+        Note => "note",
+        Option => "option",
+        Paragraph => "paragraph",
+        Presentation => "presentation",
+        ProgressBar => "progressbar",
+        Radio => "radio",
+        RadioGroup => "radiogroup",
+        Region => "region",
+        Row => "row",
+        RowGroup => "rowgroup",
+        RowHeader => "rowheader",
+        Scrollbar => "scrollbar",
+        Search => "search",
+        Searchbox => "searchbox",
+        Separator => "separator",
+        Slider => "slider",
+        SpinButton => "spinbutton",
+        Status => "status",
+        Strong => "strong",
+        Subscript => "subscript",
+        Superscript => "superscript",
+        Switch => "switch",
+        Tab => "tab",
+        Table => "table",
+        TabList => "tablist",
+        TabPanel => "tabpanel",
+        Term => "term",
+        Textbox => "textbox",
+        Time => "time",
+        Timer => "timer",
+        Toolbar => "toolbar",
+        Tooltip => "tooltip",
+        Tree => "tree",
+        TreeGrid => "treegrid",
+        TreeItem => "treeitem",
+    }
+
+    /// A value for the `aria-live` attribute.
+    AriaLiveValue {
+        Off => "off",
+        Polite => "polite",
+        Assertive => "assertive",
+    }
+
+    /// A value for the `aria-autocomplete` attribute.
+    AriaAutocompleteValue {
+        None => "none",
+        Inline => "inline",
+        List => "list",
+        Both => "both",
+    }
+
+    /// A value for the `aria-current` attribute.
+    AriaCurrentValue {
+        Page => "page",
+        Step => "step",
+        Location => "location",
+        Date => "date",
+        Time => "time",
+        True => "true",
+        False => "false",
+    }
+
+    /// A value for the `aria-haspopup` attribute.
+    AriaHaspopupValue {
+        False => "false",
+        True => "true",
+        Menu => "menu",
+        Listbox => "listbox",
+        Tree => "tree",
+        Grid => "grid",
+        Dialog => "dialog",
+    }
+
+    /// A value for the `aria-invalid` attribute.
+    AriaInvalidValue {
+        False => "false",
+        True => "true",
+        Grammar => "grammar",
+        Spelling => "spelling",
+    }
+
+    /// A value for the `aria-orientation` attribute.
+    AriaOrientationValue {
+        Horizontal => "horizontal",
+        Vertical => "vertical",
+        Undefined => "undefined",
+    }
+
+    /// A value for the `aria-sort` attribute.
+    AriaSortValue {
+        None => "none",
+        Ascending => "ascending",
+        Descending => "descending",
+        Other => "other",
+    }
+
+    /// A value for tri-state attributes like `aria-checked` and `aria-pressed`.
+    AriaTristate {
+        False => "false",
+        True => "true",
+        Mixed => "mixed",
+    }
+}
@@ -0,0 +1,178 @@
+use super::{
+    any_attribute::{AnyAttribute, IntoAnyAttribute},
+    custom::{custom_attribute, CustomAttr},
+    Attribute, AttributeValue, NamedAttributeKey, NextAttribute,
+};
+use std::borrow::Cow;
+
+/// Returns a `data-*` attribute, where `key` is the part of the name after `data-`.
+///
+/// The value can be any [`AttributeValue`], including a signal or closure.
+///
+/// # Panics
+/// Panics if `key` is not a valid name for a `data-*` attribute. See [`is_valid_data_key`].
+#[track_caller]
+pub fn data_attribute<V>(
+    key: &str,
+    value: V,
+) -> CustomAttr<Cow<'static, str>, V>
+where
+    V: AttributeValue,
+{
+    assert!(
+        is_valid_data_key(key),
+        "`{key}` is not a valid name for a data-* attribute: it must not be \
+         empty, must not start with `xml`, and can only contain lowercase \
+         ASCII letters, digits, `-`, `_`, and `.`"
+    );
+    custom_attribute(Cow::Owned(format!("data-{key}")), value)
+}
+
+/// Whether `key` can be used as the part of a `data-*` attribute's name after `data-`.
+///
+/// The HTML standard requires these names to be valid XML names that do not start with `xml`
+/// and do not contain uppercase ASCII letters. This is slightly stricter, and only allows
+/// lowercase ASCII letters, digits, `-`, `_`, and `.`.
+pub fn is_valid_data_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with("xml")
+        && key.chars().all(|c| {
+            c.is_ascii_lowercase()
+                || c.is_ascii_digit()
+                || matches!(c, '-' | '_' | '.')
+        })
+}
+
+/// Creates an empty set of `data-*` attributes, which can be added to with
+/// [`DataAttributes::set`] and spread onto an element.
+///
+/// ```rust,ignore
+/// let attrs = data()
+///     .set("user-id", user_id)
+///     .set("selected", move || selected.get().to_string());
+///
+/// view! { <li {..attrs}>{name}</li> }
+/// ```
+pub fn data() -> DataAttributes {
+    DataAttributes::default()
+}
+
+/// A set of `data-*` attributes, created with [`data`].
+#[derive(Clone, Debug, Default)]
+pub struct DataAttributes(Vec<AnyAttribute>);
+
+impl DataAttributes {
+    /// Adds the `data-{key}` attribute with the given value.
+    ///
+    /// # Panics
+    /// Panics if `key` is not a valid name for a `data-*` attribute. See
+    /// [`is_valid_data_key`].
+    #[track_caller]
+    pub fn set<V>(mut self, key: &str, value: V) -> Self
+    where
+        V: AttributeValue,
+    {
+        self.0.push(data_attribute(key, value).into_any_attr());
+        self
+    }
+
+    /// Returns the number of attributes in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no attributes have been added.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<DataAttributes> for Vec<AnyAttribute> {
+    fn from(value: DataAttributes) -> Self {
+        value.0
+    }
+}
+
+impl NextAttribute for DataAttributes {
+    type Output<NewAttr: Attribute> = Vec<AnyAttribute>;
+
+    fn add_any_attr<NewAttr: Attribute>(
+        self,
+        new_attr: NewAttr,
+    ) -> Self::Output<NewAttr> {
+        self.0.add_any_attr(new_attr)
+    }
+}
+
+impl Attribute for DataAttributes {
+    const MIN_LENGTH: usize = 0;
+
+    type AsyncOutput = Vec<AnyAttribute>;
+    type State = <Vec<AnyAttribute> as Attribute>::State;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        self.0.html_len()
+    }
+
+    fn to_html(
+        self,
+        buf: &mut String,
+        class: &mut String,
+        style: &mut String,
+        inner_html: &mut String,
+    ) {
+        self.0.to_html(buf, class, style, inner_html);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        el: &crate::renderer::types::Element,
+    ) -> Self::State {
+        self.0.hydrate::<FROM_SERVER>(el)
+    }
+
+    fn build(self, el: &crate::renderer::types::Element) -> Self::State {
+        self.0.build(el)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        self.0.rebuild(state);
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {
+        self.0.dry_resolve();
+    }
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self.0.resolve().await
+    }
+
+    fn keys(&self) -> Vec<NamedAttributeKey> {
+        self.0.keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_data_key;
+
+    #[test]
+    fn validates_data_keys() {
+        assert!(is_valid_data_key("user-id"));
+        assert!(is_valid_data_key("v2.item_count"));
+        assert!(!is_valid_data_key(""));
+        assert!(!is_valid_data_key("userId"));
+        assert!(!is_valid_data_key("xml-thing"));
+        assert!(!is_valid_data_key("a b"));
+    }
+}
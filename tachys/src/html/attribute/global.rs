@@ -4,7 +4,10 @@ use crate::{
         attribute::*,
         class::{class, Class, IntoClass},
         element::{ElementType, HasElementType, HtmlElement},
-        event::{on, on_target, EventDescriptor, On, Targeted},
+        event::{
+            on, on_target, with_options, EventDescriptor, On, Options,
+            Targeted, WithOptions,
+        },
         property::{prop, IntoProperty, Property},
         style::{style, IntoStyle, Style},
     },
@@ -99,6 +102,25 @@ pub trait OnAttribute<E, F> {
 
     /// Adds an event listener to an element.
     fn on(self, event: E, cb: F) -> Self::Output;
+
+    /// Adds an event listener to an element, with the given listener [`Options`].
+    ///
+    /// This can be used to make `scroll` or touch handlers passive, for example.
+    fn on_with_options(
+        self,
+        event: E,
+        cb: F,
+        options: Options,
+    ) -> <Self as OnAttribute<WithOptions<E>, F>>::Output
+    where
+        Self: OnAttribute<WithOptions<E>, F> + Sized,
+    {
+        <Self as OnAttribute<WithOptions<E>, F>>::on(
+            self,
+            with_options(event, options),
+            cb,
+        )
+    }
 }
 
 impl<El, At, Ch, E, F> OnAttribute<E, F> for HtmlElement<El, At, Ch>
@@ -8,25 +8,28 @@ pub trait AttributeKey: Clone + Send + 'static {
 }
 
 macro_rules! attributes {
-	($(#[$meta:meta] $key:ident $html:literal),* $(,)?) => {
-        paste::paste! {
-            $(
-                #[$meta]
-                #[track_caller]
-                pub fn $key<V>(value: V) -> Attr<[<$key:camel>], V>
-				where V: AttributeValue,
+	(@key #[$meta:meta] $key:ident $html:literal $name:ident $($default:ident)?) => {
+        #[$meta]
+        #[track_caller]
+        pub fn $key<V>(value: V) -> Attr<$name, V>
+        where V: AttributeValue,
 
-                {
-                    Attr([<$key:camel>], value)
-                }
+        {
+            Attr($name, value)
+        }
 
-                #[$meta]
-				#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-				pub struct [<$key:camel>];
+        #[$meta]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub struct $name;
 
-				impl AttributeKey for [<$key:camel>] {
-					const KEY: &'static str = $html;
-				}
+        impl AttributeKey for $name {
+            const KEY: &'static str = $html;
+        }
+	};
+	($(#[$meta:meta] $key:ident $html:literal $(=> $name:ident)?),* $(,)?) => {
+        paste::paste! {
+            $(
+                attributes!(@key #[$meta] $key $html $($name)? [<$key:camel>]);
             )*
 		}
     }
@@ -405,6 +408,14 @@ attributes! {
     scoped "scoped",
     /// The `selected` attribute indicates that the option is selected.
     selected "selected",
+    /// The `shadowrootclonable` attribute makes a declarative shadow root clonable.
+    shadowrootclonable "shadowrootclonable",
+    /// The `shadowrootdelegatesfocus` attribute makes a declarative shadow root delegate focus.
+    shadowrootdelegatesfocus "shadowrootdelegatesfocus",
+    /// The `shadowrootmode` attribute turns a `<template>` into a declarative shadow root of its parent, with the given mode.
+    shadowrootmode "shadowrootmode",
+    /// The `shadowrootserializable` attribute makes a declarative shadow root serializable.
+    shadowrootserializable "shadowrootserializable",
     /// The `shape` attribute specifies the shape of the area.
     shape "shape",
     /// The `size` attribute specifies the width of the input element.
@@ -591,11 +602,225 @@ attributes! {
     /// The `onwheel` attribute specifies the event handler for the wheel event.
     onwheel "onwheel",
 
+    // SVG attributes
+    /// The `d` attribute defines the path to be drawn, as a list of path commands.
+    d "d" => SvgD,
+    /// The `transform` attribute defines a list of transformations applied to an element and its children.
+    transform "transform",
+    /// The `fill` attribute defines the color or paint server used to paint the interior of a shape.
+    fill "fill",
+    /// The `fill-opacity` attribute defines the opacity of the paint applied to the interior of a shape.
+    fill_opacity "fill-opacity",
+    /// The `fill-rule` attribute defines the algorithm used to determine which parts of a shape are inside it.
+    fill_rule "fill-rule",
+    /// The `stroke` attribute defines the color or paint server used to paint the outline of a shape.
+    stroke "stroke",
+    /// The `stroke-width` attribute defines the width of the stroke applied to a shape.
+    stroke_width "stroke-width",
+    /// The `stroke-opacity` attribute defines the opacity of the stroke applied to a shape.
+    stroke_opacity "stroke-opacity",
+    /// The `stroke-linecap` attribute defines the shape used at the end of open subpaths when they are stroked.
+    stroke_linecap "stroke-linecap",
+    /// The `stroke-linejoin` attribute defines the shape used at the corners of paths when they are stroked.
+    stroke_linejoin "stroke-linejoin",
+    /// The `stroke-dasharray` attribute defines the pattern of dashes and gaps used to stroke a shape.
+    stroke_dasharray "stroke-dasharray",
+    /// The `stroke-dashoffset` attribute defines an offset for the start of the dash pattern.
+    stroke_dashoffset "stroke-dashoffset",
+    /// The `opacity` attribute defines the opacity of an element and its children.
+    opacity "opacity",
+    /// The `filter` attribute references a filter effect to apply to an element.
+    filter "filter",
+    /// The `mask` attribute references a mask to apply to an element.
+    mask "mask",
+    /// The `clip-path` attribute references a clipping path to apply to an element.
+    clip_path "clip-path",
+    /// The `viewBox` attribute defines the position and dimensions of the viewport in user space.
+    view_box "viewBox",
+    /// The `preserveAspectRatio` attribute defines how the content is scaled to fit a viewport with a different aspect ratio.
+    preserve_aspect_ratio "preserveAspectRatio",
+    /// The `x` attribute defines an x coordinate in the user coordinate system.
+    x "x" => SvgX,
+    /// The `y` attribute defines a y coordinate in the user coordinate system.
+    y "y" => SvgY,
+    /// The `z` attribute defines the z coordinate of a light source.
+    z "z" => SvgZ,
+    /// The `x1` attribute defines the x coordinate of the start of a line or gradient vector.
+    x1 "x1",
+    /// The `y1` attribute defines the y coordinate of the start of a line or gradient vector.
+    y1 "y1",
+    /// The `x2` attribute defines the x coordinate of the end of a line or gradient vector.
+    x2 "x2",
+    /// The `y2` attribute defines the y coordinate of the end of a line or gradient vector.
+    y2 "y2",
+    /// The `cx` attribute defines the x coordinate of the center of a circle, ellipse, or radial gradient.
+    cx "cx",
+    /// The `cy` attribute defines the y coordinate of the center of a circle, ellipse, or radial gradient.
+    cy "cy",
+    /// The `r` attribute defines the radius of a circle or radial gradient.
+    r "r" => SvgR,
+    /// The `rx` attribute defines the horizontal radius of an ellipse or of the corners of a rectangle.
+    rx "rx",
+    /// The `ry` attribute defines the vertical radius of an ellipse or of the corners of a rectangle.
+    ry "ry",
+    /// The `fx` attribute defines the x coordinate of the focal point of a radial gradient.
+    fx "fx",
+    /// The `fy` attribute defines the y coordinate of the focal point of a radial gradient.
+    fy "fy",
+    /// The `fr` attribute defines the radius of the focal point of a radial gradient.
+    fr "fr",
+    /// The `dx` attribute defines a shift along the x axis.
+    dx "dx",
+    /// The `dy` attribute defines a shift along the y axis.
+    dy "dy",
+    /// The `points` attribute defines the list of points used to draw a polygon or polyline.
+    points "points",
+    /// The `pathLength` attribute defines the total length of a path, used to scale distance calculations.
+    path_length "pathLength",
+    /// The `gradientUnits` attribute defines the coordinate system used by the attributes of a gradient.
+    gradient_units "gradientUnits",
+    /// The `gradientTransform` attribute defines a transformation applied to a gradient.
+    gradient_transform "gradientTransform",
+    /// The `spreadMethod` attribute defines how a gradient behaves outside of its bounds.
+    spread_method "spreadMethod",
+    /// The `offset` attribute defines where a gradient stop is placed, or the offset of a transfer function.
+    offset "offset",
+    /// The `stop-color` attribute defines the color of a gradient stop.
+    stop_color "stop-color",
+    /// The `stop-opacity` attribute defines the opacity of a gradient stop.
+    stop_opacity "stop-opacity",
+    /// The `patternUnits` attribute defines the coordinate system used by the position and size of a pattern.
+    pattern_units "patternUnits",
+    /// The `patternContentUnits` attribute defines the coordinate system used by the contents of a pattern.
+    pattern_content_units "patternContentUnits",
+    /// The `patternTransform` attribute defines a transformation applied to a pattern.
+    pattern_transform "patternTransform",
+    /// The `maskUnits` attribute defines the coordinate system used by the position and size of a mask.
+    mask_units "maskUnits",
+    /// The `maskContentUnits` attribute defines the coordinate system used by the contents of a mask.
+    mask_content_units "maskContentUnits",
+    /// The `mask-type` attribute defines whether a mask uses luminance or alpha values.
+    mask_type "mask-type",
+    /// The `clipPathUnits` attribute defines the coordinate system used by the contents of a clipping path.
+    clip_path_units "clipPathUnits",
+    /// The `filterUnits` attribute defines the coordinate system used by the position and size of a filter.
+    filter_units "filterUnits",
+    /// The `primitiveUnits` attribute defines the coordinate system used by the filter primitives of a filter.
+    primitive_units "primitiveUnits",
+    /// The `in` attribute identifies the input of a filter primitive.
+    r#in "in",
+    /// The `in2` attribute identifies the second input of a filter primitive.
+    in2 "in2",
+    /// The `mode` attribute defines the blending mode of a `<feBlend>` filter primitive.
+    mode "mode",
+    /// The `values` attribute defines the values of a color matrix, or a list of values for an animation.
+    values "values",
+    /// The `operator` attribute defines the compositing or morphology operation of a filter primitive.
+    operator "operator",
+    /// The `k1` attribute defines the first constant of an arithmetic `<feComposite>` operation.
+    k1 "k1",
+    /// The `k2` attribute defines the second constant of an arithmetic `<feComposite>` operation.
+    k2 "k2",
+    /// The `k3` attribute defines the third constant of an arithmetic `<feComposite>` operation.
+    k3 "k3",
+    /// The `k4` attribute defines the fourth constant of an arithmetic `<feComposite>` operation.
+    k4 "k4",
+    /// The `scale` attribute defines the displacement scale of a `<feDisplacementMap>` filter primitive.
+    scale "scale",
+    /// The `xChannelSelector` attribute defines the color channel used to displace pixels along the x axis.
+    x_channel_selector "xChannelSelector",
+    /// The `yChannelSelector` attribute defines the color channel used to displace pixels along the y axis.
+    y_channel_selector "yChannelSelector",
+    /// The `stdDeviation` attribute defines the standard deviation of a blur.
+    std_deviation "stdDeviation",
+    /// The `flood-color` attribute defines the color used to fill a filter primitive subregion.
+    flood_color "flood-color",
+    /// The `flood-opacity` attribute defines the opacity used to fill a filter primitive subregion.
+    flood_opacity "flood-opacity",
+    /// The `edgeMode` attribute defines how a filter primitive extends the input image at its edges.
+    edge_mode "edgeMode",
+    /// The `radius` attribute defines the radius of a `<feMorphology>` operation.
+    radius "radius",
+    /// The `baseFrequency` attribute defines the base frequency of the noise generated by `<feTurbulence>`.
+    base_frequency "baseFrequency",
+    /// The `numOctaves` attribute defines the number of octaves of noise generated by `<feTurbulence>`.
+    num_octaves "numOctaves",
+    /// The `seed` attribute defines the starting number for the pseudo-random noise generated by `<feTurbulence>`.
+    seed "seed",
+    /// The `stitchTiles` attribute defines whether `<feTurbulence>` stitches noise tiles together smoothly.
+    stitch_tiles "stitchTiles",
+    /// The `tableValues` attribute defines the lookup table of a component transfer function.
+    table_values "tableValues",
+    /// The `slope` attribute defines the slope of a linear component transfer function.
+    slope "slope",
+    /// The `intercept` attribute defines the intercept of a linear component transfer function.
+    intercept "intercept",
+    /// The `amplitude` attribute defines the amplitude of a gamma component transfer function.
+    amplitude "amplitude",
+    /// The `exponent` attribute defines the exponent of a gamma component transfer function.
+    exponent "exponent",
+    /// The `order` attribute defines the size of the matrix used by `<feConvolveMatrix>`.
+    order "order",
+    /// The `kernelMatrix` attribute defines the matrix used by `<feConvolveMatrix>`.
+    kernel_matrix "kernelMatrix",
+    /// The `divisor` attribute defines the number the result of `<feConvolveMatrix>` is divided by.
+    divisor "divisor",
+    /// The `bias` attribute defines a value added to the result of `<feConvolveMatrix>`.
+    bias "bias",
+    /// The `targetX` attribute defines the x position of the target pixel of `<feConvolveMatrix>`.
+    target_x "targetX",
+    /// The `targetY` attribute defines the y position of the target pixel of `<feConvolveMatrix>`.
+    target_y "targetY",
+    /// The `preserveAlpha` attribute defines whether `<feConvolveMatrix>` applies only to the color channels.
+    preserve_alpha "preserveAlpha",
+    /// The `surfaceScale` attribute defines the height of the surface used by a lighting filter primitive.
+    surface_scale "surfaceScale",
+    /// The `diffuseConstant` attribute defines the diffuse reflection constant of `<feDiffuseLighting>`.
+    diffuse_constant "diffuseConstant",
+    /// The `specularConstant` attribute defines the specular reflection constant of `<feSpecularLighting>`.
+    specular_constant "specularConstant",
+    /// The `specularExponent` attribute defines how focused a specular highlight or spot light is.
+    specular_exponent "specularExponent",
+    /// The `kernelUnitLength` attribute defines the intended distance between pixels used by a filter primitive.
+    kernel_unit_length "kernelUnitLength",
+    /// The `lighting-color` attribute defines the color of the light source of a lighting filter primitive.
+    lighting_color "lighting-color",
+    /// The `azimuth` attribute defines the direction angle of a distant light source on the XY plane.
+    azimuth "azimuth",
+    /// The `elevation` attribute defines the direction angle of a distant light source on the YZ plane.
+    elevation "elevation",
+    /// The `pointsAtX` attribute defines the x coordinate of the point a spot light points at.
+    points_at_x "pointsAtX",
+    /// The `pointsAtY` attribute defines the y coordinate of the point a spot light points at.
+    points_at_y "pointsAtY",
+    /// The `pointsAtZ` attribute defines the z coordinate of the point a spot light points at.
+    points_at_z "pointsAtZ",
+    /// The `limitingConeAngle` attribute defines the angle of the cone that limits a spot light.
+    limiting_cone_angle "limitingConeAngle",
+    /// The `refX` attribute defines the x coordinate of the reference point of a marker or symbol.
+    ref_x "refX",
+    /// The `refY` attribute defines the y coordinate of the reference point of a marker or symbol.
+    ref_y "refY",
+    /// The `markerWidth` attribute defines the width of the viewport of a marker.
+    marker_width "markerWidth",
+    /// The `markerHeight` attribute defines the height of the viewport of a marker.
+    marker_height "markerHeight",
+    /// The `markerUnits` attribute defines the coordinate system used by the size of a marker.
+    marker_units "markerUnits",
+    /// The `orient` attribute defines how a marker is rotated when it is placed on a shape.
+    orient "orient",
+    /// The `startOffset` attribute defines how far along the path the text of a `<textPath>` starts.
+    start_offset "startOffset",
+
     // MathML attributes
     /// The `accent` attribute specifies whether the element should be treated as an accent.
     accent "accent",
     /// The `accentunder` attribute specifies whether the element should be treated as an accent under the base element.
     accentunder "accentunder",
+    /// The `actiontype` attribute specifies the action that happens when an `<maction>` element is activated.
+    actiontype "actiontype",
+    /// The `alttext` attribute provides a text alternative for a `<math>` element.
+    alttext "alttext",
     /// The `columnalign` attribute specifies the alignment of columns.
     columnalign "columnalign",
     /// The `columnlines` attribute specifies the presence of lines between columns.
@@ -610,12 +835,16 @@ attributes! {
     display "display",
     /// The `displaystyle` attribute specifies whether the element is displayed in display style.
     displaystyle "displaystyle",
+    /// The `encoding` attribute specifies the encoding of the semantic information in an annotation.
+    encoding "encoding",
     /// The `fence` attribute specifies whether the element should act as a fence.
     fence "fence",
     /// The `frame` attribute specifies the type of frame for the element.
     frame "frame",
     /// The `framespacing` attribute specifies the spacing around frames.
     framespacing "framespacing",
+    /// The `largeop` attribute specifies whether an operator should be drawn bigger when `displaystyle` is `true`.
+    largeop "largeop",
     /// The `linethickness` attribute specifies the thickness of lines.
     linethickness "linethickness",
     /// The `lspace` attribute specifies the space on the left side of the element.
@@ -646,6 +875,8 @@ attributes! {
     rspace "rspace",
     /// The `scriptlevel` attribute specifies the script level of the element.
     scriptlevel "scriptlevel",
+    /// The `selection` attribute specifies which child of an `<maction>` element is visible.
+    selection "selection",
     /// The `separator` attribute specifies whether the element is a separator.
     separator "separator",
     /// The `stretchy` attribute specifies whether the element is stretchy.
@@ -1,13 +1,114 @@
+/// Defines enums for attributes that take one of a fixed set of values.
+macro_rules! attribute_values {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident {
+            $($variant:ident => $value:literal,)*
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            #[allow(missing_docs)]
+            pub enum $name {
+                $($variant,)*
+            }
+
+            impl $name {
+                /// Every value, in the order they are declared.
+                pub const ALL: &'static [Self] = &[$($name::$variant,)*];
+
+                /// Returns the value as it appears in HTML.
+                pub const fn as_str(&self) -> &'static str {
+                    match self {
+                        $($name::$variant => $value,)*
+                    }
+                }
+            }
+
+            impl std::fmt::Display for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+
+            impl $crate::html::attribute::AttributeValue for $name {
+                type AsyncOutput = Self;
+                type State = ($crate::renderer::types::Element, Self);
+                type Cloneable = Self;
+                type CloneableOwned = Self;
+
+                fn html_len(&self) -> usize {
+                    self.as_str().len()
+                }
+
+                fn to_html(self, key: &str, buf: &mut String) {
+                    <&str as $crate::html::attribute::AttributeValue>::to_html(self.as_str(), key, buf);
+                }
+
+                fn to_template(_key: &str, _buf: &mut String) {}
+
+                fn hydrate<const FROM_SERVER: bool>(
+                    self,
+                    key: &str,
+                    el: &$crate::renderer::types::Element,
+                ) -> Self::State {
+                    if !FROM_SERVER {
+                        $crate::renderer::Rndr::set_attribute(el, key, self.as_str());
+                    }
+                    (el.clone(), self)
+                }
+
+                fn build(
+                    self,
+                    el: &$crate::renderer::types::Element,
+                    key: &str,
+                ) -> Self::State {
+                    $crate::renderer::Rndr::set_attribute(el, key, self.as_str());
+                    (el.to_owned(), self)
+                }
+
+                fn rebuild(self, key: &str, state: &mut Self::State) {
+                    let (el, prev_value) = state;
+                    if self != *prev_value {
+                        $crate::renderer::Rndr::set_attribute(el, key, self.as_str());
+                    }
+                    *prev_value = self;
+                }
+
+                fn into_cloneable(self) -> Self::Cloneable {
+                    self
+                }
+
+                fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                    self
+                }
+
+                fn dry_resolve(&mut self) {}
+
+                async fn resolve(self) -> Self::AsyncOutput {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use attribute_values;
+
 /// A type-erased `AnyAttribute`.
 pub mod any_attribute;
 /// Types for ARIA attributes.
 pub mod aria;
 /// Types for custom attributes.
 pub mod custom;
+/// Types for `data-*` attributes.
+pub mod data;
 /// Traits to define global attribute methods on all HTML elements.
 pub mod global;
 mod key;
 pub(crate) mod maybe_next_attr_erasure_macros;
+mod popover;
 mod value;
 
 use crate::view::{Position, ToTemplate};
@@ -15,6 +116,7 @@ pub use key::*;
 use maybe_next_attr_erasure_macros::{
     next_attr_combine, next_attr_output_type,
 };
+pub use popover::*;
 use std::{borrow::Cow, fmt::Debug, future::Future};
 pub use value::*;
 
@@ -87,6 +189,7 @@ pub trait Attribute: NextAttribute + Send {
 }
 
 /// An attribute key can be used to remove an attribute from an element.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NamedAttributeKey {
     /// An ordinary attribute.
     Attribute(Cow<'static, str>),
@@ -0,0 +1,18 @@
+attribute_values! {
+    /// A value for the [`popover`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/popover)
+    /// attribute.
+    ///
+    /// `popover=true` can also be used, and is the same as [`PopoverMode::Auto`].
+    PopoverMode {
+        Auto => "auto",
+        Manual => "manual",
+        Hint => "hint",
+    }
+
+    /// A value for the `popovertargetaction` attribute of a `<button>` or `<input>` element.
+    PopoverAction {
+        Toggle => "toggle",
+        Show => "show",
+        Hide => "hide",
+    }
+}
@@ -29,7 +29,7 @@ where
 impl<V, T, P, D> DirectiveAttribute<T, P, D> for V
 where
     V: AddAnyAttr,
-    D: IntoDirective<T, P>,
+    D: IntoDirective<T, P> + 'static,
     P: Clone + 'static,
     T: 'static,
 {
@@ -77,6 +77,42 @@ struct DirectiveInner<T, D, P> {
     t: PhantomData<T>,
 }
 
+/// The state retained for a directive while its element is mounted.
+#[cfg(feature = "reactive_graph")]
+pub type DirectiveState = reactive_graph::effect::RenderEffect<()>;
+
+/// The state retained for a directive while its element is mounted.
+#[cfg(not(feature = "reactive_graph"))]
+pub type DirectiveState = ();
+
+impl<T, D, P> DirectiveInner<T, D, P>
+where
+    D: IntoDirective<T, P> + 'static,
+    P: Clone + 'static,
+    T: 'static,
+{
+    fn run(
+        self,
+        el: crate::renderer::types::Element,
+    ) -> Option<DirectiveState> {
+        let DirectiveInner { handler, param, .. } = self;
+
+        // any signals read by the directive are tracked, so it runs again when they change,
+        // after running any cleanups from the previous run
+        #[cfg(feature = "reactive_graph")]
+        {
+            Some(reactive_graph::effect::RenderEffect::new(move |_| {
+                handler.run(el.clone(), param.clone())
+            }))
+        }
+        #[cfg(not(feature = "reactive_graph"))]
+        {
+            handler.run(el, param);
+            None
+        }
+    }
+}
+
 impl<T, D, P> Clone for DirectiveInner<T, D, P>
 where
     P: Clone + 'static,
@@ -93,14 +129,14 @@ where
 
 impl<T, P, D> Attribute for Directive<T, D, P>
 where
-    D: IntoDirective<T, P>,
+    D: IntoDirective<T, P> + 'static,
     P: Clone + 'static, // TODO this is just here to make them cloneable
     T: 'static,
 {
     const MIN_LENGTH: usize = 0;
 
     type AsyncOutput = Self;
-    type State = crate::renderer::types::Element;
+    type State = (crate::renderer::types::Element, Option<DirectiveState>);
     type Cloneable = Directive<T, D::Cloneable, P>;
     type CloneableOwned = Directive<T, D::Cloneable, P>;
 
@@ -122,19 +158,21 @@ where
         el: &crate::renderer::types::Element,
     ) -> Self::State {
         let inner = self.0.expect(super::FEATURE_CONFLICT_DIAGNOSTIC).take();
-        inner.handler.run(el.clone(), inner.param);
-        el.clone()
+        (el.clone(), inner.run(el.clone()))
     }
 
     fn build(self, el: &crate::renderer::types::Element) -> Self::State {
         let inner = self.0.expect(super::FEATURE_CONFLICT_DIAGNOSTIC).take();
-        inner.handler.run(el.clone(), inner.param);
-        el.clone()
+        (el.clone(), inner.run(el.clone()))
     }
 
     fn rebuild(self, state: &mut Self::State) {
         let inner = self.0.expect(super::FEATURE_CONFLICT_DIAGNOSTIC).take();
-        inner.handler.run(state.clone(), inner.param);
+        let (el, prev) = state;
+        // drop the previous state first, so the previous directive's cleanups run before the
+        // new directive does
+        *prev = None;
+        *prev = inner.run(el.clone());
     }
 
     fn into_cloneable(self) -> Self::Cloneable {
@@ -166,7 +204,7 @@ where
 
 impl<T, D, P> NextAttribute for Directive<T, D, P>
 where
-    D: IntoDirective<T, P>,
+    D: IntoDirective<T, P> + 'static,
     P: Clone + 'static,
     T: 'static,
 {
@@ -239,6 +277,30 @@ impl<T, D, P> ToTemplate for Directive<T, D, P> {
 /// A directive can be a function with one or two parameters.
 /// The first is the element the directive is added to and the optional
 /// second is the parameter that is provided in the attribute.
+///
+/// Any signals that the directive reads while it runs are tracked, and the directive runs again
+/// whenever they change. This means that the parameter can be a signal:
+///
+/// ```ignore
+/// fn highlight(el: Element, color: Signal<String>) {
+///     // runs again whenever `color` changes
+///     el.unchecked_ref::<HtmlElement>()
+///         .style()
+///         .set_property("background-color", &color.get());
+/// }
+///
+/// view! { <p use:highlight=color>"Hello"</p> }
+/// ```
+///
+/// A directive with a parameter can also return a cleanup function, which runs before the
+/// directive runs again and when the element is unmounted:
+///
+/// ```ignore
+/// fn tooltip(el: Element, text: Signal<String>) -> impl FnOnce() {
+///     let tooltip = create_tooltip(&el, &text.get());
+///     move || tooltip.remove()
+/// }
+/// ```
 pub trait IntoDirective<T: ?Sized, P> {
     /// An equivalent to this directive that is cloneable and owned.
     type Cloneable: IntoDirective<T, P> + Clone + 'static;
@@ -310,3 +372,46 @@ where
         self
     }
 }
+
+#[cfg(feature = "reactive_graph")]
+impl<F, P, C> IntoDirective<(crate::renderer::types::Element, P, C), P> for F
+where
+    F: Fn(crate::renderer::types::Element, P) -> C + 'static,
+    P: 'static,
+    C: FnOnce() + 'static,
+{
+    type Cloneable = Arc<dyn Fn(crate::renderer::types::Element, P) -> C>;
+
+    fn run(&self, el: crate::renderer::types::Element, param: P) {
+        on_directive_cleanup(self(el, param));
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        Arc::new(self)
+    }
+}
+
+#[cfg(feature = "reactive_graph")]
+impl<P, C> IntoDirective<(crate::renderer::types::Element, P, C), P>
+    for Arc<dyn Fn(crate::renderer::types::Element, P) -> C>
+where
+    P: 'static,
+    C: FnOnce() + 'static,
+{
+    type Cloneable = Arc<dyn Fn(crate::renderer::types::Element, P) -> C>;
+
+    fn run(&self, el: crate::renderer::types::Element, param: P) {
+        on_directive_cleanup(self(el, param));
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+}
+
+#[cfg(feature = "reactive_graph")]
+fn on_directive_cleanup(cleanup: impl FnOnce() + 'static) {
+    // directives only run in the browser, so the cleanup will always be run on the main thread
+    let cleanup = SendWrapper::new(cleanup);
+    reactive_graph::owner::on_cleanup(move || cleanup.take()());
+}
@@ -9,7 +9,12 @@ use crate::{
     renderer::Rndr,
     view::add_attr::AddAnyAttr,
 };
-use std::{future::Future, sync::Arc};
+use or_poisoned::OrPoisoned;
+use std::{
+    borrow::Cow,
+    future::Future,
+    sync::{Arc, RwLock},
+};
 
 /// Returns an [`Attribute`] that sets the inner HTML of an element.
 ///
@@ -18,7 +23,7 @@ use std::{future::Future, sync::Arc};
 /// # Security
 /// Be very careful when using this method. Always remember to
 /// sanitize the input to avoid a cross-site scripting (XSS)
-/// vulnerability.
+/// vulnerability, for example by setting a [`Sanitizer`].
 #[inline(always)]
 pub fn inner_html<T>(value: T) -> InnerHtml<T>
 where
@@ -163,6 +168,136 @@ where
     }
 }
 
+/// Cleans up HTML before it is used as the inner HTML of an element, for example by removing
+/// scripts and event handler attributes.
+///
+/// The sanitizer set with [`set_sanitizer`] is applied to every string passed to
+/// [`inner_html`], both when rendering to HTML on the server and when building or updating
+/// elements in the browser. Use [`TrustedHtml`] for HTML that should be used as-is.
+pub trait Sanitizer: Send + Sync {
+    /// Returns a safe version of the given HTML.
+    fn sanitize<'a>(&self, html: &'a str) -> Cow<'a, str>;
+}
+
+impl<F> Sanitizer for F
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    fn sanitize<'a>(&self, html: &'a str) -> Cow<'a, str> {
+        Cow::Owned(self(html))
+    }
+}
+
+/// A [`Sanitizer`] that uses the default settings of [`ammonia`].
+///
+/// This is used if no other sanitizer has been set.
+///
+/// The defaults remove `<script>` and `<style>` elements along with their contents, so inline
+/// scripts and styles that you have written yourself should be passed as [`TrustedHtml`].
+#[cfg(feature = "ammonia")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Ammonia;
+
+#[cfg(feature = "ammonia")]
+impl Sanitizer for Ammonia {
+    fn sanitize<'a>(&self, html: &'a str) -> Cow<'a, str> {
+        Cow::Owned(ammonia::clean(html))
+    }
+}
+
+static SANITIZER: RwLock<Option<Arc<dyn Sanitizer>>> = RwLock::new(None);
+
+/// Sets the [`Sanitizer`] that is applied to all inner HTML.
+///
+/// By default, HTML is not sanitized, unless the `ammonia` feature is enabled, in which case
+/// [`Ammonia`] is used.
+pub fn set_sanitizer(sanitizer: impl Sanitizer + 'static) {
+    *SANITIZER.write().or_poisoned() = Some(Arc::new(sanitizer));
+}
+
+pub(crate) fn sanitize(html: &str) -> Cow<'_, str> {
+    let sanitizer = SANITIZER.read().or_poisoned().clone();
+    match sanitizer {
+        Some(sanitizer) => sanitizer.sanitize(html),
+        #[cfg(feature = "ammonia")]
+        None => Ammonia.sanitize(html),
+        #[cfg(not(feature = "ammonia"))]
+        None => Cow::Borrowed(html),
+    }
+}
+
+/// HTML that is used as inner HTML without being passed to the [`Sanitizer`].
+///
+/// # Security
+/// Only use this for HTML that you control, and never for input provided by users.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrustedHtml(pub Arc<str>);
+
+impl From<String> for TrustedHtml {
+    fn from(value: String) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<&str> for TrustedHtml {
+    fn from(value: &str) -> Self {
+        Self(value.into())
+    }
+}
+
+impl InnerHtmlValue for TrustedHtml {
+    type AsyncOutput = Self;
+    type State = (crate::renderer::types::Element, Self);
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn to_html(self, buf: &mut String) {
+        buf.push_str(&self.0);
+    }
+
+    fn to_template(_buf: &mut String) {}
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        el: &crate::renderer::types::Element,
+    ) -> Self::State {
+        if !FROM_SERVER {
+            Rndr::set_inner_html(el, &self.0);
+        }
+        (el.clone(), self)
+    }
+
+    fn build(self, el: &crate::renderer::types::Element) -> Self::State {
+        Rndr::set_inner_html(el, &self.0);
+        (el.clone(), self)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if self != state.1 {
+            Rndr::set_inner_html(&state.0, &self.0);
+            state.1 = self;
+        }
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+}
+
 /// A possible value for [`InnerHtml`].
 pub trait InnerHtmlValue: Send {
     /// The type after all async data have resolved.
@@ -222,7 +357,7 @@ impl InnerHtmlValue for String {
     }
 
     fn to_html(self, buf: &mut String) {
-        buf.push_str(&self);
+        buf.push_str(&sanitize(&self));
     }
 
     fn to_template(_buf: &mut String) {}
@@ -232,19 +367,19 @@ impl InnerHtmlValue for String {
         el: &crate::renderer::types::Element,
     ) -> Self::State {
         if !FROM_SERVER {
-            Rndr::set_inner_html(el, &self);
+            Rndr::set_inner_html(el, &sanitize(&self));
         }
         (el.clone(), self)
     }
 
     fn build(self, el: &crate::renderer::types::Element) -> Self::State {
-        Rndr::set_inner_html(el, &self);
+        Rndr::set_inner_html(el, &sanitize(&self));
         (el.clone(), self)
     }
 
     fn rebuild(self, state: &mut Self::State) {
         if self != state.1 {
-            Rndr::set_inner_html(&state.0, &self);
+            Rndr::set_inner_html(&state.0, &sanitize(&self));
             state.1 = self;
         }
     }
@@ -275,7 +410,7 @@ impl InnerHtmlValue for Arc<str> {
     }
 
     fn to_html(self, buf: &mut String) {
-        buf.push_str(&self);
+        buf.push_str(&sanitize(&self));
     }
 
     fn to_template(_buf: &mut String) {}
@@ -285,19 +420,19 @@ impl InnerHtmlValue for Arc<str> {
         el: &crate::renderer::types::Element,
     ) -> Self::State {
         if !FROM_SERVER {
-            Rndr::set_inner_html(el, &self);
+            Rndr::set_inner_html(el, &sanitize(&self));
         }
         (el.clone(), self)
     }
 
     fn build(self, el: &crate::renderer::types::Element) -> Self::State {
-        Rndr::set_inner_html(el, &self);
+        Rndr::set_inner_html(el, &sanitize(&self));
         (el.clone(), self)
     }
 
     fn rebuild(self, state: &mut Self::State) {
         if self != state.1 {
-            Rndr::set_inner_html(&state.0, &self);
+            Rndr::set_inner_html(&state.0, &sanitize(&self));
             state.1 = self;
         }
     }
@@ -328,7 +463,7 @@ impl InnerHtmlValue for &str {
     }
 
     fn to_html(self, buf: &mut String) {
-        buf.push_str(self);
+        buf.push_str(&sanitize(self));
     }
 
     fn to_template(_buf: &mut String) {}
@@ -338,19 +473,19 @@ impl InnerHtmlValue for &str {
         el: &crate::renderer::types::Element,
     ) -> Self::State {
         if !FROM_SERVER {
-            Rndr::set_inner_html(el, self);
+            Rndr::set_inner_html(el, &sanitize(self));
         }
         (el.clone(), self)
     }
 
     fn build(self, el: &crate::renderer::types::Element) -> Self::State {
-        Rndr::set_inner_html(el, self);
+        Rndr::set_inner_html(el, &sanitize(self));
         (el.clone(), self)
     }
 
     fn rebuild(self, state: &mut Self::State) {
         if self != state.1 {
-            Rndr::set_inner_html(&state.0, self);
+            Rndr::set_inner_html(&state.0, &sanitize(self));
             state.1 = self;
         }
     }
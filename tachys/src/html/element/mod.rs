@@ -518,6 +518,27 @@ where
             panic!("Custom elements are not supported in ViewTemplate.");
         }
 
+        if E::TAG == "template" {
+            if let Some(shadow_root) = declarative_shadow_root(cursor, position)
+            {
+                let (el, attrs, prev) = enter_shadow_root(
+                    self.attributes,
+                    shadow_root,
+                    cursor,
+                    position,
+                );
+                let children = (Ch::EXISTS && E::ESCAPE_CHILDREN).then(|| {
+                    self.children.hydrate::<FROM_SERVER>(cursor, position)
+                });
+                leave_shadow_root(prev, cursor, position);
+                return ElementState {
+                    el,
+                    attrs,
+                    children,
+                };
+            }
+        }
+
         // codegen optimisation:
         fn inner_1(
             cursor: &Cursor,
@@ -589,6 +610,29 @@ where
         cursor: &Cursor,
         position: &PositionState,
     ) -> Self::State {
+        if E::TAG == "template" {
+            if let Some(shadow_root) = declarative_shadow_root(cursor, position)
+            {
+                let (el, attrs, prev) = enter_shadow_root(
+                    self.attributes,
+                    shadow_root,
+                    cursor,
+                    position,
+                );
+                let children = if Ch::EXISTS && E::ESCAPE_CHILDREN {
+                    Some(self.children.hydrate_async(cursor, position).await)
+                } else {
+                    None
+                };
+                leave_shadow_root(prev, cursor, position);
+                return ElementState {
+                    el,
+                    attrs,
+                    children,
+                };
+            }
+        }
+
         // codegen optimisation:
         fn inner_1(
             cursor: &Cursor,
@@ -666,6 +710,66 @@ where
     }
 }
 
+/// Returns the shadow root into which a `<template shadowrootmode="...">` was turned, if the
+/// `<template>` that is about to be hydrated was rendered on the server as a declarative shadow
+/// root.
+///
+/// The browser's HTML parser attaches the contents of such a `<template>` to its parent as a
+/// shadow root, and removes the `<template>` itself from the DOM, so it cannot be found by
+/// walking the light DOM.
+fn declarative_shadow_root(
+    cursor: &Cursor,
+    position: &PositionState,
+) -> Option<web_sys::ShadowRoot> {
+    use wasm_bindgen::JsCast;
+
+    let current = cursor.current();
+    let (parent, next) = match position.get() {
+        Position::Current => return None,
+        Position::FirstChild => (Some(current.clone()), current.first_child()),
+        _ => (current.parent_node(), current.next_sibling()),
+    };
+    // if the `<template>` is still in the DOM, it is an ordinary template
+    if next.is_some_and(|next| next.node_name() == "TEMPLATE") {
+        return None;
+    }
+    parent?.dyn_into::<web_sys::Element>().ok()?.shadow_root()
+}
+
+/// Moves the cursor into a declarative shadow root, and hydrates the attributes of its
+/// `<template>` onto a detached element that stands in for it.
+///
+/// Returns the element, its attribute state, and the cursor state to be restored by
+/// [`leave_shadow_root`].
+fn enter_shadow_root<At: Attribute>(
+    attributes: At,
+    shadow_root: web_sys::ShadowRoot,
+    cursor: &Cursor,
+    position: &PositionState,
+) -> (
+    crate::renderer::types::Element,
+    At::State,
+    (crate::renderer::types::Node, Position),
+) {
+    let el = Rndr::create_element("template", None);
+    let attrs = attributes.hydrate::<true>(&el);
+    let prev = (cursor.current(), position.get());
+    cursor.set(shadow_root.into());
+    position.set(Position::FirstChild);
+    (el, attrs, prev)
+}
+
+/// Restores the cursor to where it was before [`enter_shadow_root`], so that the siblings of the
+/// `<template>` are hydrated as if it had never been in the light DOM.
+fn leave_shadow_root(
+    (node, prev_position): (crate::renderer::types::Node, Position),
+    cursor: &Cursor,
+    position: &PositionState,
+) {
+    cursor.set(node);
+    position.set(prev_position);
+}
+
 /// Renders an [`Attribute`] (which can be one or more HTML attributes) into an HTML buffer.
 pub fn attributes_to_html<At>(attr: At, buf: &mut String) -> String
 where
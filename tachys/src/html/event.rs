@@ -15,7 +15,9 @@ use std::{
     ops::{Deref, DerefMut},
     rc::Rc,
 };
-use wasm_bindgen::convert::FromWasmAbi;
+use wasm_bindgen::{
+    convert::FromWasmAbi, describe::WasmDescribe, JsCast, JsValue,
+};
 
 /// A cloneable event callback.
 pub type SharedEventCallback<E> = Rc<RefCell<dyn FnMut(E)>>;
@@ -178,10 +180,15 @@ where
             // (rather than, say, having a const DELEGATED: bool)
             // add to binary size?
             delegation_key: Option<Cow<'static, str>>,
+            options: Option<web_sys::AddEventListenerOptions>,
         ) -> RemoveEventHandler<crate::renderer::types::Element> {
-            match delegation_key {
-                None => Rndr::add_event_listener(el, &name, cb),
-                Some(key) => {
+            match (options, delegation_key) {
+                // listeners with options can't be delegated
+                (Some(options), _) => Rndr::add_event_listener_with_options(
+                    el, &name, cb, &options,
+                ),
+                (None, None) => Rndr::add_event_listener(el, &name, cb),
+                (None, Some(key)) => {
                     Rndr::add_event_listener_delegated(el, name, key, cb)
                 }
             }
@@ -211,8 +218,11 @@ where
             el,
             cb,
             self.event.name(),
-            (E::BUBBLES && cfg!(feature = "delegation"))
-                .then(|| self.event.event_delegation_key()),
+            (E::BUBBLES
+                && (cfg!(feature = "delegation")
+                    || Rndr::has_delegation_root()))
+            .then(|| self.event.event_delegation_key()),
+            self.event.listener_options(),
         )
     }
 
@@ -226,8 +236,17 @@ where
             el: &crate::renderer::types::Element,
             cb: Box<dyn FnMut(crate::renderer::types::Event)>,
             name: Cow<'static, str>,
+            options: Option<web_sys::AddEventListenerOptions>,
         ) -> RemoveEventHandler<crate::renderer::types::Element> {
-            Rndr::add_event_listener_use_capture(el, &name, cb)
+            match options {
+                Some(options) => {
+                    options.set_capture(true);
+                    Rndr::add_event_listener_with_options(
+                        el, &name, cb, &options,
+                    )
+                }
+                None => Rndr::add_event_listener_use_capture(el, &name, cb),
+            }
         }
 
         let mut cb = self.cb.expect(super::FEATURE_CONFLICT_DIAGNOSTIC).take();
@@ -250,7 +269,7 @@ where
             cb.invoke(ev);
         }) as Box<dyn FnMut(crate::renderer::types::Event)>;
 
-        attach_inner(el, cb, self.event.name())
+        attach_inner(el, cb, self.event.name(), self.event.listener_options())
     }
 }
 
@@ -402,7 +421,8 @@ pub trait EventDescriptor: Clone {
     /// but `focus` does not.
     ///
     /// If this is true, then the event will be delegated globally if the `delegation`
-    /// feature is enabled. Otherwise, event listeners will be directly attached to the element.
+    /// feature is enabled, or to the root set with [`delegate_events_to`]. Otherwise, event
+    /// listeners will be directly attached to the element.
     const BUBBLES: bool;
 
     /// Indicates if this event should be handled during the capture phase.
@@ -420,6 +440,21 @@ pub trait EventDescriptor: Clone {
     fn options(&self) -> Option<&web_sys::AddEventListenerOptions> {
         None
     }
+
+    /// Creates a new copy of the options for this event, which is used when the listener is
+    /// attached.
+    ///
+    /// This returns a fresh object each time, so that changes made while attaching one listener
+    /// are never seen by other listeners that share the same options.
+    ///
+    /// Event listeners with options are always attached directly to the element, rather than
+    /// being delegated.
+    fn listener_options(&self) -> Option<web_sys::AddEventListenerOptions> {
+        self.options().map(|options| {
+            js_sys::Object::assign(&js_sys::Object::new(), options)
+                .unchecked_into()
+        })
+    }
 }
 
 /// A wrapper that tells the framework to handle an event during the capture phase.
@@ -448,6 +483,72 @@ impl<E: EventDescriptor> EventDescriptor for Capture<E> {
     }
 }
 
+/// Options for an event listener, which are passed to
+/// [`addEventListener`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Options {
+    /// If `true`, the handler will never call `preventDefault()`, which allows the browser to
+    /// keep scrolling smoothly while handling `scroll`, `wheel`, or touch events.
+    pub passive: bool,
+    /// If `true`, the handler will run during the capture phase, before any handlers further
+    /// down in the DOM.
+    pub capture: bool,
+    /// If `true`, the handler will be removed after it runs for the first time.
+    pub once: bool,
+}
+
+/// A wrapper that adds listener [`Options`] to an event.
+#[derive(Debug, Clone)]
+pub struct WithOptions<E> {
+    inner: E,
+    options: Options,
+}
+
+/// Wraps an event to add listener [`Options`] to it.
+pub fn with_options<E>(event: E, options: Options) -> WithOptions<E> {
+    WithOptions {
+        inner: event,
+        options,
+    }
+}
+
+impl<E: EventDescriptor> EventDescriptor for WithOptions<E> {
+    type EventType = E::EventType;
+
+    const CAPTURE: bool = E::CAPTURE;
+    const BUBBLES: bool = E::BUBBLES;
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+
+    fn event_delegation_key(&self) -> Cow<'static, str> {
+        self.inner.event_delegation_key()
+    }
+
+    fn listener_options(&self) -> Option<web_sys::AddEventListenerOptions> {
+        let options = web_sys::AddEventListenerOptions::new();
+        options.set_passive(self.options.passive);
+        options.set_capture(self.options.capture);
+        options.set_once(self.options.once);
+        Some(options)
+    }
+}
+
+/// Delegates bubbling events, like `click` or `input`, to `root`, which is usually the element
+/// that an app is mounted to.
+///
+/// Rather than attaching a listener to every element with an event handler, each kind of
+/// bubbling event is handled by a single listener on `root`, which finds the handlers by
+/// walking up from the event's target. This reduces the number of listeners that need to be
+/// allocated for large interactive lists.
+///
+/// This only applies to event handlers that are attached after it is called, so it should be
+/// called before the view is mounted. Pass `None` to stop delegating events.
+pub fn delegate_events_to(root: Option<&crate::renderer::types::Element>) {
+    Rndr::set_delegation_root(root);
+}
+
 /// A custom event.
 #[derive(Debug)]
 pub struct Custom<E: FromWasmAbi = web_sys::Event> {
@@ -525,6 +626,100 @@ impl<E: FromWasmAbi> Custom<E> {
     }
 }
 
+/// Creates a custom event type whose `detail` contains a value of type `T`.
+///
+/// This is useful for handling the events emitted by web components.
+///
+/// ```rust,ignore
+/// view! {
+///     <color-picker on:color-change=|_| {} />
+/// }
+///
+/// // or, with a typed payload:
+/// let picker = html::element::custom("color-picker").on(
+///     ev::custom_event::<String>("color-change"),
+///     |ev| {
+///         if let Some(color) = ev.detail() {
+///             // handle the new color
+///         }
+///     },
+/// );
+/// ```
+pub fn custom_event<T>(
+    name: impl Into<Cow<'static, str>>,
+) -> Custom<TypedCustomEvent<T>> {
+    Custom::new(name)
+}
+
+/// A [`CustomEvent`](web_sys::CustomEvent) whose `detail` contains a value of type `T`.
+///
+/// Created with [`custom_event`].
+pub struct TypedCustomEvent<T> {
+    event: web_sys::CustomEvent,
+    _detail: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedCustomEvent<T>
+where
+    T: TryFrom<JsValue>,
+{
+    /// Returns the event's `detail`, or `None` if it could not be converted into `T`.
+    pub fn detail(&self) -> Option<T> {
+        T::try_from(self.event.detail()).ok()
+    }
+}
+
+impl<T> TypedCustomEvent<T> {
+    /// Returns the underlying [`CustomEvent`](web_sys::CustomEvent).
+    pub fn into_inner(self) -> web_sys::CustomEvent {
+        self.event
+    }
+}
+
+impl<T> Debug for TypedCustomEvent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedCustomEvent")
+            .field("event", &self.event)
+            .finish()
+    }
+}
+
+impl<T> Deref for TypedCustomEvent<T> {
+    type Target = web_sys::CustomEvent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
+impl<T> From<JsValue> for TypedCustomEvent<T> {
+    fn from(value: JsValue) -> Self {
+        Self {
+            event: value.unchecked_into(),
+            _detail: PhantomData,
+        }
+    }
+}
+
+impl<T> WasmDescribe for TypedCustomEvent<T> {
+    #[inline]
+    fn describe() {
+        web_sys::CustomEvent::describe()
+    }
+}
+
+impl<T> FromWasmAbi for TypedCustomEvent<T> {
+    type Abi = <web_sys::CustomEvent as FromWasmAbi>::Abi;
+
+    #[inline]
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self {
+            event: web_sys::CustomEvent::from_abi(js),
+            _detail: PhantomData,
+        }
+    }
+}
+
 macro_rules! generate_event_types {
   {$(
     $( #[$does_not_bubble:ident] )?
@@ -5,14 +5,53 @@ use crate::{
     ssr::StreamBuilder,
     view::{add_attr::AddAnyAttr, Position, PositionState},
 };
+use std::borrow::Cow;
 
 /// An island of interactivity in an otherwise-inert HTML document.
 pub struct Island<View> {
     has_element_representation: bool,
     component: &'static str,
     props_json: String,
+    trigger: HydrationTrigger,
+    deferred: bool,
     view: View,
 }
+
+/// When the island runtime hydrates an [`Island`].
+///
+/// Deferring hydration means that islands below the fold, or islands that are rarely used, do
+/// not cost anything at startup. Until it is hydrated, an island is shown as its
+/// server-rendered HTML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HydrationTrigger {
+    /// Hydrates as soon as the WASM module has loaded.
+    #[default]
+    Load,
+    /// Hydrates when the island first scrolls into the viewport.
+    Visible,
+    /// Hydrates when the browser is idle.
+    Idle,
+    /// Hydrates the first time the user hovers over, focuses, or touches the island.
+    Interaction,
+    /// Hydrates once the media query matches, e.g. `(min-width: 800px)`.
+    Media(&'static str),
+}
+
+impl HydrationTrigger {
+    /// The value of the `data-hydrate` attribute that tells the island runtime when to hydrate,
+    /// or `None` if the island should hydrate immediately.
+    pub fn as_attribute_value(&self) -> Option<Cow<'static, str>> {
+        match self {
+            HydrationTrigger::Load => None,
+            HydrationTrigger::Visible => Some(Cow::Borrowed("visible")),
+            HydrationTrigger::Idle => Some(Cow::Borrowed("idle")),
+            HydrationTrigger::Interaction => Some(Cow::Borrowed("interaction")),
+            HydrationTrigger::Media(query) => {
+                Some(Cow::Owned(format!("media({query})")))
+            }
+        }
+    }
+}
 const ISLAND_TAG: &str = "leptos-island";
 const ISLAND_CHILDREN_TAG: &str = "leptos-children";
 
@@ -24,6 +63,8 @@ impl<View> Island<View> {
                 Self::should_have_element_representation(),
             component,
             props_json: String::new(),
+            trigger: HydrationTrigger::Load,
+            deferred: false,
             view,
         }
     }
@@ -34,7 +75,26 @@ impl<View> Island<View> {
         self
     }
 
-    fn open_tag(component: &'static str, props: &str, buf: &mut String) {
+    /// Sets when the island runtime should hydrate this island.
+    pub fn with_hydration_trigger(mut self, trigger: HydrationTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Marks this island as deferred: its HTML is loaded by the island runtime in a follow-up
+    /// request, rather than being sent as part of the page.
+    pub fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+
+    fn open_tag(
+        component: &'static str,
+        props: &str,
+        trigger: &HydrationTrigger,
+        deferred: bool,
+        buf: &mut String,
+    ) {
         buf.push('<');
         buf.push_str(ISLAND_TAG);
         buf.push(' ');
@@ -46,6 +106,16 @@ impl<View> Island<View> {
             buf.push_str(&html_escape::encode_double_quoted_attribute(&props));
             buf.push('"');
         }
+        if let Some(trigger) = trigger.as_attribute_value() {
+            buf.push_str(" data-hydrate=\"");
+            buf.push_str(&html_escape::encode_double_quoted_attribute(
+                &trigger,
+            ));
+            buf.push('"');
+        }
+        if deferred {
+            buf.push_str(" data-defer");
+        }
         buf.push('>');
     }
 
@@ -104,12 +174,16 @@ where
             has_element_representation,
             component,
             props_json,
+            trigger,
+            deferred,
             view,
         } = self;
         Island {
             has_element_representation,
             component,
             props_json,
+            trigger,
+            deferred,
             view: view.add_any_attr(attr),
         }
     }
@@ -137,12 +211,16 @@ where
             has_element_representation,
             component,
             props_json,
+            trigger,
+            deferred,
             view,
         } = self;
         Island {
             has_element_representation,
             component,
             props_json,
+            trigger,
+            deferred,
             view: view.resolve().await,
         }
     }
@@ -157,7 +235,13 @@ where
     ) {
         let has_element = self.has_element_representation;
         if has_element {
-            Self::open_tag(self.component, &self.props_json, buf);
+            Self::open_tag(
+                self.component,
+                &self.props_json,
+                &self.trigger,
+                self.deferred,
+                buf,
+            );
         }
         self.view.to_html_with_buf(
             buf,
@@ -185,7 +269,13 @@ where
         // insert the opening tag synchronously
         let mut tag = String::new();
         if has_element {
-            Self::open_tag(self.component, &self.props_json, &mut tag);
+            Self::open_tag(
+                self.component,
+                &self.props_json,
+                &self.trigger,
+                self.deferred,
+                &mut tag,
+            );
         }
         buf.push_sync(&tag);
 
@@ -228,6 +318,8 @@ where
             has_element_representation: self.has_element_representation,
             component: self.component,
             props_json: self.props_json,
+            trigger: self.trigger,
+            deferred: self.deferred,
             view: self.view.into_owned(),
         }
     }
@@ -223,3 +223,165 @@ impl RenderHtml for InertElement {
         self
     }
 }
+
+/// A run of sibling nodes that contain no interactivity, and whose contents can be known at
+/// compile time.
+///
+/// When rendered on the server, the nodes are wrapped in a pair of comment markers, so that
+/// hydration can skip over all of them at once, rather than walking each node in turn.
+pub struct InertNodes {
+    html: Cow<'static, str>,
+}
+
+impl InertNodes {
+    /// Creates a new run of inert nodes.
+    pub fn new(html: impl Into<Cow<'static, str>>) -> Self {
+        Self { html: html.into() }
+    }
+}
+
+/// Marks the beginning of [`InertNodes`] in server-rendered HTML.
+const INERT_NODES_OPEN: &str = "io";
+/// Marks the end of [`InertNodes`] in server-rendered HTML.
+const INERT_NODES_CLOSE: &str = "ic";
+
+/// Retained view state for [`InertNodes`].
+pub struct InertNodesState(Cow<'static, str>, Vec<Node>);
+
+impl Mountable for InertNodesState {
+    fn unmount(&mut self) {
+        for node in &self.1 {
+            Rndr::remove(node);
+        }
+    }
+
+    fn mount(&mut self, parent: &Element, marker: Option<&Node>) {
+        for node in &self.1 {
+            Rndr::insert_node(parent, node, marker);
+        }
+    }
+
+    fn insert_before_this(&self, child: &mut dyn Mountable) -> bool {
+        self.1
+            .first()
+            .map(|node| node.insert_before_this(child))
+            .unwrap_or(false)
+    }
+
+    fn elements(&self) -> Vec<crate::renderer::types::Element> {
+        self.1
+            .iter()
+            .filter_map(|node| Element::cast_from(node.clone()))
+            .collect()
+    }
+}
+
+impl Render for InertNodes {
+    type State = InertNodesState;
+
+    fn build(self) -> Self::State {
+        let nodes = Rndr::create_nodes_from_html(self.html.clone());
+        InertNodesState(self.html, nodes)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if self.html != state.0 {
+            let mut new = self.build();
+            state.insert_before_this(&mut new);
+            state.unmount();
+            *state = new;
+        }
+    }
+}
+
+impl AddAnyAttr for InertNodes {
+    type Output<SomeNewAttr: Attribute> = Self;
+
+    fn add_any_attr<NewAttr: Attribute>(
+        self,
+        _attr: NewAttr,
+    ) -> Self::Output<NewAttr>
+    where
+        Self::Output<NewAttr>: RenderHtml,
+    {
+        panic!(
+            "InertNodes does not support adding attributes. It should only be \
+             used as a child, and not returned at the top level."
+        )
+    }
+}
+
+impl RenderHtml for InertNodes {
+    type AsyncOutput = Self;
+    type Owned = Self;
+
+    const MIN_LENGTH: usize = 0;
+
+    fn html_len(&self) -> usize {
+        self.html.len() + 18
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self {
+        self
+    }
+
+    fn to_html_with_buf(
+        self,
+        buf: &mut String,
+        position: &mut Position,
+        _escape: bool,
+        _mark_branches: bool,
+        _extra_attrs: Vec<AnyAttribute>,
+    ) {
+        buf.push_str("<!--");
+        buf.push_str(INERT_NODES_OPEN);
+        buf.push_str("-->");
+        buf.push_str(&self.html);
+        buf.push_str("<!--");
+        buf.push_str(INERT_NODES_CLOSE);
+        buf.push_str("-->");
+        *position = Position::NextChild;
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        cursor: &Cursor,
+        position: &PositionState,
+    ) -> Self::State {
+        let curr_position = position.get();
+        if curr_position == Position::FirstChild {
+            cursor.child();
+        } else if curr_position != Position::Current {
+            cursor.sibling();
+        }
+        let open = cursor.current();
+        let open = crate::renderer::types::Placeholder::cast_from(open.clone())
+            .unwrap_or_else(|| {
+                crate::hydration::failed_to_cast_marker_node(open)
+            });
+
+        // skip straight to the closing marker, without hydrating anything in between
+        let mut nodes = vec![open.clone().into()];
+        let mut next = Rndr::next_sibling(&open);
+        while let Some(node) = next {
+            next = Rndr::next_sibling(&node);
+            let is_close = node.node_type() == 8
+                && node.text_content().as_deref() == Some(INERT_NODES_CLOSE);
+            nodes.push(node);
+            if is_close {
+                break;
+            }
+        }
+        if let Some(last) = nodes.last() {
+            cursor.set(last.clone());
+        }
+        position.set(Position::NextChild);
+        InertNodesState(self.html, nodes)
+    }
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
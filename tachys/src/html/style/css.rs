@@ -0,0 +1,485 @@
+use super::{IntoStyle, IntoStyleValue};
+use crate::renderer::{dom::CssStyleDeclaration, Rndr};
+use std::fmt;
+
+/// Creates an empty [`Css`] builder, which can be used to build up a set of styles with
+/// checked property names and units.
+///
+/// ```rust
+/// use tachys::html::style::{css, px, Display};
+///
+/// let styles = css().display(Display::Flex).gap(px(8));
+/// assert_eq!(styles.to_string(), "display:flex;gap:8px");
+/// ```
+#[inline(always)]
+pub fn css() -> Css {
+    Css::default()
+}
+
+/// A set of CSS declarations, which can be passed to the `style` attribute.
+///
+/// Created with [`css`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Css(String);
+
+impl Css {
+    /// Adds a declaration for any property, including custom properties.
+    ///
+    /// Prefer the typed methods where they exist, as they check both the property name and the
+    /// value.
+    pub fn property(mut self, name: &str, value: impl fmt::Display) -> Self {
+        use fmt::Write;
+
+        if !self.0.is_empty() {
+            self.0.push(';');
+        }
+        _ = write!(self.0, "{name}:{value}");
+        self
+    }
+
+    /// Returns `true` if no declarations have been added.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Css {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! css_properties {
+    ($($(#[$meta:meta])* $method:ident($name:literal: $ty:ty);)*) => {
+        impl Css {
+            $(
+                $(#[$meta])*
+                pub fn $method(self, value: impl Into<$ty>) -> Self {
+                    self.property($name, value.into())
+                }
+            )*
+        }
+    };
+}
+
+css_properties! {
+    /// Sets the `display` property.
+    display("display": Display);
+    /// Sets the `position` property.
+    position("position": CssPosition);
+    /// Sets the `top` property.
+    top("top": Length);
+    /// Sets the `right` property.
+    right("right": Length);
+    /// Sets the `bottom` property.
+    bottom("bottom": Length);
+    /// Sets the `left` property.
+    left("left": Length);
+    /// Sets the `width` property.
+    width("width": Length);
+    /// Sets the `height` property.
+    height("height": Length);
+    /// Sets the `min-width` property.
+    min_width("min-width": Length);
+    /// Sets the `min-height` property.
+    min_height("min-height": Length);
+    /// Sets the `max-width` property.
+    max_width("max-width": Length);
+    /// Sets the `max-height` property.
+    max_height("max-height": Length);
+    /// Sets the `margin` property.
+    margin("margin": Length);
+    /// Sets the `padding` property.
+    padding("padding": Length);
+    /// Sets the `gap` property.
+    gap("gap": Length);
+    /// Sets the `font-size` property.
+    font_size("font-size": Length);
+    /// Sets the `border-radius` property.
+    border_radius("border-radius": Length);
+    /// Sets the `flex-direction` property.
+    flex_direction("flex-direction": FlexDirection);
+    /// Sets the `align-items` property.
+    align_items("align-items": Align);
+    /// Sets the `justify-content` property.
+    justify_content("justify-content": Align);
+    /// Sets the `overflow` property.
+    overflow("overflow": Overflow);
+    /// Sets the `color` property.
+    color("color": Color);
+    /// Sets the `background-color` property.
+    background_color("background-color": Color);
+    /// Sets the `opacity` property.
+    opacity("opacity": f64);
+    /// Sets the `z-index` property.
+    z_index("z-index": i32);
+}
+
+/// A CSS length, like `8px` or `50%`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Length {
+    /// `0`, which needs no unit.
+    Zero,
+    /// `auto`.
+    Auto,
+    /// An absolute length in pixels.
+    Px(f64),
+    /// A length relative to the element's font size.
+    Em(f64),
+    /// A length relative to the root element's font size.
+    Rem(f64),
+    /// A percentage of the containing block.
+    Percent(f64),
+    /// A percentage of the viewport's width.
+    Vw(f64),
+    /// A percentage of the viewport's height.
+    Vh(f64),
+}
+
+/// Creates a length in pixels.
+pub fn px(value: impl Into<f64>) -> Length {
+    Length::Px(value.into())
+}
+
+/// Creates a length relative to the element's font size.
+pub fn em(value: impl Into<f64>) -> Length {
+    Length::Em(value.into())
+}
+
+/// Creates a length relative to the root element's font size.
+pub fn rem(value: impl Into<f64>) -> Length {
+    Length::Rem(value.into())
+}
+
+/// Creates a percentage of the containing block.
+pub fn percent(value: impl Into<f64>) -> Length {
+    Length::Percent(value.into())
+}
+
+/// Creates a percentage of the viewport's width.
+pub fn vw(value: impl Into<f64>) -> Length {
+    Length::Vw(value.into())
+}
+
+/// Creates a percentage of the viewport's height.
+pub fn vh(value: impl Into<f64>) -> Length {
+    Length::Vh(value.into())
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Length::Zero => f.write_str("0"),
+            Length::Auto => f.write_str("auto"),
+            Length::Px(n) => write!(f, "{n}px"),
+            Length::Em(n) => write!(f, "{n}em"),
+            Length::Rem(n) => write!(f, "{n}rem"),
+            Length::Percent(n) => write!(f, "{n}%"),
+            Length::Vw(n) => write!(f, "{n}vw"),
+            Length::Vh(n) => write!(f, "{n}vh"),
+        }
+    }
+}
+
+/// A CSS color.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Color {
+    /// `currentcolor`.
+    CurrentColor,
+    /// `transparent`.
+    Transparent,
+    /// A color given as `0xRRGGBB`.
+    Hex(u32),
+    /// A color given by its red, green, and blue components.
+    Rgb(u8, u8, u8),
+    /// A color given by its red, green, and blue components, and its opacity from `0.0` to
+    /// `1.0`.
+    Rgba(u8, u8, u8, f64),
+}
+
+/// Creates a color from its red, green, and blue components.
+pub fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+/// Creates a color from its red, green, and blue components, and its opacity.
+pub fn rgba(r: u8, g: u8, b: u8, a: f64) -> Color {
+    Color::Rgba(r, g, b, a)
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::CurrentColor => f.write_str("currentcolor"),
+            Color::Transparent => f.write_str("transparent"),
+            Color::Hex(hex) => write!(f, "#{:06x}", hex & 0xffffff),
+            Color::Rgb(r, g, b) => write!(f, "rgb({r},{g},{b})"),
+            Color::Rgba(r, g, b, a) => write!(f, "rgba({r},{g},{b},{a})"),
+        }
+    }
+}
+
+macro_rules! keywords {
+    ($(#[$meta:meta])* $name:ident { $($(#[$vmeta:meta])* $variant:ident => $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant,
+            )*
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(match self {
+                    $($name::$variant => $value,)*
+                })
+            }
+        }
+    };
+}
+
+keywords! {
+    /// A value for the `display` property.
+    Display {
+        /// `none`.
+        None => "none",
+        /// `block`.
+        Block => "block",
+        /// `inline`.
+        Inline => "inline",
+        /// `inline-block`.
+        InlineBlock => "inline-block",
+        /// `flex`.
+        Flex => "flex",
+        /// `inline-flex`.
+        InlineFlex => "inline-flex",
+        /// `grid`.
+        Grid => "grid",
+        /// `inline-grid`.
+        InlineGrid => "inline-grid",
+        /// `contents`.
+        Contents => "contents",
+    }
+}
+
+keywords! {
+    /// A value for the `position` property.
+    CssPosition {
+        /// `static`.
+        Static => "static",
+        /// `relative`.
+        Relative => "relative",
+        /// `absolute`.
+        Absolute => "absolute",
+        /// `fixed`.
+        Fixed => "fixed",
+        /// `sticky`.
+        Sticky => "sticky",
+    }
+}
+
+keywords! {
+    /// A value for the `flex-direction` property.
+    FlexDirection {
+        /// `row`.
+        Row => "row",
+        /// `row-reverse`.
+        RowReverse => "row-reverse",
+        /// `column`.
+        Column => "column",
+        /// `column-reverse`.
+        ColumnReverse => "column-reverse",
+    }
+}
+
+keywords! {
+    /// A value for the `align-items` and `justify-content` properties.
+    Align {
+        /// `normal`.
+        Normal => "normal",
+        /// `stretch`.
+        Stretch => "stretch",
+        /// `center`.
+        Center => "center",
+        /// `flex-start`.
+        FlexStart => "flex-start",
+        /// `flex-end`.
+        FlexEnd => "flex-end",
+        /// `space-between`.
+        SpaceBetween => "space-between",
+        /// `space-around`.
+        SpaceAround => "space-around",
+        /// `space-evenly`.
+        SpaceEvenly => "space-evenly",
+    }
+}
+
+keywords! {
+    /// A value for the `overflow` property.
+    Overflow {
+        /// `visible`.
+        Visible => "visible",
+        /// `hidden`.
+        Hidden => "hidden",
+        /// `clip`.
+        Clip => "clip",
+        /// `scroll`.
+        Scroll => "scroll",
+        /// `auto`.
+        Auto => "auto",
+    }
+}
+
+impl IntoStyle for Css {
+    type AsyncOutput = Self;
+    type State = (crate::renderer::types::Element, Css);
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn to_html(self, style: &mut String) {
+        if !self.is_empty() {
+            style.push_str(&self.0);
+            style.push(';');
+        }
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        el: &crate::renderer::types::Element,
+    ) -> Self::State {
+        (el.clone(), self)
+    }
+
+    fn build(self, el: &crate::renderer::types::Element) -> Self::State {
+        Rndr::set_attribute(el, "style", &self.0);
+        (el.clone(), self)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let (el, prev) = state;
+        if self != *prev {
+            Rndr::set_attribute(el, "style", &self.0);
+        }
+        *prev = self;
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn reset(state: &mut Self::State) {
+        let (el, _prev) = state;
+        Rndr::remove_attribute(el, "style");
+    }
+}
+
+macro_rules! impl_css_value {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoStyleValue for $ty {
+                type AsyncOutput = Self;
+                type State = Self;
+                type Cloneable = Self;
+                type CloneableOwned = Self;
+
+                fn to_html(self, name: &str, style: &mut String) {
+                    use fmt::Write;
+
+                    _ = write!(style, "{name}:{self};");
+                }
+
+                fn build(
+                    self,
+                    style: &CssStyleDeclaration,
+                    name: &str,
+                ) -> Self::State {
+                    Rndr::set_css_property(style, name, &self.to_string());
+                    self
+                }
+
+                fn rebuild(
+                    self,
+                    style: &CssStyleDeclaration,
+                    name: &str,
+                    state: &mut Self::State,
+                ) {
+                    if self != *state {
+                        Rndr::set_css_property(style, name, &self.to_string());
+                    }
+                    *state = self;
+                }
+
+                fn hydrate(
+                    self,
+                    _style: &CssStyleDeclaration,
+                    _name: &str,
+                ) -> Self::State {
+                    self
+                }
+
+                fn into_cloneable(self) -> Self::Cloneable {
+                    self
+                }
+
+                fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                    self
+                }
+
+                fn dry_resolve(&mut self) {}
+
+                async fn resolve(self) -> Self::AsyncOutput {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_css_value!(
+    Length,
+    Color,
+    Display,
+    CssPosition,
+    FlexDirection,
+    Align,
+    Overflow
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_declarations() {
+        let styles = css()
+            .display(Display::Flex)
+            .gap(px(8))
+            .width(percent(50))
+            .color(Color::Hex(0xff0000))
+            .property("--accent", "blue");
+        assert_eq!(
+            styles.to_string(),
+            "display:flex;gap:8px;width:50%;color:#ff0000;--accent:blue"
+        );
+    }
+
+    #[test]
+    fn renders_to_html() {
+        let mut html = String::new();
+        css().display(Display::Block).to_html(&mut html);
+        px(4).to_html("margin", &mut html);
+        assert_eq!(html, "display:block;margin:4px;");
+    }
+}
@@ -13,6 +13,9 @@ use crate::{
 };
 use std::{future::Future, sync::Arc};
 
+mod css;
+pub use css::*;
+
 /// Returns an [`Attribute`] that will add to an element's CSS styles.
 #[inline(always)]
 pub fn style<S>(style: S) -> Style<S>
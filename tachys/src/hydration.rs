@@ -159,32 +159,16 @@ pub(crate) fn set_currently_hydrating(
 pub(crate) fn failed_to_cast_element(tag_name: &str, node: Node) -> Element {
     #[cfg(not(any(debug_assertions, leptos_debuginfo)))]
     {
-        _ = node;
+        _ = (tag_name, node);
         unreachable!();
     }
     #[cfg(any(debug_assertions, leptos_debuginfo))]
     {
-        let hydrating = CURRENTLY_HYDRATING
-            .take()
-            .map(|n| n.to_string())
-            .unwrap_or_else(|| "{unknown}".to_string());
-        web_sys::console::error_3(
-            &wasm_bindgen::JsValue::from_str(&format!(
-                "A hydration error occurred while trying to hydrate an \
-                 element defined at {hydrating}.\n\nThe framework expected an \
-                 HTML <{tag_name}> element, but found this instead: ",
-            )),
+        hydration_mismatch(
+            &format!("an HTML <{tag_name}> element"),
+            &format!("<{tag_name}>"),
             &node,
-            &wasm_bindgen::JsValue::from_str(
-                "\n\nThe hydration mismatch may have occurred slightly \
-                 earlier, but this is the first time the framework found a \
-                 node of an unexpected type.",
-            ),
-        );
-        panic!(
-            "Unrecoverable hydration error. Please read the error message \
-             directly above this for more details."
-        );
+        )
     }
 }
 
@@ -196,58 +180,116 @@ pub(crate) fn failed_to_cast_marker_node(node: Node) -> Comment {
     }
     #[cfg(any(debug_assertions, leptos_debuginfo))]
     {
-        let hydrating = CURRENTLY_HYDRATING
-            .take()
-            .map(|n| n.to_string())
-            .unwrap_or_else(|| "{unknown}".to_string());
-        web_sys::console::error_3(
-            &wasm_bindgen::JsValue::from_str(&format!(
-                "A hydration error occurred while trying to hydrate an \
-                 element defined at {hydrating}.\n\nThe framework expected a \
-                 marker node, but found this instead: ",
-            )),
-            &node,
-            &wasm_bindgen::JsValue::from_str(
-                "\n\nThe hydration mismatch may have occurred slightly \
-                 earlier, but this is the first time the framework found a \
-                 node of an unexpected type.",
-            ),
-        );
-        panic!(
-            "Unrecoverable hydration error. Please read the error message \
-             directly above this for more details."
-        );
+        hydration_mismatch("a marker node", "<!---->", &node)
     }
 }
 
-pub(crate) fn failed_to_cast_text_node(node: Node) -> Text {
+pub(crate) fn failed_to_cast_text_node(text: &str, node: Node) -> Text {
     #[cfg(not(any(debug_assertions, leptos_debuginfo)))]
     {
-        _ = node;
+        _ = (text, node);
         unreachable!();
     }
     #[cfg(any(debug_assertions, leptos_debuginfo))]
     {
-        let hydrating = CURRENTLY_HYDRATING
-            .take()
-            .map(|n| n.to_string())
-            .unwrap_or_else(|| "{unknown}".to_string());
-        web_sys::console::error_3(
-            &wasm_bindgen::JsValue::from_str(&format!(
-                "A hydration error occurred while trying to hydrate an \
-                 element defined at {hydrating}.\n\nThe framework expected a \
-                 text node, but found this instead: ",
-            )),
-            &node,
-            &wasm_bindgen::JsValue::from_str(
-                "\n\nThe hydration mismatch may have occurred slightly \
-                 earlier, but this is the first time the framework found a \
-                 node of an unexpected type.",
-            ),
-        );
-        panic!(
-            "Unrecoverable hydration error. Please read the error message \
-             directly above this for more details."
-        );
+        hydration_mismatch("a text node", &format!("{text:?}"), &node)
+    }
+}
+
+/// Logs a description of a hydration mismatch, and panics.
+///
+/// The description includes where the view was defined, what kind of node it expected, the
+/// path to the node that was actually found, and the HTML that was expected and found.
+#[cfg(any(debug_assertions, leptos_debuginfo))]
+fn hydration_mismatch(expected: &str, client_html: &str, node: &Node) -> ! {
+    let hydrating = CURRENTLY_HYDRATING
+        .take()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| "{unknown}".to_string());
+    web_sys::console::error_3(
+        &wasm_bindgen::JsValue::from_str(&format!(
+            "A hydration error occurred while trying to hydrate an element \
+             defined at {hydrating}.\n\nThe framework expected {expected}, \
+             but found this instead: ",
+        )),
+        node,
+        &wasm_bindgen::JsValue::from_str(&format!(
+            "\n\nDOM path: {}\nServer HTML: {}\nClient expected: \
+             {client_html}\n\nThe hydration mismatch may have occurred \
+             slightly earlier, but this is the first time the framework \
+             found a node of an unexpected type.",
+            dom_path(node),
+            html_snippet(node),
+        )),
+    );
+    panic!(
+        "Unrecoverable hydration error. Please read the error message \
+         directly above this for more details."
+    );
+}
+
+/// Describes the position of a node in the document as a CSS-like selector, like
+/// `body > main:nth-child(2) > p:nth-child(1) > #text(3)`.
+#[cfg(any(debug_assertions, leptos_debuginfo))]
+fn dom_path(node: &Node) -> String {
+    use wasm_bindgen::JsCast;
+
+    let mut segments = Vec::new();
+    let mut current = Some(node.clone());
+    while let Some(node) = current {
+        let parent = node.parent_node();
+        if let Some(el) = node.dyn_ref::<Element>() {
+            let tag = el.tag_name().to_lowercase();
+            if tag == "html" || tag == "body" {
+                segments.push(tag);
+                break;
+            } else if !el.id().is_empty() {
+                // an id is unique, so the path can start here
+                segments.push(format!("{tag}#{}", el.id()));
+                break;
+            } else {
+                let mut index = 1;
+                let mut sibling = el.previous_element_sibling();
+                while let Some(prev) = sibling {
+                    index += 1;
+                    sibling = prev.previous_element_sibling();
+                }
+                segments.push(format!("{tag}:nth-child({index})"));
+            }
+        } else {
+            let mut index = 1;
+            let mut sibling = node.previous_sibling();
+            while let Some(prev) = sibling {
+                index += 1;
+                sibling = prev.previous_sibling();
+            }
+            segments.push(format!("{}({index})", node.node_name()));
+        }
+        current = parent.filter(|parent| parent.dyn_ref::<Element>().is_some());
+    }
+    segments.reverse();
+    segments.join(" > ")
+}
+
+/// Returns the HTML of a node, shortened to a reasonable length.
+#[cfg(any(debug_assertions, leptos_debuginfo))]
+fn html_snippet(node: &Node) -> String {
+    use wasm_bindgen::JsCast;
+
+    const MAX_LEN: usize = 200;
+
+    let html = if let Some(el) = node.dyn_ref::<Element>() {
+        el.outer_html()
+    } else if node.dyn_ref::<Comment>().is_some() {
+        format!("<!--{}-->", node.text_content().unwrap_or_default())
+    } else {
+        format!("{:?}", node.text_content().unwrap_or_default())
+    };
+    if html.chars().count() > MAX_LEN {
+        let mut html = html.chars().take(MAX_LEN).collect::<String>();
+        html.push('…');
+        html
+    } else {
+        html
     }
 }
@@ -7,6 +7,9 @@ use crate::{
 };
 use std::fmt::Debug;
 
+mod values;
+pub use values::*;
+
 macro_rules! mathml_global {
 	($tag:ty, $attr:ty) => {
 		paste::paste! {
@@ -41,7 +44,9 @@ macro_rules! mathml_global {
 }
 
 macro_rules! mathml_elements {
-	($($tag:ident  [$($attr:ty),*]),* $(,)?) => {
+	(@tag $tag:ident) => { stringify!($tag) };
+	(@tag $tag:ident $html:literal) => { $html };
+	($($tag:ident $(($html:literal))? [$($attr:ty),*]),* $(,)?) => {
         paste::paste! {
             $(
                 // `tag()` function
@@ -112,7 +117,7 @@ macro_rules! mathml_elements {
                 impl ElementType for [<$tag:camel>] {
                     type Output = web_sys::Element;
 
-                    const TAG: &'static str = stringify!($tag);
+                    const TAG: &'static str = mathml_elements!(@tag $tag $($html)?);
                     const SELF_CLOSING: bool = false;
                     const ESCAPE_CHILDREN: bool = true;
                     const NAMESPACE: Option<&'static str> = Some("http://www.w3.org/1998/Math/MathML");
@@ -130,21 +135,21 @@ macro_rules! mathml_elements {
 }
 
 mathml_elements![
-    math [display, xmlns],
+    math [alttext, display, xmlns],
     mi [],
     mn [],
     mo [
-        accent, fence, lspace, maxsize, minsize, movablelimits,
-        rspace, separator, stretchy, symmetric, form
+        accent, fence, form, largeop, lspace, maxsize, minsize,
+        movablelimits, rspace, separator, stretchy, symmetric
     ],
     ms [],
-    mspace [height, width],
+    mspace [depth, height, width],
     mtext [],
     menclose [notation],
     merror [],
     mfenced [],
     mfrac [linethickness],
-    mpadded [depth, height, voffset, width],
+    mpadded [depth, height, lspace, voffset, width],
     mphantom [],
     mroot [],
     mrow [],
@@ -164,7 +169,8 @@ mathml_elements![
     ],
     mtd [columnalign, columnspan, rowalign, rowspan],
     mtr [columnalign, rowalign],
-    maction [],
-    annotation [],
+    maction [actiontype, selection],
+    annotation [encoding],
+    annotation_xml ("annotation-xml") [encoding],
     semantics [],
 ];
@@ -0,0 +1,42 @@
+use crate::html::attribute::attribute_values;
+
+attribute_values! {
+    /// A value for the `display` attribute of a `<math>` element.
+    MathDisplay {
+        Block => "block",
+        Inline => "inline",
+    }
+
+    /// A value for the `form` attribute of an `<mo>` element.
+    MathForm {
+        Prefix => "prefix",
+        Infix => "infix",
+        Postfix => "postfix",
+    }
+
+    /// A value for the `mathvariant` attribute.
+    ///
+    /// MathML Core only defines `normal`, which prevents single-character `<mi>` elements
+    /// from being rendered in italics. The other values are from MathML 3, and are only
+    /// supported by some browsers.
+    MathVariant {
+        Normal => "normal",
+        Bold => "bold",
+        Italic => "italic",
+        BoldItalic => "bold-italic",
+        DoubleStruck => "double-struck",
+        BoldFraktur => "bold-fraktur",
+        Script => "script",
+        BoldScript => "bold-script",
+        Fraktur => "fraktur",
+        SansSerif => "sans-serif",
+        BoldSansSerif => "bold-sans-serif",
+        SansSerifItalic => "sans-serif-italic",
+        SansSerifBoldItalic => "sans-serif-bold-italic",
+        Monospace => "monospace",
+        Initial => "initial",
+        Tailed => "tailed",
+        Looped => "looped",
+        Stretched => "stretched",
+    }
+}
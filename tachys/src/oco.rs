@@ -2,7 +2,7 @@ use crate::{
     html::{
         attribute::{any_attribute::AnyAttribute, AttributeValue},
         class::IntoClass,
-        element::InnerHtmlValue,
+        element::{sanitize, InnerHtmlValue},
         property::IntoProperty,
         style::IntoStyle,
     },
@@ -354,7 +354,7 @@ impl InnerHtmlValue for Oco<'static, str> {
     }
 
     fn to_html(self, buf: &mut String) {
-        buf.push_str(&self);
+        buf.push_str(&sanitize(&self));
     }
 
     fn to_template(_buf: &mut String) {}
@@ -364,19 +364,19 @@ impl InnerHtmlValue for Oco<'static, str> {
         el: &crate::renderer::types::Element,
     ) -> Self::State {
         if !FROM_SERVER {
-            Rndr::set_inner_html(el, &self);
+            Rndr::set_inner_html(el, &sanitize(&self));
         }
         (el.clone(), self)
     }
 
     fn build(self, el: &crate::renderer::types::Element) -> Self::State {
-        Rndr::set_inner_html(el, &self);
+        Rndr::set_inner_html(el, &sanitize(&self));
         (el.clone(), self)
     }
 
     fn rebuild(self, state: &mut Self::State) {
         if self != state.1 {
-            Rndr::set_inner_html(&state.0, &self);
+            Rndr::set_inner_html(&state.0, &sanitize(&self));
             state.1 = self;
         }
     }
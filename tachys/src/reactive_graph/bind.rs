@@ -8,7 +8,8 @@ use crate::{
             Attribute, AttributeKey, AttributeValue, NamedAttributeKey,
             NextAttribute,
         },
-        event::{change, input, on},
+        element::sanitize,
+        event::{change, compositionend, input, on},
         property::{prop, IntoProperty},
     },
     prelude::AddAnyAttr,
@@ -16,12 +17,17 @@ use crate::{
     view::{Position, ToTemplate},
 };
 use reactive_graph::{
-    signal::{ReadSignal, RwSignal, WriteSignal},
+    effect::RenderEffect,
+    signal::{
+        ArcReadSignal, ArcRwSignal, ArcWriteSignal, ReadSignal, RwSignal,
+        WriteSignal,
+    },
     traits::{Get, Set},
     wrappers::read::Signal,
 };
 use send_wrapper::SendWrapper;
-use wasm_bindgen::JsValue;
+use std::{borrow::Cow, marker::PhantomData};
+use wasm_bindgen::{JsCast, JsValue};
 #[cfg(feature = "reactive_stores")]
 use {
     reactive_graph::owner::Storage,
@@ -323,7 +329,8 @@ where
 
 /// Splits a combined signal into its read and write parts.
 ///
-/// This allows you to either provide a `RwSignal` or a tuple `(ReadSignal, WriteSignal)`.
+/// This allows you to either provide a `RwSignal`, an `ArcRwSignal`, or a tuple
+/// `(ReadSignal, WriteSignal)`.
 pub trait IntoSplitSignal {
     /// The actual contained value of the signal
     type Value;
@@ -349,6 +356,20 @@ where
     }
 }
 
+impl<T> IntoSplitSignal for ArcRwSignal<T>
+where
+    T: Send + Sync + 'static,
+    ArcReadSignal<T>: Get<Value = T>,
+{
+    type Value = T;
+    type Read = ArcReadSignal<T>;
+    type Write = ArcWriteSignal<T>;
+
+    fn into_split_signal(self) -> (ArcReadSignal<T>, ArcWriteSignal<T>) {
+        self.split()
+    }
+}
+
 impl<T, R, W> IntoSplitSignal for (R, W)
 where
     R: Get<Value = T>,
@@ -628,3 +649,283 @@ where
         }
     }
 }
+
+/// `innerText` property used with `bind:innerText` on `contenteditable` elements.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InnerText;
+
+impl AttributeKey for InnerText {
+    const KEY: &'static str = "innerText";
+}
+
+/// `innerHTML` property used with `bind:innerHTML` on `contenteditable` elements.
+///
+/// HTML from the signal is passed through the [`Sanitizer`](crate::html::element::Sanitizer)
+/// before it is rendered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InnerHtml;
+
+impl AttributeKey for InnerHtml {
+    const KEY: &'static str = "innerHTML";
+}
+
+/// A property that holds the content of a `contenteditable` element.
+pub trait ContentKey: AttributeKey {
+    /// Converts the value of the signal into the value of the property.
+    fn to_property(value: &str) -> Cow<'_, str>;
+
+    /// Converts the value of the signal into the HTML that is rendered on the server.
+    fn to_inner_html(value: &str) -> Cow<'_, str>;
+}
+
+impl ContentKey for InnerText {
+    fn to_property(value: &str) -> Cow<'_, str> {
+        Cow::Borrowed(value)
+    }
+
+    fn to_inner_html(value: &str) -> Cow<'_, str> {
+        html_escape::encode_text(value)
+    }
+}
+
+impl ContentKey for InnerHtml {
+    fn to_property(value: &str) -> Cow<'_, str> {
+        sanitize(value)
+    }
+
+    fn to_inner_html(value: &str) -> Cow<'_, str> {
+        sanitize(value)
+    }
+}
+
+/// Adds a two-way binding to the content of a `contenteditable` element.
+pub trait BindContentAttribute<Key, Sig>
+where
+    Key: ContentKey,
+    Sig: IntoSplitSignal<Value = String>,
+{
+    /// The type of the element with the two-way binding added.
+    type Output;
+
+    /// Adds a two-way binding to the content of a `contenteditable` element.
+    ///
+    /// ```ignore
+    /// let text = RwSignal::new("Edit me".to_string());
+    /// div_element.contenteditable("true").bind_content(InnerText, text);
+    /// ```
+    ///
+    /// The signal is updated on the `input` event. While an input method editor is composing
+    /// text, updates are held back until the `compositionend` event. The content is only written
+    /// to the element when it differs from the signal, so the cursor does not move while
+    /// the user is typing.
+    fn bind_content(self, key: Key, signal: Sig) -> Self::Output;
+}
+
+impl<V, Key, Sig> BindContentAttribute<Key, Sig> for V
+where
+    V: AddAnyAttr,
+    Key: ContentKey,
+    Sig: IntoSplitSignal<Value = String>,
+    <Sig as IntoSplitSignal>::Read:
+        Get<Value = String> + Send + Sync + Clone + 'static,
+    <Sig as IntoSplitSignal>::Write: Send + Sync + Clone + 'static,
+{
+    type Output = <Self as AddAnyAttr>::Output<
+        BindContent<
+            Key,
+            <Sig as IntoSplitSignal>::Read,
+            <Sig as IntoSplitSignal>::Write,
+        >,
+    >;
+
+    fn bind_content(self, key: Key, signal: Sig) -> Self::Output {
+        self.add_any_attr(bind_content(key, signal))
+    }
+}
+
+/// Adds a two-way binding to the content of a `contenteditable` element.
+#[inline(always)]
+pub fn bind_content<Key, Sig>(
+    _key: Key,
+    signal: Sig,
+) -> BindContent<
+    Key,
+    <Sig as IntoSplitSignal>::Read,
+    <Sig as IntoSplitSignal>::Write,
+>
+where
+    Key: ContentKey,
+    Sig: IntoSplitSignal<Value = String>,
+{
+    let (read_signal, write_signal) = signal.into_split_signal();
+
+    BindContent {
+        key: PhantomData,
+        read_signal,
+        write_signal,
+    }
+}
+
+/// Two-way binding of the content of a `contenteditable` element.
+#[derive(Debug, Clone)]
+pub struct BindContent<Key, R, W> {
+    key: PhantomData<Key>,
+    read_signal: R,
+    write_signal: W,
+}
+
+impl<Key, R, W> BindContent<Key, R, W>
+where
+    Key: ContentKey,
+    R: Get<Value = String> + Clone + 'static,
+    W: Set<Value = String> + Clone + 'static,
+{
+    fn effect(&self, el: &Element) -> RenderEffect<()> {
+        let el = el.clone();
+        let read_signal = self.read_signal.clone();
+        RenderEffect::new(move |_| {
+            let value = read_signal.get();
+            let value = Key::to_property(&value);
+            let key = JsValue::from_str(Key::KEY);
+            let current = js_sys::Reflect::get(&el, &key)
+                .ok()
+                .and_then(|current| current.as_string());
+            // writing the content would move the cursor to the start
+            if current.as_deref() != Some(&value) {
+                _ = js_sys::Reflect::set(&el, &key, &JsValue::from_str(&value));
+            }
+        })
+    }
+
+    fn attach(&self, el: &Element) -> [RemoveEventHandler<Element>; 2] {
+        let write = {
+            let el = el.clone();
+            let write_signal = self.write_signal.clone();
+            move || {
+                let value =
+                    js_sys::Reflect::get(&el, &JsValue::from_str(Key::KEY))
+                        .ok()
+                        .and_then(|value| value.as_string())
+                        .unwrap_or_default();
+                write_signal.try_set(value);
+            }
+        };
+
+        let on_input = {
+            let write = write.clone();
+            move |ev: web_sys::Event| {
+                let composing = ev
+                    .dyn_ref::<web_sys::InputEvent>()
+                    .is_some_and(|ev| ev.is_composing());
+                if !composing {
+                    write();
+                }
+            }
+        };
+        [
+            on::<_, _>(input, on_input).attach(el),
+            on::<_, _>(compositionend, move |_| write()).attach(el),
+        ]
+    }
+}
+
+impl<Key, R, W> Attribute for BindContent<Key, R, W>
+where
+    Key: ContentKey,
+    R: Get<Value = String> + Clone + Send + Sync + 'static,
+    W: Set<Value = String> + Clone + Send + Sync + 'static,
+{
+    const MIN_LENGTH: usize = 0;
+
+    type State = (
+        RenderEffect<()>,
+        (Element, Option<[RemoveEventHandler<Element>; 2]>),
+    );
+    type AsyncOutput = Self;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(
+        self,
+        _buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        inner_html: &mut String,
+    ) {
+        inner_html.push_str(&Key::to_inner_html(&self.read_signal.get()));
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &Element) -> Self::State {
+        self.build(el)
+    }
+
+    fn build(self, el: &Element) -> Self::State {
+        let effect = self.effect(el);
+        let cleanup = self.attach(el);
+        (effect, (el.clone(), Some(cleanup)))
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let (effect, (el, prev_cleanup)) = state;
+        *effect = self.effect(el);
+
+        if let Some(prev) = prev_cleanup.take() {
+            for remove in prev {
+                if let Some(remove) = remove.into_inner() {
+                    remove();
+                }
+            }
+        }
+        *prev_cleanup = Some(self.attach(el));
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn keys(&self) -> Vec<NamedAttributeKey> {
+        vec![]
+    }
+}
+
+impl<Key, R, W> NextAttribute for BindContent<Key, R, W>
+where
+    Key: ContentKey,
+    R: Get<Value = String> + Clone + Send + Sync + 'static,
+    W: Set<Value = String> + Clone + Send + Sync + 'static,
+{
+    next_attr_output_type!(Self, NewAttr);
+
+    fn add_any_attr<NewAttr: Attribute>(
+        self,
+        new_attr: NewAttr,
+    ) -> Self::Output<NewAttr> {
+        next_attr_combine!(self, new_attr)
+    }
+}
+
+impl<Key, R, W> ToTemplate for BindContent<Key, R, W> {
+    #[inline(always)]
+    fn to_template(
+        _buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        _inner_html: &mut String,
+        _position: &mut Position,
+    ) {
+    }
+}
@@ -0,0 +1,198 @@
+use crate::html::class::IntoClass;
+use reactive_graph::{
+    effect::RenderEffect, traits::Get, wrappers::read::Signal,
+};
+use std::borrow::Cow;
+
+/// Builds a `class` attribute out of several parts, any of which can be toggled reactively.
+///
+/// Class names are added in order, and each name is only included once, so parts can overlap
+/// freely. Usually created with the [`classes!`](crate::classes) macro.
+///
+/// ```rust
+/// # use reactive_graph::{owner::Owner, signal::RwSignal};
+/// # use tachys::{classes, reactive_graph::Classes};
+/// # let owner = Owner::new(); owner.set();
+/// let selected = RwSignal::new(true);
+/// let size = Some("large");
+///
+/// let class: Classes = classes!["button", size, ("selected", selected)];
+/// assert_eq!(class.to_class_string(), "button large selected");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Classes {
+    parts: Vec<ClassPart>,
+}
+
+/// One part of a [`Classes`] list.
+///
+/// This can be created from a string, from an optional string, or from a
+/// `(name, condition)` pair, where the condition is anything that can be converted into a
+/// [`Signal<bool>`].
+#[derive(Clone, Debug)]
+pub enum ClassPart {
+    /// One or more class names, separated by whitespace, that are always included.
+    Static(Cow<'static, str>),
+    /// A class name that is only included while the condition is `true`.
+    Toggle(Cow<'static, str>, Signal<bool>),
+}
+
+impl Classes {
+    /// Creates an empty class list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a part to the class list.
+    pub fn with(mut self, part: impl Into<ClassPart>) -> Self {
+        self.parts.push(part.into());
+        self
+    }
+
+    /// Returns the current value of the `class` attribute.
+    ///
+    /// This tracks any conditions that are read, so the class list updates when called in a
+    /// reactive context.
+    pub fn to_class_string(&self) -> String {
+        let mut names: Vec<&str> = Vec::new();
+        for part in &self.parts {
+            let value = match part {
+                ClassPart::Static(value) => value,
+                ClassPart::Toggle(value, condition) => {
+                    if !condition.get() {
+                        continue;
+                    }
+                    value
+                }
+            };
+            for name in value.split_whitespace() {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names.join(" ")
+    }
+}
+
+impl From<&'static str> for ClassPart {
+    fn from(value: &'static str) -> Self {
+        ClassPart::Static(value.into())
+    }
+}
+
+impl From<String> for ClassPart {
+    fn from(value: String) -> Self {
+        ClassPart::Static(value.into())
+    }
+}
+
+impl From<Cow<'static, str>> for ClassPart {
+    fn from(value: Cow<'static, str>) -> Self {
+        ClassPart::Static(value)
+    }
+}
+
+impl<N, S> From<(N, S)> for ClassPart
+where
+    N: Into<Cow<'static, str>>,
+    S: Into<Signal<bool>>,
+{
+    fn from((name, condition): (N, S)) -> Self {
+        ClassPart::Toggle(name.into(), condition.into())
+    }
+}
+
+impl<T> From<Option<T>> for ClassPart
+where
+    T: Into<ClassPart>,
+{
+    fn from(value: Option<T>) -> Self {
+        // `None` adds no class names
+        value
+            .map(Into::into)
+            .unwrap_or(ClassPart::Static(Cow::Borrowed("")))
+    }
+}
+
+impl IntoClass for Classes {
+    type AsyncOutput = String;
+    type State = RenderEffect<<String as IntoClass>::State>;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(self, class: &mut String) {
+        self.to_class_string().to_html(class);
+    }
+
+    fn should_overwrite(&self) -> bool {
+        true
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        el: &crate::renderer::types::Element,
+    ) -> Self::State {
+        (move || self.to_class_string()).hydrate::<FROM_SERVER>(el)
+    }
+
+    fn build(self, el: &crate::renderer::types::Element) -> Self::State {
+        (move || self.to_class_string()).build(el)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        (move || self.to_class_string()).rebuild(state)
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {
+        self.to_class_string();
+    }
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self.to_class_string()
+    }
+
+    fn reset(state: &mut Self::State) {
+        *state = RenderEffect::new_with_value(
+            move |prev| {
+                if let Some(mut state) = prev {
+                    <String as IntoClass>::reset(&mut state);
+                    state
+                } else {
+                    unreachable!()
+                }
+            },
+            state.take_value(),
+        );
+    }
+}
+
+/// Creates a [`Classes`](crate::reactive_graph::Classes) list from static class names,
+/// optional class names, and `(name, condition)` pairs.
+///
+/// ```rust,ignore
+/// view! {
+///     <button class=classes!["button", variant, ("active", is_active)]>
+///         "Click"
+///     </button>
+/// }
+/// ```
+#[macro_export]
+macro_rules! classes {
+    ($($part:expr),* $(,)?) => {
+        $crate::reactive_graph::Classes::new()
+            $(.with($part))*
+    };
+}
@@ -0,0 +1,297 @@
+use super::bind::FromEventTarget;
+use crate::{
+    html::{
+        attribute::{
+            maybe_next_attr_erasure_macros::{
+                next_attr_combine, next_attr_output_type,
+            },
+            Attribute, AttributeKey, AttributeValue, NamedAttributeKey,
+            NextAttribute,
+        },
+        event::{change, input, on},
+    },
+    prelude::AddAnyAttr,
+    renderer::{types::Element, RemoveEventHandler},
+    view::{Position, ToTemplate},
+};
+use reactive_graph::{
+    effect::RenderEffect,
+    traits::{Get, GetUntracked},
+};
+use std::{fmt::Debug, sync::Arc};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Adds a controlled `value` or `checked` property to an element.
+pub trait ControlledAttribute<Key, R, T>
+where
+    Key: AttributeKey,
+    R: Get<Value = T> + GetUntracked<Value = T>,
+{
+    /// The type of the element with the controlled property added.
+    type Output;
+
+    /// Adds a controlled property to the element. See [`controlled`] for details.
+    fn controlled(
+        self,
+        key: Key,
+        value: R,
+        on_change: impl Fn(T) + Send + Sync + 'static,
+    ) -> Self::Output;
+}
+
+impl<V, Key, R, T> ControlledAttribute<Key, R, T> for V
+where
+    V: AddAnyAttr,
+    Key: AttributeKey,
+    R: Get<Value = T> + GetUntracked<Value = T> + Clone + Send + Sync + 'static,
+    T: FromEventTarget + AttributeValue + Into<JsValue> + 'static,
+{
+    type Output = <Self as AddAnyAttr>::Output<Controlled<Key, T, R>>;
+
+    fn controlled(
+        self,
+        key: Key,
+        value: R,
+        on_change: impl Fn(T) + Send + Sync + 'static,
+    ) -> Self::Output {
+        self.add_any_attr(controlled(key, value, on_change))
+    }
+}
+
+/// Controls the `value` or `checked` property of an input with a signal.
+///
+/// Unlike a two-way binding, the property is always driven by the signal. Edits made by the
+/// user are passed to `on_change`; if `on_change` does not update the signal to match, the edit
+/// is reverted. The property is only written when it differs from the signal, and the cursor
+/// is kept in place, so typing into a controlled input does not make the cursor jump.
+///
+/// ```rust,ignore
+/// let (name, set_name) = signal(String::new());
+///
+/// view! {
+///     // only allows lowercase letters
+///     <input {..controlled(Value, name, move |value: String| {
+///         set_name.set(value.to_lowercase().replace(|c: char| !c.is_ascii_lowercase(), ""));
+///     })}/>
+/// }
+/// ```
+///
+/// `<input type="checkbox">`, `<input type="radio">` and `<select>` listen to the `change` event,
+/// and other elements listen to the `input` event.
+pub fn controlled<Key, R, T>(
+    key: Key,
+    value: R,
+    on_change: impl Fn(T) + Send + Sync + 'static,
+) -> Controlled<Key, T, R>
+where
+    Key: AttributeKey,
+    R: Get<Value = T> + GetUntracked<Value = T>,
+{
+    Controlled {
+        key,
+        value,
+        on_change: Arc::new(on_change),
+    }
+}
+
+/// A property that is controlled by a signal, created with [`controlled`].
+pub struct Controlled<Key, T, R> {
+    key: Key,
+    value: R,
+    on_change: Arc<dyn Fn(T) + Send + Sync>,
+}
+
+impl<Key, T, R> Clone for Controlled<Key, T, R>
+where
+    Key: Clone,
+    R: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            value: self.value.clone(),
+            on_change: Arc::clone(&self.on_change),
+        }
+    }
+}
+
+impl<Key, T, R> Debug for Controlled<Key, T, R>
+where
+    Key: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Controlled")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Key, T, R> Controlled<Key, T, R>
+where
+    Key: AttributeKey,
+    R: Get<Value = T> + GetUntracked<Value = T> + Clone + 'static,
+    T: FromEventTarget + Into<JsValue> + 'static,
+{
+    fn effect(&self, el: &Element) -> RenderEffect<()> {
+        let el = el.clone();
+        let value = self.value.clone();
+        RenderEffect::new(move |_| {
+            sync_property(&el, Key::KEY, value.get().into());
+        })
+    }
+
+    fn attach(&self, el: &Element) -> RemoveEventHandler<Element> {
+        let target = el.clone();
+        let value = self.value.clone();
+        let on_change = Arc::clone(&self.on_change);
+        let handler = move |ev| {
+            on_change(T::from_event_target(&ev));
+            // reverts any edit that the handler did not accept
+            sync_property(&target, Key::KEY, value.get_untracked().into());
+        };
+
+        if Key::KEY == "checked" || el.tag_name() == "SELECT" {
+            on::<_, _>(change, handler).attach(el)
+        } else {
+            on::<_, _>(input, handler).attach(el)
+        }
+    }
+}
+
+/// Sets a property if it is different from its current value, keeping the text selection in
+/// place.
+fn sync_property(el: &Element, key: &str, value: JsValue) {
+    let key = JsValue::from_str(key);
+    if js_sys::Reflect::get(el, &key).ok().as_ref() == Some(&value) {
+        return;
+    }
+
+    let selection = selection_range(el);
+    _ = js_sys::Reflect::set(el, &key, &value);
+    if let Some((start, end)) = selection {
+        if let Some(input_el) = el.dyn_ref::<web_sys::HtmlInputElement>() {
+            _ = input_el.set_selection_range(start, end);
+        } else if let Some(textarea) =
+            el.dyn_ref::<web_sys::HtmlTextAreaElement>()
+        {
+            _ = textarea.set_selection_range(start, end);
+        }
+    }
+}
+
+fn selection_range(el: &Element) -> Option<(u32, u32)> {
+    if let Some(input_el) = el.dyn_ref::<web_sys::HtmlInputElement>() {
+        Some((
+            input_el.selection_start().ok()??,
+            input_el.selection_end().ok()??,
+        ))
+    } else if let Some(textarea) = el.dyn_ref::<web_sys::HtmlTextAreaElement>()
+    {
+        Some((
+            textarea.selection_start().ok()??,
+            textarea.selection_end().ok()??,
+        ))
+    } else {
+        None
+    }
+}
+
+impl<Key, T, R> Attribute for Controlled<Key, T, R>
+where
+    Key: AttributeKey,
+    R: Get<Value = T> + GetUntracked<Value = T> + Clone + Send + Sync + 'static,
+    T: FromEventTarget + AttributeValue + Into<JsValue> + 'static,
+{
+    const MIN_LENGTH: usize = 0;
+
+    type State = (
+        RenderEffect<()>,
+        (Element, Option<RemoveEventHandler<Element>>),
+    );
+    type AsyncOutput = Self;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(
+        self,
+        buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        _inner_html: &mut String,
+    ) {
+        self.value.get().to_html(Key::KEY, buf);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &Element) -> Self::State {
+        // the effect only writes the property if it differs from the server-rendered value
+        self.build(el)
+    }
+
+    fn build(self, el: &Element) -> Self::State {
+        let effect = self.effect(el);
+        let cleanup = self.attach(el);
+        (effect, (el.clone(), Some(cleanup)))
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let (effect, (el, prev_cleanup)) = state;
+        *effect = self.effect(el);
+
+        if let Some(prev) = prev_cleanup.take() {
+            if let Some(remove) = prev.into_inner() {
+                remove();
+            }
+        }
+        *prev_cleanup = Some(self.attach(el));
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn keys(&self) -> Vec<NamedAttributeKey> {
+        vec![]
+    }
+}
+
+impl<Key, T, R> NextAttribute for Controlled<Key, T, R>
+where
+    Key: AttributeKey,
+    R: Get<Value = T> + GetUntracked<Value = T> + Clone + Send + Sync + 'static,
+    T: FromEventTarget + AttributeValue + Into<JsValue> + 'static,
+{
+    next_attr_output_type!(Self, NewAttr);
+
+    fn add_any_attr<NewAttr: Attribute>(
+        self,
+        new_attr: NewAttr,
+    ) -> Self::Output<NewAttr> {
+        next_attr_combine!(self, new_attr)
+    }
+}
+
+impl<Key, T, R> ToTemplate for Controlled<Key, T, R> {
+    #[inline(always)]
+    fn to_template(
+        _buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        _inner_html: &mut String,
+        _position: &mut Position,
+    ) {
+    }
+}
@@ -18,6 +18,9 @@ use std::{
 /// Types for two way data binding.
 pub mod bind;
 mod class;
+mod classes;
+/// Inputs whose value is always driven by a signal.
+pub mod controlled;
 mod inner_html;
 /// Provides a reactive [`NodeRef`](node_ref::NodeRef) type.
 pub mod node_ref;
@@ -26,6 +29,7 @@ mod property;
 mod style;
 mod suspense;
 
+pub use classes::*;
 pub use owned::*;
 pub use suspense::*;
 
@@ -7,13 +7,13 @@ use reactive_graph::{
         RwSignal,
     },
     traits::{
-        DefinedAt, Get, IsDisposed, Notify, ReadUntracked, Set, Track,
-        UntrackableGuard, Write,
+        DefinedAt, Get, GetUntracked, IsDisposed, Notify, ReadUntracked, Set,
+        Track, UntrackableGuard, Write,
     },
 };
 use send_wrapper::SendWrapper;
 use std::{cell::Cell, ops::DerefMut};
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
 
 /// A reactive reference to a DOM node that can be used with the `node_ref` attribute.
 #[derive(Debug)]
@@ -56,6 +56,95 @@ where
     }
 }
 
+impl<E> NodeRef<E>
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    /// Dispatches a [`CustomEvent`](web_sys::CustomEvent) named `name` from the element,
+    /// with `detail` as its payload. The event bubbles, and crosses shadow DOM boundaries.
+    ///
+    /// Returns `false` if the element has not been mounted yet, or if a handler called
+    /// `preventDefault()` on the event.
+    pub fn dispatch(&self, name: &str, detail: impl Into<JsValue>) -> bool {
+        let Some(el) = self.get_untracked() else {
+            return false;
+        };
+        let init = web_sys::CustomEventInit::new();
+        init.set_bubbles(true);
+        init.set_composed(true);
+        init.set_detail(&detail.into());
+        let Ok(event) =
+            web_sys::CustomEvent::new_with_event_init_dict(name, &init)
+        else {
+            return false;
+        };
+        el.unchecked_ref::<web_sys::EventTarget>()
+            .dispatch_event(&event)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the element's `data-{key}` attribute, or `None` if the element has
+    /// not been mounted yet or does not have the attribute.
+    ///
+    /// This tracks the node reference, so it will run again once the element is mounted when
+    /// called in a reactive context.
+    pub fn data(&self, key: &str) -> Option<String> {
+        self.get()?
+            .unchecked_ref::<web_sys::Element>()
+            .get_attribute(&format!("data-{key}"))
+    }
+
+    /// Shows the element as a popover, using
+    /// [`showPopover()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/showPopover).
+    ///
+    /// Returns `false` if the element has not been mounted yet, or could not be shown, for
+    /// example because it does not have a `popover` attribute.
+    pub fn show_popover(&self) -> bool {
+        self.call_popover_method("showPopover").is_some()
+    }
+
+    /// Hides the element if it is shown as a popover, using
+    /// [`hidePopover()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/hidePopover).
+    ///
+    /// Returns `false` if the element has not been mounted yet, or could not be hidden.
+    pub fn hide_popover(&self) -> bool {
+        self.call_popover_method("hidePopover").is_some()
+    }
+
+    /// Toggles the element between showing and hiding as a popover, using
+    /// [`togglePopover()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/togglePopover).
+    ///
+    /// Returns whether the popover is now open, or `None` if the element has not been mounted
+    /// yet or could not be toggled.
+    pub fn toggle_popover(&self) -> Option<bool> {
+        self.call_popover_method("togglePopover")?.as_bool()
+    }
+
+    /// Returns `true` if the element is currently shown as a popover.
+    ///
+    /// This tracks the node reference, but not the state of the popover, which can be followed
+    /// with the `toggle` event.
+    pub fn is_popover_open(&self) -> bool {
+        self.get().is_some_and(|el| {
+            el.unchecked_ref::<web_sys::Element>()
+                .matches(":popover-open")
+                .unwrap_or(false)
+        })
+    }
+
+    fn call_popover_method(&self, name: &str) -> Option<JsValue> {
+        let el = self.get_untracked()?;
+        let el = el.unchecked_ref::<JsValue>();
+        js_sys::Reflect::get(el, &JsValue::from_str(name))
+            .ok()?
+            .dyn_into::<js_sys::Function>()
+            .ok()?
+            .call0(el)
+            .ok()
+    }
+}
+
 impl<E> Default for NodeRef<E>
 where
     E: ElementType,
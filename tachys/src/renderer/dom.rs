@@ -8,7 +8,7 @@ use crate::{
     ok_or_debug, or_debug,
     view::{Mountable, ToTemplate},
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
     any::TypeId,
     borrow::Cow,
@@ -23,9 +23,13 @@ pub struct Dom;
 
 thread_local! {
     pub(crate) static GLOBAL_EVENTS: RefCell<FxHashSet<Cow<'static, str>>> = Default::default();
+    pub(crate) static DELEGATION_ROOT: RefCell<Option<DelegationRoot>> = Default::default();
     pub static TEMPLATE_CACHE: RefCell<Vec<(Cow<'static, str>, web_sys::Element)>> = Default::default();
 }
 
+/// The root element that delegated events are attached to, with the listener for each event.
+type DelegationRoot = (Element, FxHashMap<Cow<'static, str>, JsValue>);
+
 pub type Node = web_sys::Node;
 pub type Text = web_sys::Text;
 pub type Element = web_sys::Element;
@@ -75,6 +79,96 @@ fn queue(fun: Box<dyn FnOnce()>) {
     }
 }
 
+/// Set on an event by the listener on a delegation root, once it has called the handlers inside
+/// the root, so that the global listener only calls the handlers outside of it.
+const HANDLED_UP_TO: &str = "$$leptosHandledUpTo";
+
+/// Creates the listener used for delegated events, which walks up the tree from the event's
+/// target and calls the handler stored at `key` on each node, stopping at `root` if one is given.
+fn delegated_handler(key: &str, root: Option<Element>) -> JsValue {
+    let key = JsValue::from_str(key);
+    let root = root.map(JsValue::from);
+    let handled_up_to = JsValue::from_str(HANDLED_UP_TO);
+    let handler = move |ev: web_sys::Event| {
+        let handled = js_sys::Reflect::get(&ev, &handled_up_to)
+            .unwrap_or(JsValue::UNDEFINED);
+        let mut node = if root.is_none() && !handled.is_undefined() {
+            // the handlers up to the delegation root have already been called
+            next_node(&handled)
+        } else {
+            let target = ev.target();
+            let node = ev.composed_path().get(0);
+            if node.is_undefined() || node.is_null() {
+                JsValue::from(target)
+            } else {
+                node
+            }
+        };
+
+        // TODO reverse Shadow DOM retargetting
+        // TODO simulate currentTarget
+
+        while !node.is_null() {
+            let node_is_disabled =
+                js_sys::Reflect::get(&node, &JsValue::from_str("disabled"))
+                    .unwrap()
+                    .is_truthy();
+            if !node_is_disabled {
+                let maybe_handler = js_sys::Reflect::get(&node, &key).unwrap();
+                if !maybe_handler.is_undefined() {
+                    let f = maybe_handler.unchecked_ref::<js_sys::Function>();
+                    let _ = f.call1(&node, &ev);
+
+                    if ev.cancel_bubble() {
+                        return;
+                    }
+                }
+            }
+
+            // handlers outside the root are not delegated to it
+            if root.as_ref() == Some(&node) {
+                _ = js_sys::Reflect::set(&ev, &handled_up_to, &node);
+                return;
+            }
+
+            node = next_node(&node);
+        }
+    };
+
+    let handler = Box::new(handler) as Box<dyn FnMut(web_sys::Event)>;
+    Closure::wrap(handler).into_js_value()
+}
+
+/// The node that a delegated event bubbles to after `node`, or `null` at the top of the tree.
+fn next_node(node: &JsValue) -> JsValue {
+    if let Some(parent) = node.unchecked_ref::<web_sys::Node>().parent_node() {
+        parent.into()
+    } else if let Some(root) = node.dyn_ref::<web_sys::ShadowRoot>() {
+        root.host().unchecked_into()
+    } else {
+        JsValue::null()
+    }
+}
+
+/// Adds the global listener for a delegated event, unless it has already been added.
+fn add_global_listener(name: Cow<'static, str>, key: &str) {
+    GLOBAL_EVENTS.with_borrow_mut(|events| {
+        if !events.contains(&name) {
+            // create global handler
+            let handler = delegated_handler(key, None);
+            window()
+                .add_event_listener_with_callback(
+                    &name,
+                    handler.unchecked_ref(),
+                )
+                .unwrap();
+
+            // register that we've created handler
+            events.insert(name);
+        }
+    });
+}
+
 impl Dom {
     pub fn intern(text: &str) -> &str {
         intern(text)
@@ -352,6 +446,50 @@ impl Dom {
         })
     }
 
+    pub fn add_event_listener_with_options(
+        el: &Element,
+        name: &str,
+        cb: Box<dyn FnMut(Event)>,
+        options: &AddEventListenerOptions,
+    ) -> RemoveEventHandler<Element> {
+        let cb = wasm_bindgen::closure::Closure::wrap(cb);
+        let name = intern(name);
+        or_debug!(
+            el.add_event_listener_with_callback_and_add_event_listener_options(
+                name,
+                cb.as_ref().unchecked_ref(),
+                options
+            ),
+            el,
+            "addEventListenerWithOptions"
+        );
+        // the listener can only be removed using the same value for `capture`
+        let capture =
+            js_sys::Reflect::get(options, &JsValue::from_str("capture"))
+                .map(|capture| capture.is_truthy())
+                .unwrap_or(false);
+
+        // return the remover
+        RemoveEventHandler::new({
+            let name = name.to_owned();
+            let el = el.clone();
+            // safe to construct this here, because it will only run in the browser
+            // so it will always be accessed or dropped from the main thread
+            let cb = send_wrapper::SendWrapper::new(move || {
+                or_debug!(
+                    el.remove_event_listener_with_callback_and_bool(
+                        intern(&name),
+                        cb.as_ref().unchecked_ref(),
+                        capture
+                    ),
+                    &el,
+                    "removeEventListener"
+                )
+            });
+            move || cb()
+        })
+    }
+
     pub fn event_target<T>(ev: &Event) -> T
     where
         T: CastFrom<Element>,
@@ -378,73 +516,33 @@ impl Dom {
             "set property"
         );
 
-        GLOBAL_EVENTS.with_borrow_mut(|events| {
-            if !events.contains(&name) {
-                // create global handler
-                let key = JsValue::from_str(key);
-                let handler = move |ev: web_sys::Event| {
-                    let target = ev.target();
-                    let node = ev.composed_path().get(0);
-                    let mut node = if node.is_undefined() || node.is_null() {
-                        JsValue::from(target)
-                    } else {
-                        node
-                    };
-
-                    // TODO reverse Shadow DOM retargetting
-                    // TODO simulate currentTarget
-
-                    while !node.is_null() {
-                        let node_is_disabled = js_sys::Reflect::get(
-                            &node,
-                            &JsValue::from_str("disabled"),
-                        )
-                        .unwrap()
-                        .is_truthy();
-                        if !node_is_disabled {
-                            let maybe_handler =
-                                js_sys::Reflect::get(&node, &key).unwrap();
-                            if !maybe_handler.is_undefined() {
-                                let f = maybe_handler
-                                    .unchecked_ref::<js_sys::Function>();
-                                let _ = f.call1(&node, &ev);
-
-                                if ev.cancel_bubble() {
-                                    return;
-                                }
-                            }
-                        }
-
-                        // navigate up tree
-                        if let Some(parent) =
-                            node.unchecked_ref::<web_sys::Node>().parent_node()
-                        {
-                            node = parent.into()
-                        } else if let Some(root) =
-                            node.dyn_ref::<web_sys::ShadowRoot>()
-                        {
-                            node = root.host().unchecked_into();
-                        } else {
-                            node = JsValue::null()
-                        }
-                    }
-                };
-
-                let handler =
-                    Box::new(handler) as Box<dyn FnMut(web_sys::Event)>;
-                let handler = Closure::wrap(handler).into_js_value();
-                window()
-                    .add_event_listener_with_callback(
-                        &name,
-                        handler.unchecked_ref(),
-                    )
-                    .unwrap();
+        let has_root = DELEGATION_ROOT.with_borrow_mut(|root| {
+            if let Some((root, events)) = root {
+                if !events.contains_key(&name) {
+                    // create handler on the delegation root
+                    let handler = delegated_handler(key, Some(root.clone()));
+                    or_debug!(
+                        root.add_event_listener_with_callback(
+                            &name,
+                            handler.unchecked_ref(),
+                        ),
+                        root,
+                        "addEventListener"
+                    );
 
-                // register that we've created handler
-                events.insert(name);
+                    // register that we've created handler
+                    events.insert(name.clone(), handler);
+                }
+                true
+            } else {
+                false
             }
         });
 
+        if !has_root {
+            add_global_listener(name, key);
+        }
+
         // return the remover
         RemoveEventHandler::new({
             let key = key.to_owned();
@@ -467,6 +565,48 @@ impl Dom {
         })
     }
 
+    /// Sets the element that handlers for bubbling events will be delegated to.
+    ///
+    /// While a root is set, each bubbling event registers a single listener on the root, which
+    /// walks up from the event's target and calls the handler stored on each node. This applies
+    /// even if the `delegation` feature is not enabled, and only to event listeners that are
+    /// added after this is called.
+    ///
+    /// When the root changes, the listeners on the previous root are removed. Handlers that were
+    /// delegated to it are handled by a listener on the `window` instead, so elements that are
+    /// still mounted keep working.
+    pub fn set_delegation_root(root: Option<&Element>) {
+        let prev = DELEGATION_ROOT.with_borrow_mut(|prev| {
+            if prev.as_ref().map(|(prev, _)| prev) == root {
+                return None;
+            }
+            std::mem::replace(
+                prev,
+                root.map(|root| (root.clone(), FxHashMap::default())),
+            )
+        });
+
+        if let Some((prev, events)) = prev {
+            for (name, handler) in events {
+                or_debug!(
+                    prev.remove_event_listener_with_callback(
+                        &name,
+                        handler.unchecked_ref(),
+                    ),
+                    &prev,
+                    "removeEventListener"
+                );
+                let key = format!("$$${name}");
+                add_global_listener(name, intern(&key));
+            }
+        }
+    }
+
+    /// Whether event handlers are currently being delegated to a root element.
+    pub fn has_delegation_root() -> bool {
+        DELEGATION_ROOT.with_borrow(Option::is_some)
+    }
+
     pub fn class_list(el: &Element) -> ClassList {
         el.class_list()
     }
@@ -569,6 +709,29 @@ impl Dom {
         tpl.first_element_child().unwrap_or(tpl)
     }
 
+    pub fn create_nodes_from_html(html: Cow<'static, str>) -> Vec<Node> {
+        let fragment = TEMPLATE_CACHE.with_borrow_mut(|cache| {
+            if let Some(tpl_content) = cache.iter().find_map(|(key, tpl)| {
+                (html == *key)
+                    .then_some(Self::clone_template(tpl.unchecked_ref()))
+            }) {
+                tpl_content
+            } else {
+                let tpl = document()
+                    .create_element(Self::intern("template"))
+                    .unwrap();
+                tpl.set_inner_html(&html);
+                let tpl_content = Self::clone_template(tpl.unchecked_ref());
+                cache.push((html, tpl));
+                tpl_content
+            }
+        });
+        let children = fragment.child_nodes();
+        (0..children.length())
+            .filter_map(|idx| children.item(idx))
+            .collect()
+    }
+
     pub fn create_svg_element_from_html(html: Cow<'static, str>) -> Element {
         let tpl = TEMPLATE_CACHE.with_borrow_mut(|cache| {
             if let Some(tpl_content) = cache.iter().find_map(|(key, tpl)| {
@@ -21,6 +21,8 @@ pub struct StreamBuilder {
     pending: Option<ChunkFuture>,
     pending_ooo: VecDeque<PinnedFuture<OooChunk>>,
     id: Option<Vec<u16>>,
+    on_shell_complete: Option<Box<dyn FnOnce() -> String + Send>>,
+    shell_complete: bool,
 }
 
 type PinnedFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
@@ -90,6 +92,18 @@ impl StreamBuilder {
         self.sync_buf.push_str(&other.sync_buf);
     }
 
+    /// Sets a function that is called once the in-order part of the stream, its “shell,” has
+    /// been rendered, while any out-of-order chunks may still be pending.
+    ///
+    /// The HTML it returns is inserted right after the shell. This can be used to send data
+    /// that is already available, without waiting for the rest of the stream.
+    pub fn on_shell_complete(
+        &mut self,
+        fun: impl FnOnce() -> String + Send + 'static,
+    ) {
+        self.on_shell_complete = Some(Box::new(fun));
+    }
+
     /// Completes the stream.
     pub fn finish(mut self) -> Self {
         let sync_buf_remaining = mem::take(&mut self.sync_buf);
@@ -378,6 +392,16 @@ impl Stream for StreamBuilder {
             let next_chunk = this.chunks.pop_front();
             match next_chunk {
                 None => {
+                    // every in-order chunk has been rendered, so the shell is complete
+                    if !this.shell_complete {
+                        this.shell_complete = true;
+                        if let Some(on_shell_complete) =
+                            this.on_shell_complete.take()
+                        {
+                            this.sync_buf.push_str(&on_shell_complete());
+                        }
+                    }
+
                     if this.pending_ooo.is_empty() {
                         if this.sync_buf.is_empty() {
                             Poll::Ready(None)
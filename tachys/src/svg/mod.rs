@@ -1,6 +1,9 @@
 use crate::{
     html::{
-        attribute::{any_attribute::AnyAttribute, Attribute},
+        attribute::{
+            any_attribute::AnyAttribute, Attr, Attribute, AttributeValue,
+            NextAttribute,
+        },
         element::{ElementType, ElementWithChildren, HtmlElement},
     },
     hydration::Cursor,
@@ -13,8 +16,120 @@ use crate::{
 };
 use std::{borrow::Cow, fmt::Debug};
 
+/// Implements [`AttributeValue`] for a builder that wraps the `String` it builds.
+macro_rules! string_attribute_value {
+    ($ty:ty) => {
+        impl $crate::html::attribute::AttributeValue for $ty {
+            type AsyncOutput = Self;
+            type State =
+                <String as $crate::html::attribute::AttributeValue>::State;
+            type Cloneable = std::sync::Arc<str>;
+            type CloneableOwned = std::sync::Arc<str>;
+
+            fn html_len(&self) -> usize {
+                self.0.len()
+            }
+
+            fn to_html(self, key: &str, buf: &mut String) {
+                <String as $crate::html::attribute::AttributeValue>::to_html(
+                    self.0, key, buf,
+                );
+            }
+
+            fn to_template(_key: &str, _buf: &mut String) {}
+
+            fn hydrate<const FROM_SERVER: bool>(
+                self,
+                key: &str,
+                el: &$crate::renderer::types::Element,
+            ) -> Self::State {
+                <String as $crate::html::attribute::AttributeValue>::hydrate::<
+                    FROM_SERVER,
+                >(self.0, key, el)
+            }
+
+            fn build(
+                self,
+                el: &$crate::renderer::types::Element,
+                key: &str,
+            ) -> Self::State {
+                <String as $crate::html::attribute::AttributeValue>::build(
+                    self.0, el, key,
+                )
+            }
+
+            fn rebuild(self, key: &str, state: &mut Self::State) {
+                <String as $crate::html::attribute::AttributeValue>::rebuild(
+                    self.0, key, state,
+                );
+            }
+
+            fn into_cloneable(self) -> Self::Cloneable {
+                self.0.into()
+            }
+
+            fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                self.0.into()
+            }
+
+            fn dry_resolve(&mut self) {}
+
+            async fn resolve(self) -> Self::AsyncOutput {
+                self
+            }
+        }
+    };
+}
+
+mod path_data;
+mod transform;
+pub use path_data::*;
+pub use transform::*;
+
+macro_rules! svg_key {
+    (d) => { $crate::html::attribute::SvgD };
+    (r) => { $crate::html::attribute::SvgR };
+    (x) => { $crate::html::attribute::SvgX };
+    (y) => { $crate::html::attribute::SvgY };
+    (z) => { $crate::html::attribute::SvgZ };
+    ($attr:ident) => { paste::paste! { $crate::html::attribute::[<$attr:camel>] } };
+}
+
+macro_rules! svg_global {
+	($tag:ident, $attr:ident) => {
+		paste::paste! {
+            /// An SVG attribute.
+			pub fn $attr<V>(self, value: V) -> HtmlElement <
+				[<$tag:camel>],
+				<At as NextAttribute>::Output<Attr<svg_key!($attr), V>>,
+				Ch
+			>
+			where
+				V: AttributeValue,
+				At: NextAttribute,
+				<At as NextAttribute>::Output<Attr<svg_key!($attr), V>>: Attribute,
+			{
+				let HtmlElement {
+                    #[cfg(any(debug_assertions, leptos_debuginfo))]
+                    defined_at,
+                    tag,
+                    children,
+                    attributes
+                } = self;
+				HtmlElement {
+                    #[cfg(any(debug_assertions, leptos_debuginfo))]
+                    defined_at,
+					tag,
+					children,
+					attributes: attributes.add_any_attr($crate::html::attribute::$attr(value)),
+				}
+			}
+		}
+	}
+}
+
 macro_rules! svg_elements {
-	($($tag:ident  [$($attr:ty),*]),* $(,)?) => {
+	($($tag:ident  [$($attr:ident),*]),* $(,)?) => {
         paste::paste! {
             $(
                 /// An SVG element.
@@ -43,30 +158,24 @@ macro_rules! svg_elements {
 					Ch: Render,
 
 				{
+					svg_global!($tag, clip_path);
+					svg_global!($tag, fill);
+					svg_global!($tag, fill_opacity);
+					svg_global!($tag, fill_rule);
+					svg_global!($tag, filter);
+					svg_global!($tag, mask);
+					svg_global!($tag, opacity);
+					svg_global!($tag, stroke);
+					svg_global!($tag, stroke_dasharray);
+					svg_global!($tag, stroke_dashoffset);
+					svg_global!($tag, stroke_linecap);
+					svg_global!($tag, stroke_linejoin);
+					svg_global!($tag, stroke_opacity);
+					svg_global!($tag, stroke_width);
+					svg_global!($tag, transform);
+
 					$(
-                        pub fn $attr<V>(self, value: V) -> HtmlElement <
-                            [<$tag:camel>],
-                            <At as $crate::html::attribute::NextAttribute<Attr<$crate::html::attribute::[<$attr:camel>], V>>>::Output,
-                            Ch
-                        >
-                        where
-                            V: AttributeValue,
-                            At: $crate::html::attribute::NextAttribute<Attr<$crate::html::attribute::[<$attr:camel>], V>>,
-                            <At as $crate::html::attribute::NextAttribute<Attr<$crate::html::attribute::[<$attr:camel>], V>>>::Output: Attribute,
-                        {
-                            let HtmlElement { tag, children, attributes,
-                                #[cfg(any(debug_assertions, leptos_debuginfo))]
-                                defined_at
-                            } = self;
-                            HtmlElement {
-                                tag,
-
-                                children,
-                                attributes: attributes.add_any_attr($crate::html::attribute::$attr(value)),
-                                #[cfg(any(debug_assertions, leptos_debuginfo))]
-                                defined_at
-                            }
-                        }
+                        svg_global!($tag, $attr);
 					)*
 				}
 
@@ -91,71 +200,71 @@ macro_rules! svg_elements {
 }
 
 svg_elements![
-  a [],
+  a [href, target],
   animate [],
   animateMotion [],
   animateTransform [],
-  circle [],
-  clipPath [],
+  circle [cx, cy, r, path_length],
+  clipPath [clip_path_units],
   defs [],
   desc [],
   discard [],
-  ellipse [],
-  feBlend [],
-  feColorMatrix [],
-  feComponentTransfer [],
-  feComposite [],
-  feConvolveMatrix [],
-  feDiffuseLighting [],
-  feDisplacementMap [],
-  feDistantLight [],
-  feDropShadow [],
-  feFlood [],
-  feFuncA [],
-  feFuncB [],
-  feFuncG [],
-  feFuncR [],
-  feGaussianBlur [],
-  feImage [],
-  feMerge [],
-  feMergeNode [],
-  feMorphology [],
-  feOffset [],
-  fePointLight [],
-  feSpecularLighting [],
-  feSpotLight [],
-  feTile [],
-  feTurbulence [],
-  filter [],
-  foreignObject [],
+  ellipse [cx, cy, rx, ry, path_length],
+  feBlend [r#in, in2, mode, x, y, width, height],
+  feColorMatrix [r#in, r#type, values, x, y, width, height],
+  feComponentTransfer [r#in, x, y, width, height],
+  feComposite [r#in, in2, operator, k1, k2, k3, k4, x, y, width, height],
+  feConvolveMatrix [r#in, order, kernel_matrix, divisor, bias, target_x, target_y, edge_mode, kernel_unit_length, preserve_alpha, x, y, width, height],
+  feDiffuseLighting [r#in, surface_scale, diffuse_constant, kernel_unit_length, lighting_color, x, y, width, height],
+  feDisplacementMap [r#in, in2, scale, x_channel_selector, y_channel_selector, x, y, width, height],
+  feDistantLight [azimuth, elevation],
+  feDropShadow [r#in, dx, dy, std_deviation, flood_color, flood_opacity, x, y, width, height],
+  feFlood [flood_color, flood_opacity, x, y, width, height],
+  feFuncA [r#type, table_values, slope, intercept, amplitude, exponent, offset],
+  feFuncB [r#type, table_values, slope, intercept, amplitude, exponent, offset],
+  feFuncG [r#type, table_values, slope, intercept, amplitude, exponent, offset],
+  feFuncR [r#type, table_values, slope, intercept, amplitude, exponent, offset],
+  feGaussianBlur [r#in, std_deviation, edge_mode, x, y, width, height],
+  feImage [href, preserve_aspect_ratio, x, y, width, height],
+  feMerge [x, y, width, height],
+  feMergeNode [r#in],
+  feMorphology [r#in, operator, radius, x, y, width, height],
+  feOffset [r#in, dx, dy, x, y, width, height],
+  fePointLight [x, y, z],
+  feSpecularLighting [r#in, surface_scale, specular_constant, specular_exponent, kernel_unit_length, lighting_color, x, y, width, height],
+  feSpotLight [x, y, z, points_at_x, points_at_y, points_at_z, specular_exponent, limiting_cone_angle],
+  feTile [r#in, x, y, width, height],
+  feTurbulence [base_frequency, num_octaves, seed, stitch_tiles, r#type, x, y, width, height],
+  filter [filter_units, primitive_units, x, y, width, height],
+  foreignObject [x, y, width, height],
   g [],
   hatch [],
   hatchpath [],
-  image [],
-  line [],
-  linearGradient [],
-  marker [],
-  mask [],
+  image [href, preserve_aspect_ratio, x, y, width, height],
+  line [x1, y1, x2, y2, path_length],
+  linearGradient [gradient_units, gradient_transform, spread_method, href, x1, y1, x2, y2],
+  marker [marker_units, marker_width, marker_height, orient, preserve_aspect_ratio, ref_x, ref_y, view_box],
+  mask [mask_units, mask_content_units, mask_type, x, y, width, height],
   metadata [],
   mpath [],
-  path [],
-  pattern [],
-  polygon [],
-  polyline [],
-  radialGradient [],
-  rect [],
+  path [d, path_length],
+  pattern [pattern_units, pattern_content_units, pattern_transform, href, preserve_aspect_ratio, view_box, x, y, width, height],
+  polygon [points, path_length],
+  polyline [points, path_length],
+  radialGradient [gradient_units, gradient_transform, spread_method, href, cx, cy, r, fx, fy, fr],
+  rect [x, y, width, height, rx, ry, path_length],
   script [],
   set [],
-  stop [],
+  stop [offset, stop_color, stop_opacity],
   style [],
-  svg [],
+  svg [view_box, preserve_aspect_ratio, x, y, width, height],
   switch [],
-  symbol [],
-  text [],
-  textPath [],
+  symbol [view_box, preserve_aspect_ratio, ref_x, ref_y, x, y, width, height],
+  text [x, y, dx, dy],
+  textPath [href, path_length, start_offset],
   title [],
-  tspan [],
-  view [],
+  tspan [x, y, dx, dy],
+  view [view_box, preserve_aspect_ratio],
 ];
 
 /// An SVG element.
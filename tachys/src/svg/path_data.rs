@@ -0,0 +1,173 @@
+use std::fmt::{self, Display, Write};
+
+/// Builds the value of a `d` attribute out of typed path commands.
+///
+/// Absolute commands end in `_to`, and relative commands end in `_by`.
+///
+/// ```rust
+/// # use tachys::svg::PathData;
+/// let d = PathData::new()
+///     .move_to(10, 10)
+///     .line_to(90, 10)
+///     .vertical_by(80)
+///     .close();
+/// assert_eq!(d.to_string(), "M10 10 L90 10 v80 Z");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PathData(String);
+
+impl PathData {
+    /// Creates empty path data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no commands have been added.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn command(mut self, command: char, args: &[f64]) -> Self {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push(command);
+        for (idx, arg) in args.iter().enumerate() {
+            if idx > 0 {
+                self.0.push(' ');
+            }
+            _ = write!(self.0, "{arg}");
+        }
+        self
+    }
+
+    /// Starts a new subpath at `(x, y)`.
+    pub fn move_to(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.command('M', &[x.into(), y.into()])
+    }
+
+    /// Starts a new subpath, offset from the current point by `(dx, dy)`.
+    pub fn move_by(self, dx: impl Into<f64>, dy: impl Into<f64>) -> Self {
+        self.command('m', &[dx.into(), dy.into()])
+    }
+
+    /// Draws a straight line to `(x, y)`.
+    pub fn line_to(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.command('L', &[x.into(), y.into()])
+    }
+
+    /// Draws a straight line, offset from the current point by `(dx, dy)`.
+    pub fn line_by(self, dx: impl Into<f64>, dy: impl Into<f64>) -> Self {
+        self.command('l', &[dx.into(), dy.into()])
+    }
+
+    /// Draws a horizontal line to `x`.
+    pub fn horizontal_to(self, x: impl Into<f64>) -> Self {
+        self.command('H', &[x.into()])
+    }
+
+    /// Draws a horizontal line of length `dx`.
+    pub fn horizontal_by(self, dx: impl Into<f64>) -> Self {
+        self.command('h', &[dx.into()])
+    }
+
+    /// Draws a vertical line to `y`.
+    pub fn vertical_to(self, y: impl Into<f64>) -> Self {
+        self.command('V', &[y.into()])
+    }
+
+    /// Draws a vertical line of length `dy`.
+    pub fn vertical_by(self, dy: impl Into<f64>) -> Self {
+        self.command('v', &[dy.into()])
+    }
+
+    /// Draws a cubic Bézier curve to `(x, y)`, using `(x1, y1)` and `(x2, y2)` as control
+    /// points.
+    pub fn cubic_to(
+        self,
+        (x1, y1): (f64, f64),
+        (x2, y2): (f64, f64),
+        (x, y): (f64, f64),
+    ) -> Self {
+        self.command('C', &[x1, y1, x2, y2, x, y])
+    }
+
+    /// Draws a cubic Bézier curve to `(x, y)`, reflecting the previous control point and
+    /// using `(x2, y2)` as the second control point.
+    pub fn smooth_cubic_to(
+        self,
+        (x2, y2): (f64, f64),
+        (x, y): (f64, f64),
+    ) -> Self {
+        self.command('S', &[x2, y2, x, y])
+    }
+
+    /// Draws a quadratic Bézier curve to `(x, y)`, using `(x1, y1)` as the control point.
+    pub fn quad_to(self, (x1, y1): (f64, f64), (x, y): (f64, f64)) -> Self {
+        self.command('Q', &[x1, y1, x, y])
+    }
+
+    /// Draws a quadratic Bézier curve to `(x, y)`, reflecting the previous control point.
+    pub fn smooth_quad_to(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.command('T', &[x.into(), y.into()])
+    }
+
+    /// Draws an elliptical arc to `(x, y)` with the radii `(rx, ry)`, rotated by `rotation`
+    /// degrees.
+    pub fn arc_to(
+        self,
+        (rx, ry): (f64, f64),
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        (x, y): (f64, f64),
+    ) -> Self {
+        let flag = |value: bool| if value { 1.0 } else { 0.0 };
+        self.command(
+            'A',
+            &[rx, ry, rotation, flag(large_arc), flag(sweep), x, y],
+        )
+    }
+
+    /// Closes the current subpath with a straight line to its start.
+    pub fn close(mut self) -> Self {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push('Z');
+        self
+    }
+}
+
+impl Display for PathData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<PathData> for String {
+    fn from(value: PathData) -> Self {
+        value.0
+    }
+}
+
+string_attribute_value!(PathData);
+
+#[cfg(test)]
+mod tests {
+    use super::PathData;
+
+    #[test]
+    fn builds_path_commands() {
+        let d = PathData::new()
+            .move_to(0, 0)
+            .cubic_to((10.0, 0.0), (20.0, 10.5), (20.0, 20.0))
+            .arc_to((5.0, 5.0), 0.0, false, true, (30.0, 30.0))
+            .horizontal_by(-30)
+            .close();
+        assert_eq!(
+            d.to_string(),
+            "M0 0 C10 0 20 10.5 20 20 A5 5 0 0 1 30 30 h-30 Z"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::{self, Display, Write};
+
+/// Builds the value of a `transform` attribute out of typed transform functions.
+///
+/// As in SVG, the last transform in the list is applied to the element first.
+///
+/// ```rust
+/// # use tachys::svg::Transform;
+/// let transform = Transform::new().translate(50, 50).rotate(45).scale(2, 2);
+/// assert_eq!(transform.to_string(), "translate(50 50) rotate(45) scale(2 2)");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Transform(String);
+
+impl Transform {
+    /// Creates an empty transform list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no transforms have been added.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn function(mut self, name: &str, args: &[f64]) -> Self {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push_str(name);
+        self.0.push('(');
+        for (idx, arg) in args.iter().enumerate() {
+            if idx > 0 {
+                self.0.push(' ');
+            }
+            _ = write!(self.0, "{arg}");
+        }
+        self.0.push(')');
+        self
+    }
+
+    /// Moves the element by `(x, y)`.
+    pub fn translate(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.function("translate", &[x.into(), y.into()])
+    }
+
+    /// Scales the element by `x` horizontally and `y` vertically.
+    pub fn scale(self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        self.function("scale", &[x.into(), y.into()])
+    }
+
+    /// Rotates the element by `angle` degrees around the origin.
+    pub fn rotate(self, angle: impl Into<f64>) -> Self {
+        self.function("rotate", &[angle.into()])
+    }
+
+    /// Rotates the element by `angle` degrees around `(cx, cy)`.
+    pub fn rotate_around(
+        self,
+        angle: impl Into<f64>,
+        cx: impl Into<f64>,
+        cy: impl Into<f64>,
+    ) -> Self {
+        self.function("rotate", &[angle.into(), cx.into(), cy.into()])
+    }
+
+    /// Skews the element by `angle` degrees along the x axis.
+    pub fn skew_x(self, angle: impl Into<f64>) -> Self {
+        self.function("skewX", &[angle.into()])
+    }
+
+    /// Skews the element by `angle` degrees along the y axis.
+    pub fn skew_y(self, angle: impl Into<f64>) -> Self {
+        self.function("skewY", &[angle.into()])
+    }
+
+    /// Applies the transformation matrix `[a c e] [b d f] [0 0 1]`.
+    pub fn matrix(self, [a, b, c, d, e, f]: [f64; 6]) -> Self {
+        self.function("matrix", &[a, b, c, d, e, f])
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Transform> for String {
+    fn from(value: Transform) -> Self {
+        value.0
+    }
+}
+
+string_attribute_value!(Transform);
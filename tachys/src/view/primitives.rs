@@ -107,7 +107,7 @@ macro_rules! render_primitive {
 
 					let node = cursor.current();
 					let node = crate::renderer::types::Text::cast_from(node.clone())
-						.unwrap_or_else(|| crate::hydration::failed_to_cast_text_node(node));
+						.unwrap_or_else(|| crate::hydration::failed_to_cast_text_node(&self.to_string(), node));
 
 					if !FROM_SERVER {
 						Rndr::set_text(&node, &self.to_string());
@@ -223,7 +223,7 @@ impl<const V: &'static str> RenderHtml for Static<V> {
         let node = cursor.current();
         let node = crate::renderer::types::Text::cast_from(node.clone())
             .unwrap_or_else(|| {
-                crate::hydration::failed_to_cast_text_node(node)
+                crate::hydration::failed_to_cast_text_node(V, node)
             });
 
         position.set(Position::NextChildAfterText);
@@ -95,7 +95,7 @@ impl RenderHtml for &str {
         let node = cursor.current();
         let node = crate::renderer::types::Text::cast_from(node.clone())
             .unwrap_or_else(|| {
-                crate::hydration::failed_to_cast_text_node(node)
+                crate::hydration::failed_to_cast_text_node(self, node)
             });
 
         if !FROM_SERVER {